@@ -10,6 +10,7 @@ const data = [];
 const enums = [];
 const getMethod = [];
 const getPath = [];
+const getTemplate = [];
 const implementsFunctions = [];
 const structs = [];
 const functions = [];
@@ -168,6 +169,8 @@ for (const [key, val] of Object.entries(api.paths)) {
           .join("")
           .replace(/{|}/gim, ""),
       dosc: values.description,
+      template: key,
+      tag: values.tags[0],
       path: key
         .replace("{ref}", "{aref}")
         .replace("{content_reference_id}", "{content_areference_id}"),
@@ -189,7 +192,7 @@ for (const [key, val] of Object.entries(api.paths)) {
 let toWrite = [
   `use serde::{Deserialize, Serialize};\n` + `use serde_json::Value;\n`,
   // `use std::collections::HashMap;\n`,
-  `pub enum Methods { \n    ${Array.from(methods).join(",\n    ")} \n}`,
+  `#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\npub enum Methods { \n    ${Array.from(methods).join(",\n    ")} \n}`,
 ];
 
 for (const key of data) {
@@ -248,6 +251,13 @@ for (const key of data) {
 
   getMethod.push([key.name, upperFirst(key.method)]);
   getPath.push([key.name, key.path, key.vars]);
+  getTemplate.push([
+    key.name,
+    key.template,
+    upperFirst(key.method),
+    key.vars.length,
+    key.tag,
+  ]);
 }
 
 implementsFunctions.push(
@@ -256,6 +266,36 @@ implementsFunctions.push(
       .map((x) => `EndPoints::${x[0]}(..) => Methods::${x[1]}`)
       .join(",\n  ")
   }} }`,
+  `pub const fn name(&self) -> &'static str { match *self { ${
+    getMethod
+      .map((x) => `EndPoints::${x[0]}(..) => "${x[0]}"`)
+      .join(",\n  ")
+  }} }`,
+  `pub const fn template(&self) -> &'static str { match *self { ${
+    getTemplate
+      .map((x) => `EndPoints::${x[0]}(..) => "${x[1]}"`)
+      .join(",\n  ")
+  }} }`,
+  `pub const fn tag(&self) -> &'static str { match *self { ${
+    getTemplate
+      .map((x) => `EndPoints::${x[0]}(..) => "${x[4]}"`)
+      .join(",\n  ")
+  }} }`,
+  `pub const ROUTES: &'static [(&'static str, Methods, &'static str)] = &[${
+    getTemplate
+      .map((x) => `("${x[0]}", Methods::${x[2]}, "${x[1]}")`)
+      .join(",\n  ")
+  }];`,
+  `pub fn from_parts(name: &str, args: Vec<String>) -> Option<EndPoints> { let mut args = args.into_iter(); let endpoint = match name { ${
+    getTemplate
+      .map(
+        (x) =>
+          `"${x[0]}" => EndPoints::${x[0]}(${
+            "args.next()?,".repeat(x[3]).slice(0, -1)
+          })`,
+      )
+      .join(",\n  ")
+  },\n  _ => return None }; if args.next().is_some() { return None; } Some(endpoint) }`,
   `pub fn path(&self) -> String { match self { ${
     getPath
       .map(
@@ -270,13 +310,28 @@ implementsFunctions.push(
       )
       .join(",\n  ")
   }} }`,
+  `pub fn args(&self) -> Vec<&str> { match self { ${
+    getPath
+      .map(
+        (x) =>
+          //@ts-ignore -
+          `EndPoints::${x[0]}(${x[2].join(",")}) => vec![${
+            x[2]
+              //@ts-ignore -
+              .map((x) => `${x}.as_str()`)
+              .join(", ")
+          }]`,
+      )
+      .join(",\n  ")
+  }} }`,
 );
 
 for (const [key, val] of Object.entries(b)) {
   structs.push(`pub enum ${key} { \n${val}\n}`);
 }
 
-toWrite.push(`pub enum EndPoints { \n    ${enums.join(",\n    ")} \n}`);
+toWrite.push(
+  `#[derive(Debug, Clone, PartialEq, Eq, Hash)]\npub enum EndPoints { \n    ${enums.join(",\n    ")} \n}`);
 toWrite.push(`impl EndPoints {  ${implementsFunctions.join("\n")} }`);
 toWrite.push(structs.join("\n"));
 
@@ -0,0 +1,257 @@
+//! The [GitHub App Manifest flow](https://docs.github.com/apps/building-github-apps/creating-github-apps-from-a-manifest/).
+//!
+//! Build an [`AppManifest`], POST it as the `manifest` form field to [`AppManifest::form_url`],
+//! and exchange the `code` GitHub redirects back with for [`AppCredentials`].
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::end_points::EndPoints;
+use crate::error::Error;
+use crate::http::{encode_component, ApiBase, Request, Transport};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    Read,
+    Write,
+    Admin,
+}
+
+/// Who will own the app once it is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestOwner {
+    /// The user submitting the form.
+    User,
+    Organization(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HookAttributes {
+    pub url: String,
+    pub active: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AppManifest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hook_attributes: Option<HookAttributes>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    redirect_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    callback_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    setup_url: Option<String>,
+    public: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    default_events: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    default_permissions: BTreeMap<String, Permission>,
+    request_oauth_on_install: bool,
+    setup_on_update: bool,
+}
+
+impl AppManifest {
+    /// `url` is the homepage of the app, the only field GitHub requires.
+    pub fn new(url: impl Into<String>) -> Self {
+        AppManifest {
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Webhook target. Without one GitHub creates the app with webhooks disabled.
+    pub fn hook(mut self, url: impl Into<String>, active: bool) -> Self {
+        self.hook_attributes = Some(HookAttributes {
+            url: url.into(),
+            active,
+        });
+        self
+    }
+
+    /// Where GitHub sends the user, with `?code=`, once the app is created.
+    pub fn redirect_url(mut self, url: impl Into<String>) -> Self {
+        self.redirect_url = Some(url.into());
+        self
+    }
+
+    pub fn callback_url(mut self, url: impl Into<String>) -> Self {
+        self.callback_urls.push(url.into());
+        self
+    }
+
+    pub fn setup_url(mut self, url: impl Into<String>) -> Self {
+        self.setup_url = Some(url.into());
+        self
+    }
+
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = public;
+        self
+    }
+
+    /// Subscribes to a webhook event such as `issues` or `pull_request`.
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        let event = event.into();
+        if !self.default_events.contains(&event) {
+            self.default_events.push(event);
+        }
+        self
+    }
+
+    /// Requests a permission such as `contents` or `pull_requests`.
+    pub fn permission(mut self, name: impl Into<String>, level: Permission) -> Self {
+        self.default_permissions.insert(name.into(), level);
+        self
+    }
+
+    pub fn request_oauth_on_install(mut self, value: bool) -> Self {
+        self.request_oauth_on_install = value;
+        self
+    }
+
+    pub fn setup_on_update(mut self, value: bool) -> Self {
+        self.setup_on_update = value;
+        self
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("manifest is always serializable")
+    }
+
+    /// The form action the manifest has to be POSTed to. `state` is echoed back on redirect.
+    pub fn form_url(base: &ApiBase, owner: &ManifestOwner, state: Option<&str>) -> String {
+        let web = base.web.trim_end_matches('/');
+        let mut url = match owner {
+            ManifestOwner::User => format!("{}/settings/apps/new", web),
+            ManifestOwner::Organization(org) => {
+                format!(
+                    "{}/organizations/{}/settings/apps/new",
+                    web,
+                    encode_component(org)
+                )
+            }
+        };
+        if let Some(state) = state {
+            url.push_str("?state=");
+            url.push_str(&encode_component(state));
+        }
+        url
+    }
+}
+
+/// What `PostAppManifestscodeConversions` hands back: everything needed to authenticate as the app.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AppCredentials {
+    /// The app id, used as the `iss` claim of app JWTs.
+    pub id: i64,
+    pub slug: String,
+    pub node_id: String,
+    pub name: String,
+    pub html_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Absent when the manifest had no `hook_attributes`.
+    pub webhook_secret: Option<String>,
+    /// PEM encoded RSA private key used to sign app JWTs.
+    pub pem: String,
+}
+
+impl AppCredentials {
+    /// Exchanges the temporary `code` from the redirect. Codes expire after an hour and work once.
+    pub fn from_code<T: Transport>(transport: &T, code: &str) -> Result<Self, Error> {
+        let request = Request::new(EndPoints::PostAppManifestscodeConversions(code.to_string()))
+            .header("Accept", "application/vnd.github.v3+json");
+        transport.execute(&request)?.json()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn form_url_for_a_user_app() {
+        assert_eq!(
+            AppManifest::form_url(&ApiBase::github(), &ManifestOwner::User, None),
+            "https://github.com/settings/apps/new"
+        );
+    }
+
+    #[test]
+    fn form_url_for_an_organization_app() {
+        let owner = ManifestOwner::Organization("acme".to_string());
+        assert_eq!(
+            AppManifest::form_url(&ApiBase::github(), &owner, None),
+            "https://github.com/organizations/acme/settings/apps/new"
+        );
+        // Not a real org name, but it cannot escape its path segment.
+        let owner = ManifestOwner::Organization("acme/../x".to_string());
+        assert_eq!(
+            AppManifest::form_url(&ApiBase::github(), &owner, None),
+            "https://github.com/organizations/acme%2F..%2Fx/settings/apps/new"
+        );
+    }
+
+    #[test]
+    fn form_url_encodes_the_state() {
+        assert_eq!(
+            AppManifest::form_url(&ApiBase::github(), &ManifestOwner::User, Some("a b&c=d/é")),
+            "https://github.com/settings/apps/new?state=a%20b%26c%3Dd%2F%C3%A9"
+        );
+    }
+
+    #[test]
+    fn form_url_on_enterprise_server() {
+        let base = ApiBase::enterprise("ghe.acme.dev/");
+        assert_eq!(
+            AppManifest::form_url(&base, &ManifestOwner::User, Some("s1")),
+            "https://ghe.acme.dev/settings/apps/new?state=s1"
+        );
+        let mut base = ApiBase::github();
+        base.web = "https://github.com/".to_string();
+        assert_eq!(
+            AppManifest::form_url(&base, &ManifestOwner::User, None),
+            "https://github.com/settings/apps/new"
+        );
+    }
+
+    #[test]
+    fn to_json_leaves_out_unset_fields() {
+        let manifest = AppManifest::new("https://acme.dev")
+            .name("Acme Bot")
+            .hook("https://acme.dev/hooks", true)
+            .event("push")
+            .permission("contents", Permission::Write);
+        let json: serde_json::Value = serde_json::from_str(&manifest.to_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "Acme Bot",
+                "url": "https://acme.dev",
+                "hook_attributes": { "url": "https://acme.dev/hooks", "active": true },
+                "public": false,
+                "default_events": ["push"],
+                "default_permissions": { "contents": "write" },
+                "request_oauth_on_install": false,
+                "setup_on_update": false,
+            })
+        );
+    }
+}
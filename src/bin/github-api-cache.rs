@@ -0,0 +1,67 @@
+//! Inspect and prune the on-disk response cache.
+//!
+//! ```text
+//! github-api-cache [--dir DIR] list
+//! github-api-cache [--dir DIR] prune SECONDS
+//! github-api-cache [--dir DIR] clear
+//! ```
+
+use std::process::exit;
+use std::time::{Duration, SystemTime};
+
+use github_api_octocat::cache::DiskStore;
+use github_api_octocat::clock::SystemClock;
+
+fn main() {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let dir = match args.iter().position(|a| a == "--dir") {
+        Some(i) if i + 1 < args.len() => {
+            let dir = args.remove(i + 1);
+            args.remove(i);
+            Some(dir.into())
+        }
+        Some(_) => usage(),
+        None => DiskStore::default_dir(),
+    };
+    let Some(dir) = dir else {
+        eprintln!("cannot find a cache directory, pass --dir");
+        exit(1)
+    };
+    let store = DiskStore::new(dir).unwrap_or_else(|e| fail(e));
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["list"] => {
+            let now = SystemTime::now();
+            for entry in store.entries().unwrap_or_else(|e| fail(e)) {
+                println!(
+                    "{:>6}s {:>9}B {}{}",
+                    entry.age(now).as_secs(),
+                    entry.body.len(),
+                    entry.key,
+                    if entry.is_fresh(now) { " (fresh)" } else { "" }
+                );
+            }
+        }
+        ["prune", secs] => {
+            let secs = secs.parse().unwrap_or_else(|_| usage());
+            let removed = store
+                .prune(Duration::from_secs(secs), &SystemClock)
+                .unwrap_or_else(|e| fail(e));
+            println!("removed {} entries from {}", removed, store.dir().display());
+        }
+        ["clear"] => {
+            let removed = store.clear().unwrap_or_else(|e| fail(e));
+            println!("removed {} entries from {}", removed, store.dir().display());
+        }
+        _ => usage(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: github-api-cache [--dir DIR] list | prune SECONDS | clear");
+    exit(2)
+}
+
+fn fail(e: std::io::Error) -> ! {
+    eprintln!("github-api-cache: {}", e);
+    exit(1)
+}
@@ -0,0 +1,972 @@
+//! Conditional requests: responses that come back `304 Not Modified` do not count
+//! against the rate limit, so polling through [`ConditionalCache`] is nearly free.
+//!
+//! With a [`DiskStore`] and [`CacheOptions::offline`] the same cache replays GETs
+//! when there is no network. The `github-api-cache` binary lists and prunes it.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::clock::{Clock, SystemClock};
+use crate::end_points::Methods;
+use crate::error::Error;
+use crate::http::{encode_query, Headers, Request, Response, Transport};
+use crate::util::fnv1a;
+
+/// A stored response and the validators to revalidate it with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    pub key: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub status: u16,
+    pub headers: Headers,
+    #[serde(skip)]
+    pub body: Vec<u8>,
+    /// Seconds since the unix epoch.
+    pub stored_at: u64,
+    /// `max-age` of `Cache-Control`, in seconds. `no-cache` is stored as 0.
+    #[serde(default)]
+    pub max_age: Option<u64>,
+}
+
+impl CacheEntry {
+    pub fn age(&self, now: SystemTime) -> Duration {
+        now.duration_since(UNIX_EPOCH + Duration::from_secs(self.stored_at))
+            .unwrap_or_default()
+    }
+
+    /// Whether `Cache-Control` allows serving the entry without asking GitHub.
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        self.max_age
+            .is_some_and(|max_age| self.age(now) < Duration::from_secs(max_age))
+    }
+
+    pub fn response(&self) -> Response {
+        Response {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+pub trait CacheStore {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+    fn put(&self, entry: CacheEntry) -> io::Result<()>;
+    fn remove(&self, key: &str) -> io::Result<()>;
+}
+
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        MemoryStore::default()
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    fn put(&self, entry: CacheEntry) -> io::Result<()> {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(entry.key.clone(), entry);
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+/// One `<hash>.json` metadata file and one `<hash>.body` file per entry, each
+/// written to a temporary file first and renamed into place.
+#[derive(Debug, Clone)]
+pub struct DiskStore {
+    dir: PathBuf,
+}
+
+impl DiskStore {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(DiskStore { dir })
+    }
+
+    /// `$XDG_CACHE_HOME/github-api-octocat`, falling back to `~/.cache/github-api-octocat`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+        Some(base.join("github-api-octocat"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Every readable entry, bodies included.
+    pub fn entries(&self) -> io::Result<Vec<CacheEntry>> {
+        let mut entries = Vec::new();
+        for file in fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().is_some_and(|e| e == "json") {
+                if let Ok(meta) = fs::read(&path) {
+                    if let Ok(key) = serde_json::from_slice::<CacheEntry>(&meta).map(|e| e.key) {
+                        entries.extend(self.get(&key));
+                    }
+                }
+            }
+        }
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(entries)
+    }
+
+    /// Removes entries stored more than `older_than` before `clock`'s now and
+    /// returns how many.
+    pub fn prune(&self, older_than: Duration, clock: &impl Clock) -> io::Result<usize> {
+        let now = clock.now();
+        let mut removed = 0;
+        for entry in self.entries()? {
+            if entry.age(now) > older_than {
+                self.remove(&entry.key)?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Deletes the store's own files: entries, bodies whose metadata is gone
+    /// and files left by interrupted writes. Anything else in the directory,
+    /// subdirectories included, is left alone. Returns how many entries there
+    /// were.
+    pub fn clear(&self) -> io::Result<usize> {
+        let mut removed = 0;
+        for file in fs::read_dir(&self.dir)? {
+            let file = file?;
+            let name = file.file_name();
+            let Some(name) = name.to_str().filter(|name| is_store_file(name)) else {
+                continue;
+            };
+            if !file.file_type()?.is_file() {
+                continue;
+            }
+            match fs::remove_file(file.path()) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+            if name.ends_with(".json") {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    fn paths(&self, key: &str) -> (PathBuf, PathBuf) {
+        let name = format!("{:016x}", fnv1a(key.as_bytes()));
+        (
+            self.dir.join(format!("{}.json", name)),
+            self.dir.join(format!("{}.body", name)),
+        )
+    }
+}
+
+impl CacheStore for DiskStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let (meta, body) = self.paths(key);
+        let mut entry: CacheEntry = serde_json::from_slice(&fs::read(meta).ok()?).ok()?;
+        // Guards against hash collisions.
+        if entry.key != key {
+            return None;
+        }
+        entry.body = fs::read(body).ok()?;
+        Some(entry)
+    }
+
+    fn put(&self, entry: CacheEntry) -> io::Result<()> {
+        let (meta, body) = self.paths(&entry.key);
+        write_atomic(&body, &entry.body)?;
+        write_atomic(&meta, &serde_json::to_vec(&entry)?)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        let (meta, body) = self.paths(key);
+        for path in [meta, body] {
+            match fs::remove_file(path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `<hash>.json`, `<hash>.body`, or the `.<hash>.<ext>.<pid>-<n>.tmp` of a
+/// [`write_atomic`] into one of them.
+fn is_store_file(name: &str) -> bool {
+    let stored = |name: &str| {
+        name.strip_suffix(".json")
+            .or_else(|| name.strip_suffix(".body"))
+            .is_some_and(|hash| hash.len() == 16 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+    };
+    match name.strip_prefix('.').and_then(|n| n.strip_suffix(".tmp")) {
+        Some(temp) => temp.rsplit_once('.').is_some_and(|(target, writer)| {
+            stored(target)
+                && writer
+                    .split_once('-')
+                    .is_some_and(|(pid, n)| is_digits(pid) && is_digits(n))
+        }),
+        None => stored(name),
+    }
+}
+
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Writes `contents` next to `path` and renames it into place, so readers and
+/// other processes never see a partly written file.
+fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    static WRITES: AtomicU64 = AtomicU64::new(0);
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let temp = path.with_file_name(format!(
+        ".{}.{}-{}.tmp",
+        name,
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&temp, contents)?;
+    fs::rename(&temp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp);
+    })
+}
+
+/// The cache key of a request: method, path, query, `Accept` and a hash of the
+/// `Authorization` header, so different tokens never see each other's responses.
+pub fn cache_key(request: &Request) -> String {
+    let mut key = format!("{} {}", request.method().as_str(), request.endpoint.path());
+    if !request.query.is_empty() {
+        key.push('?');
+        key.push_str(&encode_query(&request.query));
+    }
+    if let Some(accept) = request.headers.get("accept") {
+        key.push_str(" accept=");
+        key.push_str(accept);
+    }
+    if let Some(auth) = request.headers.get("authorization") {
+        key.push_str(&format!(" auth={:016x}", fnv1a(auth.as_bytes())));
+    }
+    key
+}
+
+/// What to do when GitHub cannot be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Offline {
+    /// Surface the transport error.
+    #[default]
+    Off,
+    /// Serve the stored response if GitHub cannot be reached or answers with a
+    /// 5xx, unless it is older than `max_stale`.
+    StaleIfError { max_stale: Option<Duration> },
+    /// Never touch the network; uncached GETs and every other method fail.
+    Only,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CacheOptions {
+    /// Serve entries still within their `Cache-Control: max-age` without revalidating.
+    pub honour_max_age: bool,
+    pub offline: Offline,
+}
+
+/// A [`Transport`] that revalidates GETs with `If-None-Match` / `If-Modified-Since`
+/// and serves the stored body when GitHub answers `304`.
+///
+/// Responses served from the store carry an `X-Cache` header of `HIT`,
+/// `REVALIDATED` or `STALE`.
+#[derive(Debug)]
+pub struct ConditionalCache<T, S = MemoryStore, C = SystemClock> {
+    inner: T,
+    store: S,
+    options: CacheOptions,
+    clock: C,
+}
+
+impl<T> ConditionalCache<T> {
+    pub fn new(inner: T) -> Self {
+        ConditionalCache::with_store(inner, MemoryStore::new())
+    }
+}
+
+impl<T, S: CacheStore> ConditionalCache<T, S> {
+    pub fn with_store(inner: T, store: S) -> Self {
+        ConditionalCache::with_options(inner, store, CacheOptions::default())
+    }
+
+    pub fn with_options(inner: T, store: S, options: CacheOptions) -> Self {
+        ConditionalCache::with_clock(inner, store, options, SystemClock)
+    }
+}
+
+impl<T, S: CacheStore, C: Clock> ConditionalCache<T, S, C> {
+    /// Takes the time entries are stored at and aged by from `clock`.
+    pub fn with_clock(inner: T, store: S, options: CacheOptions, clock: C) -> Self {
+        ConditionalCache {
+            inner,
+            store,
+            options,
+            clock,
+        }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+impl<T: Transport, S: CacheStore, C: Clock> Transport for ConditionalCache<T, S, C> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        if request.method() != Methods::Get {
+            if self.options.offline == Offline::Only {
+                return Err(not_sent(request));
+            }
+            return self.inner.send(request);
+        }
+        let key = cache_key(request);
+        let cached = self.store.get(&key);
+        let now = self.clock.now();
+        match (&cached, self.options.offline) {
+            (Some(entry), Offline::Only) => return Ok(served(entry, "STALE")),
+            (None, Offline::Only) => {
+                return Err(Error::transport(format!("offline and not cached: {}", key)))
+            }
+            (Some(entry), _) if self.options.honour_max_age && entry.is_fresh(now) => {
+                return Ok(served(entry, "HIT"))
+            }
+            _ => {}
+        }
+        let conditional = match &cached {
+            Some(entry) => {
+                let mut request = request.clone();
+                if let Some(etag) = &entry.etag {
+                    request.headers.insert("If-None-Match", etag.as_str());
+                }
+                if let Some(modified) = &entry.last_modified {
+                    request
+                        .headers
+                        .insert("If-Modified-Since", modified.as_str());
+                }
+                Some(request)
+            }
+            None => None,
+        };
+        let outcome = self.inner.send(conditional.as_ref().unwrap_or(request));
+        let failed = match &outcome {
+            Ok(response) => response.status >= 500,
+            Err(e) => matches!(e, Error::Transport(_)),
+        };
+        if let (true, Some(entry), Offline::StaleIfError { max_stale }) =
+            (failed, &cached, self.options.offline)
+        {
+            if max_stale.is_none_or(|max| entry.age(now) <= max) {
+                return Ok(served(entry, "STALE"));
+            }
+        }
+        let response = outcome?;
+        match cached {
+            Some(mut entry) if response.status == 304 => {
+                for (name, value) in response.headers.iter() {
+                    entry.headers.insert(name, value);
+                }
+                entry.stored_at = unix_secs(now);
+                entry.max_age = max_age(&response.headers).or(entry.max_age);
+                // A cache that cannot be written is just a slower cache.
+                let _ = self.store.put(entry.clone());
+                Ok(served(&entry, "REVALIDATED"))
+            }
+            _ => {
+                if response.status == 200 {
+                    if let Some(entry) = entry_for(key, &response, now) {
+                        let _ = self.store.put(entry);
+                    }
+                }
+                Ok(response)
+            }
+        }
+    }
+
+    /// Streamed bodies go straight through; only [`Transport::send`] is cached.
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        if self.options.offline == Offline::Only {
+            return Err(not_sent(request));
+        }
+        self.inner.send_body(request, body, len)
+    }
+}
+
+/// For calls that would have to reach GitHub while [`Offline::Only`].
+fn not_sent(request: &Request) -> Error {
+    Error::transport(format!(
+        "offline, not sent: {} {}",
+        request.method().as_str(),
+        request.endpoint.path()
+    ))
+}
+
+fn served(entry: &CacheEntry, how: &str) -> Response {
+    let mut response = entry.response();
+    response.headers.insert("X-Cache", how);
+    response
+}
+
+fn entry_for(key: String, response: &Response, now: SystemTime) -> Option<CacheEntry> {
+    let cache_control = response.headers.get("cache-control").unwrap_or("");
+    if directives(cache_control).any(|d| d == "no-store") {
+        return None;
+    }
+    Some(CacheEntry {
+        key,
+        etag: response.headers.get("etag").map(str::to_string),
+        last_modified: response.headers.get("last-modified").map(str::to_string),
+        status: response.status,
+        headers: response.headers.clone(),
+        body: response.body.clone(),
+        stored_at: unix_secs(now),
+        max_age: max_age(&response.headers),
+    })
+}
+
+fn directives(cache_control: &str) -> impl Iterator<Item = &str> {
+    cache_control.split(',').map(str::trim)
+}
+
+fn max_age(headers: &Headers) -> Option<u64> {
+    let cache_control = headers.get("cache-control")?;
+    if directives(cache_control).any(|d| d == "no-cache") {
+        return Some(0);
+    }
+    directives(cache_control).find_map(|d| d.strip_prefix("max-age=")?.parse().ok())
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::clock::FakeClock;
+    use crate::end_points::EndPoints;
+    use crate::fake::{json, FakeGitHub};
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn clock() -> FakeClock {
+        FakeClock::new(UNIX_EPOCH + Duration::from_secs(NOW))
+    }
+
+    /// Answers `GetReposownerrepo` with `status`, `ETag: "v1"` and the
+    /// `Cache-Control` given, or with 304 when revalidated with that ETag.
+    fn serve(github: &FakeGitHub, status: u16, cache_control: &'static str) {
+        github.on(EndPoints::GetReposownerrepo, move |request| {
+            if request.headers.get("if-none-match") == Some("\"v1\"") {
+                let mut response = json(304, &json!(null));
+                response.body.clear();
+                response.headers.insert("X-RateLimit-Remaining", "4999");
+                return response;
+            }
+            let mut response = json(status, &json!({ "name": "hello" }));
+            response.headers.insert("ETag", "\"v1\"");
+            response
+                .headers
+                .insert("Last-Modified", "Tue, 14 Nov 2023 22:13:20 GMT");
+            if !cache_control.is_empty() {
+                response.headers.insert("Cache-Control", cache_control);
+            }
+            response
+        });
+    }
+
+    fn offline(github: &FakeGitHub) -> ConditionalCache<&FakeGitHub> {
+        let options = CacheOptions {
+            offline: Offline::Only,
+            ..CacheOptions::default()
+        };
+        ConditionalCache::with_options(github, MemoryStore::new(), options)
+    }
+
+    fn repo() -> Request {
+        Request::new(EndPoints::GetReposownerrepo(
+            "octocat".to_string(),
+            "hello".to_string(),
+        ))
+    }
+
+    /// A fresh directory under the system temp dir, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!(
+                "github-api-octocat-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn entry(key: &str, body: &[u8]) -> CacheEntry {
+        CacheEntry {
+            key: key.to_string(),
+            etag: Some("\"v1\"".to_string()),
+            last_modified: None,
+            status: 200,
+            headers: Headers::new(),
+            body: body.to_vec(),
+            stored_at: unix_secs(SystemTime::now()),
+            max_age: None,
+        }
+    }
+
+    fn files(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|f| f.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn revalidates_with_the_stored_validators_and_serves_the_body_on_304() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "");
+        let clock = clock();
+        let cache = ConditionalCache::with_clock(
+            &github,
+            MemoryStore::new(),
+            CacheOptions::default(),
+            clock.clone(),
+        );
+
+        let first = cache.send(&repo()).unwrap();
+        assert_eq!(first.status, 200);
+        assert_eq!(first.headers.get("x-cache"), None);
+        let stored = cache.store().get(&cache_key(&repo())).unwrap();
+        assert_eq!(stored.stored_at, NOW);
+
+        clock.advance(Duration::from_secs(90));
+        let second = cache.send(&repo()).unwrap();
+        assert_eq!(second.status, 200);
+        assert_eq!(second.body, first.body);
+        assert_eq!(second.headers.get("x-cache"), Some("REVALIDATED"));
+        // Headers of the 304 are merged into the stored ones.
+        assert_eq!(second.headers.get("x-ratelimit-remaining"), Some("4999"));
+        assert_eq!(second.headers.get("etag"), Some("\"v1\""));
+
+        let calls = github.calls(EndPoints::GetReposownerrepo);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].headers.get("if-none-match"), None);
+        assert_eq!(calls[1].headers.get("if-none-match"), Some("\"v1\""));
+        assert_eq!(
+            calls[1].headers.get("if-modified-since"),
+            Some("Tue, 14 Nov 2023 22:13:20 GMT")
+        );
+        let stored = cache.store().get(&cache_key(&repo())).unwrap();
+        assert_eq!(stored.stored_at, NOW + 90);
+        assert_eq!(stored.age(clock.now()), Duration::ZERO);
+    }
+
+    #[test]
+    fn only_200s_are_stored() {
+        for status in [201, 203, 404, 500] {
+            let github = FakeGitHub::start().unwrap();
+            serve(&github, status, "");
+            let cache = ConditionalCache::with_clock(
+                &github,
+                MemoryStore::new(),
+                CacheOptions::default(),
+                clock(),
+            );
+
+            assert_eq!(cache.send(&repo()).unwrap().status, status);
+            assert!(cache.store().get(&cache_key(&repo())).is_none());
+            cache.send(&repo()).unwrap();
+            let calls = github.calls(EndPoints::GetReposownerrepo);
+            assert_eq!(calls[1].headers.get("if-none-match"), None, "{}", status);
+        }
+    }
+
+    #[test]
+    fn other_methods_are_neither_cached_nor_revalidated() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::PatchReposownerrepo, |_| {
+            let mut response = json(200, &json!({ "name": "hello" }));
+            response.headers.insert("ETag", "\"v1\"");
+            response
+        });
+        let cache = ConditionalCache::with_clock(
+            &github,
+            MemoryStore::new(),
+            CacheOptions::default(),
+            clock(),
+        );
+        let patch = Request::new(EndPoints::PatchReposownerrepo(
+            "octocat".to_string(),
+            "hello".to_string(),
+        ));
+
+        cache.send(&patch).unwrap();
+        cache.send(&patch).unwrap();
+        let calls = github.calls(EndPoints::PatchReposownerrepo);
+        assert_eq!(calls[1].headers.get("if-none-match"), None);
+        assert!(cache.store().get(&cache_key(&patch)).is_none());
+    }
+
+    #[test]
+    fn prune_ages_entries_by_the_clock() {
+        let temp = TempDir::new("cache-prune");
+        let store = DiskStore::new(&temp.0).unwrap();
+        let clock = clock();
+        let mut old = entry("GET /old", b"old");
+        old.stored_at = NOW - 3600;
+        store.put(old).unwrap();
+        let mut new = entry("GET /new", b"new");
+        new.stored_at = NOW - 60;
+        store.put(new).unwrap();
+
+        assert_eq!(store.prune(Duration::from_secs(600), &clock).unwrap(), 1);
+        assert!(store.get("GET /old").is_none());
+        assert!(store.get("GET /new").is_some());
+        clock.advance(Duration::from_secs(600));
+        assert_eq!(store.prune(Duration::from_secs(600), &clock).unwrap(), 1);
+        assert!(store.entries().unwrap().is_empty());
+    }
+
+    fn with_options<'a>(
+        github: &'a FakeGitHub,
+        options: CacheOptions,
+        clock: &FakeClock,
+    ) -> ConditionalCache<&'a FakeGitHub, MemoryStore, FakeClock> {
+        ConditionalCache::with_clock(github, MemoryStore::new(), options, clock.clone())
+    }
+
+    fn honouring_max_age() -> CacheOptions {
+        CacheOptions {
+            honour_max_age: true,
+            ..CacheOptions::default()
+        }
+    }
+
+    fn stale_if_error(max_stale: Option<Duration>) -> CacheOptions {
+        CacheOptions {
+            offline: Offline::StaleIfError { max_stale },
+            ..CacheOptions::default()
+        }
+    }
+
+    /// Fails every call with a transport error while `down`.
+    struct Unreachable<'a> {
+        github: &'a FakeGitHub,
+        down: std::sync::atomic::AtomicBool,
+    }
+
+    impl Transport for Unreachable<'_> {
+        fn send(&self, request: &Request) -> Result<Response, Error> {
+            if self.down.load(Ordering::SeqCst) {
+                return Err(Error::transport("connection refused"));
+            }
+            self.github.send(request)
+        }
+    }
+
+    #[test]
+    fn max_age_serves_fresh_entries_without_a_request() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "private, max-age=60, s-maxage=60");
+        let clock = clock();
+        let cache = with_options(&github, honouring_max_age(), &clock);
+
+        cache.send(&repo()).unwrap();
+        clock.advance(Duration::from_secs(59));
+        let hit = cache.send(&repo()).unwrap();
+        assert_eq!(hit.headers.get("x-cache"), Some("HIT"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 1);
+
+        clock.advance(Duration::from_secs(1));
+        let revalidated = cache.send(&repo()).unwrap();
+        assert_eq!(revalidated.headers.get("x-cache"), Some("REVALIDATED"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 2);
+        // The 304 restarted the entry's max-age.
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(
+            cache.send(&repo()).unwrap().headers.get("x-cache"),
+            Some("HIT")
+        );
+    }
+
+    #[test]
+    fn max_age_is_ignored_unless_honoured() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "max-age=60");
+        let clock = clock();
+        let cache = with_options(&github, CacheOptions::default(), &clock);
+
+        cache.send(&repo()).unwrap();
+        let second = cache.send(&repo()).unwrap();
+        assert_eq!(second.headers.get("x-cache"), Some("REVALIDATED"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 2);
+    }
+
+    #[test]
+    fn no_store_responses_are_not_stored() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "no-store");
+        let clock = clock();
+        let cache = with_options(&github, honouring_max_age(), &clock);
+
+        cache.send(&repo()).unwrap();
+        assert!(cache.store().get(&cache_key(&repo())).is_none());
+        cache.send(&repo()).unwrap();
+        let calls = github.calls(EndPoints::GetReposownerrepo);
+        assert_eq!(calls[1].headers.get("if-none-match"), None);
+    }
+
+    #[test]
+    fn no_cache_responses_are_always_revalidated() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "no-cache, max-age=60");
+        let clock = clock();
+        let cache = with_options(&github, honouring_max_age(), &clock);
+
+        cache.send(&repo()).unwrap();
+        let stored = cache.store().get(&cache_key(&repo())).unwrap();
+        assert_eq!(stored.max_age, Some(0));
+        let second = cache.send(&repo()).unwrap();
+        assert_eq!(second.headers.get("x-cache"), Some("REVALIDATED"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 2);
+    }
+
+    #[test]
+    fn stale_if_error_serves_the_entry_on_a_5xx_within_max_stale() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "");
+        let clock = clock();
+        let cache = with_options(
+            &github,
+            stale_if_error(Some(Duration::from_secs(300))),
+            &clock,
+        );
+        cache.send(&repo()).unwrap();
+        github.on(EndPoints::GetReposownerrepo, |_| {
+            json(502, &json!({ "message": "Server Error" }))
+        });
+
+        clock.advance(Duration::from_secs(300));
+        let stale = cache.send(&repo()).unwrap();
+        assert_eq!(stale.status, 200);
+        assert_eq!(stale.headers.get("x-cache"), Some("STALE"));
+
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(cache.send(&repo()).unwrap().status, 502);
+    }
+
+    #[test]
+    fn stale_if_error_serves_the_entry_when_github_is_unreachable() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "");
+        let clock = clock();
+        let transport = Unreachable {
+            github: &github,
+            down: false.into(),
+        };
+        let cache = ConditionalCache::with_clock(
+            &transport,
+            MemoryStore::new(),
+            stale_if_error(None),
+            clock.clone(),
+        );
+        cache.send(&repo()).unwrap();
+        transport.down.store(true, Ordering::SeqCst);
+
+        // Without max_stale, however old.
+        clock.advance(Duration::from_secs(30 * 86_400));
+        let stale = cache.send(&repo()).unwrap();
+        assert_eq!(stale.headers.get("x-cache"), Some("STALE"));
+
+        let other = Request::new(EndPoints::GetReposownerrepo(
+            "octocat".to_string(),
+            "other".to_string(),
+        ));
+        assert!(matches!(cache.send(&other), Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn errors_go_through_when_offline_is_off() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "");
+        let clock = clock();
+        let transport = Unreachable {
+            github: &github,
+            down: false.into(),
+        };
+        let cache = ConditionalCache::with_clock(
+            &transport,
+            MemoryStore::new(),
+            CacheOptions::default(),
+            clock.clone(),
+        );
+        cache.send(&repo()).unwrap();
+        github.on(EndPoints::GetReposownerrepo, |_| {
+            json(503, &json!({ "message": "Service Unavailable" }))
+        });
+        assert_eq!(cache.send(&repo()).unwrap().status, 503);
+        transport.down.store(true, Ordering::SeqCst);
+        assert!(matches!(cache.send(&repo()), Err(Error::Transport(_))));
+    }
+
+    #[test]
+    fn different_tokens_do_not_share_entries() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, 200, "max-age=60");
+        let clock = clock();
+        let cache = with_options(&github, honouring_max_age(), &clock);
+        let alice = repo().header("Authorization", "token ghp_alice");
+        let bob = repo().header("Authorization", "token ghp_bob");
+
+        assert_ne!(cache_key(&alice), cache_key(&bob));
+        assert!(!cache_key(&alice).contains("ghp_alice"));
+        cache.send(&alice).unwrap();
+        let response = cache.send(&bob).unwrap();
+        assert_eq!(response.headers.get("x-cache"), None);
+        let calls = github.calls(EndPoints::GetReposownerrepo);
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[1].headers.get("if-none-match"), None);
+        assert_eq!(
+            cache.send(&alice).unwrap().headers.get("x-cache"),
+            Some("HIT")
+        );
+    }
+
+    #[test]
+    fn disk_store_writes_through_temporary_files() {
+        let temp = TempDir::new("cache-put");
+        let store = DiskStore::new(&temp.0).unwrap();
+
+        store.put(entry("GET /a", b"first")).unwrap();
+        store.put(entry("GET /a", b"second")).unwrap();
+
+        assert_eq!(store.get("GET /a").unwrap().body, b"second");
+        let names = files(&temp.0);
+        assert_eq!(names.len(), 2, "{:?}", names);
+        assert!(names.iter().all(|n| !n.ends_with(".tmp")), "{:?}", names);
+    }
+
+    #[test]
+    fn clear_removes_only_the_stores_own_files() {
+        let temp = TempDir::new("cache-clear");
+        let store = DiskStore::new(&temp.0).unwrap();
+        store.put(entry("GET /a", b"a")).unwrap();
+        store.put(entry("GET /b", b"b")).unwrap();
+        // A body without metadata, an interrupted write and unreadable metadata.
+        fs::write(temp.0.join("00000000000000ff.body"), b"orphan").unwrap();
+        fs::write(temp.0.join(".0000000000000001.body.1-0.tmp"), b"partial").unwrap();
+        fs::write(temp.0.join("0000000000000002.json"), b"not json").unwrap();
+        // What `--dir` pointed at by mistake would hold.
+        fs::write(temp.0.join("notes.json"), b"{}").unwrap();
+        fs::write(temp.0.join("00000000000000ff.txt"), b"keep").unwrap();
+        fs::write(temp.0.join(".notes.json.1-0.tmp"), b"keep").unwrap();
+        fs::create_dir(temp.0.join("0000000000000003.json")).unwrap();
+        fs::create_dir(temp.0.join("src")).unwrap();
+        fs::write(temp.0.join("src").join("0000000000000004.json"), b"{}").unwrap();
+
+        assert_eq!(store.clear().unwrap(), 3);
+        assert_eq!(
+            files(&temp.0),
+            [
+                ".notes.json.1-0.tmp",
+                "0000000000000003.json",
+                "00000000000000ff.txt",
+                "notes.json",
+                "src",
+            ]
+        );
+        assert_eq!(files(&temp.0.join("src")), ["0000000000000004.json"]);
+        assert!(store.get("GET /a").is_none());
+    }
+
+    #[test]
+    fn offline_only_sends_nothing() {
+        let github = FakeGitHub::start().unwrap();
+        github.expect(EndPoints::PostReposownerrepoIssues).times(0);
+        github.expect(EndPoints::GetReposownerrepo).times(0);
+        let cache = offline(&github);
+
+        let post = Request::new(EndPoints::PostReposownerrepoIssues(
+            "octocat".to_string(),
+            "hello".to_string(),
+        ));
+        let error = cache.send(&post).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("offline, not sent: POST /repos/octocat/hello/issues"));
+        let error = cache.send_body(&post, &mut &b"{}"[..], 2).unwrap_err();
+        assert!(matches!(error, Error::Transport(_)));
+        assert!(cache.send(&repo()).is_err());
+    }
+
+    #[test]
+    fn offline_only_serves_what_is_cached() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepo, |_| {
+            let mut response = json(200, &json!({ "name": "hello" }));
+            response.headers.insert("ETag", "\"v1\"");
+            response
+        });
+        let online = ConditionalCache::new(&github);
+        online.send(&repo()).unwrap();
+        let store = MemoryStore::new();
+        store
+            .put(online.store().get(&cache_key(&repo())).unwrap())
+            .unwrap();
+
+        let options = CacheOptions {
+            offline: Offline::Only,
+            ..CacheOptions::default()
+        };
+        let cache = ConditionalCache::with_options(&github, store, options);
+        let response = cache.send(&repo()).unwrap();
+        assert_eq!(response.headers.get("x-cache"), Some("STALE"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 1);
+    }
+}
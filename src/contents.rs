@@ -0,0 +1,808 @@
+//! Reading and writing single files with the Contents API.
+//!
+//! The helpers take care of the base64 encoding, of the blob `sha` GitHub
+//! needs to update or delete a file, and of telling files, directories,
+//! symlinks and submodules apart. Files over 1 MB come back from the Contents
+//! API without their content; [`read_file`] then fetches them from the Git
+//! blobs API, which serves up to 100 MB.
+//!
+//! ```no_run
+//! use github_api_octocat::contents::{self, CommitOptions};
+//! use github_api_octocat::http::Transport;
+//! # fn bump(transport: impl Transport) -> Result<(), github_api_octocat::contents::ContentsError> {
+//! let file = contents::read_file(&transport, "octocat", "hello-world", "VERSION", None)?;
+//! let next = format!("{}.1\n", file.text().unwrap_or_default().trim());
+//! let written = contents::write_file(
+//!     &transport,
+//!     "octocat",
+//!     "hello-world",
+//!     "VERSION",
+//!     next.as_bytes(),
+//!     &CommitOptions::new("Bump version").branch("main"),
+//! )?;
+//! println!("{:?}", written.commit);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha1::{Digest, Sha1};
+
+use crate::end_points::EndPoints;
+use crate::error::{Error, GitHubError};
+use crate::http::{encode_path, Request, Transport};
+
+/// The largest file the Contents API returns inline.
+pub const MAX_INLINE_SIZE: u64 = 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    File,
+    Dir,
+    Symlink,
+    Submodule,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Kind::File => "file",
+            Kind::Dir => "directory",
+            Kind::Symlink => "symlink",
+            Kind::Submodule => "submodule",
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum ContentsError {
+    /// The call failed, e.g. with a 404 or a 409 for a stale `sha`.
+    Api(Error),
+    /// `path` is not what the helper works on, e.g. [`read_file`] on a directory.
+    WrongKind {
+        path: String,
+        expected: Kind,
+        found: Kind,
+    },
+    /// GitHub sent content that is not valid base64.
+    Base64(base64::DecodeError),
+}
+
+impl fmt::Display for ContentsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContentsError::Api(e) => e.fmt(f),
+            ContentsError::WrongKind {
+                path,
+                expected,
+                found,
+            } => {
+                let path = if path.is_empty() { "/" } else { path };
+                write!(f, "{} is a {}, not a {}", path, found, expected)
+            }
+            ContentsError::Base64(e) => write!(f, "invalid base64 content: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ContentsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ContentsError::Api(e) => Some(e),
+            ContentsError::WrongKind { .. } => None,
+            ContentsError::Base64(e) => Some(e),
+        }
+    }
+}
+
+impl From<Error> for ContentsError {
+    fn from(e: Error) -> Self {
+        ContentsError::Api(e)
+    }
+}
+
+impl From<serde_json::Error> for ContentsError {
+    fn from(e: serde_json::Error) -> Self {
+        ContentsError::Api(e.into())
+    }
+}
+
+impl From<base64::DecodeError> for ContentsError {
+    fn from(e: base64::DecodeError) -> Self {
+        ContentsError::Base64(e)
+    }
+}
+
+/// One item of a [`list_dir`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    pub name: String,
+    pub path: String,
+    /// The blob, tree or submodule commit sha.
+    pub sha: String,
+    #[serde(default)]
+    pub size: u64,
+    pub html_url: Option<String>,
+    /// `None` for directories and submodules.
+    pub download_url: Option<String>,
+}
+
+/// A file read with [`read_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    pub name: String,
+    pub path: String,
+    /// The blob sha, needed to update or delete the file.
+    pub sha: String,
+    pub size: u64,
+    pub content: Vec<u8>,
+    pub html_url: Option<String>,
+}
+
+impl File {
+    /// The content, if it is UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.content).ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Person {
+    pub name: String,
+    pub email: String,
+}
+
+/// The commit [`write_file`] and [`delete_file`] make.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitOptions {
+    message: String,
+    branch: Option<String>,
+    committer: Option<Person>,
+    author: Option<Person>,
+}
+
+impl CommitOptions {
+    pub fn new(message: impl Into<String>) -> Self {
+        CommitOptions {
+            message: message.into(),
+            branch: None,
+            committer: None,
+            author: None,
+        }
+    }
+
+    /// The branch to commit to, and to look the current file up on. The
+    /// default branch otherwise.
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    /// Defaults to the authenticated user, or to the app's bot.
+    pub fn committer(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.committer = Some(Person {
+            name: name.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    /// Defaults to the committer.
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(Person {
+            name: name.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    fn body(&self) -> Map<String, Value> {
+        let mut body = Map::new();
+        body.insert("message".to_string(), json!(self.message));
+        if let Some(branch) = &self.branch {
+            body.insert("branch".to_string(), json!(branch));
+        }
+        if let Some(committer) = &self.committer {
+            body.insert("committer".to_string(), json!(committer));
+        }
+        if let Some(author) = &self.author {
+            body.insert("author".to_string(), json!(author));
+        }
+        body
+    }
+}
+
+/// What [`write_file`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Written {
+    pub path: String,
+    /// The blob sha of the new content.
+    pub sha: String,
+    /// Whether the file did not exist before.
+    pub created: bool,
+    /// The commit made, `None` when the file already had this content.
+    pub commit: Option<String>,
+}
+
+/// The raw Contents API answer for a single path.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Contents {
+    Dir(Vec<Entry>),
+    Item(Item),
+}
+
+#[derive(Deserialize)]
+struct Item {
+    #[serde(flatten)]
+    entry: Entry,
+    /// `none` when the file is too large to inline.
+    encoding: Option<String>,
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Blob {
+    content: String,
+    encoding: String,
+}
+
+#[derive(Deserialize)]
+struct CommitResponse {
+    content: Option<Entry>,
+    commit: CommitRef,
+}
+
+#[derive(Deserialize)]
+struct CommitRef {
+    sha: String,
+}
+
+/// Reads the file at `path`, at `git_ref` or on the default branch.
+pub fn read_file<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    git_ref: Option<&str>,
+) -> Result<File, ContentsError> {
+    let item = match get(transport, owner, repo, path, git_ref)? {
+        Contents::Item(item) if item.entry.kind == Kind::File => item,
+        contents => return Err(wrong_kind(path, Kind::File, &contents)),
+    };
+    let content = match (item.encoding.as_deref(), item.content) {
+        (Some("base64"), Some(content)) if !content.is_empty() || item.entry.size == 0 => {
+            decode(&content)?
+        }
+        _ => read_blob(transport, owner, repo, &item.entry.sha)?,
+    };
+    Ok(File {
+        name: item.entry.name,
+        path: item.entry.path,
+        sha: item.entry.sha,
+        size: item.entry.size,
+        content,
+        html_url: item.entry.html_url,
+    })
+}
+
+/// Lists the directory at `path`, `""` for the root. The Contents API lists up
+/// to 1,000 entries; use the Git trees API for larger directories.
+pub fn list_dir<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    git_ref: Option<&str>,
+) -> Result<Vec<Entry>, ContentsError> {
+    match get(transport, owner, repo, path, git_ref)? {
+        Contents::Dir(entries) => Ok(entries),
+        contents => Err(wrong_kind(path, Kind::Dir, &contents)),
+    }
+}
+
+/// Creates or replaces the file at `path` with `content`, looking up the sha
+/// of the current file first. Nothing is committed when the content is
+/// unchanged.
+///
+/// The Contents API cannot commit files over 100 MB, nor several files at once.
+pub fn write_file<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    content: &[u8],
+    options: &CommitOptions,
+) -> Result<Written, ContentsError> {
+    let sha = blob_sha(content);
+    let current = current_sha(transport, owner, repo, path, options)?;
+    if current.as_deref() == Some(sha.as_str()) {
+        return Ok(Written {
+            path: path.to_string(),
+            sha,
+            created: false,
+            commit: None,
+        });
+    }
+    let mut body = options.body();
+    body.insert("content".to_string(), json!(STANDARD.encode(content)));
+    if let Some(current) = &current {
+        body.insert("sha".to_string(), json!(current));
+    }
+    let request = Request::new(EndPoints::PutReposownerrepoContentspath(
+        owner.to_string(),
+        repo.to_string(),
+        encode_path(path),
+    ))
+    .json(&body)?;
+    let response: CommitResponse = transport.execute(&request)?.json()?;
+    Ok(Written {
+        path: response
+            .content
+            .map_or_else(|| path.to_string(), |entry| entry.path),
+        sha,
+        created: current.is_none(),
+        commit: Some(response.commit.sha),
+    })
+}
+
+/// Deletes the file at `path` and returns the commit sha, `None` when there
+/// was no such file.
+pub fn delete_file<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    options: &CommitOptions,
+) -> Result<Option<String>, ContentsError> {
+    let Some(current) = current_sha(transport, owner, repo, path, options)? else {
+        return Ok(None);
+    };
+    let mut body = options.body();
+    body.insert("sha".to_string(), json!(current));
+    let request = Request::new(EndPoints::DeleteReposownerrepoContentspath(
+        owner.to_string(),
+        repo.to_string(),
+        encode_path(path),
+    ))
+    .json(&body)?;
+    let response: CommitResponse = transport.execute(&request)?.json()?;
+    Ok(Some(response.commit.sha))
+}
+
+/// The sha git gives `content` as a blob, what GitHub reports for files.
+///
+/// ```
+/// use github_api_octocat::contents::blob_sha;
+///
+/// assert_eq!(blob_sha(b"hello world\n"), "3b18e512dba79e4c8300dd08aeb37f8e728b8dad");
+/// ```
+pub fn blob_sha(content: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(format!("blob {}\0", content.len()));
+    hasher.update(content);
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn get<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    git_ref: Option<&str>,
+) -> Result<Contents, ContentsError> {
+    let mut request = Request::new(EndPoints::GetReposownerrepoContentspath(
+        owner.to_string(),
+        repo.to_string(),
+        encode_path(path),
+    ));
+    if let Some(git_ref) = git_ref {
+        request = request.query("ref", git_ref);
+    }
+    Ok(transport.execute(&request)?.json()?)
+}
+
+/// The sha of the file being replaced, `None` when there is none yet.
+fn current_sha<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    path: &str,
+    options: &CommitOptions,
+) -> Result<Option<String>, ContentsError> {
+    match get(transport, owner, repo, path, options.branch.as_deref()) {
+        Ok(Contents::Item(item)) if item.entry.kind == Kind::File => Ok(Some(item.entry.sha)),
+        Ok(contents) => Err(wrong_kind(path, Kind::File, &contents)),
+        Err(ContentsError::Api(Error::GitHub(e))) if matches!(*e, GitHubError::NotFound(_)) => {
+            Ok(None)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn read_blob<T: Transport>(
+    transport: &T,
+    owner: &str,
+    repo: &str,
+    sha: &str,
+) -> Result<Vec<u8>, ContentsError> {
+    let request = Request::new(EndPoints::GetReposownerrepoGitBlobsfileSha(
+        owner.to_string(),
+        repo.to_string(),
+        sha.to_string(),
+    ));
+    let blob: Blob = transport.execute(&request)?.json()?;
+    match blob.encoding.as_str() {
+        "base64" => decode(&blob.content),
+        _ => Ok(blob.content.into_bytes()),
+    }
+}
+
+/// GitHub wraps base64 content at 60 columns.
+fn decode(content: &str) -> Result<Vec<u8>, ContentsError> {
+    let content: String = content.split_whitespace().collect();
+    Ok(STANDARD.decode(content)?)
+}
+
+fn wrong_kind(path: &str, expected: Kind, contents: &Contents) -> ContentsError {
+    ContentsError::WrongKind {
+        path: path.to_string(),
+        expected,
+        found: match contents {
+            Contents::Dir(_) => Kind::Dir,
+            Contents::Item(item) => item.entry.kind,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake::{json, FakeGitHub};
+    use crate::http::Response;
+
+    fn file(path: &str, size: u64, encoding: &str, content: &str) -> Value {
+        json!({
+            "type": "file",
+            "name": path.rsplit('/').next().unwrap(),
+            "path": path,
+            "sha": "3b18e512dba79e4c8300dd08aeb37f8e728b8dad",
+            "size": size,
+            "html_url": format!("https://github.com/acme/api/blob/main/{}", path),
+            "download_url": format!("https://raw.githubusercontent.com/acme/api/main/{}", path),
+            "encoding": encoding,
+            "content": content,
+        })
+    }
+
+    fn not_found() -> Response {
+        json(404, &json!({ "message": "Not Found" }))
+    }
+
+    #[test]
+    fn read_file_decodes_inline_content() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| {
+            // Wrapped the way GitHub wraps it.
+            json(200, &file("VERSION", 12, "base64", "aGVsbG8g\nd29ybGQK\n"))
+        });
+
+        let file = read_file(&github, "acme", "api", "VERSION", Some("v1.0")).unwrap();
+        assert_eq!(file.text(), Some("hello world\n"));
+        assert_eq!(file.sha, "3b18e512dba79e4c8300dd08aeb37f8e728b8dad");
+        let calls = github.calls(EndPoints::GetReposownerrepoContentspath);
+        assert_eq!(calls[0].query, [("ref".to_string(), "v1.0".to_string())]);
+        assert!(github
+            .calls(EndPoints::GetReposownerrepoGitBlobsfileSha)
+            .is_empty());
+    }
+
+    #[test]
+    fn read_file_fetches_large_files_from_the_blobs_api() {
+        let github = FakeGitHub::start().unwrap();
+        github
+            .on(EndPoints::GetReposownerrepoContentspath, |_| {
+                json(
+                    200,
+                    &file("assets/big.bin", 2 * MAX_INLINE_SIZE, "none", ""),
+                )
+            })
+            .on(EndPoints::GetReposownerrepoGitBlobsfileSha, |_| {
+                json(
+                    200,
+                    &json!({
+                        "sha": "3b18e512dba79e4c8300dd08aeb37f8e728b8dad",
+                        "size": 12,
+                        "encoding": "base64",
+                        "content": "aGVsbG8gd29ybGQK\n",
+                    }),
+                )
+            });
+
+        let file = read_file(&github, "acme", "api", "assets/big.bin", None).unwrap();
+        assert_eq!(file.content, b"hello world\n");
+        let blobs = github.calls(EndPoints::GetReposownerrepoGitBlobsfileSha);
+        assert_eq!(
+            blobs[0].endpoint.path(),
+            "/repos/acme/api/git/blobs/3b18e512dba79e4c8300dd08aeb37f8e728b8dad"
+        );
+    }
+
+    #[test]
+    fn read_file_falls_back_when_content_is_missing_or_empty() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoGitBlobsfileSha, |_| {
+            json(
+                200,
+                &json!({ "content": "hello world\n", "encoding": "utf-8" }),
+            )
+        });
+        let mut without_encoding = file("a.txt", 12, "base64", "");
+        without_encoding.as_object_mut().unwrap().remove("encoding");
+        without_encoding.as_object_mut().unwrap().remove("content");
+        for listed in [file("a.txt", 12, "base64", ""), without_encoding] {
+            github.on(EndPoints::GetReposownerrepoContentspath, move |_| {
+                json(200, &listed)
+            });
+            let file = read_file(&github, "acme", "api", "a.txt", None).unwrap();
+            assert_eq!(file.text(), Some("hello world\n"));
+        }
+        assert_eq!(
+            github
+                .calls(EndPoints::GetReposownerrepoGitBlobsfileSha)
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn read_file_keeps_an_empty_file_inline() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| {
+            json(200, &file(".keep", 0, "base64", ""))
+        });
+
+        let file = read_file(&github, "acme", "api", ".keep", None).unwrap();
+        assert!(file.content.is_empty());
+        assert!(github
+            .calls(EndPoints::GetReposownerrepoGitBlobsfileSha)
+            .is_empty());
+    }
+
+    #[test]
+    fn read_file_refuses_a_directory() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| {
+            json(200, &json!([]))
+        });
+
+        let error = read_file(&github, "acme", "api", "", None).unwrap_err();
+        assert!(matches!(
+            error,
+            ContentsError::WrongKind {
+                expected: Kind::File,
+                found: Kind::Dir,
+                ..
+            }
+        ));
+        assert_eq!(error.to_string(), "/ is a directory, not a file");
+    }
+
+    #[test]
+    fn read_file_refuses_symlinks_and_submodules() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |request| {
+            let item = match request.endpoint.args()[2] {
+                "docs" => json!({
+                    "type": "symlink",
+                    "target": "site/docs",
+                    "size": 9,
+                    "name": "docs",
+                    "path": "docs",
+                    "sha": "8e1d1d5d6b3c2e0b3b6e2cb9f2a3d1e5b3b0f1c2",
+                    "html_url": "https://github.com/acme/api/blob/main/docs",
+                    "download_url": "https://raw.githubusercontent.com/acme/api/main/docs",
+                }),
+                _ => json!({
+                    "type": "submodule",
+                    "submodule_git_url": "git://github.com/acme/proto.git",
+                    "size": 0,
+                    "name": "proto",
+                    "path": "vendor/proto",
+                    "sha": "fa0b4f4a2b8e4bd9a4c6b5a3d8e3c9b1f2a6d7e8",
+                    "html_url": "https://github.com/acme/proto/tree/fa0b4f4a2b8e4bd9a4c6b5a3d8e3c9b1f2a6d7e8",
+                    "download_url": null,
+                }),
+            };
+            json(200, &item)
+        });
+
+        let error = read_file(&github, "acme", "api", "docs", None).unwrap_err();
+        assert!(matches!(
+            error,
+            ContentsError::WrongKind {
+                expected: Kind::File,
+                found: Kind::Symlink,
+                ..
+            }
+        ));
+        assert_eq!(error.to_string(), "docs is a symlink, not a file");
+        let error = read_file(&github, "acme", "api", "vendor/proto", None).unwrap_err();
+        assert!(matches!(
+            error,
+            ContentsError::WrongKind {
+                expected: Kind::File,
+                found: Kind::Submodule,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn list_dir_lists_a_directory() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |request| {
+            if request.endpoint.args()[2] == "README.md" {
+                return json(200, &file("README.md", 6, "base64", "aGVsbG8K"));
+            }
+            json(
+                200,
+                &json!([
+                    {
+                        "type": "file",
+                        "name": "lib.rs",
+                        "path": "src/lib.rs",
+                        "sha": "3b18e512dba79e4c8300dd08aeb37f8e728b8dad",
+                        "size": 1204,
+                        "url": "https://api.github.com/repos/acme/api/contents/src/lib.rs?ref=main",
+                        "html_url": "https://github.com/acme/api/blob/main/src/lib.rs",
+                        "git_url": "https://api.github.com/repos/acme/api/git/blobs/3b18e512dba79e4c8300dd08aeb37f8e728b8dad",
+                        "download_url": "https://raw.githubusercontent.com/acme/api/main/src/lib.rs",
+                        "_links": {}
+                    },
+                    {
+                        "type": "dir",
+                        "name": "bin",
+                        "path": "src/bin",
+                        "sha": "a84d88e7554fc1fa21bcbc4efae3c782a70d2b9d",
+                        "size": 0,
+                        "html_url": "https://github.com/acme/api/tree/main/src/bin",
+                        "download_url": null
+                    }
+                ]),
+            )
+        });
+
+        let entries = list_dir(&github, "acme", "api", "src", Some("main")).unwrap();
+        let listed: Vec<(Kind, &str, u64)> = entries
+            .iter()
+            .map(|e| (e.kind, e.path.as_str(), e.size))
+            .collect();
+        assert_eq!(
+            listed,
+            [(Kind::File, "src/lib.rs", 1204), (Kind::Dir, "src/bin", 0)]
+        );
+        assert_eq!(entries[1].download_url, None);
+        let calls = github.calls(EndPoints::GetReposownerrepoContentspath);
+        assert_eq!(calls[0].query, [("ref".to_string(), "main".to_string())]);
+
+        let error = list_dir(&github, "acme", "api", "README.md", None).unwrap_err();
+        assert!(matches!(
+            error,
+            ContentsError::WrongKind {
+                expected: Kind::Dir,
+                found: Kind::File,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn write_file_creates_a_file_that_is_not_there() {
+        let github = FakeGitHub::start().unwrap();
+        github
+            .on(EndPoints::GetReposownerrepoContentspath, |_| not_found())
+            .on(EndPoints::PutReposownerrepoContentspath, |_| {
+                json(
+                    201,
+                    &json!({ "content": null, "commit": { "sha": "c-new" } }),
+                )
+            });
+
+        let options = CommitOptions::new("Add notes").branch("docs");
+        let written = write_file(
+            &github,
+            "acme",
+            "api",
+            "docs/release notes.md",
+            b"hello world\n",
+            &options,
+        )
+        .unwrap();
+        assert!(written.created);
+        assert_eq!(written.commit.as_deref(), Some("c-new"));
+        assert_eq!(written.sha, "3b18e512dba79e4c8300dd08aeb37f8e728b8dad");
+
+        let lookup = &github.calls(EndPoints::GetReposownerrepoContentspath)[0];
+        assert_eq!(lookup.query, [("ref".to_string(), "docs".to_string())]);
+        let put = &github.calls(EndPoints::PutReposownerrepoContentspath)[0];
+        assert_eq!(
+            put.endpoint.path(),
+            "/repos/acme/api/contents/docs/release%20notes.md"
+        );
+        let body: Value = serde_json::from_slice(put.body.as_deref().unwrap()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "message": "Add notes",
+                "branch": "docs",
+                "content": "aGVsbG8gd29ybGQK",
+            })
+        );
+    }
+
+    #[test]
+    fn write_file_skips_unchanged_content() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| {
+            json(200, &file("VERSION", 12, "base64", "aGVsbG8gd29ybGQK"))
+        });
+
+        let written = write_file(
+            &github,
+            "acme",
+            "api",
+            "VERSION",
+            b"hello world\n",
+            &CommitOptions::new("Bump"),
+        )
+        .unwrap();
+        assert_eq!(written.commit, None);
+        assert!(!written.created);
+        assert!(github
+            .calls(EndPoints::PutReposownerrepoContentspath)
+            .is_empty());
+    }
+
+    #[test]
+    fn delete_file_of_a_missing_file_does_nothing() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| not_found());
+
+        let deleted = delete_file(
+            &github,
+            "acme",
+            "api",
+            "gone.txt",
+            &CommitOptions::new("Tidy"),
+        )
+        .unwrap();
+        assert_eq!(deleted, None);
+    }
+
+    #[test]
+    fn errors_other_than_404_are_not_taken_for_a_missing_file() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoContentspath, |_| {
+            json(
+                403,
+                &json!({ "message": "Resource not accessible by integration" }),
+            )
+        });
+
+        let result = delete_file(&github, "acme", "api", "a.txt", &CommitOptions::new("Tidy"));
+        assert!(matches!(result, Err(ContentsError::Api(Error::GitHub(_)))));
+    }
+}
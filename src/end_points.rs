@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Methods {
     Get,
     Post,
@@ -6,6 +7,7 @@ pub enum Methods {
     Put,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EndPoints {
     /// * tags meta
     /// * get `/`
@@ -9225,6 +9227,4195 @@ impl EndPoints {
   EndPoints::GetUsersusernameSubscriptions(..) => Methods::Get,
   EndPoints::GetZen(..) => Methods::Get}
     }
+    pub const fn name(&self) -> &'static str {
+        match *self { EndPoints::Get(..) => "Get",
+  EndPoints::GetApp(..) => "GetApp",
+  EndPoints::PostAppManifestscodeConversions(..) => "PostAppManifestscodeConversions",
+  EndPoints::GetAppHookConfig(..) => "GetAppHookConfig",
+  EndPoints::PatchAppHookConfig(..) => "PatchAppHookConfig",
+  EndPoints::GetAppHookDeliveries(..) => "GetAppHookDeliveries",
+  EndPoints::GetAppHookDeliveriesdeliveryId(..) => "GetAppHookDeliveriesdeliveryId",
+  EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(..) => "PostAppHookDeliveriesdeliveryIdAttempts",
+  EndPoints::GetAppInstallations(..) => "GetAppInstallations",
+  EndPoints::GetAppInstallationsinstallationId(..) => "GetAppInstallationsinstallationId",
+  EndPoints::DeleteAppInstallationsinstallationId(..) => "DeleteAppInstallationsinstallationId",
+  EndPoints::PostAppInstallationsinstallationIdAccessTokens(..) => "PostAppInstallationsinstallationIdAccessTokens",
+  EndPoints::PutAppInstallationsinstallationIdSuspended(..) => "PutAppInstallationsinstallationIdSuspended",
+  EndPoints::DeleteAppInstallationsinstallationIdSuspended(..) => "DeleteAppInstallationsinstallationIdSuspended",
+  EndPoints::GetApplicationsGrants(..) => "GetApplicationsGrants",
+  EndPoints::GetApplicationsGrantsgrantId(..) => "GetApplicationsGrantsgrantId",
+  EndPoints::DeleteApplicationsGrantsgrantId(..) => "DeleteApplicationsGrantsgrantId",
+  EndPoints::DeleteApplicationsclientIdGrant(..) => "DeleteApplicationsclientIdGrant",
+  EndPoints::PostApplicationsclientIdToken(..) => "PostApplicationsclientIdToken",
+  EndPoints::PatchApplicationsclientIdToken(..) => "PatchApplicationsclientIdToken",
+  EndPoints::DeleteApplicationsclientIdToken(..) => "DeleteApplicationsclientIdToken",
+  EndPoints::PostApplicationsclientIdTokenScoped(..) => "PostApplicationsclientIdTokenScoped",
+  EndPoints::GetAppsappSlug(..) => "GetAppsappSlug",
+  EndPoints::GetAuthorizations(..) => "GetAuthorizations",
+  EndPoints::PostAuthorizations(..) => "PostAuthorizations",
+  EndPoints::PutAuthorizationsClientsclientId(..) => "PutAuthorizationsClientsclientId",
+  EndPoints::PutAuthorizationsClientsclientIdfingerprint(..) => "PutAuthorizationsClientsclientIdfingerprint",
+  EndPoints::GetAuthorizationsauthorizationId(..) => "GetAuthorizationsauthorizationId",
+  EndPoints::PatchAuthorizationsauthorizationId(..) => "PatchAuthorizationsauthorizationId",
+  EndPoints::DeleteAuthorizationsauthorizationId(..) => "DeleteAuthorizationsauthorizationId",
+  EndPoints::GetCodesOfConduct(..) => "GetCodesOfConduct",
+  EndPoints::GetCodesOfConductkey(..) => "GetCodesOfConductkey",
+  EndPoints::GetEmojis(..) => "GetEmojis",
+  EndPoints::GetEnterprisesenterpriseActionsPermissions(..) => "GetEnterprisesenterpriseActionsPermissions",
+  EndPoints::PutEnterprisesenterpriseActionsPermissions(..) => "PutEnterprisesenterpriseActionsPermissions",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsOrganizations(..) => "GetEnterprisesenterpriseActionsPermissionsOrganizations",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizations(..) => "PutEnterprisesenterpriseActionsPermissionsOrganizations",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId",
+  EndPoints::DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "GetEnterprisesenterpriseActionsPermissionsSelectedActions",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "PutEnterprisesenterpriseActionsPermissionsSelectedActions",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroups(..) => "GetEnterprisesenterpriseActionsRunnerGroups",
+  EndPoints::PostEnterprisesenterpriseActionsRunnerGroups(..) => "PostEnterprisesenterpriseActionsRunnerGroups",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId",
+  EndPoints::PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId",
+  EndPoints::GetEnterprisesenterpriseActionsRunners(..) => "GetEnterprisesenterpriseActionsRunners",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersDownloads(..) => "GetEnterprisesenterpriseActionsRunnersDownloads",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(..) => "PostEnterprisesenterpriseActionsRunnersRegistrationToken",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(..) => "PostEnterprisesenterpriseActionsRunnersRemoveToken",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerId(..) => "GetEnterprisesenterpriseActionsRunnersrunnerId",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerId(..) => "DeleteEnterprisesenterpriseActionsRunnersrunnerId",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "GetEnterprisesenterpriseActionsRunnersrunnerIdLabels",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "PostEnterprisesenterpriseActionsRunnersrunnerIdLabels",
+  EndPoints::PutEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "PutEnterprisesenterpriseActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname(..) => "DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname",
+  EndPoints::GetEnterprisesenterpriseAuditLog(..) => "GetEnterprisesenterpriseAuditLog",
+  EndPoints::GetEnterprisesenterpriseSecretScanningAlerts(..) => "GetEnterprisesenterpriseSecretScanningAlerts",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingActions(..) => "GetEnterprisesenterpriseSettingsBillingActions",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingAdvancedSecurity(..) => "GetEnterprisesenterpriseSettingsBillingAdvancedSecurity",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingPackages(..) => "GetEnterprisesenterpriseSettingsBillingPackages",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingSharedStorage(..) => "GetEnterprisesenterpriseSettingsBillingSharedStorage",
+  EndPoints::GetEvents(..) => "GetEvents",
+  EndPoints::GetFeeds(..) => "GetFeeds",
+  EndPoints::GetGists(..) => "GetGists",
+  EndPoints::PostGists(..) => "PostGists",
+  EndPoints::GetGistsPublic(..) => "GetGistsPublic",
+  EndPoints::GetGistsStarred(..) => "GetGistsStarred",
+  EndPoints::GetGistsgistId(..) => "GetGistsgistId",
+  EndPoints::PatchGistsgistId(..) => "PatchGistsgistId",
+  EndPoints::DeleteGistsgistId(..) => "DeleteGistsgistId",
+  EndPoints::GetGistsgistIdComments(..) => "GetGistsgistIdComments",
+  EndPoints::PostGistsgistIdComments(..) => "PostGistsgistIdComments",
+  EndPoints::GetGistsgistIdCommentscommentId(..) => "GetGistsgistIdCommentscommentId",
+  EndPoints::PatchGistsgistIdCommentscommentId(..) => "PatchGistsgistIdCommentscommentId",
+  EndPoints::DeleteGistsgistIdCommentscommentId(..) => "DeleteGistsgistIdCommentscommentId",
+  EndPoints::GetGistsgistIdCommits(..) => "GetGistsgistIdCommits",
+  EndPoints::GetGistsgistIdForks(..) => "GetGistsgistIdForks",
+  EndPoints::PostGistsgistIdForks(..) => "PostGistsgistIdForks",
+  EndPoints::GetGistsgistIdStar(..) => "GetGistsgistIdStar",
+  EndPoints::PutGistsgistIdStar(..) => "PutGistsgistIdStar",
+  EndPoints::DeleteGistsgistIdStar(..) => "DeleteGistsgistIdStar",
+  EndPoints::GetGistsgistIdsha(..) => "GetGistsgistIdsha",
+  EndPoints::GetGitignoreTemplates(..) => "GetGitignoreTemplates",
+  EndPoints::GetGitignoreTemplatesname(..) => "GetGitignoreTemplatesname",
+  EndPoints::GetInstallationRepositories(..) => "GetInstallationRepositories",
+  EndPoints::DeleteInstallationToken(..) => "DeleteInstallationToken",
+  EndPoints::GetIssues(..) => "GetIssues",
+  EndPoints::GetLicenses(..) => "GetLicenses",
+  EndPoints::GetLicenseslicense(..) => "GetLicenseslicense",
+  EndPoints::PostMarkdown(..) => "PostMarkdown",
+  EndPoints::PostMarkdownRaw(..) => "PostMarkdownRaw",
+  EndPoints::GetMarketplaceListingAccountsaccountId(..) => "GetMarketplaceListingAccountsaccountId",
+  EndPoints::GetMarketplaceListingPlans(..) => "GetMarketplaceListingPlans",
+  EndPoints::GetMarketplaceListingPlansplanIdAccounts(..) => "GetMarketplaceListingPlansplanIdAccounts",
+  EndPoints::GetMarketplaceListingStubbedAccountsaccountId(..) => "GetMarketplaceListingStubbedAccountsaccountId",
+  EndPoints::GetMarketplaceListingStubbedPlans(..) => "GetMarketplaceListingStubbedPlans",
+  EndPoints::GetMarketplaceListingStubbedPlansplanIdAccounts(..) => "GetMarketplaceListingStubbedPlansplanIdAccounts",
+  EndPoints::GetMeta(..) => "GetMeta",
+  EndPoints::GetNetworksownerrepoEvents(..) => "GetNetworksownerrepoEvents",
+  EndPoints::GetNotifications(..) => "GetNotifications",
+  EndPoints::PutNotifications(..) => "PutNotifications",
+  EndPoints::GetNotificationsThreadsthreadId(..) => "GetNotificationsThreadsthreadId",
+  EndPoints::PatchNotificationsThreadsthreadId(..) => "PatchNotificationsThreadsthreadId",
+  EndPoints::GetNotificationsThreadsthreadIdSubscription(..) => "GetNotificationsThreadsthreadIdSubscription",
+  EndPoints::PutNotificationsThreadsthreadIdSubscription(..) => "PutNotificationsThreadsthreadIdSubscription",
+  EndPoints::DeleteNotificationsThreadsthreadIdSubscription(..) => "DeleteNotificationsThreadsthreadIdSubscription",
+  EndPoints::GetOctocat(..) => "GetOctocat",
+  EndPoints::GetOrganizations(..) => "GetOrganizations",
+  EndPoints::GetOrganizationsorganizationIdCustomRoles(..) => "GetOrganizationsorganizationIdCustomRoles",
+  EndPoints::GetOrganizationsorgTeamteamSlugExternalGroups(..) => "GetOrganizationsorgTeamteamSlugExternalGroups",
+  EndPoints::GetOrgsorg(..) => "GetOrgsorg",
+  EndPoints::PatchOrgsorg(..) => "PatchOrgsorg",
+  EndPoints::GetOrgsorgActionsPermissions(..) => "GetOrgsorgActionsPermissions",
+  EndPoints::PutOrgsorgActionsPermissions(..) => "PutOrgsorgActionsPermissions",
+  EndPoints::GetOrgsorgActionsPermissionsRepositories(..) => "GetOrgsorgActionsPermissionsRepositories",
+  EndPoints::PutOrgsorgActionsPermissionsRepositories(..) => "PutOrgsorgActionsPermissionsRepositories",
+  EndPoints::PutOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "PutOrgsorgActionsPermissionsRepositoriesrepositoryId",
+  EndPoints::DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId",
+  EndPoints::GetOrgsorgActionsPermissionsSelectedActions(..) => "GetOrgsorgActionsPermissionsSelectedActions",
+  EndPoints::PutOrgsorgActionsPermissionsSelectedActions(..) => "PutOrgsorgActionsPermissionsSelectedActions",
+  EndPoints::GetOrgsorgActionsPermissionsWorkflow(..) => "GetOrgsorgActionsPermissionsWorkflow",
+  EndPoints::PutOrgsorgActionsPermissionsWorkflow(..) => "PutOrgsorgActionsPermissionsWorkflow",
+  EndPoints::GetOrgsorgActionsRunnerGroups(..) => "GetOrgsorgActionsRunnerGroups",
+  EndPoints::PostOrgsorgActionsRunnerGroups(..) => "PostOrgsorgActionsRunnerGroups",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "GetOrgsorgActionsRunnerGroupsrunnerGroupId",
+  EndPoints::PatchOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "PatchOrgsorgActionsRunnerGroupsrunnerGroupId",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "DeleteOrgsorgActionsRunnerGroupsrunnerGroupId",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId",
+  EndPoints::GetOrgsorgActionsRunners(..) => "GetOrgsorgActionsRunners",
+  EndPoints::GetOrgsorgActionsRunnersDownloads(..) => "GetOrgsorgActionsRunnersDownloads",
+  EndPoints::PostOrgsorgActionsRunnersRegistrationToken(..) => "PostOrgsorgActionsRunnersRegistrationToken",
+  EndPoints::PostOrgsorgActionsRunnersRemoveToken(..) => "PostOrgsorgActionsRunnersRemoveToken",
+  EndPoints::GetOrgsorgActionsRunnersrunnerId(..) => "GetOrgsorgActionsRunnersrunnerId",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerId(..) => "DeleteOrgsorgActionsRunnersrunnerId",
+  EndPoints::GetOrgsorgActionsRunnersrunnerIdLabels(..) => "GetOrgsorgActionsRunnersrunnerIdLabels",
+  EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(..) => "PostOrgsorgActionsRunnersrunnerIdLabels",
+  EndPoints::PutOrgsorgActionsRunnersrunnerIdLabels(..) => "PutOrgsorgActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabels(..) => "DeleteOrgsorgActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabelsname(..) => "DeleteOrgsorgActionsRunnersrunnerIdLabelsname",
+  EndPoints::GetOrgsorgActionsSecrets(..) => "GetOrgsorgActionsSecrets",
+  EndPoints::GetOrgsorgActionsSecretsPublicKey(..) => "GetOrgsorgActionsSecretsPublicKey",
+  EndPoints::GetOrgsorgActionsSecretssecretName(..) => "GetOrgsorgActionsSecretssecretName",
+  EndPoints::PutOrgsorgActionsSecretssecretName(..) => "PutOrgsorgActionsSecretssecretName",
+  EndPoints::DeleteOrgsorgActionsSecretssecretName(..) => "DeleteOrgsorgActionsSecretssecretName",
+  EndPoints::GetOrgsorgActionsSecretssecretNameRepositories(..) => "GetOrgsorgActionsSecretssecretNameRepositories",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositories(..) => "PutOrgsorgActionsSecretssecretNameRepositories",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::GetOrgsorgAuditLog(..) => "GetOrgsorgAuditLog",
+  EndPoints::GetOrgsorgBlocks(..) => "GetOrgsorgBlocks",
+  EndPoints::GetOrgsorgBlocksusername(..) => "GetOrgsorgBlocksusername",
+  EndPoints::PutOrgsorgBlocksusername(..) => "PutOrgsorgBlocksusername",
+  EndPoints::DeleteOrgsorgBlocksusername(..) => "DeleteOrgsorgBlocksusername",
+  EndPoints::GetOrgsorgCodeScanningAlerts(..) => "GetOrgsorgCodeScanningAlerts",
+  EndPoints::GetOrgsorgCredentialAuthorizations(..) => "GetOrgsorgCredentialAuthorizations",
+  EndPoints::DeleteOrgsorgCredentialAuthorizationscredentialId(..) => "DeleteOrgsorgCredentialAuthorizationscredentialId",
+  EndPoints::GetOrgsorgDependabotSecrets(..) => "GetOrgsorgDependabotSecrets",
+  EndPoints::GetOrgsorgDependabotSecretsPublicKey(..) => "GetOrgsorgDependabotSecretsPublicKey",
+  EndPoints::GetOrgsorgDependabotSecretssecretName(..) => "GetOrgsorgDependabotSecretssecretName",
+  EndPoints::PutOrgsorgDependabotSecretssecretName(..) => "PutOrgsorgDependabotSecretssecretName",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretName(..) => "DeleteOrgsorgDependabotSecretssecretName",
+  EndPoints::GetOrgsorgDependabotSecretssecretNameRepositories(..) => "GetOrgsorgDependabotSecretssecretNameRepositories",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositories(..) => "PutOrgsorgDependabotSecretssecretNameRepositories",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::GetOrgsorgEvents(..) => "GetOrgsorgEvents",
+  EndPoints::GetOrgsorgExternalGroupgroupId(..) => "GetOrgsorgExternalGroupgroupId",
+  EndPoints::GetOrgsorgExternalGroups(..) => "GetOrgsorgExternalGroups",
+  EndPoints::GetOrgsorgFailedInvitations(..) => "GetOrgsorgFailedInvitations",
+  EndPoints::GetOrgsorgHooks(..) => "GetOrgsorgHooks",
+  EndPoints::PostOrgsorgHooks(..) => "PostOrgsorgHooks",
+  EndPoints::GetOrgsorgHookshookId(..) => "GetOrgsorgHookshookId",
+  EndPoints::PatchOrgsorgHookshookId(..) => "PatchOrgsorgHookshookId",
+  EndPoints::DeleteOrgsorgHookshookId(..) => "DeleteOrgsorgHookshookId",
+  EndPoints::GetOrgsorgHookshookIdConfig(..) => "GetOrgsorgHookshookIdConfig",
+  EndPoints::PatchOrgsorgHookshookIdConfig(..) => "PatchOrgsorgHookshookIdConfig",
+  EndPoints::GetOrgsorgHookshookIdDeliveries(..) => "GetOrgsorgHookshookIdDeliveries",
+  EndPoints::GetOrgsorgHookshookIdDeliveriesdeliveryId(..) => "GetOrgsorgHookshookIdDeliveriesdeliveryId",
+  EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(..) => "PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts",
+  EndPoints::PostOrgsorgHookshookIdPings(..) => "PostOrgsorgHookshookIdPings",
+  EndPoints::GetOrgsorgInstallation(..) => "GetOrgsorgInstallation",
+  EndPoints::GetOrgsorgInstallations(..) => "GetOrgsorgInstallations",
+  EndPoints::GetOrgsorgInteractionLimits(..) => "GetOrgsorgInteractionLimits",
+  EndPoints::PutOrgsorgInteractionLimits(..) => "PutOrgsorgInteractionLimits",
+  EndPoints::DeleteOrgsorgInteractionLimits(..) => "DeleteOrgsorgInteractionLimits",
+  EndPoints::GetOrgsorgInvitations(..) => "GetOrgsorgInvitations",
+  EndPoints::PostOrgsorgInvitations(..) => "PostOrgsorgInvitations",
+  EndPoints::DeleteOrgsorgInvitationsinvitationId(..) => "DeleteOrgsorgInvitationsinvitationId",
+  EndPoints::GetOrgsorgInvitationsinvitationIdTeams(..) => "GetOrgsorgInvitationsinvitationIdTeams",
+  EndPoints::GetOrgsorgIssues(..) => "GetOrgsorgIssues",
+  EndPoints::GetOrgsorgMembers(..) => "GetOrgsorgMembers",
+  EndPoints::GetOrgsorgMembersusername(..) => "GetOrgsorgMembersusername",
+  EndPoints::DeleteOrgsorgMembersusername(..) => "DeleteOrgsorgMembersusername",
+  EndPoints::GetOrgsorgMembershipsusername(..) => "GetOrgsorgMembershipsusername",
+  EndPoints::PutOrgsorgMembershipsusername(..) => "PutOrgsorgMembershipsusername",
+  EndPoints::DeleteOrgsorgMembershipsusername(..) => "DeleteOrgsorgMembershipsusername",
+  EndPoints::GetOrgsorgMigrations(..) => "GetOrgsorgMigrations",
+  EndPoints::PostOrgsorgMigrations(..) => "PostOrgsorgMigrations",
+  EndPoints::GetOrgsorgMigrationsmigrationId(..) => "GetOrgsorgMigrationsmigrationId",
+  EndPoints::GetOrgsorgMigrationsmigrationIdArchive(..) => "GetOrgsorgMigrationsmigrationIdArchive",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdArchive(..) => "DeleteOrgsorgMigrationsmigrationIdArchive",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock(..) => "DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock",
+  EndPoints::GetOrgsorgMigrationsmigrationIdRepositories(..) => "GetOrgsorgMigrationsmigrationIdRepositories",
+  EndPoints::GetOrgsorgOutsideCollaborators(..) => "GetOrgsorgOutsideCollaborators",
+  EndPoints::PutOrgsorgOutsideCollaboratorsusername(..) => "PutOrgsorgOutsideCollaboratorsusername",
+  EndPoints::DeleteOrgsorgOutsideCollaboratorsusername(..) => "DeleteOrgsorgOutsideCollaboratorsusername",
+  EndPoints::GetOrgsorgPackages(..) => "GetOrgsorgPackages",
+  EndPoints::GetOrgsorgPackagespackageTypepackageName(..) => "GetOrgsorgPackagespackageTypepackageName",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageName(..) => "DeleteOrgsorgPackagespackageTypepackageName",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameRestore(..) => "PostOrgsorgPackagespackageTypepackageNameRestore",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersions(..) => "GetOrgsorgPackagespackageTypepackageNameVersions",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore",
+  EndPoints::GetOrgsorgProjects(..) => "GetOrgsorgProjects",
+  EndPoints::PostOrgsorgProjects(..) => "PostOrgsorgProjects",
+  EndPoints::GetOrgsorgPublicMembers(..) => "GetOrgsorgPublicMembers",
+  EndPoints::GetOrgsorgPublicMembersusername(..) => "GetOrgsorgPublicMembersusername",
+  EndPoints::PutOrgsorgPublicMembersusername(..) => "PutOrgsorgPublicMembersusername",
+  EndPoints::DeleteOrgsorgPublicMembersusername(..) => "DeleteOrgsorgPublicMembersusername",
+  EndPoints::GetOrgsorgRepos(..) => "GetOrgsorgRepos",
+  EndPoints::PostOrgsorgRepos(..) => "PostOrgsorgRepos",
+  EndPoints::GetOrgsorgSecretScanningAlerts(..) => "GetOrgsorgSecretScanningAlerts",
+  EndPoints::GetOrgsorgSettingsBillingActions(..) => "GetOrgsorgSettingsBillingActions",
+  EndPoints::GetOrgsorgSettingsBillingAdvancedSecurity(..) => "GetOrgsorgSettingsBillingAdvancedSecurity",
+  EndPoints::GetOrgsorgSettingsBillingPackages(..) => "GetOrgsorgSettingsBillingPackages",
+  EndPoints::GetOrgsorgSettingsBillingSharedStorage(..) => "GetOrgsorgSettingsBillingSharedStorage",
+  EndPoints::GetOrgsorgTeamSyncGroups(..) => "GetOrgsorgTeamSyncGroups",
+  EndPoints::GetOrgsorgTeams(..) => "GetOrgsorgTeams",
+  EndPoints::PostOrgsorgTeams(..) => "PostOrgsorgTeams",
+  EndPoints::GetOrgsorgTeamsteamSlug(..) => "GetOrgsorgTeamsteamSlug",
+  EndPoints::PatchOrgsorgTeamsteamSlug(..) => "PatchOrgsorgTeamsteamSlug",
+  EndPoints::DeleteOrgsorgTeamsteamSlug(..) => "DeleteOrgsorgTeamsteamSlug",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussions(..) => "GetOrgsorgTeamsteamSlugDiscussions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussions(..) => "PostOrgsorgTeamsteamSlugDiscussions",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId(..) => "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId(..) => "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId",
+  EndPoints::PatchOrgsorgTeamsteamSlugExternalGroups(..) => "PatchOrgsorgTeamsteamSlugExternalGroups",
+  EndPoints::DeleteOrgsorgTeamsteamSlugExternalGroups(..) => "DeleteOrgsorgTeamsteamSlugExternalGroups",
+  EndPoints::GetOrgsorgTeamsteamSlugInvitations(..) => "GetOrgsorgTeamsteamSlugInvitations",
+  EndPoints::GetOrgsorgTeamsteamSlugMembers(..) => "GetOrgsorgTeamsteamSlugMembers",
+  EndPoints::GetOrgsorgTeamsteamSlugMembershipsusername(..) => "GetOrgsorgTeamsteamSlugMembershipsusername",
+  EndPoints::PutOrgsorgTeamsteamSlugMembershipsusername(..) => "PutOrgsorgTeamsteamSlugMembershipsusername",
+  EndPoints::DeleteOrgsorgTeamsteamSlugMembershipsusername(..) => "DeleteOrgsorgTeamsteamSlugMembershipsusername",
+  EndPoints::GetOrgsorgTeamsteamSlugProjects(..) => "GetOrgsorgTeamsteamSlugProjects",
+  EndPoints::GetOrgsorgTeamsteamSlugProjectsprojectId(..) => "GetOrgsorgTeamsteamSlugProjectsprojectId",
+  EndPoints::PutOrgsorgTeamsteamSlugProjectsprojectId(..) => "PutOrgsorgTeamsteamSlugProjectsprojectId",
+  EndPoints::DeleteOrgsorgTeamsteamSlugProjectsprojectId(..) => "DeleteOrgsorgTeamsteamSlugProjectsprojectId",
+  EndPoints::GetOrgsorgTeamsteamSlugRepos(..) => "GetOrgsorgTeamsteamSlugRepos",
+  EndPoints::GetOrgsorgTeamsteamSlugReposownerrepo(..) => "GetOrgsorgTeamsteamSlugReposownerrepo",
+  EndPoints::PutOrgsorgTeamsteamSlugReposownerrepo(..) => "PutOrgsorgTeamsteamSlugReposownerrepo",
+  EndPoints::DeleteOrgsorgTeamsteamSlugReposownerrepo(..) => "DeleteOrgsorgTeamsteamSlugReposownerrepo",
+  EndPoints::GetOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "GetOrgsorgTeamsteamSlugTeamSyncGroupMappings",
+  EndPoints::PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings",
+  EndPoints::GetOrgsorgTeamsteamSlugTeams(..) => "GetOrgsorgTeamsteamSlugTeams",
+  EndPoints::GetProjectsColumnsCardscardId(..) => "GetProjectsColumnsCardscardId",
+  EndPoints::PatchProjectsColumnsCardscardId(..) => "PatchProjectsColumnsCardscardId",
+  EndPoints::DeleteProjectsColumnsCardscardId(..) => "DeleteProjectsColumnsCardscardId",
+  EndPoints::PostProjectsColumnsCardscardIdMoves(..) => "PostProjectsColumnsCardscardIdMoves",
+  EndPoints::GetProjectsColumnscolumnId(..) => "GetProjectsColumnscolumnId",
+  EndPoints::PatchProjectsColumnscolumnId(..) => "PatchProjectsColumnscolumnId",
+  EndPoints::DeleteProjectsColumnscolumnId(..) => "DeleteProjectsColumnscolumnId",
+  EndPoints::GetProjectsColumnscolumnIdCards(..) => "GetProjectsColumnscolumnIdCards",
+  EndPoints::PostProjectsColumnscolumnIdCards(..) => "PostProjectsColumnscolumnIdCards",
+  EndPoints::PostProjectsColumnscolumnIdMoves(..) => "PostProjectsColumnscolumnIdMoves",
+  EndPoints::GetProjectsprojectId(..) => "GetProjectsprojectId",
+  EndPoints::PatchProjectsprojectId(..) => "PatchProjectsprojectId",
+  EndPoints::DeleteProjectsprojectId(..) => "DeleteProjectsprojectId",
+  EndPoints::GetProjectsprojectIdCollaborators(..) => "GetProjectsprojectIdCollaborators",
+  EndPoints::PutProjectsprojectIdCollaboratorsusername(..) => "PutProjectsprojectIdCollaboratorsusername",
+  EndPoints::DeleteProjectsprojectIdCollaboratorsusername(..) => "DeleteProjectsprojectIdCollaboratorsusername",
+  EndPoints::GetProjectsprojectIdCollaboratorsusernamePermission(..) => "GetProjectsprojectIdCollaboratorsusernamePermission",
+  EndPoints::GetProjectsprojectIdColumns(..) => "GetProjectsprojectIdColumns",
+  EndPoints::PostProjectsprojectIdColumns(..) => "PostProjectsprojectIdColumns",
+  EndPoints::GetRateLimit(..) => "GetRateLimit",
+  EndPoints::DeleteReactionsreactionId(..) => "DeleteReactionsreactionId",
+  EndPoints::GetReposownerrepo(..) => "GetReposownerrepo",
+  EndPoints::PatchReposownerrepo(..) => "PatchReposownerrepo",
+  EndPoints::DeleteReposownerrepo(..) => "DeleteReposownerrepo",
+  EndPoints::GetReposownerrepoActionsArtifacts(..) => "GetReposownerrepoActionsArtifacts",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactId(..) => "GetReposownerrepoActionsArtifactsartifactId",
+  EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(..) => "DeleteReposownerrepoActionsArtifactsartifactId",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(..) => "GetReposownerrepoActionsArtifactsartifactIdarchiveFormat",
+  EndPoints::GetReposownerrepoActionsJobsjobId(..) => "GetReposownerrepoActionsJobsjobId",
+  EndPoints::GetReposownerrepoActionsJobsjobIdLogs(..) => "GetReposownerrepoActionsJobsjobIdLogs",
+  EndPoints::GetReposownerrepoActionsPermissions(..) => "GetReposownerrepoActionsPermissions",
+  EndPoints::PutReposownerrepoActionsPermissions(..) => "PutReposownerrepoActionsPermissions",
+  EndPoints::GetReposownerrepoActionsPermissionsSelectedActions(..) => "GetReposownerrepoActionsPermissionsSelectedActions",
+  EndPoints::PutReposownerrepoActionsPermissionsSelectedActions(..) => "PutReposownerrepoActionsPermissionsSelectedActions",
+  EndPoints::GetReposownerrepoActionsRunners(..) => "GetReposownerrepoActionsRunners",
+  EndPoints::GetReposownerrepoActionsRunnersDownloads(..) => "GetReposownerrepoActionsRunnersDownloads",
+  EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(..) => "PostReposownerrepoActionsRunnersRegistrationToken",
+  EndPoints::PostReposownerrepoActionsRunnersRemoveToken(..) => "PostReposownerrepoActionsRunnersRemoveToken",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerId(..) => "GetReposownerrepoActionsRunnersrunnerId",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerId(..) => "DeleteReposownerrepoActionsRunnersrunnerId",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerIdLabels(..) => "GetReposownerrepoActionsRunnersrunnerIdLabels",
+  EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(..) => "PostReposownerrepoActionsRunnersrunnerIdLabels",
+  EndPoints::PutReposownerrepoActionsRunnersrunnerIdLabels(..) => "PutReposownerrepoActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabels(..) => "DeleteReposownerrepoActionsRunnersrunnerIdLabels",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabelsname(..) => "DeleteReposownerrepoActionsRunnersrunnerIdLabelsname",
+  EndPoints::GetReposownerrepoActionsRuns(..) => "GetReposownerrepoActionsRuns",
+  EndPoints::GetReposownerrepoActionsRunsrunId(..) => "GetReposownerrepoActionsRunsrunId",
+  EndPoints::DeleteReposownerrepoActionsRunsrunId(..) => "DeleteReposownerrepoActionsRunsrunId",
+  EndPoints::GetReposownerrepoActionsRunsrunIdApprovals(..) => "GetReposownerrepoActionsRunsrunIdApprovals",
+  EndPoints::PostReposownerrepoActionsRunsrunIdApprove(..) => "PostReposownerrepoActionsRunsrunIdApprove",
+  EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(..) => "GetReposownerrepoActionsRunsrunIdArtifacts",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber(..) => "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs(..) => "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs(..) => "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs",
+  EndPoints::PostReposownerrepoActionsRunsrunIdCancel(..) => "PostReposownerrepoActionsRunsrunIdCancel",
+  EndPoints::GetReposownerrepoActionsRunsrunIdJobs(..) => "GetReposownerrepoActionsRunsrunIdJobs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdLogs(..) => "GetReposownerrepoActionsRunsrunIdLogs",
+  EndPoints::DeleteReposownerrepoActionsRunsrunIdLogs(..) => "DeleteReposownerrepoActionsRunsrunIdLogs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdPendingDeployments(..) => "GetReposownerrepoActionsRunsrunIdPendingDeployments",
+  EndPoints::PostReposownerrepoActionsRunsrunIdPendingDeployments(..) => "PostReposownerrepoActionsRunsrunIdPendingDeployments",
+  EndPoints::PostReposownerrepoActionsRunsrunIdRerun(..) => "PostReposownerrepoActionsRunsrunIdRerun",
+  EndPoints::GetReposownerrepoActionsRunsrunIdTiming(..) => "GetReposownerrepoActionsRunsrunIdTiming",
+  EndPoints::GetReposownerrepoActionsSecrets(..) => "GetReposownerrepoActionsSecrets",
+  EndPoints::GetReposownerrepoActionsSecretsPublicKey(..) => "GetReposownerrepoActionsSecretsPublicKey",
+  EndPoints::GetReposownerrepoActionsSecretssecretName(..) => "GetReposownerrepoActionsSecretssecretName",
+  EndPoints::PutReposownerrepoActionsSecretssecretName(..) => "PutReposownerrepoActionsSecretssecretName",
+  EndPoints::DeleteReposownerrepoActionsSecretssecretName(..) => "DeleteReposownerrepoActionsSecretssecretName",
+  EndPoints::GetReposownerrepoActionsWorkflows(..) => "GetReposownerrepoActionsWorkflows",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(..) => "GetReposownerrepoActionsWorkflowsworkflowId",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdDisable(..) => "PutReposownerrepoActionsWorkflowsworkflowIdDisable",
+  EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(..) => "PostReposownerrepoActionsWorkflowsworkflowIdDispatches",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdEnable(..) => "PutReposownerrepoActionsWorkflowsworkflowIdEnable",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(..) => "GetReposownerrepoActionsWorkflowsworkflowIdRuns",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdTiming(..) => "GetReposownerrepoActionsWorkflowsworkflowIdTiming",
+  EndPoints::GetReposownerrepoAssignees(..) => "GetReposownerrepoAssignees",
+  EndPoints::GetReposownerrepoAssigneesassignee(..) => "GetReposownerrepoAssigneesassignee",
+  EndPoints::GetReposownerrepoAutolinks(..) => "GetReposownerrepoAutolinks",
+  EndPoints::PostReposownerrepoAutolinks(..) => "PostReposownerrepoAutolinks",
+  EndPoints::GetReposownerrepoAutolinksautolinkId(..) => "GetReposownerrepoAutolinksautolinkId",
+  EndPoints::DeleteReposownerrepoAutolinksautolinkId(..) => "DeleteReposownerrepoAutolinksautolinkId",
+  EndPoints::PutReposownerrepoAutomatedSecurityFixes(..) => "PutReposownerrepoAutomatedSecurityFixes",
+  EndPoints::DeleteReposownerrepoAutomatedSecurityFixes(..) => "DeleteReposownerrepoAutomatedSecurityFixes",
+  EndPoints::GetReposownerrepoBranches(..) => "GetReposownerrepoBranches",
+  EndPoints::GetReposownerrepoBranchesbranch(..) => "GetReposownerrepoBranchesbranch",
+  EndPoints::GetReposownerrepoBranchesbranchProtection(..) => "GetReposownerrepoBranchesbranchProtection",
+  EndPoints::PutReposownerrepoBranchesbranchProtection(..) => "PutReposownerrepoBranchesbranchProtection",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtection(..) => "DeleteReposownerrepoBranchesbranchProtection",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "GetReposownerrepoBranchesbranchProtectionEnforceAdmins",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "PostReposownerrepoBranchesbranchProtectionEnforceAdmins",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "GetReposownerrepoBranchesbranchProtectionRequiredSignatures",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "PostReposownerrepoBranchesbranchProtectionRequiredSignatures",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictions(..) => "GetReposownerrepoBranchesbranchProtectionRestrictions",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictions(..) => "DeleteReposownerrepoBranchesbranchProtectionRestrictions",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "GetReposownerrepoBranchesbranchProtectionRestrictionsApps",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "PostReposownerrepoBranchesbranchProtectionRestrictionsApps",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "PutReposownerrepoBranchesbranchProtectionRestrictionsApps",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "GetReposownerrepoBranchesbranchProtectionRestrictionsTeams",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "PostReposownerrepoBranchesbranchProtectionRestrictionsTeams",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "PutReposownerrepoBranchesbranchProtectionRestrictionsTeams",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "GetReposownerrepoBranchesbranchProtectionRestrictionsUsers",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "PostReposownerrepoBranchesbranchProtectionRestrictionsUsers",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "PutReposownerrepoBranchesbranchProtectionRestrictionsUsers",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers",
+  EndPoints::PostReposownerrepoBranchesbranchRename(..) => "PostReposownerrepoBranchesbranchRename",
+  EndPoints::PostReposownerrepoCheckRuns(..) => "PostReposownerrepoCheckRuns",
+  EndPoints::GetReposownerrepoCheckRunscheckRunId(..) => "GetReposownerrepoCheckRunscheckRunId",
+  EndPoints::PatchReposownerrepoCheckRunscheckRunId(..) => "PatchReposownerrepoCheckRunscheckRunId",
+  EndPoints::GetReposownerrepoCheckRunscheckRunIdAnnotations(..) => "GetReposownerrepoCheckRunscheckRunIdAnnotations",
+  EndPoints::PostReposownerrepoCheckRunscheckRunIdRerequest(..) => "PostReposownerrepoCheckRunscheckRunIdRerequest",
+  EndPoints::PostReposownerrepoCheckSuites(..) => "PostReposownerrepoCheckSuites",
+  EndPoints::PatchReposownerrepoCheckSuitesPreferences(..) => "PatchReposownerrepoCheckSuitesPreferences",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteId(..) => "GetReposownerrepoCheckSuitescheckSuiteId",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(..) => "GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns",
+  EndPoints::PostReposownerrepoCheckSuitescheckSuiteIdRerequest(..) => "PostReposownerrepoCheckSuitescheckSuiteIdRerequest",
+  EndPoints::GetReposownerrepoCodeScanningAlerts(..) => "GetReposownerrepoCodeScanningAlerts",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumber(..) => "GetReposownerrepoCodeScanningAlertsalertNumber",
+  EndPoints::PatchReposownerrepoCodeScanningAlertsalertNumber(..) => "PatchReposownerrepoCodeScanningAlertsalertNumber",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumberInstances(..) => "GetReposownerrepoCodeScanningAlertsalertNumberInstances",
+  EndPoints::GetReposownerrepoCodeScanningAnalyses(..) => "GetReposownerrepoCodeScanningAnalyses",
+  EndPoints::GetReposownerrepoCodeScanningAnalysesanalysisId(..) => "GetReposownerrepoCodeScanningAnalysesanalysisId",
+  EndPoints::DeleteReposownerrepoCodeScanningAnalysesanalysisId(..) => "DeleteReposownerrepoCodeScanningAnalysesanalysisId",
+  EndPoints::PostReposownerrepoCodeScanningSarifs(..) => "PostReposownerrepoCodeScanningSarifs",
+  EndPoints::GetReposownerrepoCodeScanningSarifssarifId(..) => "GetReposownerrepoCodeScanningSarifssarifId",
+  EndPoints::GetReposownerrepoCodespaces(..) => "GetReposownerrepoCodespaces",
+  EndPoints::PostReposownerrepoCodespaces(..) => "PostReposownerrepoCodespaces",
+  EndPoints::GetReposownerrepoCodespacesMachines(..) => "GetReposownerrepoCodespacesMachines",
+  EndPoints::GetReposownerrepoCollaborators(..) => "GetReposownerrepoCollaborators",
+  EndPoints::GetReposownerrepoCollaboratorsusername(..) => "GetReposownerrepoCollaboratorsusername",
+  EndPoints::PutReposownerrepoCollaboratorsusername(..) => "PutReposownerrepoCollaboratorsusername",
+  EndPoints::DeleteReposownerrepoCollaboratorsusername(..) => "DeleteReposownerrepoCollaboratorsusername",
+  EndPoints::GetReposownerrepoCollaboratorsusernamePermission(..) => "GetReposownerrepoCollaboratorsusernamePermission",
+  EndPoints::GetReposownerrepoComments(..) => "GetReposownerrepoComments",
+  EndPoints::GetReposownerrepoCommentscommentId(..) => "GetReposownerrepoCommentscommentId",
+  EndPoints::PatchReposownerrepoCommentscommentId(..) => "PatchReposownerrepoCommentscommentId",
+  EndPoints::DeleteReposownerrepoCommentscommentId(..) => "DeleteReposownerrepoCommentscommentId",
+  EndPoints::GetReposownerrepoCommentscommentIdReactions(..) => "GetReposownerrepoCommentscommentIdReactions",
+  EndPoints::PostReposownerrepoCommentscommentIdReactions(..) => "PostReposownerrepoCommentscommentIdReactions",
+  EndPoints::DeleteReposownerrepoCommentscommentIdReactionsreactionId(..) => "DeleteReposownerrepoCommentscommentIdReactionsreactionId",
+  EndPoints::GetReposownerrepoCommits(..) => "GetReposownerrepoCommits",
+  EndPoints::GetReposownerrepoCommitscommitShaBranchesWhereHead(..) => "GetReposownerrepoCommitscommitShaBranchesWhereHead",
+  EndPoints::GetReposownerrepoCommitscommitShaComments(..) => "GetReposownerrepoCommitscommitShaComments",
+  EndPoints::PostReposownerrepoCommitscommitShaComments(..) => "PostReposownerrepoCommitscommitShaComments",
+  EndPoints::GetReposownerrepoCommitscommitShaPulls(..) => "GetReposownerrepoCommitscommitShaPulls",
+  EndPoints::GetReposownerrepoCommitsref(..) => "GetReposownerrepoCommitsref",
+  EndPoints::GetReposownerrepoCommitsrefCheckRuns(..) => "GetReposownerrepoCommitsrefCheckRuns",
+  EndPoints::GetReposownerrepoCommitsrefCheckSuites(..) => "GetReposownerrepoCommitsrefCheckSuites",
+  EndPoints::GetReposownerrepoCommitsrefStatus(..) => "GetReposownerrepoCommitsrefStatus",
+  EndPoints::GetReposownerrepoCommitsrefStatuses(..) => "GetReposownerrepoCommitsrefStatuses",
+  EndPoints::GetReposownerrepoCommunityProfile(..) => "GetReposownerrepoCommunityProfile",
+  EndPoints::GetReposownerrepoComparebasehead(..) => "GetReposownerrepoComparebasehead",
+  EndPoints::GetReposownerrepoContentspath(..) => "GetReposownerrepoContentspath",
+  EndPoints::PutReposownerrepoContentspath(..) => "PutReposownerrepoContentspath",
+  EndPoints::DeleteReposownerrepoContentspath(..) => "DeleteReposownerrepoContentspath",
+  EndPoints::GetReposownerrepoContributors(..) => "GetReposownerrepoContributors",
+  EndPoints::GetReposownerrepoDependabotSecrets(..) => "GetReposownerrepoDependabotSecrets",
+  EndPoints::GetReposownerrepoDependabotSecretsPublicKey(..) => "GetReposownerrepoDependabotSecretsPublicKey",
+  EndPoints::GetReposownerrepoDependabotSecretssecretName(..) => "GetReposownerrepoDependabotSecretssecretName",
+  EndPoints::PutReposownerrepoDependabotSecretssecretName(..) => "PutReposownerrepoDependabotSecretssecretName",
+  EndPoints::DeleteReposownerrepoDependabotSecretssecretName(..) => "DeleteReposownerrepoDependabotSecretssecretName",
+  EndPoints::GetReposownerrepoDeployments(..) => "GetReposownerrepoDeployments",
+  EndPoints::PostReposownerrepoDeployments(..) => "PostReposownerrepoDeployments",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentId(..) => "GetReposownerrepoDeploymentsdeploymentId",
+  EndPoints::DeleteReposownerrepoDeploymentsdeploymentId(..) => "DeleteReposownerrepoDeploymentsdeploymentId",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatuses(..) => "GetReposownerrepoDeploymentsdeploymentIdStatuses",
+  EndPoints::PostReposownerrepoDeploymentsdeploymentIdStatuses(..) => "PostReposownerrepoDeploymentsdeploymentIdStatuses",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId(..) => "GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId",
+  EndPoints::PostReposownerrepoDispatches(..) => "PostReposownerrepoDispatches",
+  EndPoints::GetReposownerrepoEnvironments(..) => "GetReposownerrepoEnvironments",
+  EndPoints::GetReposownerrepoEnvironmentsenvironmentName(..) => "GetReposownerrepoEnvironmentsenvironmentName",
+  EndPoints::PutReposownerrepoEnvironmentsenvironmentName(..) => "PutReposownerrepoEnvironmentsenvironmentName",
+  EndPoints::DeleteReposownerrepoEnvironmentsenvironmentName(..) => "DeleteReposownerrepoEnvironmentsenvironmentName",
+  EndPoints::GetReposownerrepoEvents(..) => "GetReposownerrepoEvents",
+  EndPoints::GetReposownerrepoForks(..) => "GetReposownerrepoForks",
+  EndPoints::PostReposownerrepoForks(..) => "PostReposownerrepoForks",
+  EndPoints::PostReposownerrepoGitBlobs(..) => "PostReposownerrepoGitBlobs",
+  EndPoints::GetReposownerrepoGitBlobsfileSha(..) => "GetReposownerrepoGitBlobsfileSha",
+  EndPoints::PostReposownerrepoGitCommits(..) => "PostReposownerrepoGitCommits",
+  EndPoints::GetReposownerrepoGitCommitscommitSha(..) => "GetReposownerrepoGitCommitscommitSha",
+  EndPoints::GetReposownerrepoGitMatchingRefsref(..) => "GetReposownerrepoGitMatchingRefsref",
+  EndPoints::GetReposownerrepoGitRefref(..) => "GetReposownerrepoGitRefref",
+  EndPoints::PostReposownerrepoGitRefs(..) => "PostReposownerrepoGitRefs",
+  EndPoints::PatchReposownerrepoGitRefsref(..) => "PatchReposownerrepoGitRefsref",
+  EndPoints::DeleteReposownerrepoGitRefsref(..) => "DeleteReposownerrepoGitRefsref",
+  EndPoints::PostReposownerrepoGitTags(..) => "PostReposownerrepoGitTags",
+  EndPoints::GetReposownerrepoGitTagstagSha(..) => "GetReposownerrepoGitTagstagSha",
+  EndPoints::PostReposownerrepoGitTrees(..) => "PostReposownerrepoGitTrees",
+  EndPoints::GetReposownerrepoGitTreestreeSha(..) => "GetReposownerrepoGitTreestreeSha",
+  EndPoints::GetReposownerrepoHooks(..) => "GetReposownerrepoHooks",
+  EndPoints::PostReposownerrepoHooks(..) => "PostReposownerrepoHooks",
+  EndPoints::GetReposownerrepoHookshookId(..) => "GetReposownerrepoHookshookId",
+  EndPoints::PatchReposownerrepoHookshookId(..) => "PatchReposownerrepoHookshookId",
+  EndPoints::DeleteReposownerrepoHookshookId(..) => "DeleteReposownerrepoHookshookId",
+  EndPoints::GetReposownerrepoHookshookIdConfig(..) => "GetReposownerrepoHookshookIdConfig",
+  EndPoints::PatchReposownerrepoHookshookIdConfig(..) => "PatchReposownerrepoHookshookIdConfig",
+  EndPoints::GetReposownerrepoHookshookIdDeliveries(..) => "GetReposownerrepoHookshookIdDeliveries",
+  EndPoints::GetReposownerrepoHookshookIdDeliveriesdeliveryId(..) => "GetReposownerrepoHookshookIdDeliveriesdeliveryId",
+  EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(..) => "PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts",
+  EndPoints::PostReposownerrepoHookshookIdPings(..) => "PostReposownerrepoHookshookIdPings",
+  EndPoints::PostReposownerrepoHookshookIdTests(..) => "PostReposownerrepoHookshookIdTests",
+  EndPoints::GetReposownerrepoImport(..) => "GetReposownerrepoImport",
+  EndPoints::PutReposownerrepoImport(..) => "PutReposownerrepoImport",
+  EndPoints::PatchReposownerrepoImport(..) => "PatchReposownerrepoImport",
+  EndPoints::DeleteReposownerrepoImport(..) => "DeleteReposownerrepoImport",
+  EndPoints::GetReposownerrepoImportAuthors(..) => "GetReposownerrepoImportAuthors",
+  EndPoints::PatchReposownerrepoImportAuthorsauthorId(..) => "PatchReposownerrepoImportAuthorsauthorId",
+  EndPoints::GetReposownerrepoImportLargeFiles(..) => "GetReposownerrepoImportLargeFiles",
+  EndPoints::PatchReposownerrepoImportLfs(..) => "PatchReposownerrepoImportLfs",
+  EndPoints::GetReposownerrepoInstallation(..) => "GetReposownerrepoInstallation",
+  EndPoints::GetReposownerrepoInteractionLimits(..) => "GetReposownerrepoInteractionLimits",
+  EndPoints::PutReposownerrepoInteractionLimits(..) => "PutReposownerrepoInteractionLimits",
+  EndPoints::DeleteReposownerrepoInteractionLimits(..) => "DeleteReposownerrepoInteractionLimits",
+  EndPoints::GetReposownerrepoInvitations(..) => "GetReposownerrepoInvitations",
+  EndPoints::PatchReposownerrepoInvitationsinvitationId(..) => "PatchReposownerrepoInvitationsinvitationId",
+  EndPoints::DeleteReposownerrepoInvitationsinvitationId(..) => "DeleteReposownerrepoInvitationsinvitationId",
+  EndPoints::GetReposownerrepoIssues(..) => "GetReposownerrepoIssues",
+  EndPoints::PostReposownerrepoIssues(..) => "PostReposownerrepoIssues",
+  EndPoints::GetReposownerrepoIssuesComments(..) => "GetReposownerrepoIssuesComments",
+  EndPoints::GetReposownerrepoIssuesCommentscommentId(..) => "GetReposownerrepoIssuesCommentscommentId",
+  EndPoints::PatchReposownerrepoIssuesCommentscommentId(..) => "PatchReposownerrepoIssuesCommentscommentId",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentId(..) => "DeleteReposownerrepoIssuesCommentscommentId",
+  EndPoints::GetReposownerrepoIssuesCommentscommentIdReactions(..) => "GetReposownerrepoIssuesCommentscommentIdReactions",
+  EndPoints::PostReposownerrepoIssuesCommentscommentIdReactions(..) => "PostReposownerrepoIssuesCommentscommentIdReactions",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId(..) => "DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId",
+  EndPoints::GetReposownerrepoIssuesEvents(..) => "GetReposownerrepoIssuesEvents",
+  EndPoints::GetReposownerrepoIssuesEventseventId(..) => "GetReposownerrepoIssuesEventseventId",
+  EndPoints::GetReposownerrepoIssuesissueNumber(..) => "GetReposownerrepoIssuesissueNumber",
+  EndPoints::PatchReposownerrepoIssuesissueNumber(..) => "PatchReposownerrepoIssuesissueNumber",
+  EndPoints::PostReposownerrepoIssuesissueNumberAssignees(..) => "PostReposownerrepoIssuesissueNumberAssignees",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(..) => "DeleteReposownerrepoIssuesissueNumberAssignees",
+  EndPoints::GetReposownerrepoIssuesissueNumberComments(..) => "GetReposownerrepoIssuesissueNumberComments",
+  EndPoints::PostReposownerrepoIssuesissueNumberComments(..) => "PostReposownerrepoIssuesissueNumberComments",
+  EndPoints::GetReposownerrepoIssuesissueNumberEvents(..) => "GetReposownerrepoIssuesissueNumberEvents",
+  EndPoints::GetReposownerrepoIssuesissueNumberLabels(..) => "GetReposownerrepoIssuesissueNumberLabels",
+  EndPoints::PostReposownerrepoIssuesissueNumberLabels(..) => "PostReposownerrepoIssuesissueNumberLabels",
+  EndPoints::PutReposownerrepoIssuesissueNumberLabels(..) => "PutReposownerrepoIssuesissueNumberLabels",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(..) => "DeleteReposownerrepoIssuesissueNumberLabels",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(..) => "DeleteReposownerrepoIssuesissueNumberLabelsname",
+  EndPoints::PutReposownerrepoIssuesissueNumberLock(..) => "PutReposownerrepoIssuesissueNumberLock",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLock(..) => "DeleteReposownerrepoIssuesissueNumberLock",
+  EndPoints::GetReposownerrepoIssuesissueNumberReactions(..) => "GetReposownerrepoIssuesissueNumberReactions",
+  EndPoints::PostReposownerrepoIssuesissueNumberReactions(..) => "PostReposownerrepoIssuesissueNumberReactions",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberReactionsreactionId(..) => "DeleteReposownerrepoIssuesissueNumberReactionsreactionId",
+  EndPoints::GetReposownerrepoIssuesissueNumberTimeline(..) => "GetReposownerrepoIssuesissueNumberTimeline",
+  EndPoints::GetReposownerrepoKeys(..) => "GetReposownerrepoKeys",
+  EndPoints::PostReposownerrepoKeys(..) => "PostReposownerrepoKeys",
+  EndPoints::GetReposownerrepoKeyskeyId(..) => "GetReposownerrepoKeyskeyId",
+  EndPoints::DeleteReposownerrepoKeyskeyId(..) => "DeleteReposownerrepoKeyskeyId",
+  EndPoints::GetReposownerrepoLabels(..) => "GetReposownerrepoLabels",
+  EndPoints::PostReposownerrepoLabels(..) => "PostReposownerrepoLabels",
+  EndPoints::GetReposownerrepoLabelsname(..) => "GetReposownerrepoLabelsname",
+  EndPoints::PatchReposownerrepoLabelsname(..) => "PatchReposownerrepoLabelsname",
+  EndPoints::DeleteReposownerrepoLabelsname(..) => "DeleteReposownerrepoLabelsname",
+  EndPoints::GetReposownerrepoLanguages(..) => "GetReposownerrepoLanguages",
+  EndPoints::PutReposownerrepoLfs(..) => "PutReposownerrepoLfs",
+  EndPoints::DeleteReposownerrepoLfs(..) => "DeleteReposownerrepoLfs",
+  EndPoints::GetReposownerrepoLicense(..) => "GetReposownerrepoLicense",
+  EndPoints::PostReposownerrepoMergeUpstream(..) => "PostReposownerrepoMergeUpstream",
+  EndPoints::PostReposownerrepoMerges(..) => "PostReposownerrepoMerges",
+  EndPoints::GetReposownerrepoMilestones(..) => "GetReposownerrepoMilestones",
+  EndPoints::PostReposownerrepoMilestones(..) => "PostReposownerrepoMilestones",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumber(..) => "GetReposownerrepoMilestonesmilestoneNumber",
+  EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(..) => "PatchReposownerrepoMilestonesmilestoneNumber",
+  EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(..) => "DeleteReposownerrepoMilestonesmilestoneNumber",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(..) => "GetReposownerrepoMilestonesmilestoneNumberLabels",
+  EndPoints::GetReposownerrepoNotifications(..) => "GetReposownerrepoNotifications",
+  EndPoints::PutReposownerrepoNotifications(..) => "PutReposownerrepoNotifications",
+  EndPoints::GetReposownerrepoPages(..) => "GetReposownerrepoPages",
+  EndPoints::PostReposownerrepoPages(..) => "PostReposownerrepoPages",
+  EndPoints::PutReposownerrepoPages(..) => "PutReposownerrepoPages",
+  EndPoints::DeleteReposownerrepoPages(..) => "DeleteReposownerrepoPages",
+  EndPoints::GetReposownerrepoPagesBuilds(..) => "GetReposownerrepoPagesBuilds",
+  EndPoints::PostReposownerrepoPagesBuilds(..) => "PostReposownerrepoPagesBuilds",
+  EndPoints::GetReposownerrepoPagesBuildsLatest(..) => "GetReposownerrepoPagesBuildsLatest",
+  EndPoints::GetReposownerrepoPagesBuildsbuildId(..) => "GetReposownerrepoPagesBuildsbuildId",
+  EndPoints::GetReposownerrepoPagesHealth(..) => "GetReposownerrepoPagesHealth",
+  EndPoints::GetReposownerrepoProjects(..) => "GetReposownerrepoProjects",
+  EndPoints::PostReposownerrepoProjects(..) => "PostReposownerrepoProjects",
+  EndPoints::GetReposownerrepoPulls(..) => "GetReposownerrepoPulls",
+  EndPoints::PostReposownerrepoPulls(..) => "PostReposownerrepoPulls",
+  EndPoints::GetReposownerrepoPullsComments(..) => "GetReposownerrepoPullsComments",
+  EndPoints::GetReposownerrepoPullsCommentscommentId(..) => "GetReposownerrepoPullsCommentscommentId",
+  EndPoints::PatchReposownerrepoPullsCommentscommentId(..) => "PatchReposownerrepoPullsCommentscommentId",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentId(..) => "DeleteReposownerrepoPullsCommentscommentId",
+  EndPoints::GetReposownerrepoPullsCommentscommentIdReactions(..) => "GetReposownerrepoPullsCommentscommentIdReactions",
+  EndPoints::PostReposownerrepoPullsCommentscommentIdReactions(..) => "PostReposownerrepoPullsCommentscommentIdReactions",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId(..) => "DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId",
+  EndPoints::GetReposownerrepoPullspullNumber(..) => "GetReposownerrepoPullspullNumber",
+  EndPoints::PatchReposownerrepoPullspullNumber(..) => "PatchReposownerrepoPullspullNumber",
+  EndPoints::PostReposownerrepoPullspullNumberCodespaces(..) => "PostReposownerrepoPullspullNumberCodespaces",
+  EndPoints::GetReposownerrepoPullspullNumberComments(..) => "GetReposownerrepoPullspullNumberComments",
+  EndPoints::PostReposownerrepoPullspullNumberComments(..) => "PostReposownerrepoPullspullNumberComments",
+  EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(..) => "PostReposownerrepoPullspullNumberCommentscommentIdReplies",
+  EndPoints::GetReposownerrepoPullspullNumberCommits(..) => "GetReposownerrepoPullspullNumberCommits",
+  EndPoints::GetReposownerrepoPullspullNumberFiles(..) => "GetReposownerrepoPullspullNumberFiles",
+  EndPoints::GetReposownerrepoPullspullNumberMerge(..) => "GetReposownerrepoPullspullNumberMerge",
+  EndPoints::PutReposownerrepoPullspullNumberMerge(..) => "PutReposownerrepoPullspullNumberMerge",
+  EndPoints::GetReposownerrepoPullspullNumberRequestedReviewers(..) => "GetReposownerrepoPullspullNumberRequestedReviewers",
+  EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(..) => "PostReposownerrepoPullspullNumberRequestedReviewers",
+  EndPoints::DeleteReposownerrepoPullspullNumberRequestedReviewers(..) => "DeleteReposownerrepoPullspullNumberRequestedReviewers",
+  EndPoints::GetReposownerrepoPullspullNumberReviews(..) => "GetReposownerrepoPullspullNumberReviews",
+  EndPoints::PostReposownerrepoPullspullNumberReviews(..) => "PostReposownerrepoPullspullNumberReviews",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(..) => "GetReposownerrepoPullspullNumberReviewsreviewId",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(..) => "PutReposownerrepoPullspullNumberReviewsreviewId",
+  EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(..) => "DeleteReposownerrepoPullspullNumberReviewsreviewId",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewIdComments(..) => "GetReposownerrepoPullspullNumberReviewsreviewIdComments",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(..) => "PutReposownerrepoPullspullNumberReviewsreviewIdDismissals",
+  EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(..) => "PostReposownerrepoPullspullNumberReviewsreviewIdEvents",
+  EndPoints::PutReposownerrepoPullspullNumberUpdateBranch(..) => "PutReposownerrepoPullspullNumberUpdateBranch",
+  EndPoints::GetReposownerrepoReadme(..) => "GetReposownerrepoReadme",
+  EndPoints::GetReposownerrepoReadmedir(..) => "GetReposownerrepoReadmedir",
+  EndPoints::GetReposownerrepoReleases(..) => "GetReposownerrepoReleases",
+  EndPoints::PostReposownerrepoReleases(..) => "PostReposownerrepoReleases",
+  EndPoints::GetReposownerrepoReleasesAssetsassetId(..) => "GetReposownerrepoReleasesAssetsassetId",
+  EndPoints::PatchReposownerrepoReleasesAssetsassetId(..) => "PatchReposownerrepoReleasesAssetsassetId",
+  EndPoints::DeleteReposownerrepoReleasesAssetsassetId(..) => "DeleteReposownerrepoReleasesAssetsassetId",
+  EndPoints::PostReposownerrepoReleasesGenerateNotes(..) => "PostReposownerrepoReleasesGenerateNotes",
+  EndPoints::GetReposownerrepoReleasesLatest(..) => "GetReposownerrepoReleasesLatest",
+  EndPoints::GetReposownerrepoReleasesTagstag(..) => "GetReposownerrepoReleasesTagstag",
+  EndPoints::GetReposownerrepoReleasesreleaseId(..) => "GetReposownerrepoReleasesreleaseId",
+  EndPoints::PatchReposownerrepoReleasesreleaseId(..) => "PatchReposownerrepoReleasesreleaseId",
+  EndPoints::DeleteReposownerrepoReleasesreleaseId(..) => "DeleteReposownerrepoReleasesreleaseId",
+  EndPoints::GetReposownerrepoReleasesreleaseIdAssets(..) => "GetReposownerrepoReleasesreleaseIdAssets",
+  EndPoints::PostReposownerrepoReleasesreleaseIdAssets(..) => "PostReposownerrepoReleasesreleaseIdAssets",
+  EndPoints::PostReposownerrepoReleasesreleaseIdReactions(..) => "PostReposownerrepoReleasesreleaseIdReactions",
+  EndPoints::GetReposownerrepoSecretScanningAlerts(..) => "GetReposownerrepoSecretScanningAlerts",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumber(..) => "GetReposownerrepoSecretScanningAlertsalertNumber",
+  EndPoints::PatchReposownerrepoSecretScanningAlertsalertNumber(..) => "PatchReposownerrepoSecretScanningAlertsalertNumber",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumberLocations(..) => "GetReposownerrepoSecretScanningAlertsalertNumberLocations",
+  EndPoints::GetReposownerrepoStargazers(..) => "GetReposownerrepoStargazers",
+  EndPoints::GetReposownerrepoStatsCodeFrequency(..) => "GetReposownerrepoStatsCodeFrequency",
+  EndPoints::GetReposownerrepoStatsCommitActivity(..) => "GetReposownerrepoStatsCommitActivity",
+  EndPoints::GetReposownerrepoStatsContributors(..) => "GetReposownerrepoStatsContributors",
+  EndPoints::GetReposownerrepoStatsParticipation(..) => "GetReposownerrepoStatsParticipation",
+  EndPoints::GetReposownerrepoStatsPunchCard(..) => "GetReposownerrepoStatsPunchCard",
+  EndPoints::PostReposownerrepoStatusessha(..) => "PostReposownerrepoStatusessha",
+  EndPoints::GetReposownerrepoSubscribers(..) => "GetReposownerrepoSubscribers",
+  EndPoints::GetReposownerrepoSubscription(..) => "GetReposownerrepoSubscription",
+  EndPoints::PutReposownerrepoSubscription(..) => "PutReposownerrepoSubscription",
+  EndPoints::DeleteReposownerrepoSubscription(..) => "DeleteReposownerrepoSubscription",
+  EndPoints::GetReposownerrepoTags(..) => "GetReposownerrepoTags",
+  EndPoints::GetReposownerrepoTarballref(..) => "GetReposownerrepoTarballref",
+  EndPoints::GetReposownerrepoTeams(..) => "GetReposownerrepoTeams",
+  EndPoints::GetReposownerrepoTopics(..) => "GetReposownerrepoTopics",
+  EndPoints::PutReposownerrepoTopics(..) => "PutReposownerrepoTopics",
+  EndPoints::GetReposownerrepoTrafficClones(..) => "GetReposownerrepoTrafficClones",
+  EndPoints::GetReposownerrepoTrafficPopularPaths(..) => "GetReposownerrepoTrafficPopularPaths",
+  EndPoints::GetReposownerrepoTrafficPopularReferrers(..) => "GetReposownerrepoTrafficPopularReferrers",
+  EndPoints::GetReposownerrepoTrafficViews(..) => "GetReposownerrepoTrafficViews",
+  EndPoints::PostReposownerrepoTransfer(..) => "PostReposownerrepoTransfer",
+  EndPoints::GetReposownerrepoVulnerabilityAlerts(..) => "GetReposownerrepoVulnerabilityAlerts",
+  EndPoints::PutReposownerrepoVulnerabilityAlerts(..) => "PutReposownerrepoVulnerabilityAlerts",
+  EndPoints::DeleteReposownerrepoVulnerabilityAlerts(..) => "DeleteReposownerrepoVulnerabilityAlerts",
+  EndPoints::GetReposownerrepoZipballref(..) => "GetReposownerrepoZipballref",
+  EndPoints::PostRepostemplateOwnertemplateRepoGenerate(..) => "PostRepostemplateOwnertemplateRepoGenerate",
+  EndPoints::GetRepositories(..) => "GetRepositories",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets(..) => "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey(..) => "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName",
+  EndPoints::PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName",
+  EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName",
+  EndPoints::GetScimV2EnterprisesenterpriseGroups(..) => "GetScimV2EnterprisesenterpriseGroups",
+  EndPoints::PostScimV2EnterprisesenterpriseGroups(..) => "PostScimV2EnterprisesenterpriseGroups",
+  EndPoints::GetScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "GetScimV2EnterprisesenterpriseGroupsscimGroupId",
+  EndPoints::PutScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "PutScimV2EnterprisesenterpriseGroupsscimGroupId",
+  EndPoints::PatchScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "PatchScimV2EnterprisesenterpriseGroupsscimGroupId",
+  EndPoints::DeleteScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "DeleteScimV2EnterprisesenterpriseGroupsscimGroupId",
+  EndPoints::GetScimV2EnterprisesenterpriseUsers(..) => "GetScimV2EnterprisesenterpriseUsers",
+  EndPoints::PostScimV2EnterprisesenterpriseUsers(..) => "PostScimV2EnterprisesenterpriseUsers",
+  EndPoints::GetScimV2EnterprisesenterpriseUsersscimUserId(..) => "GetScimV2EnterprisesenterpriseUsersscimUserId",
+  EndPoints::PutScimV2EnterprisesenterpriseUsersscimUserId(..) => "PutScimV2EnterprisesenterpriseUsersscimUserId",
+  EndPoints::PatchScimV2EnterprisesenterpriseUsersscimUserId(..) => "PatchScimV2EnterprisesenterpriseUsersscimUserId",
+  EndPoints::DeleteScimV2EnterprisesenterpriseUsersscimUserId(..) => "DeleteScimV2EnterprisesenterpriseUsersscimUserId",
+  EndPoints::GetScimV2OrganizationsorgUsers(..) => "GetScimV2OrganizationsorgUsers",
+  EndPoints::PostScimV2OrganizationsorgUsers(..) => "PostScimV2OrganizationsorgUsers",
+  EndPoints::GetScimV2OrganizationsorgUsersscimUserId(..) => "GetScimV2OrganizationsorgUsersscimUserId",
+  EndPoints::PutScimV2OrganizationsorgUsersscimUserId(..) => "PutScimV2OrganizationsorgUsersscimUserId",
+  EndPoints::PatchScimV2OrganizationsorgUsersscimUserId(..) => "PatchScimV2OrganizationsorgUsersscimUserId",
+  EndPoints::DeleteScimV2OrganizationsorgUsersscimUserId(..) => "DeleteScimV2OrganizationsorgUsersscimUserId",
+  EndPoints::GetSearchCode(..) => "GetSearchCode",
+  EndPoints::GetSearchCommits(..) => "GetSearchCommits",
+  EndPoints::GetSearchIssues(..) => "GetSearchIssues",
+  EndPoints::GetSearchLabels(..) => "GetSearchLabels",
+  EndPoints::GetSearchRepositories(..) => "GetSearchRepositories",
+  EndPoints::GetSearchTopics(..) => "GetSearchTopics",
+  EndPoints::GetSearchUsers(..) => "GetSearchUsers",
+  EndPoints::GetTeamsteamId(..) => "GetTeamsteamId",
+  EndPoints::PatchTeamsteamId(..) => "PatchTeamsteamId",
+  EndPoints::DeleteTeamsteamId(..) => "DeleteTeamsteamId",
+  EndPoints::GetTeamsteamIdDiscussions(..) => "GetTeamsteamIdDiscussions",
+  EndPoints::PostTeamsteamIdDiscussions(..) => "PostTeamsteamIdDiscussions",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumber(..) => "GetTeamsteamIdDiscussionsdiscussionNumber",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumber(..) => "PatchTeamsteamIdDiscussionsdiscussionNumber",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumber(..) => "DeleteTeamsteamIdDiscussionsdiscussionNumber",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberComments(..) => "GetTeamsteamIdDiscussionsdiscussionNumberComments",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(..) => "PostTeamsteamIdDiscussionsdiscussionNumberComments",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "GetTeamsteamIdDiscussionsdiscussionNumberReactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "PostTeamsteamIdDiscussionsdiscussionNumberReactions",
+  EndPoints::GetTeamsteamIdInvitations(..) => "GetTeamsteamIdInvitations",
+  EndPoints::GetTeamsteamIdMembers(..) => "GetTeamsteamIdMembers",
+  EndPoints::GetTeamsteamIdMembersusername(..) => "GetTeamsteamIdMembersusername",
+  EndPoints::PutTeamsteamIdMembersusername(..) => "PutTeamsteamIdMembersusername",
+  EndPoints::DeleteTeamsteamIdMembersusername(..) => "DeleteTeamsteamIdMembersusername",
+  EndPoints::GetTeamsteamIdMembershipsusername(..) => "GetTeamsteamIdMembershipsusername",
+  EndPoints::PutTeamsteamIdMembershipsusername(..) => "PutTeamsteamIdMembershipsusername",
+  EndPoints::DeleteTeamsteamIdMembershipsusername(..) => "DeleteTeamsteamIdMembershipsusername",
+  EndPoints::GetTeamsteamIdProjects(..) => "GetTeamsteamIdProjects",
+  EndPoints::GetTeamsteamIdProjectsprojectId(..) => "GetTeamsteamIdProjectsprojectId",
+  EndPoints::PutTeamsteamIdProjectsprojectId(..) => "PutTeamsteamIdProjectsprojectId",
+  EndPoints::DeleteTeamsteamIdProjectsprojectId(..) => "DeleteTeamsteamIdProjectsprojectId",
+  EndPoints::GetTeamsteamIdRepos(..) => "GetTeamsteamIdRepos",
+  EndPoints::GetTeamsteamIdReposownerrepo(..) => "GetTeamsteamIdReposownerrepo",
+  EndPoints::PutTeamsteamIdReposownerrepo(..) => "PutTeamsteamIdReposownerrepo",
+  EndPoints::DeleteTeamsteamIdReposownerrepo(..) => "DeleteTeamsteamIdReposownerrepo",
+  EndPoints::GetTeamsteamIdTeamSyncGroupMappings(..) => "GetTeamsteamIdTeamSyncGroupMappings",
+  EndPoints::PatchTeamsteamIdTeamSyncGroupMappings(..) => "PatchTeamsteamIdTeamSyncGroupMappings",
+  EndPoints::GetTeamsteamIdTeams(..) => "GetTeamsteamIdTeams",
+  EndPoints::GetUser(..) => "GetUser",
+  EndPoints::PatchUser(..) => "PatchUser",
+  EndPoints::GetUserBlocks(..) => "GetUserBlocks",
+  EndPoints::GetUserBlocksusername(..) => "GetUserBlocksusername",
+  EndPoints::PutUserBlocksusername(..) => "PutUserBlocksusername",
+  EndPoints::DeleteUserBlocksusername(..) => "DeleteUserBlocksusername",
+  EndPoints::GetUserCodespaces(..) => "GetUserCodespaces",
+  EndPoints::PostUserCodespaces(..) => "PostUserCodespaces",
+  EndPoints::GetUserCodespacesSecrets(..) => "GetUserCodespacesSecrets",
+  EndPoints::GetUserCodespacesSecretsPublicKey(..) => "GetUserCodespacesSecretsPublicKey",
+  EndPoints::GetUserCodespacesSecretssecretName(..) => "GetUserCodespacesSecretssecretName",
+  EndPoints::PutUserCodespacesSecretssecretName(..) => "PutUserCodespacesSecretssecretName",
+  EndPoints::DeleteUserCodespacesSecretssecretName(..) => "DeleteUserCodespacesSecretssecretName",
+  EndPoints::GetUserCodespacesSecretssecretNameRepositories(..) => "GetUserCodespacesSecretssecretNameRepositories",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositories(..) => "PutUserCodespacesSecretssecretNameRepositories",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "PutUserCodespacesSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId",
+  EndPoints::GetUserCodespacescodespaceName(..) => "GetUserCodespacescodespaceName",
+  EndPoints::PatchUserCodespacescodespaceName(..) => "PatchUserCodespacescodespaceName",
+  EndPoints::DeleteUserCodespacescodespaceName(..) => "DeleteUserCodespacescodespaceName",
+  EndPoints::PostUserCodespacescodespaceNameExports(..) => "PostUserCodespacescodespaceNameExports",
+  EndPoints::GetUserCodespacescodespaceNameExportsexportId(..) => "GetUserCodespacescodespaceNameExportsexportId",
+  EndPoints::GetUserCodespacescodespaceNameMachines(..) => "GetUserCodespacescodespaceNameMachines",
+  EndPoints::PostUserCodespacescodespaceNameStart(..) => "PostUserCodespacescodespaceNameStart",
+  EndPoints::PostUserCodespacescodespaceNameStop(..) => "PostUserCodespacescodespaceNameStop",
+  EndPoints::PatchUserEmailVisibility(..) => "PatchUserEmailVisibility",
+  EndPoints::GetUserEmails(..) => "GetUserEmails",
+  EndPoints::PostUserEmails(..) => "PostUserEmails",
+  EndPoints::DeleteUserEmails(..) => "DeleteUserEmails",
+  EndPoints::GetUserFollowers(..) => "GetUserFollowers",
+  EndPoints::GetUserFollowing(..) => "GetUserFollowing",
+  EndPoints::GetUserFollowingusername(..) => "GetUserFollowingusername",
+  EndPoints::PutUserFollowingusername(..) => "PutUserFollowingusername",
+  EndPoints::DeleteUserFollowingusername(..) => "DeleteUserFollowingusername",
+  EndPoints::GetUserGpgKeys(..) => "GetUserGpgKeys",
+  EndPoints::PostUserGpgKeys(..) => "PostUserGpgKeys",
+  EndPoints::GetUserGpgKeysgpgKeyId(..) => "GetUserGpgKeysgpgKeyId",
+  EndPoints::DeleteUserGpgKeysgpgKeyId(..) => "DeleteUserGpgKeysgpgKeyId",
+  EndPoints::GetUserInstallations(..) => "GetUserInstallations",
+  EndPoints::GetUserInstallationsinstallationIdRepositories(..) => "GetUserInstallationsinstallationIdRepositories",
+  EndPoints::PutUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "PutUserInstallationsinstallationIdRepositoriesrepositoryId",
+  EndPoints::DeleteUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "DeleteUserInstallationsinstallationIdRepositoriesrepositoryId",
+  EndPoints::GetUserInteractionLimits(..) => "GetUserInteractionLimits",
+  EndPoints::PutUserInteractionLimits(..) => "PutUserInteractionLimits",
+  EndPoints::DeleteUserInteractionLimits(..) => "DeleteUserInteractionLimits",
+  EndPoints::GetUserIssues(..) => "GetUserIssues",
+  EndPoints::GetUserKeys(..) => "GetUserKeys",
+  EndPoints::PostUserKeys(..) => "PostUserKeys",
+  EndPoints::GetUserKeyskeyId(..) => "GetUserKeyskeyId",
+  EndPoints::DeleteUserKeyskeyId(..) => "DeleteUserKeyskeyId",
+  EndPoints::GetUserMarketplacePurchases(..) => "GetUserMarketplacePurchases",
+  EndPoints::GetUserMarketplacePurchasesStubbed(..) => "GetUserMarketplacePurchasesStubbed",
+  EndPoints::GetUserMembershipsOrgs(..) => "GetUserMembershipsOrgs",
+  EndPoints::GetUserMembershipsOrgsorg(..) => "GetUserMembershipsOrgsorg",
+  EndPoints::PatchUserMembershipsOrgsorg(..) => "PatchUserMembershipsOrgsorg",
+  EndPoints::GetUserMigrations(..) => "GetUserMigrations",
+  EndPoints::PostUserMigrations(..) => "PostUserMigrations",
+  EndPoints::GetUserMigrationsmigrationId(..) => "GetUserMigrationsmigrationId",
+  EndPoints::GetUserMigrationsmigrationIdArchive(..) => "GetUserMigrationsmigrationIdArchive",
+  EndPoints::DeleteUserMigrationsmigrationIdArchive(..) => "DeleteUserMigrationsmigrationIdArchive",
+  EndPoints::DeleteUserMigrationsmigrationIdReposrepoNameLock(..) => "DeleteUserMigrationsmigrationIdReposrepoNameLock",
+  EndPoints::GetUserMigrationsmigrationIdRepositories(..) => "GetUserMigrationsmigrationIdRepositories",
+  EndPoints::GetUserOrgs(..) => "GetUserOrgs",
+  EndPoints::GetUserPackages(..) => "GetUserPackages",
+  EndPoints::GetUserPackagespackageTypepackageName(..) => "GetUserPackagespackageTypepackageName",
+  EndPoints::DeleteUserPackagespackageTypepackageName(..) => "DeleteUserPackagespackageTypepackageName",
+  EndPoints::PostUserPackagespackageTypepackageNameRestore(..) => "PostUserPackagespackageTypepackageNameRestore",
+  EndPoints::GetUserPackagespackageTypepackageNameVersions(..) => "GetUserPackagespackageTypepackageNameVersions",
+  EndPoints::GetUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "GetUserPackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::DeleteUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "DeleteUserPackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore",
+  EndPoints::PostUserProjects(..) => "PostUserProjects",
+  EndPoints::GetUserPublicEmails(..) => "GetUserPublicEmails",
+  EndPoints::GetUserRepos(..) => "GetUserRepos",
+  EndPoints::PostUserRepos(..) => "PostUserRepos",
+  EndPoints::GetUserRepositoryInvitations(..) => "GetUserRepositoryInvitations",
+  EndPoints::PatchUserRepositoryInvitationsinvitationId(..) => "PatchUserRepositoryInvitationsinvitationId",
+  EndPoints::DeleteUserRepositoryInvitationsinvitationId(..) => "DeleteUserRepositoryInvitationsinvitationId",
+  EndPoints::GetUserStarred(..) => "GetUserStarred",
+  EndPoints::GetUserStarredownerrepo(..) => "GetUserStarredownerrepo",
+  EndPoints::PutUserStarredownerrepo(..) => "PutUserStarredownerrepo",
+  EndPoints::DeleteUserStarredownerrepo(..) => "DeleteUserStarredownerrepo",
+  EndPoints::GetUserSubscriptions(..) => "GetUserSubscriptions",
+  EndPoints::GetUserTeams(..) => "GetUserTeams",
+  EndPoints::GetUsers(..) => "GetUsers",
+  EndPoints::GetUsersusername(..) => "GetUsersusername",
+  EndPoints::GetUsersusernameEvents(..) => "GetUsersusernameEvents",
+  EndPoints::GetUsersusernameEventsOrgsorg(..) => "GetUsersusernameEventsOrgsorg",
+  EndPoints::GetUsersusernameEventsPublic(..) => "GetUsersusernameEventsPublic",
+  EndPoints::GetUsersusernameFollowers(..) => "GetUsersusernameFollowers",
+  EndPoints::GetUsersusernameFollowing(..) => "GetUsersusernameFollowing",
+  EndPoints::GetUsersusernameFollowingtargetUser(..) => "GetUsersusernameFollowingtargetUser",
+  EndPoints::GetUsersusernameGists(..) => "GetUsersusernameGists",
+  EndPoints::GetUsersusernameGpgKeys(..) => "GetUsersusernameGpgKeys",
+  EndPoints::GetUsersusernameHovercard(..) => "GetUsersusernameHovercard",
+  EndPoints::GetUsersusernameInstallation(..) => "GetUsersusernameInstallation",
+  EndPoints::GetUsersusernameKeys(..) => "GetUsersusernameKeys",
+  EndPoints::GetUsersusernameOrgs(..) => "GetUsersusernameOrgs",
+  EndPoints::GetUsersusernamePackages(..) => "GetUsersusernamePackages",
+  EndPoints::GetUsersusernamePackagespackageTypepackageName(..) => "GetUsersusernamePackagespackageTypepackageName",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageName(..) => "DeleteUsersusernamePackagespackageTypepackageName",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameRestore(..) => "PostUsersusernamePackagespackageTypepackageNameRestore",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersions(..) => "GetUsersusernamePackagespackageTypepackageNameVersions",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore",
+  EndPoints::GetUsersusernameProjects(..) => "GetUsersusernameProjects",
+  EndPoints::GetUsersusernameReceivedEvents(..) => "GetUsersusernameReceivedEvents",
+  EndPoints::GetUsersusernameReceivedEventsPublic(..) => "GetUsersusernameReceivedEventsPublic",
+  EndPoints::GetUsersusernameRepos(..) => "GetUsersusernameRepos",
+  EndPoints::GetUsersusernameSettingsBillingActions(..) => "GetUsersusernameSettingsBillingActions",
+  EndPoints::GetUsersusernameSettingsBillingPackages(..) => "GetUsersusernameSettingsBillingPackages",
+  EndPoints::GetUsersusernameSettingsBillingSharedStorage(..) => "GetUsersusernameSettingsBillingSharedStorage",
+  EndPoints::GetUsersusernameStarred(..) => "GetUsersusernameStarred",
+  EndPoints::GetUsersusernameSubscriptions(..) => "GetUsersusernameSubscriptions",
+  EndPoints::GetZen(..) => "GetZen"}
+    }
+    pub const fn template(&self) -> &'static str {
+        match *self { EndPoints::Get(..) => "/",
+  EndPoints::GetApp(..) => "/app",
+  EndPoints::PostAppManifestscodeConversions(..) => "/app-manifests/{code}/conversions",
+  EndPoints::GetAppHookConfig(..) => "/app/hook/config",
+  EndPoints::PatchAppHookConfig(..) => "/app/hook/config",
+  EndPoints::GetAppHookDeliveries(..) => "/app/hook/deliveries",
+  EndPoints::GetAppHookDeliveriesdeliveryId(..) => "/app/hook/deliveries/{delivery_id}",
+  EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(..) => "/app/hook/deliveries/{delivery_id}/attempts",
+  EndPoints::GetAppInstallations(..) => "/app/installations",
+  EndPoints::GetAppInstallationsinstallationId(..) => "/app/installations/{installation_id}",
+  EndPoints::DeleteAppInstallationsinstallationId(..) => "/app/installations/{installation_id}",
+  EndPoints::PostAppInstallationsinstallationIdAccessTokens(..) => "/app/installations/{installation_id}/access_tokens",
+  EndPoints::PutAppInstallationsinstallationIdSuspended(..) => "/app/installations/{installation_id}/suspended",
+  EndPoints::DeleteAppInstallationsinstallationIdSuspended(..) => "/app/installations/{installation_id}/suspended",
+  EndPoints::GetApplicationsGrants(..) => "/applications/grants",
+  EndPoints::GetApplicationsGrantsgrantId(..) => "/applications/grants/{grant_id}",
+  EndPoints::DeleteApplicationsGrantsgrantId(..) => "/applications/grants/{grant_id}",
+  EndPoints::DeleteApplicationsclientIdGrant(..) => "/applications/{client_id}/grant",
+  EndPoints::PostApplicationsclientIdToken(..) => "/applications/{client_id}/token",
+  EndPoints::PatchApplicationsclientIdToken(..) => "/applications/{client_id}/token",
+  EndPoints::DeleteApplicationsclientIdToken(..) => "/applications/{client_id}/token",
+  EndPoints::PostApplicationsclientIdTokenScoped(..) => "/applications/{client_id}/token/scoped",
+  EndPoints::GetAppsappSlug(..) => "/apps/{app_slug}",
+  EndPoints::GetAuthorizations(..) => "/authorizations",
+  EndPoints::PostAuthorizations(..) => "/authorizations",
+  EndPoints::PutAuthorizationsClientsclientId(..) => "/authorizations/clients/{client_id}",
+  EndPoints::PutAuthorizationsClientsclientIdfingerprint(..) => "/authorizations/clients/{client_id}/{fingerprint}",
+  EndPoints::GetAuthorizationsauthorizationId(..) => "/authorizations/{authorization_id}",
+  EndPoints::PatchAuthorizationsauthorizationId(..) => "/authorizations/{authorization_id}",
+  EndPoints::DeleteAuthorizationsauthorizationId(..) => "/authorizations/{authorization_id}",
+  EndPoints::GetCodesOfConduct(..) => "/codes_of_conduct",
+  EndPoints::GetCodesOfConductkey(..) => "/codes_of_conduct/{key}",
+  EndPoints::GetEmojis(..) => "/emojis",
+  EndPoints::GetEnterprisesenterpriseActionsPermissions(..) => "/enterprises/{enterprise}/actions/permissions",
+  EndPoints::PutEnterprisesenterpriseActionsPermissions(..) => "/enterprises/{enterprise}/actions/permissions",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsOrganizations(..) => "/enterprises/{enterprise}/actions/permissions/organizations",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizations(..) => "/enterprises/{enterprise}/actions/permissions/organizations",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}",
+  EndPoints::DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "/enterprises/{enterprise}/actions/permissions/selected-actions",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "/enterprises/{enterprise}/actions/permissions/selected-actions",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroups(..) => "/enterprises/{enterprise}/actions/runner-groups",
+  EndPoints::PostEnterprisesenterpriseActionsRunnerGroups(..) => "/enterprises/{enterprise}/actions/runner-groups",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+  EndPoints::PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+  EndPoints::GetEnterprisesenterpriseActionsRunners(..) => "/enterprises/{enterprise}/actions/runners",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersDownloads(..) => "/enterprises/{enterprise}/actions/runners/downloads",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(..) => "/enterprises/{enterprise}/actions/runners/registration-token",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(..) => "/enterprises/{enterprise}/actions/runners/remove-token",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerId(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerId(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+  EndPoints::PutEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname(..) => "/enterprises/{enterprise}/actions/runners/{runner_id}/labels/{name}",
+  EndPoints::GetEnterprisesenterpriseAuditLog(..) => "/enterprises/{enterprise}/audit-log",
+  EndPoints::GetEnterprisesenterpriseSecretScanningAlerts(..) => "/enterprises/{enterprise}/secret-scanning/alerts",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingActions(..) => "/enterprises/{enterprise}/settings/billing/actions",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingAdvancedSecurity(..) => "/enterprises/{enterprise}/settings/billing/advanced-security",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingPackages(..) => "/enterprises/{enterprise}/settings/billing/packages",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingSharedStorage(..) => "/enterprises/{enterprise}/settings/billing/shared-storage",
+  EndPoints::GetEvents(..) => "/events",
+  EndPoints::GetFeeds(..) => "/feeds",
+  EndPoints::GetGists(..) => "/gists",
+  EndPoints::PostGists(..) => "/gists",
+  EndPoints::GetGistsPublic(..) => "/gists/public",
+  EndPoints::GetGistsStarred(..) => "/gists/starred",
+  EndPoints::GetGistsgistId(..) => "/gists/{gist_id}",
+  EndPoints::PatchGistsgistId(..) => "/gists/{gist_id}",
+  EndPoints::DeleteGistsgistId(..) => "/gists/{gist_id}",
+  EndPoints::GetGistsgistIdComments(..) => "/gists/{gist_id}/comments",
+  EndPoints::PostGistsgistIdComments(..) => "/gists/{gist_id}/comments",
+  EndPoints::GetGistsgistIdCommentscommentId(..) => "/gists/{gist_id}/comments/{comment_id}",
+  EndPoints::PatchGistsgistIdCommentscommentId(..) => "/gists/{gist_id}/comments/{comment_id}",
+  EndPoints::DeleteGistsgistIdCommentscommentId(..) => "/gists/{gist_id}/comments/{comment_id}",
+  EndPoints::GetGistsgistIdCommits(..) => "/gists/{gist_id}/commits",
+  EndPoints::GetGistsgistIdForks(..) => "/gists/{gist_id}/forks",
+  EndPoints::PostGistsgistIdForks(..) => "/gists/{gist_id}/forks",
+  EndPoints::GetGistsgistIdStar(..) => "/gists/{gist_id}/star",
+  EndPoints::PutGistsgistIdStar(..) => "/gists/{gist_id}/star",
+  EndPoints::DeleteGistsgistIdStar(..) => "/gists/{gist_id}/star",
+  EndPoints::GetGistsgistIdsha(..) => "/gists/{gist_id}/{sha}",
+  EndPoints::GetGitignoreTemplates(..) => "/gitignore/templates",
+  EndPoints::GetGitignoreTemplatesname(..) => "/gitignore/templates/{name}",
+  EndPoints::GetInstallationRepositories(..) => "/installation/repositories",
+  EndPoints::DeleteInstallationToken(..) => "/installation/token",
+  EndPoints::GetIssues(..) => "/issues",
+  EndPoints::GetLicenses(..) => "/licenses",
+  EndPoints::GetLicenseslicense(..) => "/licenses/{license}",
+  EndPoints::PostMarkdown(..) => "/markdown",
+  EndPoints::PostMarkdownRaw(..) => "/markdown/raw",
+  EndPoints::GetMarketplaceListingAccountsaccountId(..) => "/marketplace_listing/accounts/{account_id}",
+  EndPoints::GetMarketplaceListingPlans(..) => "/marketplace_listing/plans",
+  EndPoints::GetMarketplaceListingPlansplanIdAccounts(..) => "/marketplace_listing/plans/{plan_id}/accounts",
+  EndPoints::GetMarketplaceListingStubbedAccountsaccountId(..) => "/marketplace_listing/stubbed/accounts/{account_id}",
+  EndPoints::GetMarketplaceListingStubbedPlans(..) => "/marketplace_listing/stubbed/plans",
+  EndPoints::GetMarketplaceListingStubbedPlansplanIdAccounts(..) => "/marketplace_listing/stubbed/plans/{plan_id}/accounts",
+  EndPoints::GetMeta(..) => "/meta",
+  EndPoints::GetNetworksownerrepoEvents(..) => "/networks/{owner}/{repo}/events",
+  EndPoints::GetNotifications(..) => "/notifications",
+  EndPoints::PutNotifications(..) => "/notifications",
+  EndPoints::GetNotificationsThreadsthreadId(..) => "/notifications/threads/{thread_id}",
+  EndPoints::PatchNotificationsThreadsthreadId(..) => "/notifications/threads/{thread_id}",
+  EndPoints::GetNotificationsThreadsthreadIdSubscription(..) => "/notifications/threads/{thread_id}/subscription",
+  EndPoints::PutNotificationsThreadsthreadIdSubscription(..) => "/notifications/threads/{thread_id}/subscription",
+  EndPoints::DeleteNotificationsThreadsthreadIdSubscription(..) => "/notifications/threads/{thread_id}/subscription",
+  EndPoints::GetOctocat(..) => "/octocat",
+  EndPoints::GetOrganizations(..) => "/organizations",
+  EndPoints::GetOrganizationsorganizationIdCustomRoles(..) => "/organizations/{organization_id}/custom_roles",
+  EndPoints::GetOrganizationsorgTeamteamSlugExternalGroups(..) => "/organizations/{org}/team/{team_slug}/external-groups",
+  EndPoints::GetOrgsorg(..) => "/orgs/{org}",
+  EndPoints::PatchOrgsorg(..) => "/orgs/{org}",
+  EndPoints::GetOrgsorgActionsPermissions(..) => "/orgs/{org}/actions/permissions",
+  EndPoints::PutOrgsorgActionsPermissions(..) => "/orgs/{org}/actions/permissions",
+  EndPoints::GetOrgsorgActionsPermissionsRepositories(..) => "/orgs/{org}/actions/permissions/repositories",
+  EndPoints::PutOrgsorgActionsPermissionsRepositories(..) => "/orgs/{org}/actions/permissions/repositories",
+  EndPoints::PutOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+  EndPoints::DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "/orgs/{org}/actions/permissions/repositories/{repository_id}",
+  EndPoints::GetOrgsorgActionsPermissionsSelectedActions(..) => "/orgs/{org}/actions/permissions/selected-actions",
+  EndPoints::PutOrgsorgActionsPermissionsSelectedActions(..) => "/orgs/{org}/actions/permissions/selected-actions",
+  EndPoints::GetOrgsorgActionsPermissionsWorkflow(..) => "/orgs/{org}/actions/permissions/workflow",
+  EndPoints::PutOrgsorgActionsPermissionsWorkflow(..) => "/orgs/{org}/actions/permissions/workflow",
+  EndPoints::GetOrgsorgActionsRunnerGroups(..) => "/orgs/{org}/actions/runner-groups",
+  EndPoints::PostOrgsorgActionsRunnerGroups(..) => "/orgs/{org}/actions/runner-groups",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+  EndPoints::PatchOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}",
+  EndPoints::GetOrgsorgActionsRunners(..) => "/orgs/{org}/actions/runners",
+  EndPoints::GetOrgsorgActionsRunnersDownloads(..) => "/orgs/{org}/actions/runners/downloads",
+  EndPoints::PostOrgsorgActionsRunnersRegistrationToken(..) => "/orgs/{org}/actions/runners/registration-token",
+  EndPoints::PostOrgsorgActionsRunnersRemoveToken(..) => "/orgs/{org}/actions/runners/remove-token",
+  EndPoints::GetOrgsorgActionsRunnersrunnerId(..) => "/orgs/{org}/actions/runners/{runner_id}",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerId(..) => "/orgs/{org}/actions/runners/{runner_id}",
+  EndPoints::GetOrgsorgActionsRunnersrunnerIdLabels(..) => "/orgs/{org}/actions/runners/{runner_id}/labels",
+  EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(..) => "/orgs/{org}/actions/runners/{runner_id}/labels",
+  EndPoints::PutOrgsorgActionsRunnersrunnerIdLabels(..) => "/orgs/{org}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabels(..) => "/orgs/{org}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabelsname(..) => "/orgs/{org}/actions/runners/{runner_id}/labels/{name}",
+  EndPoints::GetOrgsorgActionsSecrets(..) => "/orgs/{org}/actions/secrets",
+  EndPoints::GetOrgsorgActionsSecretsPublicKey(..) => "/orgs/{org}/actions/secrets/public-key",
+  EndPoints::GetOrgsorgActionsSecretssecretName(..) => "/orgs/{org}/actions/secrets/{secret_name}",
+  EndPoints::PutOrgsorgActionsSecretssecretName(..) => "/orgs/{org}/actions/secrets/{secret_name}",
+  EndPoints::DeleteOrgsorgActionsSecretssecretName(..) => "/orgs/{org}/actions/secrets/{secret_name}",
+  EndPoints::GetOrgsorgActionsSecretssecretNameRepositories(..) => "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositories(..) => "/orgs/{org}/actions/secrets/{secret_name}/repositories",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::GetOrgsorgAuditLog(..) => "/orgs/{org}/audit-log",
+  EndPoints::GetOrgsorgBlocks(..) => "/orgs/{org}/blocks",
+  EndPoints::GetOrgsorgBlocksusername(..) => "/orgs/{org}/blocks/{username}",
+  EndPoints::PutOrgsorgBlocksusername(..) => "/orgs/{org}/blocks/{username}",
+  EndPoints::DeleteOrgsorgBlocksusername(..) => "/orgs/{org}/blocks/{username}",
+  EndPoints::GetOrgsorgCodeScanningAlerts(..) => "/orgs/{org}/code-scanning/alerts",
+  EndPoints::GetOrgsorgCredentialAuthorizations(..) => "/orgs/{org}/credential-authorizations",
+  EndPoints::DeleteOrgsorgCredentialAuthorizationscredentialId(..) => "/orgs/{org}/credential-authorizations/{credential_id}",
+  EndPoints::GetOrgsorgDependabotSecrets(..) => "/orgs/{org}/dependabot/secrets",
+  EndPoints::GetOrgsorgDependabotSecretsPublicKey(..) => "/orgs/{org}/dependabot/secrets/public-key",
+  EndPoints::GetOrgsorgDependabotSecretssecretName(..) => "/orgs/{org}/dependabot/secrets/{secret_name}",
+  EndPoints::PutOrgsorgDependabotSecretssecretName(..) => "/orgs/{org}/dependabot/secrets/{secret_name}",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretName(..) => "/orgs/{org}/dependabot/secrets/{secret_name}",
+  EndPoints::GetOrgsorgDependabotSecretssecretNameRepositories(..) => "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositories(..) => "/orgs/{org}/dependabot/secrets/{secret_name}/repositories",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::GetOrgsorgEvents(..) => "/orgs/{org}/events",
+  EndPoints::GetOrgsorgExternalGroupgroupId(..) => "/orgs/{org}/external-group/{group_id}",
+  EndPoints::GetOrgsorgExternalGroups(..) => "/orgs/{org}/external-groups",
+  EndPoints::GetOrgsorgFailedInvitations(..) => "/orgs/{org}/failed_invitations",
+  EndPoints::GetOrgsorgHooks(..) => "/orgs/{org}/hooks",
+  EndPoints::PostOrgsorgHooks(..) => "/orgs/{org}/hooks",
+  EndPoints::GetOrgsorgHookshookId(..) => "/orgs/{org}/hooks/{hook_id}",
+  EndPoints::PatchOrgsorgHookshookId(..) => "/orgs/{org}/hooks/{hook_id}",
+  EndPoints::DeleteOrgsorgHookshookId(..) => "/orgs/{org}/hooks/{hook_id}",
+  EndPoints::GetOrgsorgHookshookIdConfig(..) => "/orgs/{org}/hooks/{hook_id}/config",
+  EndPoints::PatchOrgsorgHookshookIdConfig(..) => "/orgs/{org}/hooks/{hook_id}/config",
+  EndPoints::GetOrgsorgHookshookIdDeliveries(..) => "/orgs/{org}/hooks/{hook_id}/deliveries",
+  EndPoints::GetOrgsorgHookshookIdDeliveriesdeliveryId(..) => "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}",
+  EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(..) => "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+  EndPoints::PostOrgsorgHookshookIdPings(..) => "/orgs/{org}/hooks/{hook_id}/pings",
+  EndPoints::GetOrgsorgInstallation(..) => "/orgs/{org}/installation",
+  EndPoints::GetOrgsorgInstallations(..) => "/orgs/{org}/installations",
+  EndPoints::GetOrgsorgInteractionLimits(..) => "/orgs/{org}/interaction-limits",
+  EndPoints::PutOrgsorgInteractionLimits(..) => "/orgs/{org}/interaction-limits",
+  EndPoints::DeleteOrgsorgInteractionLimits(..) => "/orgs/{org}/interaction-limits",
+  EndPoints::GetOrgsorgInvitations(..) => "/orgs/{org}/invitations",
+  EndPoints::PostOrgsorgInvitations(..) => "/orgs/{org}/invitations",
+  EndPoints::DeleteOrgsorgInvitationsinvitationId(..) => "/orgs/{org}/invitations/{invitation_id}",
+  EndPoints::GetOrgsorgInvitationsinvitationIdTeams(..) => "/orgs/{org}/invitations/{invitation_id}/teams",
+  EndPoints::GetOrgsorgIssues(..) => "/orgs/{org}/issues",
+  EndPoints::GetOrgsorgMembers(..) => "/orgs/{org}/members",
+  EndPoints::GetOrgsorgMembersusername(..) => "/orgs/{org}/members/{username}",
+  EndPoints::DeleteOrgsorgMembersusername(..) => "/orgs/{org}/members/{username}",
+  EndPoints::GetOrgsorgMembershipsusername(..) => "/orgs/{org}/memberships/{username}",
+  EndPoints::PutOrgsorgMembershipsusername(..) => "/orgs/{org}/memberships/{username}",
+  EndPoints::DeleteOrgsorgMembershipsusername(..) => "/orgs/{org}/memberships/{username}",
+  EndPoints::GetOrgsorgMigrations(..) => "/orgs/{org}/migrations",
+  EndPoints::PostOrgsorgMigrations(..) => "/orgs/{org}/migrations",
+  EndPoints::GetOrgsorgMigrationsmigrationId(..) => "/orgs/{org}/migrations/{migration_id}",
+  EndPoints::GetOrgsorgMigrationsmigrationIdArchive(..) => "/orgs/{org}/migrations/{migration_id}/archive",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdArchive(..) => "/orgs/{org}/migrations/{migration_id}/archive",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock(..) => "/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock",
+  EndPoints::GetOrgsorgMigrationsmigrationIdRepositories(..) => "/orgs/{org}/migrations/{migration_id}/repositories",
+  EndPoints::GetOrgsorgOutsideCollaborators(..) => "/orgs/{org}/outside_collaborators",
+  EndPoints::PutOrgsorgOutsideCollaboratorsusername(..) => "/orgs/{org}/outside_collaborators/{username}",
+  EndPoints::DeleteOrgsorgOutsideCollaboratorsusername(..) => "/orgs/{org}/outside_collaborators/{username}",
+  EndPoints::GetOrgsorgPackages(..) => "/orgs/{org}/packages",
+  EndPoints::GetOrgsorgPackagespackageTypepackageName(..) => "/orgs/{org}/packages/{package_type}/{package_name}",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageName(..) => "/orgs/{org}/packages/{package_type}/{package_name}",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameRestore(..) => "/orgs/{org}/packages/{package_type}/{package_name}/restore",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersions(..) => "/orgs/{org}/packages/{package_type}/{package_name}/versions",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  EndPoints::GetOrgsorgProjects(..) => "/orgs/{org}/projects",
+  EndPoints::PostOrgsorgProjects(..) => "/orgs/{org}/projects",
+  EndPoints::GetOrgsorgPublicMembers(..) => "/orgs/{org}/public_members",
+  EndPoints::GetOrgsorgPublicMembersusername(..) => "/orgs/{org}/public_members/{username}",
+  EndPoints::PutOrgsorgPublicMembersusername(..) => "/orgs/{org}/public_members/{username}",
+  EndPoints::DeleteOrgsorgPublicMembersusername(..) => "/orgs/{org}/public_members/{username}",
+  EndPoints::GetOrgsorgRepos(..) => "/orgs/{org}/repos",
+  EndPoints::PostOrgsorgRepos(..) => "/orgs/{org}/repos",
+  EndPoints::GetOrgsorgSecretScanningAlerts(..) => "/orgs/{org}/secret-scanning/alerts",
+  EndPoints::GetOrgsorgSettingsBillingActions(..) => "/orgs/{org}/settings/billing/actions",
+  EndPoints::GetOrgsorgSettingsBillingAdvancedSecurity(..) => "/orgs/{org}/settings/billing/advanced-security",
+  EndPoints::GetOrgsorgSettingsBillingPackages(..) => "/orgs/{org}/settings/billing/packages",
+  EndPoints::GetOrgsorgSettingsBillingSharedStorage(..) => "/orgs/{org}/settings/billing/shared-storage",
+  EndPoints::GetOrgsorgTeamSyncGroups(..) => "/orgs/{org}/team-sync/groups",
+  EndPoints::GetOrgsorgTeams(..) => "/orgs/{org}/teams",
+  EndPoints::PostOrgsorgTeams(..) => "/orgs/{org}/teams",
+  EndPoints::GetOrgsorgTeamsteamSlug(..) => "/orgs/{org}/teams/{team_slug}",
+  EndPoints::PatchOrgsorgTeamsteamSlug(..) => "/orgs/{org}/teams/{team_slug}",
+  EndPoints::DeleteOrgsorgTeamsteamSlug(..) => "/orgs/{org}/teams/{team_slug}",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussions(..) => "/orgs/{org}/teams/{team_slug}/discussions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussions(..) => "/orgs/{org}/teams/{team_slug}/discussions",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId(..) => "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}",
+  EndPoints::PatchOrgsorgTeamsteamSlugExternalGroups(..) => "/orgs/{org}/teams/{team_slug}/external-groups",
+  EndPoints::DeleteOrgsorgTeamsteamSlugExternalGroups(..) => "/orgs/{org}/teams/{team_slug}/external-groups",
+  EndPoints::GetOrgsorgTeamsteamSlugInvitations(..) => "/orgs/{org}/teams/{team_slug}/invitations",
+  EndPoints::GetOrgsorgTeamsteamSlugMembers(..) => "/orgs/{org}/teams/{team_slug}/members",
+  EndPoints::GetOrgsorgTeamsteamSlugMembershipsusername(..) => "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  EndPoints::PutOrgsorgTeamsteamSlugMembershipsusername(..) => "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  EndPoints::DeleteOrgsorgTeamsteamSlugMembershipsusername(..) => "/orgs/{org}/teams/{team_slug}/memberships/{username}",
+  EndPoints::GetOrgsorgTeamsteamSlugProjects(..) => "/orgs/{org}/teams/{team_slug}/projects",
+  EndPoints::GetOrgsorgTeamsteamSlugProjectsprojectId(..) => "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  EndPoints::PutOrgsorgTeamsteamSlugProjectsprojectId(..) => "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  EndPoints::DeleteOrgsorgTeamsteamSlugProjectsprojectId(..) => "/orgs/{org}/teams/{team_slug}/projects/{project_id}",
+  EndPoints::GetOrgsorgTeamsteamSlugRepos(..) => "/orgs/{org}/teams/{team_slug}/repos",
+  EndPoints::GetOrgsorgTeamsteamSlugReposownerrepo(..) => "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  EndPoints::PutOrgsorgTeamsteamSlugReposownerrepo(..) => "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  EndPoints::DeleteOrgsorgTeamsteamSlugReposownerrepo(..) => "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}",
+  EndPoints::GetOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings",
+  EndPoints::PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings",
+  EndPoints::GetOrgsorgTeamsteamSlugTeams(..) => "/orgs/{org}/teams/{team_slug}/teams",
+  EndPoints::GetProjectsColumnsCardscardId(..) => "/projects/columns/cards/{card_id}",
+  EndPoints::PatchProjectsColumnsCardscardId(..) => "/projects/columns/cards/{card_id}",
+  EndPoints::DeleteProjectsColumnsCardscardId(..) => "/projects/columns/cards/{card_id}",
+  EndPoints::PostProjectsColumnsCardscardIdMoves(..) => "/projects/columns/cards/{card_id}/moves",
+  EndPoints::GetProjectsColumnscolumnId(..) => "/projects/columns/{column_id}",
+  EndPoints::PatchProjectsColumnscolumnId(..) => "/projects/columns/{column_id}",
+  EndPoints::DeleteProjectsColumnscolumnId(..) => "/projects/columns/{column_id}",
+  EndPoints::GetProjectsColumnscolumnIdCards(..) => "/projects/columns/{column_id}/cards",
+  EndPoints::PostProjectsColumnscolumnIdCards(..) => "/projects/columns/{column_id}/cards",
+  EndPoints::PostProjectsColumnscolumnIdMoves(..) => "/projects/columns/{column_id}/moves",
+  EndPoints::GetProjectsprojectId(..) => "/projects/{project_id}",
+  EndPoints::PatchProjectsprojectId(..) => "/projects/{project_id}",
+  EndPoints::DeleteProjectsprojectId(..) => "/projects/{project_id}",
+  EndPoints::GetProjectsprojectIdCollaborators(..) => "/projects/{project_id}/collaborators",
+  EndPoints::PutProjectsprojectIdCollaboratorsusername(..) => "/projects/{project_id}/collaborators/{username}",
+  EndPoints::DeleteProjectsprojectIdCollaboratorsusername(..) => "/projects/{project_id}/collaborators/{username}",
+  EndPoints::GetProjectsprojectIdCollaboratorsusernamePermission(..) => "/projects/{project_id}/collaborators/{username}/permission",
+  EndPoints::GetProjectsprojectIdColumns(..) => "/projects/{project_id}/columns",
+  EndPoints::PostProjectsprojectIdColumns(..) => "/projects/{project_id}/columns",
+  EndPoints::GetRateLimit(..) => "/rate_limit",
+  EndPoints::DeleteReactionsreactionId(..) => "/reactions/{reaction_id}",
+  EndPoints::GetReposownerrepo(..) => "/repos/{owner}/{repo}",
+  EndPoints::PatchReposownerrepo(..) => "/repos/{owner}/{repo}",
+  EndPoints::DeleteReposownerrepo(..) => "/repos/{owner}/{repo}",
+  EndPoints::GetReposownerrepoActionsArtifacts(..) => "/repos/{owner}/{repo}/actions/artifacts",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactId(..) => "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+  EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(..) => "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(..) => "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}",
+  EndPoints::GetReposownerrepoActionsJobsjobId(..) => "/repos/{owner}/{repo}/actions/jobs/{job_id}",
+  EndPoints::GetReposownerrepoActionsJobsjobIdLogs(..) => "/repos/{owner}/{repo}/actions/jobs/{job_id}/logs",
+  EndPoints::GetReposownerrepoActionsPermissions(..) => "/repos/{owner}/{repo}/actions/permissions",
+  EndPoints::PutReposownerrepoActionsPermissions(..) => "/repos/{owner}/{repo}/actions/permissions",
+  EndPoints::GetReposownerrepoActionsPermissionsSelectedActions(..) => "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+  EndPoints::PutReposownerrepoActionsPermissionsSelectedActions(..) => "/repos/{owner}/{repo}/actions/permissions/selected-actions",
+  EndPoints::GetReposownerrepoActionsRunners(..) => "/repos/{owner}/{repo}/actions/runners",
+  EndPoints::GetReposownerrepoActionsRunnersDownloads(..) => "/repos/{owner}/{repo}/actions/runners/downloads",
+  EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(..) => "/repos/{owner}/{repo}/actions/runners/registration-token",
+  EndPoints::PostReposownerrepoActionsRunnersRemoveToken(..) => "/repos/{owner}/{repo}/actions/runners/remove-token",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerId(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerId(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerIdLabels(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  EndPoints::PutReposownerrepoActionsRunnersrunnerIdLabels(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabels(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabelsname(..) => "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels/{name}",
+  EndPoints::GetReposownerrepoActionsRuns(..) => "/repos/{owner}/{repo}/actions/runs",
+  EndPoints::GetReposownerrepoActionsRunsrunId(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}",
+  EndPoints::DeleteReposownerrepoActionsRunsrunId(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}",
+  EndPoints::GetReposownerrepoActionsRunsrunIdApprovals(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/approvals",
+  EndPoints::PostReposownerrepoActionsRunsrunIdApprove(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/approve",
+  EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/jobs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/logs",
+  EndPoints::PostReposownerrepoActionsRunsrunIdCancel(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/cancel",
+  EndPoints::GetReposownerrepoActionsRunsrunIdJobs(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/jobs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdLogs(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+  EndPoints::DeleteReposownerrepoActionsRunsrunIdLogs(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/logs",
+  EndPoints::GetReposownerrepoActionsRunsrunIdPendingDeployments(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+  EndPoints::PostReposownerrepoActionsRunsrunIdPendingDeployments(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments",
+  EndPoints::PostReposownerrepoActionsRunsrunIdRerun(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun",
+  EndPoints::GetReposownerrepoActionsRunsrunIdTiming(..) => "/repos/{owner}/{repo}/actions/runs/{run_id}/timing",
+  EndPoints::GetReposownerrepoActionsSecrets(..) => "/repos/{owner}/{repo}/actions/secrets",
+  EndPoints::GetReposownerrepoActionsSecretsPublicKey(..) => "/repos/{owner}/{repo}/actions/secrets/public-key",
+  EndPoints::GetReposownerrepoActionsSecretssecretName(..) => "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  EndPoints::PutReposownerrepoActionsSecretssecretName(..) => "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  EndPoints::DeleteReposownerrepoActionsSecretssecretName(..) => "/repos/{owner}/{repo}/actions/secrets/{secret_name}",
+  EndPoints::GetReposownerrepoActionsWorkflows(..) => "/repos/{owner}/{repo}/actions/workflows",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdDisable(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable",
+  EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdEnable(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdTiming(..) => "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing",
+  EndPoints::GetReposownerrepoAssignees(..) => "/repos/{owner}/{repo}/assignees",
+  EndPoints::GetReposownerrepoAssigneesassignee(..) => "/repos/{owner}/{repo}/assignees/{assignee}",
+  EndPoints::GetReposownerrepoAutolinks(..) => "/repos/{owner}/{repo}/autolinks",
+  EndPoints::PostReposownerrepoAutolinks(..) => "/repos/{owner}/{repo}/autolinks",
+  EndPoints::GetReposownerrepoAutolinksautolinkId(..) => "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+  EndPoints::DeleteReposownerrepoAutolinksautolinkId(..) => "/repos/{owner}/{repo}/autolinks/{autolink_id}",
+  EndPoints::PutReposownerrepoAutomatedSecurityFixes(..) => "/repos/{owner}/{repo}/automated-security-fixes",
+  EndPoints::DeleteReposownerrepoAutomatedSecurityFixes(..) => "/repos/{owner}/{repo}/automated-security-fixes",
+  EndPoints::GetReposownerrepoBranches(..) => "/repos/{owner}/{repo}/branches",
+  EndPoints::GetReposownerrepoBranchesbranch(..) => "/repos/{owner}/{repo}/branches/{branch}",
+  EndPoints::GetReposownerrepoBranchesbranchProtection(..) => "/repos/{owner}/{repo}/branches/{branch}/protection",
+  EndPoints::PutReposownerrepoBranchesbranchProtection(..) => "/repos/{owner}/{repo}/branches/{branch}/protection",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtection(..) => "/repos/{owner}/{repo}/branches/{branch}/protection",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictions(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictions(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users",
+  EndPoints::PostReposownerrepoBranchesbranchRename(..) => "/repos/{owner}/{repo}/branches/{branch}/rename",
+  EndPoints::PostReposownerrepoCheckRuns(..) => "/repos/{owner}/{repo}/check-runs",
+  EndPoints::GetReposownerrepoCheckRunscheckRunId(..) => "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+  EndPoints::PatchReposownerrepoCheckRunscheckRunId(..) => "/repos/{owner}/{repo}/check-runs/{check_run_id}",
+  EndPoints::GetReposownerrepoCheckRunscheckRunIdAnnotations(..) => "/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations",
+  EndPoints::PostReposownerrepoCheckRunscheckRunIdRerequest(..) => "/repos/{owner}/{repo}/check-runs/{check_run_id}/rerequest",
+  EndPoints::PostReposownerrepoCheckSuites(..) => "/repos/{owner}/{repo}/check-suites",
+  EndPoints::PatchReposownerrepoCheckSuitesPreferences(..) => "/repos/{owner}/{repo}/check-suites/preferences",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteId(..) => "/repos/{owner}/{repo}/check-suites/{check_suite_id}",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(..) => "/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs",
+  EndPoints::PostReposownerrepoCheckSuitescheckSuiteIdRerequest(..) => "/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest",
+  EndPoints::GetReposownerrepoCodeScanningAlerts(..) => "/repos/{owner}/{repo}/code-scanning/alerts",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumber(..) => "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+  EndPoints::PatchReposownerrepoCodeScanningAlertsalertNumber(..) => "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumberInstances(..) => "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances",
+  EndPoints::GetReposownerrepoCodeScanningAnalyses(..) => "/repos/{owner}/{repo}/code-scanning/analyses",
+  EndPoints::GetReposownerrepoCodeScanningAnalysesanalysisId(..) => "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+  EndPoints::DeleteReposownerrepoCodeScanningAnalysesanalysisId(..) => "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}",
+  EndPoints::PostReposownerrepoCodeScanningSarifs(..) => "/repos/{owner}/{repo}/code-scanning/sarifs",
+  EndPoints::GetReposownerrepoCodeScanningSarifssarifId(..) => "/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}",
+  EndPoints::GetReposownerrepoCodespaces(..) => "/repos/{owner}/{repo}/codespaces",
+  EndPoints::PostReposownerrepoCodespaces(..) => "/repos/{owner}/{repo}/codespaces",
+  EndPoints::GetReposownerrepoCodespacesMachines(..) => "/repos/{owner}/{repo}/codespaces/machines",
+  EndPoints::GetReposownerrepoCollaborators(..) => "/repos/{owner}/{repo}/collaborators",
+  EndPoints::GetReposownerrepoCollaboratorsusername(..) => "/repos/{owner}/{repo}/collaborators/{username}",
+  EndPoints::PutReposownerrepoCollaboratorsusername(..) => "/repos/{owner}/{repo}/collaborators/{username}",
+  EndPoints::DeleteReposownerrepoCollaboratorsusername(..) => "/repos/{owner}/{repo}/collaborators/{username}",
+  EndPoints::GetReposownerrepoCollaboratorsusernamePermission(..) => "/repos/{owner}/{repo}/collaborators/{username}/permission",
+  EndPoints::GetReposownerrepoComments(..) => "/repos/{owner}/{repo}/comments",
+  EndPoints::GetReposownerrepoCommentscommentId(..) => "/repos/{owner}/{repo}/comments/{comment_id}",
+  EndPoints::PatchReposownerrepoCommentscommentId(..) => "/repos/{owner}/{repo}/comments/{comment_id}",
+  EndPoints::DeleteReposownerrepoCommentscommentId(..) => "/repos/{owner}/{repo}/comments/{comment_id}",
+  EndPoints::GetReposownerrepoCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+  EndPoints::PostReposownerrepoCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/comments/{comment_id}/reactions",
+  EndPoints::DeleteReposownerrepoCommentscommentIdReactionsreactionId(..) => "/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}",
+  EndPoints::GetReposownerrepoCommits(..) => "/repos/{owner}/{repo}/commits",
+  EndPoints::GetReposownerrepoCommitscommitShaBranchesWhereHead(..) => "/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head",
+  EndPoints::GetReposownerrepoCommitscommitShaComments(..) => "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+  EndPoints::PostReposownerrepoCommitscommitShaComments(..) => "/repos/{owner}/{repo}/commits/{commit_sha}/comments",
+  EndPoints::GetReposownerrepoCommitscommitShaPulls(..) => "/repos/{owner}/{repo}/commits/{commit_sha}/pulls",
+  EndPoints::GetReposownerrepoCommitsref(..) => "/repos/{owner}/{repo}/commits/{ref}",
+  EndPoints::GetReposownerrepoCommitsrefCheckRuns(..) => "/repos/{owner}/{repo}/commits/{ref}/check-runs",
+  EndPoints::GetReposownerrepoCommitsrefCheckSuites(..) => "/repos/{owner}/{repo}/commits/{ref}/check-suites",
+  EndPoints::GetReposownerrepoCommitsrefStatus(..) => "/repos/{owner}/{repo}/commits/{ref}/status",
+  EndPoints::GetReposownerrepoCommitsrefStatuses(..) => "/repos/{owner}/{repo}/commits/{ref}/statuses",
+  EndPoints::GetReposownerrepoCommunityProfile(..) => "/repos/{owner}/{repo}/community/profile",
+  EndPoints::GetReposownerrepoComparebasehead(..) => "/repos/{owner}/{repo}/compare/{basehead}",
+  EndPoints::GetReposownerrepoContentspath(..) => "/repos/{owner}/{repo}/contents/{path}",
+  EndPoints::PutReposownerrepoContentspath(..) => "/repos/{owner}/{repo}/contents/{path}",
+  EndPoints::DeleteReposownerrepoContentspath(..) => "/repos/{owner}/{repo}/contents/{path}",
+  EndPoints::GetReposownerrepoContributors(..) => "/repos/{owner}/{repo}/contributors",
+  EndPoints::GetReposownerrepoDependabotSecrets(..) => "/repos/{owner}/{repo}/dependabot/secrets",
+  EndPoints::GetReposownerrepoDependabotSecretsPublicKey(..) => "/repos/{owner}/{repo}/dependabot/secrets/public-key",
+  EndPoints::GetReposownerrepoDependabotSecretssecretName(..) => "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  EndPoints::PutReposownerrepoDependabotSecretssecretName(..) => "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  EndPoints::DeleteReposownerrepoDependabotSecretssecretName(..) => "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}",
+  EndPoints::GetReposownerrepoDeployments(..) => "/repos/{owner}/{repo}/deployments",
+  EndPoints::PostReposownerrepoDeployments(..) => "/repos/{owner}/{repo}/deployments",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentId(..) => "/repos/{owner}/{repo}/deployments/{deployment_id}",
+  EndPoints::DeleteReposownerrepoDeploymentsdeploymentId(..) => "/repos/{owner}/{repo}/deployments/{deployment_id}",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatuses(..) => "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+  EndPoints::PostReposownerrepoDeploymentsdeploymentIdStatuses(..) => "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId(..) => "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}",
+  EndPoints::PostReposownerrepoDispatches(..) => "/repos/{owner}/{repo}/dispatches",
+  EndPoints::GetReposownerrepoEnvironments(..) => "/repos/{owner}/{repo}/environments",
+  EndPoints::GetReposownerrepoEnvironmentsenvironmentName(..) => "/repos/{owner}/{repo}/environments/{environment_name}",
+  EndPoints::PutReposownerrepoEnvironmentsenvironmentName(..) => "/repos/{owner}/{repo}/environments/{environment_name}",
+  EndPoints::DeleteReposownerrepoEnvironmentsenvironmentName(..) => "/repos/{owner}/{repo}/environments/{environment_name}",
+  EndPoints::GetReposownerrepoEvents(..) => "/repos/{owner}/{repo}/events",
+  EndPoints::GetReposownerrepoForks(..) => "/repos/{owner}/{repo}/forks",
+  EndPoints::PostReposownerrepoForks(..) => "/repos/{owner}/{repo}/forks",
+  EndPoints::PostReposownerrepoGitBlobs(..) => "/repos/{owner}/{repo}/git/blobs",
+  EndPoints::GetReposownerrepoGitBlobsfileSha(..) => "/repos/{owner}/{repo}/git/blobs/{file_sha}",
+  EndPoints::PostReposownerrepoGitCommits(..) => "/repos/{owner}/{repo}/git/commits",
+  EndPoints::GetReposownerrepoGitCommitscommitSha(..) => "/repos/{owner}/{repo}/git/commits/{commit_sha}",
+  EndPoints::GetReposownerrepoGitMatchingRefsref(..) => "/repos/{owner}/{repo}/git/matching-refs/{ref}",
+  EndPoints::GetReposownerrepoGitRefref(..) => "/repos/{owner}/{repo}/git/ref/{ref}",
+  EndPoints::PostReposownerrepoGitRefs(..) => "/repos/{owner}/{repo}/git/refs",
+  EndPoints::PatchReposownerrepoGitRefsref(..) => "/repos/{owner}/{repo}/git/refs/{ref}",
+  EndPoints::DeleteReposownerrepoGitRefsref(..) => "/repos/{owner}/{repo}/git/refs/{ref}",
+  EndPoints::PostReposownerrepoGitTags(..) => "/repos/{owner}/{repo}/git/tags",
+  EndPoints::GetReposownerrepoGitTagstagSha(..) => "/repos/{owner}/{repo}/git/tags/{tag_sha}",
+  EndPoints::PostReposownerrepoGitTrees(..) => "/repos/{owner}/{repo}/git/trees",
+  EndPoints::GetReposownerrepoGitTreestreeSha(..) => "/repos/{owner}/{repo}/git/trees/{tree_sha}",
+  EndPoints::GetReposownerrepoHooks(..) => "/repos/{owner}/{repo}/hooks",
+  EndPoints::PostReposownerrepoHooks(..) => "/repos/{owner}/{repo}/hooks",
+  EndPoints::GetReposownerrepoHookshookId(..) => "/repos/{owner}/{repo}/hooks/{hook_id}",
+  EndPoints::PatchReposownerrepoHookshookId(..) => "/repos/{owner}/{repo}/hooks/{hook_id}",
+  EndPoints::DeleteReposownerrepoHookshookId(..) => "/repos/{owner}/{repo}/hooks/{hook_id}",
+  EndPoints::GetReposownerrepoHookshookIdConfig(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+  EndPoints::PatchReposownerrepoHookshookIdConfig(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/config",
+  EndPoints::GetReposownerrepoHookshookIdDeliveries(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries",
+  EndPoints::GetReposownerrepoHookshookIdDeliveriesdeliveryId(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}",
+  EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts",
+  EndPoints::PostReposownerrepoHookshookIdPings(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/pings",
+  EndPoints::PostReposownerrepoHookshookIdTests(..) => "/repos/{owner}/{repo}/hooks/{hook_id}/tests",
+  EndPoints::GetReposownerrepoImport(..) => "/repos/{owner}/{repo}/import",
+  EndPoints::PutReposownerrepoImport(..) => "/repos/{owner}/{repo}/import",
+  EndPoints::PatchReposownerrepoImport(..) => "/repos/{owner}/{repo}/import",
+  EndPoints::DeleteReposownerrepoImport(..) => "/repos/{owner}/{repo}/import",
+  EndPoints::GetReposownerrepoImportAuthors(..) => "/repos/{owner}/{repo}/import/authors",
+  EndPoints::PatchReposownerrepoImportAuthorsauthorId(..) => "/repos/{owner}/{repo}/import/authors/{author_id}",
+  EndPoints::GetReposownerrepoImportLargeFiles(..) => "/repos/{owner}/{repo}/import/large_files",
+  EndPoints::PatchReposownerrepoImportLfs(..) => "/repos/{owner}/{repo}/import/lfs",
+  EndPoints::GetReposownerrepoInstallation(..) => "/repos/{owner}/{repo}/installation",
+  EndPoints::GetReposownerrepoInteractionLimits(..) => "/repos/{owner}/{repo}/interaction-limits",
+  EndPoints::PutReposownerrepoInteractionLimits(..) => "/repos/{owner}/{repo}/interaction-limits",
+  EndPoints::DeleteReposownerrepoInteractionLimits(..) => "/repos/{owner}/{repo}/interaction-limits",
+  EndPoints::GetReposownerrepoInvitations(..) => "/repos/{owner}/{repo}/invitations",
+  EndPoints::PatchReposownerrepoInvitationsinvitationId(..) => "/repos/{owner}/{repo}/invitations/{invitation_id}",
+  EndPoints::DeleteReposownerrepoInvitationsinvitationId(..) => "/repos/{owner}/{repo}/invitations/{invitation_id}",
+  EndPoints::GetReposownerrepoIssues(..) => "/repos/{owner}/{repo}/issues",
+  EndPoints::PostReposownerrepoIssues(..) => "/repos/{owner}/{repo}/issues",
+  EndPoints::GetReposownerrepoIssuesComments(..) => "/repos/{owner}/{repo}/issues/comments",
+  EndPoints::GetReposownerrepoIssuesCommentscommentId(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  EndPoints::PatchReposownerrepoIssuesCommentscommentId(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentId(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}",
+  EndPoints::GetReposownerrepoIssuesCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+  EndPoints::PostReposownerrepoIssuesCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId(..) => "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}",
+  EndPoints::GetReposownerrepoIssuesEvents(..) => "/repos/{owner}/{repo}/issues/events",
+  EndPoints::GetReposownerrepoIssuesEventseventId(..) => "/repos/{owner}/{repo}/issues/events/{event_id}",
+  EndPoints::GetReposownerrepoIssuesissueNumber(..) => "/repos/{owner}/{repo}/issues/{issue_number}",
+  EndPoints::PatchReposownerrepoIssuesissueNumber(..) => "/repos/{owner}/{repo}/issues/{issue_number}",
+  EndPoints::PostReposownerrepoIssuesissueNumberAssignees(..) => "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(..) => "/repos/{owner}/{repo}/issues/{issue_number}/assignees",
+  EndPoints::GetReposownerrepoIssuesissueNumberComments(..) => "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+  EndPoints::PostReposownerrepoIssuesissueNumberComments(..) => "/repos/{owner}/{repo}/issues/{issue_number}/comments",
+  EndPoints::GetReposownerrepoIssuesissueNumberEvents(..) => "/repos/{owner}/{repo}/issues/{issue_number}/events",
+  EndPoints::GetReposownerrepoIssuesissueNumberLabels(..) => "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  EndPoints::PostReposownerrepoIssuesissueNumberLabels(..) => "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  EndPoints::PutReposownerrepoIssuesissueNumberLabels(..) => "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(..) => "/repos/{owner}/{repo}/issues/{issue_number}/labels",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(..) => "/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}",
+  EndPoints::PutReposownerrepoIssuesissueNumberLock(..) => "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLock(..) => "/repos/{owner}/{repo}/issues/{issue_number}/lock",
+  EndPoints::GetReposownerrepoIssuesissueNumberReactions(..) => "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+  EndPoints::PostReposownerrepoIssuesissueNumberReactions(..) => "/repos/{owner}/{repo}/issues/{issue_number}/reactions",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberReactionsreactionId(..) => "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}",
+  EndPoints::GetReposownerrepoIssuesissueNumberTimeline(..) => "/repos/{owner}/{repo}/issues/{issue_number}/timeline",
+  EndPoints::GetReposownerrepoKeys(..) => "/repos/{owner}/{repo}/keys",
+  EndPoints::PostReposownerrepoKeys(..) => "/repos/{owner}/{repo}/keys",
+  EndPoints::GetReposownerrepoKeyskeyId(..) => "/repos/{owner}/{repo}/keys/{key_id}",
+  EndPoints::DeleteReposownerrepoKeyskeyId(..) => "/repos/{owner}/{repo}/keys/{key_id}",
+  EndPoints::GetReposownerrepoLabels(..) => "/repos/{owner}/{repo}/labels",
+  EndPoints::PostReposownerrepoLabels(..) => "/repos/{owner}/{repo}/labels",
+  EndPoints::GetReposownerrepoLabelsname(..) => "/repos/{owner}/{repo}/labels/{name}",
+  EndPoints::PatchReposownerrepoLabelsname(..) => "/repos/{owner}/{repo}/labels/{name}",
+  EndPoints::DeleteReposownerrepoLabelsname(..) => "/repos/{owner}/{repo}/labels/{name}",
+  EndPoints::GetReposownerrepoLanguages(..) => "/repos/{owner}/{repo}/languages",
+  EndPoints::PutReposownerrepoLfs(..) => "/repos/{owner}/{repo}/lfs",
+  EndPoints::DeleteReposownerrepoLfs(..) => "/repos/{owner}/{repo}/lfs",
+  EndPoints::GetReposownerrepoLicense(..) => "/repos/{owner}/{repo}/license",
+  EndPoints::PostReposownerrepoMergeUpstream(..) => "/repos/{owner}/{repo}/merge-upstream",
+  EndPoints::PostReposownerrepoMerges(..) => "/repos/{owner}/{repo}/merges",
+  EndPoints::GetReposownerrepoMilestones(..) => "/repos/{owner}/{repo}/milestones",
+  EndPoints::PostReposownerrepoMilestones(..) => "/repos/{owner}/{repo}/milestones",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumber(..) => "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(..) => "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(..) => "/repos/{owner}/{repo}/milestones/{milestone_number}",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(..) => "/repos/{owner}/{repo}/milestones/{milestone_number}/labels",
+  EndPoints::GetReposownerrepoNotifications(..) => "/repos/{owner}/{repo}/notifications",
+  EndPoints::PutReposownerrepoNotifications(..) => "/repos/{owner}/{repo}/notifications",
+  EndPoints::GetReposownerrepoPages(..) => "/repos/{owner}/{repo}/pages",
+  EndPoints::PostReposownerrepoPages(..) => "/repos/{owner}/{repo}/pages",
+  EndPoints::PutReposownerrepoPages(..) => "/repos/{owner}/{repo}/pages",
+  EndPoints::DeleteReposownerrepoPages(..) => "/repos/{owner}/{repo}/pages",
+  EndPoints::GetReposownerrepoPagesBuilds(..) => "/repos/{owner}/{repo}/pages/builds",
+  EndPoints::PostReposownerrepoPagesBuilds(..) => "/repos/{owner}/{repo}/pages/builds",
+  EndPoints::GetReposownerrepoPagesBuildsLatest(..) => "/repos/{owner}/{repo}/pages/builds/latest",
+  EndPoints::GetReposownerrepoPagesBuildsbuildId(..) => "/repos/{owner}/{repo}/pages/builds/{build_id}",
+  EndPoints::GetReposownerrepoPagesHealth(..) => "/repos/{owner}/{repo}/pages/health",
+  EndPoints::GetReposownerrepoProjects(..) => "/repos/{owner}/{repo}/projects",
+  EndPoints::PostReposownerrepoProjects(..) => "/repos/{owner}/{repo}/projects",
+  EndPoints::GetReposownerrepoPulls(..) => "/repos/{owner}/{repo}/pulls",
+  EndPoints::PostReposownerrepoPulls(..) => "/repos/{owner}/{repo}/pulls",
+  EndPoints::GetReposownerrepoPullsComments(..) => "/repos/{owner}/{repo}/pulls/comments",
+  EndPoints::GetReposownerrepoPullsCommentscommentId(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  EndPoints::PatchReposownerrepoPullsCommentscommentId(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentId(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+  EndPoints::GetReposownerrepoPullsCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+  EndPoints::PostReposownerrepoPullsCommentscommentIdReactions(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId(..) => "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}",
+  EndPoints::GetReposownerrepoPullspullNumber(..) => "/repos/{owner}/{repo}/pulls/{pull_number}",
+  EndPoints::PatchReposownerrepoPullspullNumber(..) => "/repos/{owner}/{repo}/pulls/{pull_number}",
+  EndPoints::PostReposownerrepoPullspullNumberCodespaces(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/codespaces",
+  EndPoints::GetReposownerrepoPullspullNumberComments(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+  EndPoints::PostReposownerrepoPullspullNumberComments(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/comments",
+  EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies",
+  EndPoints::GetReposownerrepoPullspullNumberCommits(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/commits",
+  EndPoints::GetReposownerrepoPullspullNumberFiles(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/files",
+  EndPoints::GetReposownerrepoPullspullNumberMerge(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+  EndPoints::PutReposownerrepoPullspullNumberMerge(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/merge",
+  EndPoints::GetReposownerrepoPullspullNumberRequestedReviewers(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  EndPoints::DeleteReposownerrepoPullspullNumberRequestedReviewers(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers",
+  EndPoints::GetReposownerrepoPullspullNumberReviews(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+  EndPoints::PostReposownerrepoPullspullNumberReviews(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewIdComments(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals",
+  EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events",
+  EndPoints::PutReposownerrepoPullspullNumberUpdateBranch(..) => "/repos/{owner}/{repo}/pulls/{pull_number}/update-branch",
+  EndPoints::GetReposownerrepoReadme(..) => "/repos/{owner}/{repo}/readme",
+  EndPoints::GetReposownerrepoReadmedir(..) => "/repos/{owner}/{repo}/readme/{dir}",
+  EndPoints::GetReposownerrepoReleases(..) => "/repos/{owner}/{repo}/releases",
+  EndPoints::PostReposownerrepoReleases(..) => "/repos/{owner}/{repo}/releases",
+  EndPoints::GetReposownerrepoReleasesAssetsassetId(..) => "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  EndPoints::PatchReposownerrepoReleasesAssetsassetId(..) => "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  EndPoints::DeleteReposownerrepoReleasesAssetsassetId(..) => "/repos/{owner}/{repo}/releases/assets/{asset_id}",
+  EndPoints::PostReposownerrepoReleasesGenerateNotes(..) => "/repos/{owner}/{repo}/releases/generate-notes",
+  EndPoints::GetReposownerrepoReleasesLatest(..) => "/repos/{owner}/{repo}/releases/latest",
+  EndPoints::GetReposownerrepoReleasesTagstag(..) => "/repos/{owner}/{repo}/releases/tags/{tag}",
+  EndPoints::GetReposownerrepoReleasesreleaseId(..) => "/repos/{owner}/{repo}/releases/{release_id}",
+  EndPoints::PatchReposownerrepoReleasesreleaseId(..) => "/repos/{owner}/{repo}/releases/{release_id}",
+  EndPoints::DeleteReposownerrepoReleasesreleaseId(..) => "/repos/{owner}/{repo}/releases/{release_id}",
+  EndPoints::GetReposownerrepoReleasesreleaseIdAssets(..) => "/repos/{owner}/{repo}/releases/{release_id}/assets",
+  EndPoints::PostReposownerrepoReleasesreleaseIdAssets(..) => "/repos/{owner}/{repo}/releases/{release_id}/assets",
+  EndPoints::PostReposownerrepoReleasesreleaseIdReactions(..) => "/repos/{owner}/{repo}/releases/{release_id}/reactions",
+  EndPoints::GetReposownerrepoSecretScanningAlerts(..) => "/repos/{owner}/{repo}/secret-scanning/alerts",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumber(..) => "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+  EndPoints::PatchReposownerrepoSecretScanningAlertsalertNumber(..) => "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumberLocations(..) => "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}/locations",
+  EndPoints::GetReposownerrepoStargazers(..) => "/repos/{owner}/{repo}/stargazers",
+  EndPoints::GetReposownerrepoStatsCodeFrequency(..) => "/repos/{owner}/{repo}/stats/code_frequency",
+  EndPoints::GetReposownerrepoStatsCommitActivity(..) => "/repos/{owner}/{repo}/stats/commit_activity",
+  EndPoints::GetReposownerrepoStatsContributors(..) => "/repos/{owner}/{repo}/stats/contributors",
+  EndPoints::GetReposownerrepoStatsParticipation(..) => "/repos/{owner}/{repo}/stats/participation",
+  EndPoints::GetReposownerrepoStatsPunchCard(..) => "/repos/{owner}/{repo}/stats/punch_card",
+  EndPoints::PostReposownerrepoStatusessha(..) => "/repos/{owner}/{repo}/statuses/{sha}",
+  EndPoints::GetReposownerrepoSubscribers(..) => "/repos/{owner}/{repo}/subscribers",
+  EndPoints::GetReposownerrepoSubscription(..) => "/repos/{owner}/{repo}/subscription",
+  EndPoints::PutReposownerrepoSubscription(..) => "/repos/{owner}/{repo}/subscription",
+  EndPoints::DeleteReposownerrepoSubscription(..) => "/repos/{owner}/{repo}/subscription",
+  EndPoints::GetReposownerrepoTags(..) => "/repos/{owner}/{repo}/tags",
+  EndPoints::GetReposownerrepoTarballref(..) => "/repos/{owner}/{repo}/tarball/{ref}",
+  EndPoints::GetReposownerrepoTeams(..) => "/repos/{owner}/{repo}/teams",
+  EndPoints::GetReposownerrepoTopics(..) => "/repos/{owner}/{repo}/topics",
+  EndPoints::PutReposownerrepoTopics(..) => "/repos/{owner}/{repo}/topics",
+  EndPoints::GetReposownerrepoTrafficClones(..) => "/repos/{owner}/{repo}/traffic/clones",
+  EndPoints::GetReposownerrepoTrafficPopularPaths(..) => "/repos/{owner}/{repo}/traffic/popular/paths",
+  EndPoints::GetReposownerrepoTrafficPopularReferrers(..) => "/repos/{owner}/{repo}/traffic/popular/referrers",
+  EndPoints::GetReposownerrepoTrafficViews(..) => "/repos/{owner}/{repo}/traffic/views",
+  EndPoints::PostReposownerrepoTransfer(..) => "/repos/{owner}/{repo}/transfer",
+  EndPoints::GetReposownerrepoVulnerabilityAlerts(..) => "/repos/{owner}/{repo}/vulnerability-alerts",
+  EndPoints::PutReposownerrepoVulnerabilityAlerts(..) => "/repos/{owner}/{repo}/vulnerability-alerts",
+  EndPoints::DeleteReposownerrepoVulnerabilityAlerts(..) => "/repos/{owner}/{repo}/vulnerability-alerts",
+  EndPoints::GetReposownerrepoZipballref(..) => "/repos/{owner}/{repo}/zipball/{ref}",
+  EndPoints::PostRepostemplateOwnertemplateRepoGenerate(..) => "/repos/{template_owner}/{template_repo}/generate",
+  EndPoints::GetRepositories(..) => "/repositories",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets(..) => "/repositories/{repository_id}/environments/{environment_name}/secrets",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey(..) => "/repositories/{repository_id}/environments/{environment_name}/secrets/public-key",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+  EndPoints::PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+  EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}",
+  EndPoints::GetScimV2EnterprisesenterpriseGroups(..) => "/scim/v2/enterprises/{enterprise}/Groups",
+  EndPoints::PostScimV2EnterprisesenterpriseGroups(..) => "/scim/v2/enterprises/{enterprise}/Groups",
+  EndPoints::GetScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+  EndPoints::PutScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+  EndPoints::PatchScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+  EndPoints::DeleteScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}",
+  EndPoints::GetScimV2EnterprisesenterpriseUsers(..) => "/scim/v2/enterprises/{enterprise}/Users",
+  EndPoints::PostScimV2EnterprisesenterpriseUsers(..) => "/scim/v2/enterprises/{enterprise}/Users",
+  EndPoints::GetScimV2EnterprisesenterpriseUsersscimUserId(..) => "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+  EndPoints::PutScimV2EnterprisesenterpriseUsersscimUserId(..) => "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+  EndPoints::PatchScimV2EnterprisesenterpriseUsersscimUserId(..) => "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+  EndPoints::DeleteScimV2EnterprisesenterpriseUsersscimUserId(..) => "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}",
+  EndPoints::GetScimV2OrganizationsorgUsers(..) => "/scim/v2/organizations/{org}/Users",
+  EndPoints::PostScimV2OrganizationsorgUsers(..) => "/scim/v2/organizations/{org}/Users",
+  EndPoints::GetScimV2OrganizationsorgUsersscimUserId(..) => "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+  EndPoints::PutScimV2OrganizationsorgUsersscimUserId(..) => "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+  EndPoints::PatchScimV2OrganizationsorgUsersscimUserId(..) => "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+  EndPoints::DeleteScimV2OrganizationsorgUsersscimUserId(..) => "/scim/v2/organizations/{org}/Users/{scim_user_id}",
+  EndPoints::GetSearchCode(..) => "/search/code",
+  EndPoints::GetSearchCommits(..) => "/search/commits",
+  EndPoints::GetSearchIssues(..) => "/search/issues",
+  EndPoints::GetSearchLabels(..) => "/search/labels",
+  EndPoints::GetSearchRepositories(..) => "/search/repositories",
+  EndPoints::GetSearchTopics(..) => "/search/topics",
+  EndPoints::GetSearchUsers(..) => "/search/users",
+  EndPoints::GetTeamsteamId(..) => "/teams/{team_id}",
+  EndPoints::PatchTeamsteamId(..) => "/teams/{team_id}",
+  EndPoints::DeleteTeamsteamId(..) => "/teams/{team_id}",
+  EndPoints::GetTeamsteamIdDiscussions(..) => "/teams/{team_id}/discussions",
+  EndPoints::PostTeamsteamIdDiscussions(..) => "/teams/{team_id}/discussions",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumber(..) => "/teams/{team_id}/discussions/{discussion_number}",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumber(..) => "/teams/{team_id}/discussions/{discussion_number}",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumber(..) => "/teams/{team_id}/discussions/{discussion_number}",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberComments(..) => "/teams/{team_id}/discussions/{discussion_number}/comments",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(..) => "/teams/{team_id}/discussions/{discussion_number}/comments",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "/teams/{team_id}/discussions/{discussion_number}/reactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "/teams/{team_id}/discussions/{discussion_number}/reactions",
+  EndPoints::GetTeamsteamIdInvitations(..) => "/teams/{team_id}/invitations",
+  EndPoints::GetTeamsteamIdMembers(..) => "/teams/{team_id}/members",
+  EndPoints::GetTeamsteamIdMembersusername(..) => "/teams/{team_id}/members/{username}",
+  EndPoints::PutTeamsteamIdMembersusername(..) => "/teams/{team_id}/members/{username}",
+  EndPoints::DeleteTeamsteamIdMembersusername(..) => "/teams/{team_id}/members/{username}",
+  EndPoints::GetTeamsteamIdMembershipsusername(..) => "/teams/{team_id}/memberships/{username}",
+  EndPoints::PutTeamsteamIdMembershipsusername(..) => "/teams/{team_id}/memberships/{username}",
+  EndPoints::DeleteTeamsteamIdMembershipsusername(..) => "/teams/{team_id}/memberships/{username}",
+  EndPoints::GetTeamsteamIdProjects(..) => "/teams/{team_id}/projects",
+  EndPoints::GetTeamsteamIdProjectsprojectId(..) => "/teams/{team_id}/projects/{project_id}",
+  EndPoints::PutTeamsteamIdProjectsprojectId(..) => "/teams/{team_id}/projects/{project_id}",
+  EndPoints::DeleteTeamsteamIdProjectsprojectId(..) => "/teams/{team_id}/projects/{project_id}",
+  EndPoints::GetTeamsteamIdRepos(..) => "/teams/{team_id}/repos",
+  EndPoints::GetTeamsteamIdReposownerrepo(..) => "/teams/{team_id}/repos/{owner}/{repo}",
+  EndPoints::PutTeamsteamIdReposownerrepo(..) => "/teams/{team_id}/repos/{owner}/{repo}",
+  EndPoints::DeleteTeamsteamIdReposownerrepo(..) => "/teams/{team_id}/repos/{owner}/{repo}",
+  EndPoints::GetTeamsteamIdTeamSyncGroupMappings(..) => "/teams/{team_id}/team-sync/group-mappings",
+  EndPoints::PatchTeamsteamIdTeamSyncGroupMappings(..) => "/teams/{team_id}/team-sync/group-mappings",
+  EndPoints::GetTeamsteamIdTeams(..) => "/teams/{team_id}/teams",
+  EndPoints::GetUser(..) => "/user",
+  EndPoints::PatchUser(..) => "/user",
+  EndPoints::GetUserBlocks(..) => "/user/blocks",
+  EndPoints::GetUserBlocksusername(..) => "/user/blocks/{username}",
+  EndPoints::PutUserBlocksusername(..) => "/user/blocks/{username}",
+  EndPoints::DeleteUserBlocksusername(..) => "/user/blocks/{username}",
+  EndPoints::GetUserCodespaces(..) => "/user/codespaces",
+  EndPoints::PostUserCodespaces(..) => "/user/codespaces",
+  EndPoints::GetUserCodespacesSecrets(..) => "/user/codespaces/secrets",
+  EndPoints::GetUserCodespacesSecretsPublicKey(..) => "/user/codespaces/secrets/public-key",
+  EndPoints::GetUserCodespacesSecretssecretName(..) => "/user/codespaces/secrets/{secret_name}",
+  EndPoints::PutUserCodespacesSecretssecretName(..) => "/user/codespaces/secrets/{secret_name}",
+  EndPoints::DeleteUserCodespacesSecretssecretName(..) => "/user/codespaces/secrets/{secret_name}",
+  EndPoints::GetUserCodespacesSecretssecretNameRepositories(..) => "/user/codespaces/secrets/{secret_name}/repositories",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositories(..) => "/user/codespaces/secrets/{secret_name}/repositories",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}",
+  EndPoints::GetUserCodespacescodespaceName(..) => "/user/codespaces/{codespace_name}",
+  EndPoints::PatchUserCodespacescodespaceName(..) => "/user/codespaces/{codespace_name}",
+  EndPoints::DeleteUserCodespacescodespaceName(..) => "/user/codespaces/{codespace_name}",
+  EndPoints::PostUserCodespacescodespaceNameExports(..) => "/user/codespaces/{codespace_name}/exports",
+  EndPoints::GetUserCodespacescodespaceNameExportsexportId(..) => "/user/codespaces/{codespace_name}/exports/{export_id}",
+  EndPoints::GetUserCodespacescodespaceNameMachines(..) => "/user/codespaces/{codespace_name}/machines",
+  EndPoints::PostUserCodespacescodespaceNameStart(..) => "/user/codespaces/{codespace_name}/start",
+  EndPoints::PostUserCodespacescodespaceNameStop(..) => "/user/codespaces/{codespace_name}/stop",
+  EndPoints::PatchUserEmailVisibility(..) => "/user/email/visibility",
+  EndPoints::GetUserEmails(..) => "/user/emails",
+  EndPoints::PostUserEmails(..) => "/user/emails",
+  EndPoints::DeleteUserEmails(..) => "/user/emails",
+  EndPoints::GetUserFollowers(..) => "/user/followers",
+  EndPoints::GetUserFollowing(..) => "/user/following",
+  EndPoints::GetUserFollowingusername(..) => "/user/following/{username}",
+  EndPoints::PutUserFollowingusername(..) => "/user/following/{username}",
+  EndPoints::DeleteUserFollowingusername(..) => "/user/following/{username}",
+  EndPoints::GetUserGpgKeys(..) => "/user/gpg_keys",
+  EndPoints::PostUserGpgKeys(..) => "/user/gpg_keys",
+  EndPoints::GetUserGpgKeysgpgKeyId(..) => "/user/gpg_keys/{gpg_key_id}",
+  EndPoints::DeleteUserGpgKeysgpgKeyId(..) => "/user/gpg_keys/{gpg_key_id}",
+  EndPoints::GetUserInstallations(..) => "/user/installations",
+  EndPoints::GetUserInstallationsinstallationIdRepositories(..) => "/user/installations/{installation_id}/repositories",
+  EndPoints::PutUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "/user/installations/{installation_id}/repositories/{repository_id}",
+  EndPoints::DeleteUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "/user/installations/{installation_id}/repositories/{repository_id}",
+  EndPoints::GetUserInteractionLimits(..) => "/user/interaction-limits",
+  EndPoints::PutUserInteractionLimits(..) => "/user/interaction-limits",
+  EndPoints::DeleteUserInteractionLimits(..) => "/user/interaction-limits",
+  EndPoints::GetUserIssues(..) => "/user/issues",
+  EndPoints::GetUserKeys(..) => "/user/keys",
+  EndPoints::PostUserKeys(..) => "/user/keys",
+  EndPoints::GetUserKeyskeyId(..) => "/user/keys/{key_id}",
+  EndPoints::DeleteUserKeyskeyId(..) => "/user/keys/{key_id}",
+  EndPoints::GetUserMarketplacePurchases(..) => "/user/marketplace_purchases",
+  EndPoints::GetUserMarketplacePurchasesStubbed(..) => "/user/marketplace_purchases/stubbed",
+  EndPoints::GetUserMembershipsOrgs(..) => "/user/memberships/orgs",
+  EndPoints::GetUserMembershipsOrgsorg(..) => "/user/memberships/orgs/{org}",
+  EndPoints::PatchUserMembershipsOrgsorg(..) => "/user/memberships/orgs/{org}",
+  EndPoints::GetUserMigrations(..) => "/user/migrations",
+  EndPoints::PostUserMigrations(..) => "/user/migrations",
+  EndPoints::GetUserMigrationsmigrationId(..) => "/user/migrations/{migration_id}",
+  EndPoints::GetUserMigrationsmigrationIdArchive(..) => "/user/migrations/{migration_id}/archive",
+  EndPoints::DeleteUserMigrationsmigrationIdArchive(..) => "/user/migrations/{migration_id}/archive",
+  EndPoints::DeleteUserMigrationsmigrationIdReposrepoNameLock(..) => "/user/migrations/{migration_id}/repos/{repo_name}/lock",
+  EndPoints::GetUserMigrationsmigrationIdRepositories(..) => "/user/migrations/{migration_id}/repositories",
+  EndPoints::GetUserOrgs(..) => "/user/orgs",
+  EndPoints::GetUserPackages(..) => "/user/packages",
+  EndPoints::GetUserPackagespackageTypepackageName(..) => "/user/packages/{package_type}/{package_name}",
+  EndPoints::DeleteUserPackagespackageTypepackageName(..) => "/user/packages/{package_type}/{package_name}",
+  EndPoints::PostUserPackagespackageTypepackageNameRestore(..) => "/user/packages/{package_type}/{package_name}/restore",
+  EndPoints::GetUserPackagespackageTypepackageNameVersions(..) => "/user/packages/{package_type}/{package_name}/versions",
+  EndPoints::GetUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::DeleteUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "/user/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  EndPoints::PostUserProjects(..) => "/user/projects",
+  EndPoints::GetUserPublicEmails(..) => "/user/public_emails",
+  EndPoints::GetUserRepos(..) => "/user/repos",
+  EndPoints::PostUserRepos(..) => "/user/repos",
+  EndPoints::GetUserRepositoryInvitations(..) => "/user/repository_invitations",
+  EndPoints::PatchUserRepositoryInvitationsinvitationId(..) => "/user/repository_invitations/{invitation_id}",
+  EndPoints::DeleteUserRepositoryInvitationsinvitationId(..) => "/user/repository_invitations/{invitation_id}",
+  EndPoints::GetUserStarred(..) => "/user/starred",
+  EndPoints::GetUserStarredownerrepo(..) => "/user/starred/{owner}/{repo}",
+  EndPoints::PutUserStarredownerrepo(..) => "/user/starred/{owner}/{repo}",
+  EndPoints::DeleteUserStarredownerrepo(..) => "/user/starred/{owner}/{repo}",
+  EndPoints::GetUserSubscriptions(..) => "/user/subscriptions",
+  EndPoints::GetUserTeams(..) => "/user/teams",
+  EndPoints::GetUsers(..) => "/users",
+  EndPoints::GetUsersusername(..) => "/users/{username}",
+  EndPoints::GetUsersusernameEvents(..) => "/users/{username}/events",
+  EndPoints::GetUsersusernameEventsOrgsorg(..) => "/users/{username}/events/orgs/{org}",
+  EndPoints::GetUsersusernameEventsPublic(..) => "/users/{username}/events/public",
+  EndPoints::GetUsersusernameFollowers(..) => "/users/{username}/followers",
+  EndPoints::GetUsersusernameFollowing(..) => "/users/{username}/following",
+  EndPoints::GetUsersusernameFollowingtargetUser(..) => "/users/{username}/following/{target_user}",
+  EndPoints::GetUsersusernameGists(..) => "/users/{username}/gists",
+  EndPoints::GetUsersusernameGpgKeys(..) => "/users/{username}/gpg_keys",
+  EndPoints::GetUsersusernameHovercard(..) => "/users/{username}/hovercard",
+  EndPoints::GetUsersusernameInstallation(..) => "/users/{username}/installation",
+  EndPoints::GetUsersusernameKeys(..) => "/users/{username}/keys",
+  EndPoints::GetUsersusernameOrgs(..) => "/users/{username}/orgs",
+  EndPoints::GetUsersusernamePackages(..) => "/users/{username}/packages",
+  EndPoints::GetUsersusernamePackagespackageTypepackageName(..) => "/users/{username}/packages/{package_type}/{package_name}",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageName(..) => "/users/{username}/packages/{package_type}/{package_name}",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameRestore(..) => "/users/{username}/packages/{package_type}/{package_name}/restore",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersions(..) => "/users/{username}/packages/{package_type}/{package_name}/versions",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore",
+  EndPoints::GetUsersusernameProjects(..) => "/users/{username}/projects",
+  EndPoints::GetUsersusernameReceivedEvents(..) => "/users/{username}/received_events",
+  EndPoints::GetUsersusernameReceivedEventsPublic(..) => "/users/{username}/received_events/public",
+  EndPoints::GetUsersusernameRepos(..) => "/users/{username}/repos",
+  EndPoints::GetUsersusernameSettingsBillingActions(..) => "/users/{username}/settings/billing/actions",
+  EndPoints::GetUsersusernameSettingsBillingPackages(..) => "/users/{username}/settings/billing/packages",
+  EndPoints::GetUsersusernameSettingsBillingSharedStorage(..) => "/users/{username}/settings/billing/shared-storage",
+  EndPoints::GetUsersusernameStarred(..) => "/users/{username}/starred",
+  EndPoints::GetUsersusernameSubscriptions(..) => "/users/{username}/subscriptions",
+  EndPoints::GetZen(..) => "/zen"}
+    }
+    pub const fn tag(&self) -> &'static str {
+        match *self { EndPoints::Get(..) => "meta",
+  EndPoints::GetApp(..) => "apps",
+  EndPoints::PostAppManifestscodeConversions(..) => "apps",
+  EndPoints::GetAppHookConfig(..) => "apps",
+  EndPoints::PatchAppHookConfig(..) => "apps",
+  EndPoints::GetAppHookDeliveries(..) => "apps",
+  EndPoints::GetAppHookDeliveriesdeliveryId(..) => "apps",
+  EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(..) => "apps",
+  EndPoints::GetAppInstallations(..) => "apps",
+  EndPoints::GetAppInstallationsinstallationId(..) => "apps",
+  EndPoints::DeleteAppInstallationsinstallationId(..) => "apps",
+  EndPoints::PostAppInstallationsinstallationIdAccessTokens(..) => "apps",
+  EndPoints::PutAppInstallationsinstallationIdSuspended(..) => "apps",
+  EndPoints::DeleteAppInstallationsinstallationIdSuspended(..) => "apps",
+  EndPoints::GetApplicationsGrants(..) => "oauth-authorizations",
+  EndPoints::GetApplicationsGrantsgrantId(..) => "oauth-authorizations",
+  EndPoints::DeleteApplicationsGrantsgrantId(..) => "oauth-authorizations",
+  EndPoints::DeleteApplicationsclientIdGrant(..) => "apps",
+  EndPoints::PostApplicationsclientIdToken(..) => "apps",
+  EndPoints::PatchApplicationsclientIdToken(..) => "apps",
+  EndPoints::DeleteApplicationsclientIdToken(..) => "apps",
+  EndPoints::PostApplicationsclientIdTokenScoped(..) => "apps",
+  EndPoints::GetAppsappSlug(..) => "apps",
+  EndPoints::GetAuthorizations(..) => "oauth-authorizations",
+  EndPoints::PostAuthorizations(..) => "oauth-authorizations",
+  EndPoints::PutAuthorizationsClientsclientId(..) => "oauth-authorizations",
+  EndPoints::PutAuthorizationsClientsclientIdfingerprint(..) => "oauth-authorizations",
+  EndPoints::GetAuthorizationsauthorizationId(..) => "oauth-authorizations",
+  EndPoints::PatchAuthorizationsauthorizationId(..) => "oauth-authorizations",
+  EndPoints::DeleteAuthorizationsauthorizationId(..) => "oauth-authorizations",
+  EndPoints::GetCodesOfConduct(..) => "codes-of-conduct",
+  EndPoints::GetCodesOfConductkey(..) => "codes-of-conduct",
+  EndPoints::GetEmojis(..) => "emojis",
+  EndPoints::GetEnterprisesenterpriseActionsPermissions(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsPermissions(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsOrganizations(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizations(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsSelectedActions(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroups(..) => "enterprise-admin",
+  EndPoints::PostEnterprisesenterpriseActionsRunnerGroups(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "enterprise-admin",
+  EndPoints::PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunners(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersDownloads(..) => "enterprise-admin",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(..) => "enterprise-admin",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerId(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerId(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "enterprise-admin",
+  EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "enterprise-admin",
+  EndPoints::PutEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels(..) => "enterprise-admin",
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseAuditLog(..) => "enterprise-admin",
+  EndPoints::GetEnterprisesenterpriseSecretScanningAlerts(..) => "secret-scanning",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingActions(..) => "billing",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingAdvancedSecurity(..) => "billing",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingPackages(..) => "billing",
+  EndPoints::GetEnterprisesenterpriseSettingsBillingSharedStorage(..) => "billing",
+  EndPoints::GetEvents(..) => "activity",
+  EndPoints::GetFeeds(..) => "activity",
+  EndPoints::GetGists(..) => "gists",
+  EndPoints::PostGists(..) => "gists",
+  EndPoints::GetGistsPublic(..) => "gists",
+  EndPoints::GetGistsStarred(..) => "gists",
+  EndPoints::GetGistsgistId(..) => "gists",
+  EndPoints::PatchGistsgistId(..) => "gists",
+  EndPoints::DeleteGistsgistId(..) => "gists",
+  EndPoints::GetGistsgistIdComments(..) => "gists",
+  EndPoints::PostGistsgistIdComments(..) => "gists",
+  EndPoints::GetGistsgistIdCommentscommentId(..) => "gists",
+  EndPoints::PatchGistsgistIdCommentscommentId(..) => "gists",
+  EndPoints::DeleteGistsgistIdCommentscommentId(..) => "gists",
+  EndPoints::GetGistsgistIdCommits(..) => "gists",
+  EndPoints::GetGistsgistIdForks(..) => "gists",
+  EndPoints::PostGistsgistIdForks(..) => "gists",
+  EndPoints::GetGistsgistIdStar(..) => "gists",
+  EndPoints::PutGistsgistIdStar(..) => "gists",
+  EndPoints::DeleteGistsgistIdStar(..) => "gists",
+  EndPoints::GetGistsgistIdsha(..) => "gists",
+  EndPoints::GetGitignoreTemplates(..) => "gitignore",
+  EndPoints::GetGitignoreTemplatesname(..) => "gitignore",
+  EndPoints::GetInstallationRepositories(..) => "apps",
+  EndPoints::DeleteInstallationToken(..) => "apps",
+  EndPoints::GetIssues(..) => "issues",
+  EndPoints::GetLicenses(..) => "licenses",
+  EndPoints::GetLicenseslicense(..) => "licenses",
+  EndPoints::PostMarkdown(..) => "markdown",
+  EndPoints::PostMarkdownRaw(..) => "markdown",
+  EndPoints::GetMarketplaceListingAccountsaccountId(..) => "apps",
+  EndPoints::GetMarketplaceListingPlans(..) => "apps",
+  EndPoints::GetMarketplaceListingPlansplanIdAccounts(..) => "apps",
+  EndPoints::GetMarketplaceListingStubbedAccountsaccountId(..) => "apps",
+  EndPoints::GetMarketplaceListingStubbedPlans(..) => "apps",
+  EndPoints::GetMarketplaceListingStubbedPlansplanIdAccounts(..) => "apps",
+  EndPoints::GetMeta(..) => "meta",
+  EndPoints::GetNetworksownerrepoEvents(..) => "activity",
+  EndPoints::GetNotifications(..) => "activity",
+  EndPoints::PutNotifications(..) => "activity",
+  EndPoints::GetNotificationsThreadsthreadId(..) => "activity",
+  EndPoints::PatchNotificationsThreadsthreadId(..) => "activity",
+  EndPoints::GetNotificationsThreadsthreadIdSubscription(..) => "activity",
+  EndPoints::PutNotificationsThreadsthreadIdSubscription(..) => "activity",
+  EndPoints::DeleteNotificationsThreadsthreadIdSubscription(..) => "activity",
+  EndPoints::GetOctocat(..) => "meta",
+  EndPoints::GetOrganizations(..) => "orgs",
+  EndPoints::GetOrganizationsorganizationIdCustomRoles(..) => "orgs",
+  EndPoints::GetOrganizationsorgTeamteamSlugExternalGroups(..) => "teams",
+  EndPoints::GetOrgsorg(..) => "orgs",
+  EndPoints::PatchOrgsorg(..) => "orgs",
+  EndPoints::GetOrgsorgActionsPermissions(..) => "actions",
+  EndPoints::PutOrgsorgActionsPermissions(..) => "actions",
+  EndPoints::GetOrgsorgActionsPermissionsRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsPermissionsRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId(..) => "actions",
+  EndPoints::GetOrgsorgActionsPermissionsSelectedActions(..) => "actions",
+  EndPoints::PutOrgsorgActionsPermissionsSelectedActions(..) => "actions",
+  EndPoints::GetOrgsorgActionsPermissionsWorkflow(..) => "actions",
+  EndPoints::PutOrgsorgActionsPermissionsWorkflow(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnerGroups(..) => "actions",
+  EndPoints::PostOrgsorgActionsRunnerGroups(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "actions",
+  EndPoints::PatchOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupId(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "actions",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(..) => "actions",
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunners(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnersDownloads(..) => "actions",
+  EndPoints::PostOrgsorgActionsRunnersRegistrationToken(..) => "actions",
+  EndPoints::PostOrgsorgActionsRunnersRemoveToken(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnersrunnerId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerId(..) => "actions",
+  EndPoints::GetOrgsorgActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::PutOrgsorgActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabelsname(..) => "actions",
+  EndPoints::GetOrgsorgActionsSecrets(..) => "actions",
+  EndPoints::GetOrgsorgActionsSecretsPublicKey(..) => "actions",
+  EndPoints::GetOrgsorgActionsSecretssecretName(..) => "actions",
+  EndPoints::PutOrgsorgActionsSecretssecretName(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsSecretssecretName(..) => "actions",
+  EndPoints::GetOrgsorgActionsSecretssecretNameRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositories(..) => "actions",
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "actions",
+  EndPoints::DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(..) => "actions",
+  EndPoints::GetOrgsorgAuditLog(..) => "orgs",
+  EndPoints::GetOrgsorgBlocks(..) => "orgs",
+  EndPoints::GetOrgsorgBlocksusername(..) => "orgs",
+  EndPoints::PutOrgsorgBlocksusername(..) => "orgs",
+  EndPoints::DeleteOrgsorgBlocksusername(..) => "orgs",
+  EndPoints::GetOrgsorgCodeScanningAlerts(..) => "code-scanning",
+  EndPoints::GetOrgsorgCredentialAuthorizations(..) => "orgs",
+  EndPoints::DeleteOrgsorgCredentialAuthorizationscredentialId(..) => "orgs",
+  EndPoints::GetOrgsorgDependabotSecrets(..) => "dependabot",
+  EndPoints::GetOrgsorgDependabotSecretsPublicKey(..) => "dependabot",
+  EndPoints::GetOrgsorgDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::PutOrgsorgDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::GetOrgsorgDependabotSecretssecretNameRepositories(..) => "dependabot",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositories(..) => "dependabot",
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "dependabot",
+  EndPoints::DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(..) => "dependabot",
+  EndPoints::GetOrgsorgEvents(..) => "activity",
+  EndPoints::GetOrgsorgExternalGroupgroupId(..) => "teams",
+  EndPoints::GetOrgsorgExternalGroups(..) => "teams",
+  EndPoints::GetOrgsorgFailedInvitations(..) => "orgs",
+  EndPoints::GetOrgsorgHooks(..) => "orgs",
+  EndPoints::PostOrgsorgHooks(..) => "orgs",
+  EndPoints::GetOrgsorgHookshookId(..) => "orgs",
+  EndPoints::PatchOrgsorgHookshookId(..) => "orgs",
+  EndPoints::DeleteOrgsorgHookshookId(..) => "orgs",
+  EndPoints::GetOrgsorgHookshookIdConfig(..) => "orgs",
+  EndPoints::PatchOrgsorgHookshookIdConfig(..) => "orgs",
+  EndPoints::GetOrgsorgHookshookIdDeliveries(..) => "orgs",
+  EndPoints::GetOrgsorgHookshookIdDeliveriesdeliveryId(..) => "orgs",
+  EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(..) => "orgs",
+  EndPoints::PostOrgsorgHookshookIdPings(..) => "orgs",
+  EndPoints::GetOrgsorgInstallation(..) => "apps",
+  EndPoints::GetOrgsorgInstallations(..) => "orgs",
+  EndPoints::GetOrgsorgInteractionLimits(..) => "interactions",
+  EndPoints::PutOrgsorgInteractionLimits(..) => "interactions",
+  EndPoints::DeleteOrgsorgInteractionLimits(..) => "interactions",
+  EndPoints::GetOrgsorgInvitations(..) => "orgs",
+  EndPoints::PostOrgsorgInvitations(..) => "orgs",
+  EndPoints::DeleteOrgsorgInvitationsinvitationId(..) => "orgs",
+  EndPoints::GetOrgsorgInvitationsinvitationIdTeams(..) => "orgs",
+  EndPoints::GetOrgsorgIssues(..) => "issues",
+  EndPoints::GetOrgsorgMembers(..) => "orgs",
+  EndPoints::GetOrgsorgMembersusername(..) => "orgs",
+  EndPoints::DeleteOrgsorgMembersusername(..) => "orgs",
+  EndPoints::GetOrgsorgMembershipsusername(..) => "orgs",
+  EndPoints::PutOrgsorgMembershipsusername(..) => "orgs",
+  EndPoints::DeleteOrgsorgMembershipsusername(..) => "orgs",
+  EndPoints::GetOrgsorgMigrations(..) => "migrations",
+  EndPoints::PostOrgsorgMigrations(..) => "migrations",
+  EndPoints::GetOrgsorgMigrationsmigrationId(..) => "migrations",
+  EndPoints::GetOrgsorgMigrationsmigrationIdArchive(..) => "migrations",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdArchive(..) => "migrations",
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock(..) => "migrations",
+  EndPoints::GetOrgsorgMigrationsmigrationIdRepositories(..) => "migrations",
+  EndPoints::GetOrgsorgOutsideCollaborators(..) => "orgs",
+  EndPoints::PutOrgsorgOutsideCollaboratorsusername(..) => "orgs",
+  EndPoints::DeleteOrgsorgOutsideCollaboratorsusername(..) => "orgs",
+  EndPoints::GetOrgsorgPackages(..) => "packages",
+  EndPoints::GetOrgsorgPackagespackageTypepackageName(..) => "packages",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageName(..) => "packages",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameRestore(..) => "packages",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersions(..) => "packages",
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "packages",
+  EndPoints::GetOrgsorgProjects(..) => "projects",
+  EndPoints::PostOrgsorgProjects(..) => "projects",
+  EndPoints::GetOrgsorgPublicMembers(..) => "orgs",
+  EndPoints::GetOrgsorgPublicMembersusername(..) => "orgs",
+  EndPoints::PutOrgsorgPublicMembersusername(..) => "orgs",
+  EndPoints::DeleteOrgsorgPublicMembersusername(..) => "orgs",
+  EndPoints::GetOrgsorgRepos(..) => "repos",
+  EndPoints::PostOrgsorgRepos(..) => "repos",
+  EndPoints::GetOrgsorgSecretScanningAlerts(..) => "secret-scanning",
+  EndPoints::GetOrgsorgSettingsBillingActions(..) => "billing",
+  EndPoints::GetOrgsorgSettingsBillingAdvancedSecurity(..) => "billing",
+  EndPoints::GetOrgsorgSettingsBillingPackages(..) => "billing",
+  EndPoints::GetOrgsorgSettingsBillingSharedStorage(..) => "billing",
+  EndPoints::GetOrgsorgTeamSyncGroups(..) => "teams",
+  EndPoints::GetOrgsorgTeams(..) => "teams",
+  EndPoints::PostOrgsorgTeams(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlug(..) => "teams",
+  EndPoints::PatchOrgsorgTeamsteamSlug(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlug(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussions(..) => "teams",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussions(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "teams",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "reactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "reactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId(..) => "reactions",
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "reactions",
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(..) => "reactions",
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId(..) => "reactions",
+  EndPoints::PatchOrgsorgTeamsteamSlugExternalGroups(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugExternalGroups(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugInvitations(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugMembers(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugMembershipsusername(..) => "teams",
+  EndPoints::PutOrgsorgTeamsteamSlugMembershipsusername(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugMembershipsusername(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugProjects(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugProjectsprojectId(..) => "teams",
+  EndPoints::PutOrgsorgTeamsteamSlugProjectsprojectId(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugProjectsprojectId(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugRepos(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugReposownerrepo(..) => "teams",
+  EndPoints::PutOrgsorgTeamsteamSlugReposownerrepo(..) => "teams",
+  EndPoints::DeleteOrgsorgTeamsteamSlugReposownerrepo(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "teams",
+  EndPoints::PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings(..) => "teams",
+  EndPoints::GetOrgsorgTeamsteamSlugTeams(..) => "teams",
+  EndPoints::GetProjectsColumnsCardscardId(..) => "projects",
+  EndPoints::PatchProjectsColumnsCardscardId(..) => "projects",
+  EndPoints::DeleteProjectsColumnsCardscardId(..) => "projects",
+  EndPoints::PostProjectsColumnsCardscardIdMoves(..) => "projects",
+  EndPoints::GetProjectsColumnscolumnId(..) => "projects",
+  EndPoints::PatchProjectsColumnscolumnId(..) => "projects",
+  EndPoints::DeleteProjectsColumnscolumnId(..) => "projects",
+  EndPoints::GetProjectsColumnscolumnIdCards(..) => "projects",
+  EndPoints::PostProjectsColumnscolumnIdCards(..) => "projects",
+  EndPoints::PostProjectsColumnscolumnIdMoves(..) => "projects",
+  EndPoints::GetProjectsprojectId(..) => "projects",
+  EndPoints::PatchProjectsprojectId(..) => "projects",
+  EndPoints::DeleteProjectsprojectId(..) => "projects",
+  EndPoints::GetProjectsprojectIdCollaborators(..) => "projects",
+  EndPoints::PutProjectsprojectIdCollaboratorsusername(..) => "projects",
+  EndPoints::DeleteProjectsprojectIdCollaboratorsusername(..) => "projects",
+  EndPoints::GetProjectsprojectIdCollaboratorsusernamePermission(..) => "projects",
+  EndPoints::GetProjectsprojectIdColumns(..) => "projects",
+  EndPoints::PostProjectsprojectIdColumns(..) => "projects",
+  EndPoints::GetRateLimit(..) => "rate-limit",
+  EndPoints::DeleteReactionsreactionId(..) => "reactions",
+  EndPoints::GetReposownerrepo(..) => "repos",
+  EndPoints::PatchReposownerrepo(..) => "repos",
+  EndPoints::DeleteReposownerrepo(..) => "repos",
+  EndPoints::GetReposownerrepoActionsArtifacts(..) => "actions",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactId(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(..) => "actions",
+  EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(..) => "actions",
+  EndPoints::GetReposownerrepoActionsJobsjobId(..) => "actions",
+  EndPoints::GetReposownerrepoActionsJobsjobIdLogs(..) => "actions",
+  EndPoints::GetReposownerrepoActionsPermissions(..) => "actions",
+  EndPoints::PutReposownerrepoActionsPermissions(..) => "actions",
+  EndPoints::GetReposownerrepoActionsPermissionsSelectedActions(..) => "actions",
+  EndPoints::PutReposownerrepoActionsPermissionsSelectedActions(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunners(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunnersDownloads(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunnersRemoveToken(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerId(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerId(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::PutReposownerrepoActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabels(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabelsname(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRuns(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunId(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsRunsrunId(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdApprovals(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunsrunIdApprove(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunsrunIdCancel(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdJobs(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdLogs(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsRunsrunIdLogs(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdPendingDeployments(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunsrunIdPendingDeployments(..) => "actions",
+  EndPoints::PostReposownerrepoActionsRunsrunIdRerun(..) => "actions",
+  EndPoints::GetReposownerrepoActionsRunsrunIdTiming(..) => "actions",
+  EndPoints::GetReposownerrepoActionsSecrets(..) => "actions",
+  EndPoints::GetReposownerrepoActionsSecretsPublicKey(..) => "actions",
+  EndPoints::GetReposownerrepoActionsSecretssecretName(..) => "actions",
+  EndPoints::PutReposownerrepoActionsSecretssecretName(..) => "actions",
+  EndPoints::DeleteReposownerrepoActionsSecretssecretName(..) => "actions",
+  EndPoints::GetReposownerrepoActionsWorkflows(..) => "actions",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(..) => "actions",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdDisable(..) => "actions",
+  EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(..) => "actions",
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdEnable(..) => "actions",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(..) => "actions",
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdTiming(..) => "actions",
+  EndPoints::GetReposownerrepoAssignees(..) => "issues",
+  EndPoints::GetReposownerrepoAssigneesassignee(..) => "issues",
+  EndPoints::GetReposownerrepoAutolinks(..) => "repos",
+  EndPoints::PostReposownerrepoAutolinks(..) => "repos",
+  EndPoints::GetReposownerrepoAutolinksautolinkId(..) => "repos",
+  EndPoints::DeleteReposownerrepoAutolinksautolinkId(..) => "repos",
+  EndPoints::PutReposownerrepoAutomatedSecurityFixes(..) => "repos",
+  EndPoints::DeleteReposownerrepoAutomatedSecurityFixes(..) => "repos",
+  EndPoints::GetReposownerrepoBranches(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranch(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtection(..) => "repos",
+  EndPoints::PutReposownerrepoBranchesbranchProtection(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtection(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "repos",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "repos",
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "repos",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictions(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictions(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "repos",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "repos",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams(..) => "repos",
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "repos",
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "repos",
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers(..) => "repos",
+  EndPoints::PostReposownerrepoBranchesbranchRename(..) => "repos",
+  EndPoints::PostReposownerrepoCheckRuns(..) => "checks",
+  EndPoints::GetReposownerrepoCheckRunscheckRunId(..) => "checks",
+  EndPoints::PatchReposownerrepoCheckRunscheckRunId(..) => "checks",
+  EndPoints::GetReposownerrepoCheckRunscheckRunIdAnnotations(..) => "checks",
+  EndPoints::PostReposownerrepoCheckRunscheckRunIdRerequest(..) => "checks",
+  EndPoints::PostReposownerrepoCheckSuites(..) => "checks",
+  EndPoints::PatchReposownerrepoCheckSuitesPreferences(..) => "checks",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteId(..) => "checks",
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(..) => "checks",
+  EndPoints::PostReposownerrepoCheckSuitescheckSuiteIdRerequest(..) => "checks",
+  EndPoints::GetReposownerrepoCodeScanningAlerts(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumber(..) => "code-scanning",
+  EndPoints::PatchReposownerrepoCodeScanningAlertsalertNumber(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumberInstances(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodeScanningAnalyses(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodeScanningAnalysesanalysisId(..) => "code-scanning",
+  EndPoints::DeleteReposownerrepoCodeScanningAnalysesanalysisId(..) => "code-scanning",
+  EndPoints::PostReposownerrepoCodeScanningSarifs(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodeScanningSarifssarifId(..) => "code-scanning",
+  EndPoints::GetReposownerrepoCodespaces(..) => "codespaces",
+  EndPoints::PostReposownerrepoCodespaces(..) => "codespaces",
+  EndPoints::GetReposownerrepoCodespacesMachines(..) => "codespaces",
+  EndPoints::GetReposownerrepoCollaborators(..) => "repos",
+  EndPoints::GetReposownerrepoCollaboratorsusername(..) => "repos",
+  EndPoints::PutReposownerrepoCollaboratorsusername(..) => "repos",
+  EndPoints::DeleteReposownerrepoCollaboratorsusername(..) => "repos",
+  EndPoints::GetReposownerrepoCollaboratorsusernamePermission(..) => "repos",
+  EndPoints::GetReposownerrepoComments(..) => "repos",
+  EndPoints::GetReposownerrepoCommentscommentId(..) => "repos",
+  EndPoints::PatchReposownerrepoCommentscommentId(..) => "repos",
+  EndPoints::DeleteReposownerrepoCommentscommentId(..) => "repos",
+  EndPoints::GetReposownerrepoCommentscommentIdReactions(..) => "reactions",
+  EndPoints::PostReposownerrepoCommentscommentIdReactions(..) => "reactions",
+  EndPoints::DeleteReposownerrepoCommentscommentIdReactionsreactionId(..) => "reactions",
+  EndPoints::GetReposownerrepoCommits(..) => "repos",
+  EndPoints::GetReposownerrepoCommitscommitShaBranchesWhereHead(..) => "repos",
+  EndPoints::GetReposownerrepoCommitscommitShaComments(..) => "repos",
+  EndPoints::PostReposownerrepoCommitscommitShaComments(..) => "repos",
+  EndPoints::GetReposownerrepoCommitscommitShaPulls(..) => "repos",
+  EndPoints::GetReposownerrepoCommitsref(..) => "repos",
+  EndPoints::GetReposownerrepoCommitsrefCheckRuns(..) => "checks",
+  EndPoints::GetReposownerrepoCommitsrefCheckSuites(..) => "checks",
+  EndPoints::GetReposownerrepoCommitsrefStatus(..) => "repos",
+  EndPoints::GetReposownerrepoCommitsrefStatuses(..) => "repos",
+  EndPoints::GetReposownerrepoCommunityProfile(..) => "repos",
+  EndPoints::GetReposownerrepoComparebasehead(..) => "repos",
+  EndPoints::GetReposownerrepoContentspath(..) => "repos",
+  EndPoints::PutReposownerrepoContentspath(..) => "repos",
+  EndPoints::DeleteReposownerrepoContentspath(..) => "repos",
+  EndPoints::GetReposownerrepoContributors(..) => "repos",
+  EndPoints::GetReposownerrepoDependabotSecrets(..) => "dependabot",
+  EndPoints::GetReposownerrepoDependabotSecretsPublicKey(..) => "dependabot",
+  EndPoints::GetReposownerrepoDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::PutReposownerrepoDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::DeleteReposownerrepoDependabotSecretssecretName(..) => "dependabot",
+  EndPoints::GetReposownerrepoDeployments(..) => "repos",
+  EndPoints::PostReposownerrepoDeployments(..) => "repos",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentId(..) => "repos",
+  EndPoints::DeleteReposownerrepoDeploymentsdeploymentId(..) => "repos",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatuses(..) => "repos",
+  EndPoints::PostReposownerrepoDeploymentsdeploymentIdStatuses(..) => "repos",
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId(..) => "repos",
+  EndPoints::PostReposownerrepoDispatches(..) => "repos",
+  EndPoints::GetReposownerrepoEnvironments(..) => "repos",
+  EndPoints::GetReposownerrepoEnvironmentsenvironmentName(..) => "repos",
+  EndPoints::PutReposownerrepoEnvironmentsenvironmentName(..) => "repos",
+  EndPoints::DeleteReposownerrepoEnvironmentsenvironmentName(..) => "repos",
+  EndPoints::GetReposownerrepoEvents(..) => "activity",
+  EndPoints::GetReposownerrepoForks(..) => "repos",
+  EndPoints::PostReposownerrepoForks(..) => "repos",
+  EndPoints::PostReposownerrepoGitBlobs(..) => "git",
+  EndPoints::GetReposownerrepoGitBlobsfileSha(..) => "git",
+  EndPoints::PostReposownerrepoGitCommits(..) => "git",
+  EndPoints::GetReposownerrepoGitCommitscommitSha(..) => "git",
+  EndPoints::GetReposownerrepoGitMatchingRefsref(..) => "git",
+  EndPoints::GetReposownerrepoGitRefref(..) => "git",
+  EndPoints::PostReposownerrepoGitRefs(..) => "git",
+  EndPoints::PatchReposownerrepoGitRefsref(..) => "git",
+  EndPoints::DeleteReposownerrepoGitRefsref(..) => "git",
+  EndPoints::PostReposownerrepoGitTags(..) => "git",
+  EndPoints::GetReposownerrepoGitTagstagSha(..) => "git",
+  EndPoints::PostReposownerrepoGitTrees(..) => "git",
+  EndPoints::GetReposownerrepoGitTreestreeSha(..) => "git",
+  EndPoints::GetReposownerrepoHooks(..) => "repos",
+  EndPoints::PostReposownerrepoHooks(..) => "repos",
+  EndPoints::GetReposownerrepoHookshookId(..) => "repos",
+  EndPoints::PatchReposownerrepoHookshookId(..) => "repos",
+  EndPoints::DeleteReposownerrepoHookshookId(..) => "repos",
+  EndPoints::GetReposownerrepoHookshookIdConfig(..) => "repos",
+  EndPoints::PatchReposownerrepoHookshookIdConfig(..) => "repos",
+  EndPoints::GetReposownerrepoHookshookIdDeliveries(..) => "repos",
+  EndPoints::GetReposownerrepoHookshookIdDeliveriesdeliveryId(..) => "repos",
+  EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(..) => "repos",
+  EndPoints::PostReposownerrepoHookshookIdPings(..) => "repos",
+  EndPoints::PostReposownerrepoHookshookIdTests(..) => "repos",
+  EndPoints::GetReposownerrepoImport(..) => "migrations",
+  EndPoints::PutReposownerrepoImport(..) => "migrations",
+  EndPoints::PatchReposownerrepoImport(..) => "migrations",
+  EndPoints::DeleteReposownerrepoImport(..) => "migrations",
+  EndPoints::GetReposownerrepoImportAuthors(..) => "migrations",
+  EndPoints::PatchReposownerrepoImportAuthorsauthorId(..) => "migrations",
+  EndPoints::GetReposownerrepoImportLargeFiles(..) => "migrations",
+  EndPoints::PatchReposownerrepoImportLfs(..) => "migrations",
+  EndPoints::GetReposownerrepoInstallation(..) => "apps",
+  EndPoints::GetReposownerrepoInteractionLimits(..) => "interactions",
+  EndPoints::PutReposownerrepoInteractionLimits(..) => "interactions",
+  EndPoints::DeleteReposownerrepoInteractionLimits(..) => "interactions",
+  EndPoints::GetReposownerrepoInvitations(..) => "repos",
+  EndPoints::PatchReposownerrepoInvitationsinvitationId(..) => "repos",
+  EndPoints::DeleteReposownerrepoInvitationsinvitationId(..) => "repos",
+  EndPoints::GetReposownerrepoIssues(..) => "issues",
+  EndPoints::PostReposownerrepoIssues(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesComments(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesCommentscommentId(..) => "issues",
+  EndPoints::PatchReposownerrepoIssuesCommentscommentId(..) => "issues",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentId(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesCommentscommentIdReactions(..) => "reactions",
+  EndPoints::PostReposownerrepoIssuesCommentscommentIdReactions(..) => "reactions",
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId(..) => "reactions",
+  EndPoints::GetReposownerrepoIssuesEvents(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesEventseventId(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesissueNumber(..) => "issues",
+  EndPoints::PatchReposownerrepoIssuesissueNumber(..) => "issues",
+  EndPoints::PostReposownerrepoIssuesissueNumberAssignees(..) => "issues",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesissueNumberComments(..) => "issues",
+  EndPoints::PostReposownerrepoIssuesissueNumberComments(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesissueNumberEvents(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesissueNumberLabels(..) => "issues",
+  EndPoints::PostReposownerrepoIssuesissueNumberLabels(..) => "issues",
+  EndPoints::PutReposownerrepoIssuesissueNumberLabels(..) => "issues",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(..) => "issues",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(..) => "issues",
+  EndPoints::PutReposownerrepoIssuesissueNumberLock(..) => "issues",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLock(..) => "issues",
+  EndPoints::GetReposownerrepoIssuesissueNumberReactions(..) => "reactions",
+  EndPoints::PostReposownerrepoIssuesissueNumberReactions(..) => "reactions",
+  EndPoints::DeleteReposownerrepoIssuesissueNumberReactionsreactionId(..) => "reactions",
+  EndPoints::GetReposownerrepoIssuesissueNumberTimeline(..) => "issues",
+  EndPoints::GetReposownerrepoKeys(..) => "repos",
+  EndPoints::PostReposownerrepoKeys(..) => "repos",
+  EndPoints::GetReposownerrepoKeyskeyId(..) => "repos",
+  EndPoints::DeleteReposownerrepoKeyskeyId(..) => "repos",
+  EndPoints::GetReposownerrepoLabels(..) => "issues",
+  EndPoints::PostReposownerrepoLabels(..) => "issues",
+  EndPoints::GetReposownerrepoLabelsname(..) => "issues",
+  EndPoints::PatchReposownerrepoLabelsname(..) => "issues",
+  EndPoints::DeleteReposownerrepoLabelsname(..) => "issues",
+  EndPoints::GetReposownerrepoLanguages(..) => "repos",
+  EndPoints::PutReposownerrepoLfs(..) => "repos",
+  EndPoints::DeleteReposownerrepoLfs(..) => "repos",
+  EndPoints::GetReposownerrepoLicense(..) => "licenses",
+  EndPoints::PostReposownerrepoMergeUpstream(..) => "repos",
+  EndPoints::PostReposownerrepoMerges(..) => "repos",
+  EndPoints::GetReposownerrepoMilestones(..) => "issues",
+  EndPoints::PostReposownerrepoMilestones(..) => "issues",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumber(..) => "issues",
+  EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(..) => "issues",
+  EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(..) => "issues",
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(..) => "issues",
+  EndPoints::GetReposownerrepoNotifications(..) => "activity",
+  EndPoints::PutReposownerrepoNotifications(..) => "activity",
+  EndPoints::GetReposownerrepoPages(..) => "repos",
+  EndPoints::PostReposownerrepoPages(..) => "repos",
+  EndPoints::PutReposownerrepoPages(..) => "repos",
+  EndPoints::DeleteReposownerrepoPages(..) => "repos",
+  EndPoints::GetReposownerrepoPagesBuilds(..) => "repos",
+  EndPoints::PostReposownerrepoPagesBuilds(..) => "repos",
+  EndPoints::GetReposownerrepoPagesBuildsLatest(..) => "repos",
+  EndPoints::GetReposownerrepoPagesBuildsbuildId(..) => "repos",
+  EndPoints::GetReposownerrepoPagesHealth(..) => "repos",
+  EndPoints::GetReposownerrepoProjects(..) => "projects",
+  EndPoints::PostReposownerrepoProjects(..) => "projects",
+  EndPoints::GetReposownerrepoPulls(..) => "pulls",
+  EndPoints::PostReposownerrepoPulls(..) => "pulls",
+  EndPoints::GetReposownerrepoPullsComments(..) => "pulls",
+  EndPoints::GetReposownerrepoPullsCommentscommentId(..) => "pulls",
+  EndPoints::PatchReposownerrepoPullsCommentscommentId(..) => "pulls",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentId(..) => "pulls",
+  EndPoints::GetReposownerrepoPullsCommentscommentIdReactions(..) => "reactions",
+  EndPoints::PostReposownerrepoPullsCommentscommentIdReactions(..) => "reactions",
+  EndPoints::DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId(..) => "reactions",
+  EndPoints::GetReposownerrepoPullspullNumber(..) => "pulls",
+  EndPoints::PatchReposownerrepoPullspullNumber(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberCodespaces(..) => "codespaces",
+  EndPoints::GetReposownerrepoPullspullNumberComments(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberComments(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberCommits(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberFiles(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberMerge(..) => "pulls",
+  EndPoints::PutReposownerrepoPullspullNumberMerge(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberRequestedReviewers(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(..) => "pulls",
+  EndPoints::DeleteReposownerrepoPullspullNumberRequestedReviewers(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberReviews(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberReviews(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(..) => "pulls",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(..) => "pulls",
+  EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(..) => "pulls",
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewIdComments(..) => "pulls",
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(..) => "pulls",
+  EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(..) => "pulls",
+  EndPoints::PutReposownerrepoPullspullNumberUpdateBranch(..) => "pulls",
+  EndPoints::GetReposownerrepoReadme(..) => "repos",
+  EndPoints::GetReposownerrepoReadmedir(..) => "repos",
+  EndPoints::GetReposownerrepoReleases(..) => "repos",
+  EndPoints::PostReposownerrepoReleases(..) => "repos",
+  EndPoints::GetReposownerrepoReleasesAssetsassetId(..) => "repos",
+  EndPoints::PatchReposownerrepoReleasesAssetsassetId(..) => "repos",
+  EndPoints::DeleteReposownerrepoReleasesAssetsassetId(..) => "repos",
+  EndPoints::PostReposownerrepoReleasesGenerateNotes(..) => "repos",
+  EndPoints::GetReposownerrepoReleasesLatest(..) => "repos",
+  EndPoints::GetReposownerrepoReleasesTagstag(..) => "repos",
+  EndPoints::GetReposownerrepoReleasesreleaseId(..) => "repos",
+  EndPoints::PatchReposownerrepoReleasesreleaseId(..) => "repos",
+  EndPoints::DeleteReposownerrepoReleasesreleaseId(..) => "repos",
+  EndPoints::GetReposownerrepoReleasesreleaseIdAssets(..) => "repos",
+  EndPoints::PostReposownerrepoReleasesreleaseIdAssets(..) => "repos",
+  EndPoints::PostReposownerrepoReleasesreleaseIdReactions(..) => "reactions",
+  EndPoints::GetReposownerrepoSecretScanningAlerts(..) => "secret-scanning",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumber(..) => "secret-scanning",
+  EndPoints::PatchReposownerrepoSecretScanningAlertsalertNumber(..) => "secret-scanning",
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumberLocations(..) => "secret-scanning",
+  EndPoints::GetReposownerrepoStargazers(..) => "activity",
+  EndPoints::GetReposownerrepoStatsCodeFrequency(..) => "repos",
+  EndPoints::GetReposownerrepoStatsCommitActivity(..) => "repos",
+  EndPoints::GetReposownerrepoStatsContributors(..) => "repos",
+  EndPoints::GetReposownerrepoStatsParticipation(..) => "repos",
+  EndPoints::GetReposownerrepoStatsPunchCard(..) => "repos",
+  EndPoints::PostReposownerrepoStatusessha(..) => "repos",
+  EndPoints::GetReposownerrepoSubscribers(..) => "activity",
+  EndPoints::GetReposownerrepoSubscription(..) => "activity",
+  EndPoints::PutReposownerrepoSubscription(..) => "activity",
+  EndPoints::DeleteReposownerrepoSubscription(..) => "activity",
+  EndPoints::GetReposownerrepoTags(..) => "repos",
+  EndPoints::GetReposownerrepoTarballref(..) => "repos",
+  EndPoints::GetReposownerrepoTeams(..) => "repos",
+  EndPoints::GetReposownerrepoTopics(..) => "repos",
+  EndPoints::PutReposownerrepoTopics(..) => "repos",
+  EndPoints::GetReposownerrepoTrafficClones(..) => "repos",
+  EndPoints::GetReposownerrepoTrafficPopularPaths(..) => "repos",
+  EndPoints::GetReposownerrepoTrafficPopularReferrers(..) => "repos",
+  EndPoints::GetReposownerrepoTrafficViews(..) => "repos",
+  EndPoints::PostReposownerrepoTransfer(..) => "repos",
+  EndPoints::GetReposownerrepoVulnerabilityAlerts(..) => "repos",
+  EndPoints::PutReposownerrepoVulnerabilityAlerts(..) => "repos",
+  EndPoints::DeleteReposownerrepoVulnerabilityAlerts(..) => "repos",
+  EndPoints::GetReposownerrepoZipballref(..) => "repos",
+  EndPoints::PostRepostemplateOwnertemplateRepoGenerate(..) => "repos",
+  EndPoints::GetRepositories(..) => "repos",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets(..) => "actions",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey(..) => "actions",
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "actions",
+  EndPoints::PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "actions",
+  EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(..) => "actions",
+  EndPoints::GetScimV2EnterprisesenterpriseGroups(..) => "enterprise-admin",
+  EndPoints::PostScimV2EnterprisesenterpriseGroups(..) => "enterprise-admin",
+  EndPoints::GetScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "enterprise-admin",
+  EndPoints::PutScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "enterprise-admin",
+  EndPoints::PatchScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "enterprise-admin",
+  EndPoints::DeleteScimV2EnterprisesenterpriseGroupsscimGroupId(..) => "enterprise-admin",
+  EndPoints::GetScimV2EnterprisesenterpriseUsers(..) => "enterprise-admin",
+  EndPoints::PostScimV2EnterprisesenterpriseUsers(..) => "enterprise-admin",
+  EndPoints::GetScimV2EnterprisesenterpriseUsersscimUserId(..) => "enterprise-admin",
+  EndPoints::PutScimV2EnterprisesenterpriseUsersscimUserId(..) => "enterprise-admin",
+  EndPoints::PatchScimV2EnterprisesenterpriseUsersscimUserId(..) => "enterprise-admin",
+  EndPoints::DeleteScimV2EnterprisesenterpriseUsersscimUserId(..) => "enterprise-admin",
+  EndPoints::GetScimV2OrganizationsorgUsers(..) => "scim",
+  EndPoints::PostScimV2OrganizationsorgUsers(..) => "scim",
+  EndPoints::GetScimV2OrganizationsorgUsersscimUserId(..) => "scim",
+  EndPoints::PutScimV2OrganizationsorgUsersscimUserId(..) => "scim",
+  EndPoints::PatchScimV2OrganizationsorgUsersscimUserId(..) => "scim",
+  EndPoints::DeleteScimV2OrganizationsorgUsersscimUserId(..) => "scim",
+  EndPoints::GetSearchCode(..) => "search",
+  EndPoints::GetSearchCommits(..) => "search",
+  EndPoints::GetSearchIssues(..) => "search",
+  EndPoints::GetSearchLabels(..) => "search",
+  EndPoints::GetSearchRepositories(..) => "search",
+  EndPoints::GetSearchTopics(..) => "search",
+  EndPoints::GetSearchUsers(..) => "search",
+  EndPoints::GetTeamsteamId(..) => "teams",
+  EndPoints::PatchTeamsteamId(..) => "teams",
+  EndPoints::DeleteTeamsteamId(..) => "teams",
+  EndPoints::GetTeamsteamIdDiscussions(..) => "teams",
+  EndPoints::PostTeamsteamIdDiscussions(..) => "teams",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumber(..) => "teams",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberComments(..) => "teams",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(..) => "teams",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(..) => "teams",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "reactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(..) => "reactions",
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "reactions",
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberReactions(..) => "reactions",
+  EndPoints::GetTeamsteamIdInvitations(..) => "teams",
+  EndPoints::GetTeamsteamIdMembers(..) => "teams",
+  EndPoints::GetTeamsteamIdMembersusername(..) => "teams",
+  EndPoints::PutTeamsteamIdMembersusername(..) => "teams",
+  EndPoints::DeleteTeamsteamIdMembersusername(..) => "teams",
+  EndPoints::GetTeamsteamIdMembershipsusername(..) => "teams",
+  EndPoints::PutTeamsteamIdMembershipsusername(..) => "teams",
+  EndPoints::DeleteTeamsteamIdMembershipsusername(..) => "teams",
+  EndPoints::GetTeamsteamIdProjects(..) => "teams",
+  EndPoints::GetTeamsteamIdProjectsprojectId(..) => "teams",
+  EndPoints::PutTeamsteamIdProjectsprojectId(..) => "teams",
+  EndPoints::DeleteTeamsteamIdProjectsprojectId(..) => "teams",
+  EndPoints::GetTeamsteamIdRepos(..) => "teams",
+  EndPoints::GetTeamsteamIdReposownerrepo(..) => "teams",
+  EndPoints::PutTeamsteamIdReposownerrepo(..) => "teams",
+  EndPoints::DeleteTeamsteamIdReposownerrepo(..) => "teams",
+  EndPoints::GetTeamsteamIdTeamSyncGroupMappings(..) => "teams",
+  EndPoints::PatchTeamsteamIdTeamSyncGroupMappings(..) => "teams",
+  EndPoints::GetTeamsteamIdTeams(..) => "teams",
+  EndPoints::GetUser(..) => "users",
+  EndPoints::PatchUser(..) => "users",
+  EndPoints::GetUserBlocks(..) => "users",
+  EndPoints::GetUserBlocksusername(..) => "users",
+  EndPoints::PutUserBlocksusername(..) => "users",
+  EndPoints::DeleteUserBlocksusername(..) => "users",
+  EndPoints::GetUserCodespaces(..) => "codespaces",
+  EndPoints::PostUserCodespaces(..) => "codespaces",
+  EndPoints::GetUserCodespacesSecrets(..) => "codespaces",
+  EndPoints::GetUserCodespacesSecretsPublicKey(..) => "codespaces",
+  EndPoints::GetUserCodespacesSecretssecretName(..) => "codespaces",
+  EndPoints::PutUserCodespacesSecretssecretName(..) => "codespaces",
+  EndPoints::DeleteUserCodespacesSecretssecretName(..) => "codespaces",
+  EndPoints::GetUserCodespacesSecretssecretNameRepositories(..) => "codespaces",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositories(..) => "codespaces",
+  EndPoints::PutUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "codespaces",
+  EndPoints::DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId(..) => "codespaces",
+  EndPoints::GetUserCodespacescodespaceName(..) => "codespaces",
+  EndPoints::PatchUserCodespacescodespaceName(..) => "codespaces",
+  EndPoints::DeleteUserCodespacescodespaceName(..) => "codespaces",
+  EndPoints::PostUserCodespacescodespaceNameExports(..) => "codespaces",
+  EndPoints::GetUserCodespacescodespaceNameExportsexportId(..) => "codespaces",
+  EndPoints::GetUserCodespacescodespaceNameMachines(..) => "codespaces",
+  EndPoints::PostUserCodespacescodespaceNameStart(..) => "codespaces",
+  EndPoints::PostUserCodespacescodespaceNameStop(..) => "codespaces",
+  EndPoints::PatchUserEmailVisibility(..) => "users",
+  EndPoints::GetUserEmails(..) => "users",
+  EndPoints::PostUserEmails(..) => "users",
+  EndPoints::DeleteUserEmails(..) => "users",
+  EndPoints::GetUserFollowers(..) => "users",
+  EndPoints::GetUserFollowing(..) => "users",
+  EndPoints::GetUserFollowingusername(..) => "users",
+  EndPoints::PutUserFollowingusername(..) => "users",
+  EndPoints::DeleteUserFollowingusername(..) => "users",
+  EndPoints::GetUserGpgKeys(..) => "users",
+  EndPoints::PostUserGpgKeys(..) => "users",
+  EndPoints::GetUserGpgKeysgpgKeyId(..) => "users",
+  EndPoints::DeleteUserGpgKeysgpgKeyId(..) => "users",
+  EndPoints::GetUserInstallations(..) => "apps",
+  EndPoints::GetUserInstallationsinstallationIdRepositories(..) => "apps",
+  EndPoints::PutUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "apps",
+  EndPoints::DeleteUserInstallationsinstallationIdRepositoriesrepositoryId(..) => "apps",
+  EndPoints::GetUserInteractionLimits(..) => "interactions",
+  EndPoints::PutUserInteractionLimits(..) => "interactions",
+  EndPoints::DeleteUserInteractionLimits(..) => "interactions",
+  EndPoints::GetUserIssues(..) => "issues",
+  EndPoints::GetUserKeys(..) => "users",
+  EndPoints::PostUserKeys(..) => "users",
+  EndPoints::GetUserKeyskeyId(..) => "users",
+  EndPoints::DeleteUserKeyskeyId(..) => "users",
+  EndPoints::GetUserMarketplacePurchases(..) => "apps",
+  EndPoints::GetUserMarketplacePurchasesStubbed(..) => "apps",
+  EndPoints::GetUserMembershipsOrgs(..) => "orgs",
+  EndPoints::GetUserMembershipsOrgsorg(..) => "orgs",
+  EndPoints::PatchUserMembershipsOrgsorg(..) => "orgs",
+  EndPoints::GetUserMigrations(..) => "migrations",
+  EndPoints::PostUserMigrations(..) => "migrations",
+  EndPoints::GetUserMigrationsmigrationId(..) => "migrations",
+  EndPoints::GetUserMigrationsmigrationIdArchive(..) => "migrations",
+  EndPoints::DeleteUserMigrationsmigrationIdArchive(..) => "migrations",
+  EndPoints::DeleteUserMigrationsmigrationIdReposrepoNameLock(..) => "migrations",
+  EndPoints::GetUserMigrationsmigrationIdRepositories(..) => "migrations",
+  EndPoints::GetUserOrgs(..) => "orgs",
+  EndPoints::GetUserPackages(..) => "packages",
+  EndPoints::GetUserPackagespackageTypepackageName(..) => "packages",
+  EndPoints::DeleteUserPackagespackageTypepackageName(..) => "packages",
+  EndPoints::PostUserPackagespackageTypepackageNameRestore(..) => "packages",
+  EndPoints::GetUserPackagespackageTypepackageNameVersions(..) => "packages",
+  EndPoints::GetUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::DeleteUserPackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "packages",
+  EndPoints::PostUserProjects(..) => "projects",
+  EndPoints::GetUserPublicEmails(..) => "users",
+  EndPoints::GetUserRepos(..) => "repos",
+  EndPoints::PostUserRepos(..) => "repos",
+  EndPoints::GetUserRepositoryInvitations(..) => "repos",
+  EndPoints::PatchUserRepositoryInvitationsinvitationId(..) => "repos",
+  EndPoints::DeleteUserRepositoryInvitationsinvitationId(..) => "repos",
+  EndPoints::GetUserStarred(..) => "activity",
+  EndPoints::GetUserStarredownerrepo(..) => "activity",
+  EndPoints::PutUserStarredownerrepo(..) => "activity",
+  EndPoints::DeleteUserStarredownerrepo(..) => "activity",
+  EndPoints::GetUserSubscriptions(..) => "activity",
+  EndPoints::GetUserTeams(..) => "teams",
+  EndPoints::GetUsers(..) => "users",
+  EndPoints::GetUsersusername(..) => "users",
+  EndPoints::GetUsersusernameEvents(..) => "activity",
+  EndPoints::GetUsersusernameEventsOrgsorg(..) => "activity",
+  EndPoints::GetUsersusernameEventsPublic(..) => "activity",
+  EndPoints::GetUsersusernameFollowers(..) => "users",
+  EndPoints::GetUsersusernameFollowing(..) => "users",
+  EndPoints::GetUsersusernameFollowingtargetUser(..) => "users",
+  EndPoints::GetUsersusernameGists(..) => "gists",
+  EndPoints::GetUsersusernameGpgKeys(..) => "users",
+  EndPoints::GetUsersusernameHovercard(..) => "users",
+  EndPoints::GetUsersusernameInstallation(..) => "apps",
+  EndPoints::GetUsersusernameKeys(..) => "users",
+  EndPoints::GetUsersusernameOrgs(..) => "orgs",
+  EndPoints::GetUsersusernamePackages(..) => "packages",
+  EndPoints::GetUsersusernamePackagespackageTypepackageName(..) => "packages",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageName(..) => "packages",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameRestore(..) => "packages",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersions(..) => "packages",
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(..) => "packages",
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore(..) => "packages",
+  EndPoints::GetUsersusernameProjects(..) => "projects",
+  EndPoints::GetUsersusernameReceivedEvents(..) => "activity",
+  EndPoints::GetUsersusernameReceivedEventsPublic(..) => "activity",
+  EndPoints::GetUsersusernameRepos(..) => "repos",
+  EndPoints::GetUsersusernameSettingsBillingActions(..) => "billing",
+  EndPoints::GetUsersusernameSettingsBillingPackages(..) => "billing",
+  EndPoints::GetUsersusernameSettingsBillingSharedStorage(..) => "billing",
+  EndPoints::GetUsersusernameStarred(..) => "activity",
+  EndPoints::GetUsersusernameSubscriptions(..) => "activity",
+  EndPoints::GetZen(..) => "meta"}
+    }
+    pub const ROUTES: &'static [(&'static str, Methods, &'static str)] = &[("Get", Methods::Get, "/"),
+  ("GetApp", Methods::Get, "/app"),
+  ("PostAppManifestscodeConversions", Methods::Post, "/app-manifests/{code}/conversions"),
+  ("GetAppHookConfig", Methods::Get, "/app/hook/config"),
+  ("PatchAppHookConfig", Methods::Patch, "/app/hook/config"),
+  ("GetAppHookDeliveries", Methods::Get, "/app/hook/deliveries"),
+  ("GetAppHookDeliveriesdeliveryId", Methods::Get, "/app/hook/deliveries/{delivery_id}"),
+  ("PostAppHookDeliveriesdeliveryIdAttempts", Methods::Post, "/app/hook/deliveries/{delivery_id}/attempts"),
+  ("GetAppInstallations", Methods::Get, "/app/installations"),
+  ("GetAppInstallationsinstallationId", Methods::Get, "/app/installations/{installation_id}"),
+  ("DeleteAppInstallationsinstallationId", Methods::Delete, "/app/installations/{installation_id}"),
+  ("PostAppInstallationsinstallationIdAccessTokens", Methods::Post, "/app/installations/{installation_id}/access_tokens"),
+  ("PutAppInstallationsinstallationIdSuspended", Methods::Put, "/app/installations/{installation_id}/suspended"),
+  ("DeleteAppInstallationsinstallationIdSuspended", Methods::Delete, "/app/installations/{installation_id}/suspended"),
+  ("GetApplicationsGrants", Methods::Get, "/applications/grants"),
+  ("GetApplicationsGrantsgrantId", Methods::Get, "/applications/grants/{grant_id}"),
+  ("DeleteApplicationsGrantsgrantId", Methods::Delete, "/applications/grants/{grant_id}"),
+  ("DeleteApplicationsclientIdGrant", Methods::Delete, "/applications/{client_id}/grant"),
+  ("PostApplicationsclientIdToken", Methods::Post, "/applications/{client_id}/token"),
+  ("PatchApplicationsclientIdToken", Methods::Patch, "/applications/{client_id}/token"),
+  ("DeleteApplicationsclientIdToken", Methods::Delete, "/applications/{client_id}/token"),
+  ("PostApplicationsclientIdTokenScoped", Methods::Post, "/applications/{client_id}/token/scoped"),
+  ("GetAppsappSlug", Methods::Get, "/apps/{app_slug}"),
+  ("GetAuthorizations", Methods::Get, "/authorizations"),
+  ("PostAuthorizations", Methods::Post, "/authorizations"),
+  ("PutAuthorizationsClientsclientId", Methods::Put, "/authorizations/clients/{client_id}"),
+  ("PutAuthorizationsClientsclientIdfingerprint", Methods::Put, "/authorizations/clients/{client_id}/{fingerprint}"),
+  ("GetAuthorizationsauthorizationId", Methods::Get, "/authorizations/{authorization_id}"),
+  ("PatchAuthorizationsauthorizationId", Methods::Patch, "/authorizations/{authorization_id}"),
+  ("DeleteAuthorizationsauthorizationId", Methods::Delete, "/authorizations/{authorization_id}"),
+  ("GetCodesOfConduct", Methods::Get, "/codes_of_conduct"),
+  ("GetCodesOfConductkey", Methods::Get, "/codes_of_conduct/{key}"),
+  ("GetEmojis", Methods::Get, "/emojis"),
+  ("GetEnterprisesenterpriseActionsPermissions", Methods::Get, "/enterprises/{enterprise}/actions/permissions"),
+  ("PutEnterprisesenterpriseActionsPermissions", Methods::Put, "/enterprises/{enterprise}/actions/permissions"),
+  ("GetEnterprisesenterpriseActionsPermissionsOrganizations", Methods::Get, "/enterprises/{enterprise}/actions/permissions/organizations"),
+  ("PutEnterprisesenterpriseActionsPermissionsOrganizations", Methods::Put, "/enterprises/{enterprise}/actions/permissions/organizations"),
+  ("PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId", Methods::Put, "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}"),
+  ("DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId", Methods::Delete, "/enterprises/{enterprise}/actions/permissions/organizations/{org_id}"),
+  ("GetEnterprisesenterpriseActionsPermissionsSelectedActions", Methods::Get, "/enterprises/{enterprise}/actions/permissions/selected-actions"),
+  ("PutEnterprisesenterpriseActionsPermissionsSelectedActions", Methods::Put, "/enterprises/{enterprise}/actions/permissions/selected-actions"),
+  ("GetEnterprisesenterpriseActionsRunnerGroups", Methods::Get, "/enterprises/{enterprise}/actions/runner-groups"),
+  ("PostEnterprisesenterpriseActionsRunnerGroups", Methods::Post, "/enterprises/{enterprise}/actions/runner-groups"),
+  ("GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId", Methods::Get, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}"),
+  ("PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId", Methods::Patch, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}"),
+  ("DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId", Methods::Delete, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}"),
+  ("GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations", Methods::Get, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations"),
+  ("PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations", Methods::Put, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations"),
+  ("PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId", Methods::Put, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}"),
+  ("DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId", Methods::Delete, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/organizations/{org_id}"),
+  ("GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners", Methods::Get, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners"),
+  ("PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners", Methods::Put, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners"),
+  ("PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId", Methods::Put, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}"),
+  ("DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId", Methods::Delete, "/enterprises/{enterprise}/actions/runner-groups/{runner_group_id}/runners/{runner_id}"),
+  ("GetEnterprisesenterpriseActionsRunners", Methods::Get, "/enterprises/{enterprise}/actions/runners"),
+  ("GetEnterprisesenterpriseActionsRunnersDownloads", Methods::Get, "/enterprises/{enterprise}/actions/runners/downloads"),
+  ("PostEnterprisesenterpriseActionsRunnersRegistrationToken", Methods::Post, "/enterprises/{enterprise}/actions/runners/registration-token"),
+  ("PostEnterprisesenterpriseActionsRunnersRemoveToken", Methods::Post, "/enterprises/{enterprise}/actions/runners/remove-token"),
+  ("GetEnterprisesenterpriseActionsRunnersrunnerId", Methods::Get, "/enterprises/{enterprise}/actions/runners/{runner_id}"),
+  ("DeleteEnterprisesenterpriseActionsRunnersrunnerId", Methods::Delete, "/enterprises/{enterprise}/actions/runners/{runner_id}"),
+  ("GetEnterprisesenterpriseActionsRunnersrunnerIdLabels", Methods::Get, "/enterprises/{enterprise}/actions/runners/{runner_id}/labels"),
+  ("PostEnterprisesenterpriseActionsRunnersrunnerIdLabels", Methods::Post, "/enterprises/{enterprise}/actions/runners/{runner_id}/labels"),
+  ("PutEnterprisesenterpriseActionsRunnersrunnerIdLabels", Methods::Put, "/enterprises/{enterprise}/actions/runners/{runner_id}/labels"),
+  ("DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels", Methods::Delete, "/enterprises/{enterprise}/actions/runners/{runner_id}/labels"),
+  ("DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname", Methods::Delete, "/enterprises/{enterprise}/actions/runners/{runner_id}/labels/{name}"),
+  ("GetEnterprisesenterpriseAuditLog", Methods::Get, "/enterprises/{enterprise}/audit-log"),
+  ("GetEnterprisesenterpriseSecretScanningAlerts", Methods::Get, "/enterprises/{enterprise}/secret-scanning/alerts"),
+  ("GetEnterprisesenterpriseSettingsBillingActions", Methods::Get, "/enterprises/{enterprise}/settings/billing/actions"),
+  ("GetEnterprisesenterpriseSettingsBillingAdvancedSecurity", Methods::Get, "/enterprises/{enterprise}/settings/billing/advanced-security"),
+  ("GetEnterprisesenterpriseSettingsBillingPackages", Methods::Get, "/enterprises/{enterprise}/settings/billing/packages"),
+  ("GetEnterprisesenterpriseSettingsBillingSharedStorage", Methods::Get, "/enterprises/{enterprise}/settings/billing/shared-storage"),
+  ("GetEvents", Methods::Get, "/events"),
+  ("GetFeeds", Methods::Get, "/feeds"),
+  ("GetGists", Methods::Get, "/gists"),
+  ("PostGists", Methods::Post, "/gists"),
+  ("GetGistsPublic", Methods::Get, "/gists/public"),
+  ("GetGistsStarred", Methods::Get, "/gists/starred"),
+  ("GetGistsgistId", Methods::Get, "/gists/{gist_id}"),
+  ("PatchGistsgistId", Methods::Patch, "/gists/{gist_id}"),
+  ("DeleteGistsgistId", Methods::Delete, "/gists/{gist_id}"),
+  ("GetGistsgistIdComments", Methods::Get, "/gists/{gist_id}/comments"),
+  ("PostGistsgistIdComments", Methods::Post, "/gists/{gist_id}/comments"),
+  ("GetGistsgistIdCommentscommentId", Methods::Get, "/gists/{gist_id}/comments/{comment_id}"),
+  ("PatchGistsgistIdCommentscommentId", Methods::Patch, "/gists/{gist_id}/comments/{comment_id}"),
+  ("DeleteGistsgistIdCommentscommentId", Methods::Delete, "/gists/{gist_id}/comments/{comment_id}"),
+  ("GetGistsgistIdCommits", Methods::Get, "/gists/{gist_id}/commits"),
+  ("GetGistsgistIdForks", Methods::Get, "/gists/{gist_id}/forks"),
+  ("PostGistsgistIdForks", Methods::Post, "/gists/{gist_id}/forks"),
+  ("GetGistsgistIdStar", Methods::Get, "/gists/{gist_id}/star"),
+  ("PutGistsgistIdStar", Methods::Put, "/gists/{gist_id}/star"),
+  ("DeleteGistsgistIdStar", Methods::Delete, "/gists/{gist_id}/star"),
+  ("GetGistsgistIdsha", Methods::Get, "/gists/{gist_id}/{sha}"),
+  ("GetGitignoreTemplates", Methods::Get, "/gitignore/templates"),
+  ("GetGitignoreTemplatesname", Methods::Get, "/gitignore/templates/{name}"),
+  ("GetInstallationRepositories", Methods::Get, "/installation/repositories"),
+  ("DeleteInstallationToken", Methods::Delete, "/installation/token"),
+  ("GetIssues", Methods::Get, "/issues"),
+  ("GetLicenses", Methods::Get, "/licenses"),
+  ("GetLicenseslicense", Methods::Get, "/licenses/{license}"),
+  ("PostMarkdown", Methods::Post, "/markdown"),
+  ("PostMarkdownRaw", Methods::Post, "/markdown/raw"),
+  ("GetMarketplaceListingAccountsaccountId", Methods::Get, "/marketplace_listing/accounts/{account_id}"),
+  ("GetMarketplaceListingPlans", Methods::Get, "/marketplace_listing/plans"),
+  ("GetMarketplaceListingPlansplanIdAccounts", Methods::Get, "/marketplace_listing/plans/{plan_id}/accounts"),
+  ("GetMarketplaceListingStubbedAccountsaccountId", Methods::Get, "/marketplace_listing/stubbed/accounts/{account_id}"),
+  ("GetMarketplaceListingStubbedPlans", Methods::Get, "/marketplace_listing/stubbed/plans"),
+  ("GetMarketplaceListingStubbedPlansplanIdAccounts", Methods::Get, "/marketplace_listing/stubbed/plans/{plan_id}/accounts"),
+  ("GetMeta", Methods::Get, "/meta"),
+  ("GetNetworksownerrepoEvents", Methods::Get, "/networks/{owner}/{repo}/events"),
+  ("GetNotifications", Methods::Get, "/notifications"),
+  ("PutNotifications", Methods::Put, "/notifications"),
+  ("GetNotificationsThreadsthreadId", Methods::Get, "/notifications/threads/{thread_id}"),
+  ("PatchNotificationsThreadsthreadId", Methods::Patch, "/notifications/threads/{thread_id}"),
+  ("GetNotificationsThreadsthreadIdSubscription", Methods::Get, "/notifications/threads/{thread_id}/subscription"),
+  ("PutNotificationsThreadsthreadIdSubscription", Methods::Put, "/notifications/threads/{thread_id}/subscription"),
+  ("DeleteNotificationsThreadsthreadIdSubscription", Methods::Delete, "/notifications/threads/{thread_id}/subscription"),
+  ("GetOctocat", Methods::Get, "/octocat"),
+  ("GetOrganizations", Methods::Get, "/organizations"),
+  ("GetOrganizationsorganizationIdCustomRoles", Methods::Get, "/organizations/{organization_id}/custom_roles"),
+  ("GetOrganizationsorgTeamteamSlugExternalGroups", Methods::Get, "/organizations/{org}/team/{team_slug}/external-groups"),
+  ("GetOrgsorg", Methods::Get, "/orgs/{org}"),
+  ("PatchOrgsorg", Methods::Patch, "/orgs/{org}"),
+  ("GetOrgsorgActionsPermissions", Methods::Get, "/orgs/{org}/actions/permissions"),
+  ("PutOrgsorgActionsPermissions", Methods::Put, "/orgs/{org}/actions/permissions"),
+  ("GetOrgsorgActionsPermissionsRepositories", Methods::Get, "/orgs/{org}/actions/permissions/repositories"),
+  ("PutOrgsorgActionsPermissionsRepositories", Methods::Put, "/orgs/{org}/actions/permissions/repositories"),
+  ("PutOrgsorgActionsPermissionsRepositoriesrepositoryId", Methods::Put, "/orgs/{org}/actions/permissions/repositories/{repository_id}"),
+  ("DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId", Methods::Delete, "/orgs/{org}/actions/permissions/repositories/{repository_id}"),
+  ("GetOrgsorgActionsPermissionsSelectedActions", Methods::Get, "/orgs/{org}/actions/permissions/selected-actions"),
+  ("PutOrgsorgActionsPermissionsSelectedActions", Methods::Put, "/orgs/{org}/actions/permissions/selected-actions"),
+  ("GetOrgsorgActionsPermissionsWorkflow", Methods::Get, "/orgs/{org}/actions/permissions/workflow"),
+  ("PutOrgsorgActionsPermissionsWorkflow", Methods::Put, "/orgs/{org}/actions/permissions/workflow"),
+  ("GetOrgsorgActionsRunnerGroups", Methods::Get, "/orgs/{org}/actions/runner-groups"),
+  ("PostOrgsorgActionsRunnerGroups", Methods::Post, "/orgs/{org}/actions/runner-groups"),
+  ("GetOrgsorgActionsRunnerGroupsrunnerGroupId", Methods::Get, "/orgs/{org}/actions/runner-groups/{runner_group_id}"),
+  ("PatchOrgsorgActionsRunnerGroupsrunnerGroupId", Methods::Patch, "/orgs/{org}/actions/runner-groups/{runner_group_id}"),
+  ("DeleteOrgsorgActionsRunnerGroupsrunnerGroupId", Methods::Delete, "/orgs/{org}/actions/runner-groups/{runner_group_id}"),
+  ("GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories", Methods::Get, "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories"),
+  ("PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories", Methods::Put, "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories"),
+  ("PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId", Methods::Put, "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}"),
+  ("DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId", Methods::Delete, "/orgs/{org}/actions/runner-groups/{runner_group_id}/repositories/{repository_id}"),
+  ("GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners", Methods::Get, "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners"),
+  ("PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners", Methods::Put, "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners"),
+  ("PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId", Methods::Put, "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}"),
+  ("DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId", Methods::Delete, "/orgs/{org}/actions/runner-groups/{runner_group_id}/runners/{runner_id}"),
+  ("GetOrgsorgActionsRunners", Methods::Get, "/orgs/{org}/actions/runners"),
+  ("GetOrgsorgActionsRunnersDownloads", Methods::Get, "/orgs/{org}/actions/runners/downloads"),
+  ("PostOrgsorgActionsRunnersRegistrationToken", Methods::Post, "/orgs/{org}/actions/runners/registration-token"),
+  ("PostOrgsorgActionsRunnersRemoveToken", Methods::Post, "/orgs/{org}/actions/runners/remove-token"),
+  ("GetOrgsorgActionsRunnersrunnerId", Methods::Get, "/orgs/{org}/actions/runners/{runner_id}"),
+  ("DeleteOrgsorgActionsRunnersrunnerId", Methods::Delete, "/orgs/{org}/actions/runners/{runner_id}"),
+  ("GetOrgsorgActionsRunnersrunnerIdLabels", Methods::Get, "/orgs/{org}/actions/runners/{runner_id}/labels"),
+  ("PostOrgsorgActionsRunnersrunnerIdLabels", Methods::Post, "/orgs/{org}/actions/runners/{runner_id}/labels"),
+  ("PutOrgsorgActionsRunnersrunnerIdLabels", Methods::Put, "/orgs/{org}/actions/runners/{runner_id}/labels"),
+  ("DeleteOrgsorgActionsRunnersrunnerIdLabels", Methods::Delete, "/orgs/{org}/actions/runners/{runner_id}/labels"),
+  ("DeleteOrgsorgActionsRunnersrunnerIdLabelsname", Methods::Delete, "/orgs/{org}/actions/runners/{runner_id}/labels/{name}"),
+  ("GetOrgsorgActionsSecrets", Methods::Get, "/orgs/{org}/actions/secrets"),
+  ("GetOrgsorgActionsSecretsPublicKey", Methods::Get, "/orgs/{org}/actions/secrets/public-key"),
+  ("GetOrgsorgActionsSecretssecretName", Methods::Get, "/orgs/{org}/actions/secrets/{secret_name}"),
+  ("PutOrgsorgActionsSecretssecretName", Methods::Put, "/orgs/{org}/actions/secrets/{secret_name}"),
+  ("DeleteOrgsorgActionsSecretssecretName", Methods::Delete, "/orgs/{org}/actions/secrets/{secret_name}"),
+  ("GetOrgsorgActionsSecretssecretNameRepositories", Methods::Get, "/orgs/{org}/actions/secrets/{secret_name}/repositories"),
+  ("PutOrgsorgActionsSecretssecretNameRepositories", Methods::Put, "/orgs/{org}/actions/secrets/{secret_name}/repositories"),
+  ("PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId", Methods::Put, "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}"),
+  ("DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId", Methods::Delete, "/orgs/{org}/actions/secrets/{secret_name}/repositories/{repository_id}"),
+  ("GetOrgsorgAuditLog", Methods::Get, "/orgs/{org}/audit-log"),
+  ("GetOrgsorgBlocks", Methods::Get, "/orgs/{org}/blocks"),
+  ("GetOrgsorgBlocksusername", Methods::Get, "/orgs/{org}/blocks/{username}"),
+  ("PutOrgsorgBlocksusername", Methods::Put, "/orgs/{org}/blocks/{username}"),
+  ("DeleteOrgsorgBlocksusername", Methods::Delete, "/orgs/{org}/blocks/{username}"),
+  ("GetOrgsorgCodeScanningAlerts", Methods::Get, "/orgs/{org}/code-scanning/alerts"),
+  ("GetOrgsorgCredentialAuthorizations", Methods::Get, "/orgs/{org}/credential-authorizations"),
+  ("DeleteOrgsorgCredentialAuthorizationscredentialId", Methods::Delete, "/orgs/{org}/credential-authorizations/{credential_id}"),
+  ("GetOrgsorgDependabotSecrets", Methods::Get, "/orgs/{org}/dependabot/secrets"),
+  ("GetOrgsorgDependabotSecretsPublicKey", Methods::Get, "/orgs/{org}/dependabot/secrets/public-key"),
+  ("GetOrgsorgDependabotSecretssecretName", Methods::Get, "/orgs/{org}/dependabot/secrets/{secret_name}"),
+  ("PutOrgsorgDependabotSecretssecretName", Methods::Put, "/orgs/{org}/dependabot/secrets/{secret_name}"),
+  ("DeleteOrgsorgDependabotSecretssecretName", Methods::Delete, "/orgs/{org}/dependabot/secrets/{secret_name}"),
+  ("GetOrgsorgDependabotSecretssecretNameRepositories", Methods::Get, "/orgs/{org}/dependabot/secrets/{secret_name}/repositories"),
+  ("PutOrgsorgDependabotSecretssecretNameRepositories", Methods::Put, "/orgs/{org}/dependabot/secrets/{secret_name}/repositories"),
+  ("PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId", Methods::Put, "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}"),
+  ("DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId", Methods::Delete, "/orgs/{org}/dependabot/secrets/{secret_name}/repositories/{repository_id}"),
+  ("GetOrgsorgEvents", Methods::Get, "/orgs/{org}/events"),
+  ("GetOrgsorgExternalGroupgroupId", Methods::Get, "/orgs/{org}/external-group/{group_id}"),
+  ("GetOrgsorgExternalGroups", Methods::Get, "/orgs/{org}/external-groups"),
+  ("GetOrgsorgFailedInvitations", Methods::Get, "/orgs/{org}/failed_invitations"),
+  ("GetOrgsorgHooks", Methods::Get, "/orgs/{org}/hooks"),
+  ("PostOrgsorgHooks", Methods::Post, "/orgs/{org}/hooks"),
+  ("GetOrgsorgHookshookId", Methods::Get, "/orgs/{org}/hooks/{hook_id}"),
+  ("PatchOrgsorgHookshookId", Methods::Patch, "/orgs/{org}/hooks/{hook_id}"),
+  ("DeleteOrgsorgHookshookId", Methods::Delete, "/orgs/{org}/hooks/{hook_id}"),
+  ("GetOrgsorgHookshookIdConfig", Methods::Get, "/orgs/{org}/hooks/{hook_id}/config"),
+  ("PatchOrgsorgHookshookIdConfig", Methods::Patch, "/orgs/{org}/hooks/{hook_id}/config"),
+  ("GetOrgsorgHookshookIdDeliveries", Methods::Get, "/orgs/{org}/hooks/{hook_id}/deliveries"),
+  ("GetOrgsorgHookshookIdDeliveriesdeliveryId", Methods::Get, "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}"),
+  ("PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts", Methods::Post, "/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}/attempts"),
+  ("PostOrgsorgHookshookIdPings", Methods::Post, "/orgs/{org}/hooks/{hook_id}/pings"),
+  ("GetOrgsorgInstallation", Methods::Get, "/orgs/{org}/installation"),
+  ("GetOrgsorgInstallations", Methods::Get, "/orgs/{org}/installations"),
+  ("GetOrgsorgInteractionLimits", Methods::Get, "/orgs/{org}/interaction-limits"),
+  ("PutOrgsorgInteractionLimits", Methods::Put, "/orgs/{org}/interaction-limits"),
+  ("DeleteOrgsorgInteractionLimits", Methods::Delete, "/orgs/{org}/interaction-limits"),
+  ("GetOrgsorgInvitations", Methods::Get, "/orgs/{org}/invitations"),
+  ("PostOrgsorgInvitations", Methods::Post, "/orgs/{org}/invitations"),
+  ("DeleteOrgsorgInvitationsinvitationId", Methods::Delete, "/orgs/{org}/invitations/{invitation_id}"),
+  ("GetOrgsorgInvitationsinvitationIdTeams", Methods::Get, "/orgs/{org}/invitations/{invitation_id}/teams"),
+  ("GetOrgsorgIssues", Methods::Get, "/orgs/{org}/issues"),
+  ("GetOrgsorgMembers", Methods::Get, "/orgs/{org}/members"),
+  ("GetOrgsorgMembersusername", Methods::Get, "/orgs/{org}/members/{username}"),
+  ("DeleteOrgsorgMembersusername", Methods::Delete, "/orgs/{org}/members/{username}"),
+  ("GetOrgsorgMembershipsusername", Methods::Get, "/orgs/{org}/memberships/{username}"),
+  ("PutOrgsorgMembershipsusername", Methods::Put, "/orgs/{org}/memberships/{username}"),
+  ("DeleteOrgsorgMembershipsusername", Methods::Delete, "/orgs/{org}/memberships/{username}"),
+  ("GetOrgsorgMigrations", Methods::Get, "/orgs/{org}/migrations"),
+  ("PostOrgsorgMigrations", Methods::Post, "/orgs/{org}/migrations"),
+  ("GetOrgsorgMigrationsmigrationId", Methods::Get, "/orgs/{org}/migrations/{migration_id}"),
+  ("GetOrgsorgMigrationsmigrationIdArchive", Methods::Get, "/orgs/{org}/migrations/{migration_id}/archive"),
+  ("DeleteOrgsorgMigrationsmigrationIdArchive", Methods::Delete, "/orgs/{org}/migrations/{migration_id}/archive"),
+  ("DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock", Methods::Delete, "/orgs/{org}/migrations/{migration_id}/repos/{repo_name}/lock"),
+  ("GetOrgsorgMigrationsmigrationIdRepositories", Methods::Get, "/orgs/{org}/migrations/{migration_id}/repositories"),
+  ("GetOrgsorgOutsideCollaborators", Methods::Get, "/orgs/{org}/outside_collaborators"),
+  ("PutOrgsorgOutsideCollaboratorsusername", Methods::Put, "/orgs/{org}/outside_collaborators/{username}"),
+  ("DeleteOrgsorgOutsideCollaboratorsusername", Methods::Delete, "/orgs/{org}/outside_collaborators/{username}"),
+  ("GetOrgsorgPackages", Methods::Get, "/orgs/{org}/packages"),
+  ("GetOrgsorgPackagespackageTypepackageName", Methods::Get, "/orgs/{org}/packages/{package_type}/{package_name}"),
+  ("DeleteOrgsorgPackagespackageTypepackageName", Methods::Delete, "/orgs/{org}/packages/{package_type}/{package_name}"),
+  ("PostOrgsorgPackagespackageTypepackageNameRestore", Methods::Post, "/orgs/{org}/packages/{package_type}/{package_name}/restore"),
+  ("GetOrgsorgPackagespackageTypepackageNameVersions", Methods::Get, "/orgs/{org}/packages/{package_type}/{package_name}/versions"),
+  ("GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId", Methods::Get, "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId", Methods::Delete, "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore", Methods::Post, "/orgs/{org}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore"),
+  ("GetOrgsorgProjects", Methods::Get, "/orgs/{org}/projects"),
+  ("PostOrgsorgProjects", Methods::Post, "/orgs/{org}/projects"),
+  ("GetOrgsorgPublicMembers", Methods::Get, "/orgs/{org}/public_members"),
+  ("GetOrgsorgPublicMembersusername", Methods::Get, "/orgs/{org}/public_members/{username}"),
+  ("PutOrgsorgPublicMembersusername", Methods::Put, "/orgs/{org}/public_members/{username}"),
+  ("DeleteOrgsorgPublicMembersusername", Methods::Delete, "/orgs/{org}/public_members/{username}"),
+  ("GetOrgsorgRepos", Methods::Get, "/orgs/{org}/repos"),
+  ("PostOrgsorgRepos", Methods::Post, "/orgs/{org}/repos"),
+  ("GetOrgsorgSecretScanningAlerts", Methods::Get, "/orgs/{org}/secret-scanning/alerts"),
+  ("GetOrgsorgSettingsBillingActions", Methods::Get, "/orgs/{org}/settings/billing/actions"),
+  ("GetOrgsorgSettingsBillingAdvancedSecurity", Methods::Get, "/orgs/{org}/settings/billing/advanced-security"),
+  ("GetOrgsorgSettingsBillingPackages", Methods::Get, "/orgs/{org}/settings/billing/packages"),
+  ("GetOrgsorgSettingsBillingSharedStorage", Methods::Get, "/orgs/{org}/settings/billing/shared-storage"),
+  ("GetOrgsorgTeamSyncGroups", Methods::Get, "/orgs/{org}/team-sync/groups"),
+  ("GetOrgsorgTeams", Methods::Get, "/orgs/{org}/teams"),
+  ("PostOrgsorgTeams", Methods::Post, "/orgs/{org}/teams"),
+  ("GetOrgsorgTeamsteamSlug", Methods::Get, "/orgs/{org}/teams/{team_slug}"),
+  ("PatchOrgsorgTeamsteamSlug", Methods::Patch, "/orgs/{org}/teams/{team_slug}"),
+  ("DeleteOrgsorgTeamsteamSlug", Methods::Delete, "/orgs/{org}/teams/{team_slug}"),
+  ("GetOrgsorgTeamsteamSlugDiscussions", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions"),
+  ("PostOrgsorgTeamsteamSlugDiscussions", Methods::Post, "/orgs/{org}/teams/{team_slug}/discussions"),
+  ("GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}"),
+  ("PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber", Methods::Patch, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}"),
+  ("DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber", Methods::Delete, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}"),
+  ("GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments"),
+  ("PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments", Methods::Post, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments"),
+  ("GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber", Methods::Patch, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber", Methods::Delete, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions"),
+  ("PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions", Methods::Post, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions"),
+  ("DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId", Methods::Delete, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions/{reaction_id}"),
+  ("GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions", Methods::Get, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions"),
+  ("PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions", Methods::Post, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions"),
+  ("DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId", Methods::Delete, "/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions/{reaction_id}"),
+  ("PatchOrgsorgTeamsteamSlugExternalGroups", Methods::Patch, "/orgs/{org}/teams/{team_slug}/external-groups"),
+  ("DeleteOrgsorgTeamsteamSlugExternalGroups", Methods::Delete, "/orgs/{org}/teams/{team_slug}/external-groups"),
+  ("GetOrgsorgTeamsteamSlugInvitations", Methods::Get, "/orgs/{org}/teams/{team_slug}/invitations"),
+  ("GetOrgsorgTeamsteamSlugMembers", Methods::Get, "/orgs/{org}/teams/{team_slug}/members"),
+  ("GetOrgsorgTeamsteamSlugMembershipsusername", Methods::Get, "/orgs/{org}/teams/{team_slug}/memberships/{username}"),
+  ("PutOrgsorgTeamsteamSlugMembershipsusername", Methods::Put, "/orgs/{org}/teams/{team_slug}/memberships/{username}"),
+  ("DeleteOrgsorgTeamsteamSlugMembershipsusername", Methods::Delete, "/orgs/{org}/teams/{team_slug}/memberships/{username}"),
+  ("GetOrgsorgTeamsteamSlugProjects", Methods::Get, "/orgs/{org}/teams/{team_slug}/projects"),
+  ("GetOrgsorgTeamsteamSlugProjectsprojectId", Methods::Get, "/orgs/{org}/teams/{team_slug}/projects/{project_id}"),
+  ("PutOrgsorgTeamsteamSlugProjectsprojectId", Methods::Put, "/orgs/{org}/teams/{team_slug}/projects/{project_id}"),
+  ("DeleteOrgsorgTeamsteamSlugProjectsprojectId", Methods::Delete, "/orgs/{org}/teams/{team_slug}/projects/{project_id}"),
+  ("GetOrgsorgTeamsteamSlugRepos", Methods::Get, "/orgs/{org}/teams/{team_slug}/repos"),
+  ("GetOrgsorgTeamsteamSlugReposownerrepo", Methods::Get, "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}"),
+  ("PutOrgsorgTeamsteamSlugReposownerrepo", Methods::Put, "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}"),
+  ("DeleteOrgsorgTeamsteamSlugReposownerrepo", Methods::Delete, "/orgs/{org}/teams/{team_slug}/repos/{owner}/{repo}"),
+  ("GetOrgsorgTeamsteamSlugTeamSyncGroupMappings", Methods::Get, "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings"),
+  ("PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings", Methods::Patch, "/orgs/{org}/teams/{team_slug}/team-sync/group-mappings"),
+  ("GetOrgsorgTeamsteamSlugTeams", Methods::Get, "/orgs/{org}/teams/{team_slug}/teams"),
+  ("GetProjectsColumnsCardscardId", Methods::Get, "/projects/columns/cards/{card_id}"),
+  ("PatchProjectsColumnsCardscardId", Methods::Patch, "/projects/columns/cards/{card_id}"),
+  ("DeleteProjectsColumnsCardscardId", Methods::Delete, "/projects/columns/cards/{card_id}"),
+  ("PostProjectsColumnsCardscardIdMoves", Methods::Post, "/projects/columns/cards/{card_id}/moves"),
+  ("GetProjectsColumnscolumnId", Methods::Get, "/projects/columns/{column_id}"),
+  ("PatchProjectsColumnscolumnId", Methods::Patch, "/projects/columns/{column_id}"),
+  ("DeleteProjectsColumnscolumnId", Methods::Delete, "/projects/columns/{column_id}"),
+  ("GetProjectsColumnscolumnIdCards", Methods::Get, "/projects/columns/{column_id}/cards"),
+  ("PostProjectsColumnscolumnIdCards", Methods::Post, "/projects/columns/{column_id}/cards"),
+  ("PostProjectsColumnscolumnIdMoves", Methods::Post, "/projects/columns/{column_id}/moves"),
+  ("GetProjectsprojectId", Methods::Get, "/projects/{project_id}"),
+  ("PatchProjectsprojectId", Methods::Patch, "/projects/{project_id}"),
+  ("DeleteProjectsprojectId", Methods::Delete, "/projects/{project_id}"),
+  ("GetProjectsprojectIdCollaborators", Methods::Get, "/projects/{project_id}/collaborators"),
+  ("PutProjectsprojectIdCollaboratorsusername", Methods::Put, "/projects/{project_id}/collaborators/{username}"),
+  ("DeleteProjectsprojectIdCollaboratorsusername", Methods::Delete, "/projects/{project_id}/collaborators/{username}"),
+  ("GetProjectsprojectIdCollaboratorsusernamePermission", Methods::Get, "/projects/{project_id}/collaborators/{username}/permission"),
+  ("GetProjectsprojectIdColumns", Methods::Get, "/projects/{project_id}/columns"),
+  ("PostProjectsprojectIdColumns", Methods::Post, "/projects/{project_id}/columns"),
+  ("GetRateLimit", Methods::Get, "/rate_limit"),
+  ("DeleteReactionsreactionId", Methods::Delete, "/reactions/{reaction_id}"),
+  ("GetReposownerrepo", Methods::Get, "/repos/{owner}/{repo}"),
+  ("PatchReposownerrepo", Methods::Patch, "/repos/{owner}/{repo}"),
+  ("DeleteReposownerrepo", Methods::Delete, "/repos/{owner}/{repo}"),
+  ("GetReposownerrepoActionsArtifacts", Methods::Get, "/repos/{owner}/{repo}/actions/artifacts"),
+  ("GetReposownerrepoActionsArtifactsartifactId", Methods::Get, "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}"),
+  ("DeleteReposownerrepoActionsArtifactsartifactId", Methods::Delete, "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}"),
+  ("GetReposownerrepoActionsArtifactsartifactIdarchiveFormat", Methods::Get, "/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}"),
+  ("GetReposownerrepoActionsJobsjobId", Methods::Get, "/repos/{owner}/{repo}/actions/jobs/{job_id}"),
+  ("GetReposownerrepoActionsJobsjobIdLogs", Methods::Get, "/repos/{owner}/{repo}/actions/jobs/{job_id}/logs"),
+  ("GetReposownerrepoActionsPermissions", Methods::Get, "/repos/{owner}/{repo}/actions/permissions"),
+  ("PutReposownerrepoActionsPermissions", Methods::Put, "/repos/{owner}/{repo}/actions/permissions"),
+  ("GetReposownerrepoActionsPermissionsSelectedActions", Methods::Get, "/repos/{owner}/{repo}/actions/permissions/selected-actions"),
+  ("PutReposownerrepoActionsPermissionsSelectedActions", Methods::Put, "/repos/{owner}/{repo}/actions/permissions/selected-actions"),
+  ("GetReposownerrepoActionsRunners", Methods::Get, "/repos/{owner}/{repo}/actions/runners"),
+  ("GetReposownerrepoActionsRunnersDownloads", Methods::Get, "/repos/{owner}/{repo}/actions/runners/downloads"),
+  ("PostReposownerrepoActionsRunnersRegistrationToken", Methods::Post, "/repos/{owner}/{repo}/actions/runners/registration-token"),
+  ("PostReposownerrepoActionsRunnersRemoveToken", Methods::Post, "/repos/{owner}/{repo}/actions/runners/remove-token"),
+  ("GetReposownerrepoActionsRunnersrunnerId", Methods::Get, "/repos/{owner}/{repo}/actions/runners/{runner_id}"),
+  ("DeleteReposownerrepoActionsRunnersrunnerId", Methods::Delete, "/repos/{owner}/{repo}/actions/runners/{runner_id}"),
+  ("GetReposownerrepoActionsRunnersrunnerIdLabels", Methods::Get, "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels"),
+  ("PostReposownerrepoActionsRunnersrunnerIdLabels", Methods::Post, "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels"),
+  ("PutReposownerrepoActionsRunnersrunnerIdLabels", Methods::Put, "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels"),
+  ("DeleteReposownerrepoActionsRunnersrunnerIdLabels", Methods::Delete, "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels"),
+  ("DeleteReposownerrepoActionsRunnersrunnerIdLabelsname", Methods::Delete, "/repos/{owner}/{repo}/actions/runners/{runner_id}/labels/{name}"),
+  ("GetReposownerrepoActionsRuns", Methods::Get, "/repos/{owner}/{repo}/actions/runs"),
+  ("GetReposownerrepoActionsRunsrunId", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}"),
+  ("DeleteReposownerrepoActionsRunsrunId", Methods::Delete, "/repos/{owner}/{repo}/actions/runs/{run_id}"),
+  ("GetReposownerrepoActionsRunsrunIdApprovals", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/approvals"),
+  ("PostReposownerrepoActionsRunsrunIdApprove", Methods::Post, "/repos/{owner}/{repo}/actions/runs/{run_id}/approve"),
+  ("GetReposownerrepoActionsRunsrunIdArtifacts", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/artifacts"),
+  ("GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}"),
+  ("GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/jobs"),
+  ("GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/attempts/{attempt_number}/logs"),
+  ("PostReposownerrepoActionsRunsrunIdCancel", Methods::Post, "/repos/{owner}/{repo}/actions/runs/{run_id}/cancel"),
+  ("GetReposownerrepoActionsRunsrunIdJobs", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/jobs"),
+  ("GetReposownerrepoActionsRunsrunIdLogs", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/logs"),
+  ("DeleteReposownerrepoActionsRunsrunIdLogs", Methods::Delete, "/repos/{owner}/{repo}/actions/runs/{run_id}/logs"),
+  ("GetReposownerrepoActionsRunsrunIdPendingDeployments", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments"),
+  ("PostReposownerrepoActionsRunsrunIdPendingDeployments", Methods::Post, "/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments"),
+  ("PostReposownerrepoActionsRunsrunIdRerun", Methods::Post, "/repos/{owner}/{repo}/actions/runs/{run_id}/rerun"),
+  ("GetReposownerrepoActionsRunsrunIdTiming", Methods::Get, "/repos/{owner}/{repo}/actions/runs/{run_id}/timing"),
+  ("GetReposownerrepoActionsSecrets", Methods::Get, "/repos/{owner}/{repo}/actions/secrets"),
+  ("GetReposownerrepoActionsSecretsPublicKey", Methods::Get, "/repos/{owner}/{repo}/actions/secrets/public-key"),
+  ("GetReposownerrepoActionsSecretssecretName", Methods::Get, "/repos/{owner}/{repo}/actions/secrets/{secret_name}"),
+  ("PutReposownerrepoActionsSecretssecretName", Methods::Put, "/repos/{owner}/{repo}/actions/secrets/{secret_name}"),
+  ("DeleteReposownerrepoActionsSecretssecretName", Methods::Delete, "/repos/{owner}/{repo}/actions/secrets/{secret_name}"),
+  ("GetReposownerrepoActionsWorkflows", Methods::Get, "/repos/{owner}/{repo}/actions/workflows"),
+  ("GetReposownerrepoActionsWorkflowsworkflowId", Methods::Get, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}"),
+  ("PutReposownerrepoActionsWorkflowsworkflowIdDisable", Methods::Put, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/disable"),
+  ("PostReposownerrepoActionsWorkflowsworkflowIdDispatches", Methods::Post, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/dispatches"),
+  ("PutReposownerrepoActionsWorkflowsworkflowIdEnable", Methods::Put, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/enable"),
+  ("GetReposownerrepoActionsWorkflowsworkflowIdRuns", Methods::Get, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/runs"),
+  ("GetReposownerrepoActionsWorkflowsworkflowIdTiming", Methods::Get, "/repos/{owner}/{repo}/actions/workflows/{workflow_id}/timing"),
+  ("GetReposownerrepoAssignees", Methods::Get, "/repos/{owner}/{repo}/assignees"),
+  ("GetReposownerrepoAssigneesassignee", Methods::Get, "/repos/{owner}/{repo}/assignees/{assignee}"),
+  ("GetReposownerrepoAutolinks", Methods::Get, "/repos/{owner}/{repo}/autolinks"),
+  ("PostReposownerrepoAutolinks", Methods::Post, "/repos/{owner}/{repo}/autolinks"),
+  ("GetReposownerrepoAutolinksautolinkId", Methods::Get, "/repos/{owner}/{repo}/autolinks/{autolink_id}"),
+  ("DeleteReposownerrepoAutolinksautolinkId", Methods::Delete, "/repos/{owner}/{repo}/autolinks/{autolink_id}"),
+  ("PutReposownerrepoAutomatedSecurityFixes", Methods::Put, "/repos/{owner}/{repo}/automated-security-fixes"),
+  ("DeleteReposownerrepoAutomatedSecurityFixes", Methods::Delete, "/repos/{owner}/{repo}/automated-security-fixes"),
+  ("GetReposownerrepoBranches", Methods::Get, "/repos/{owner}/{repo}/branches"),
+  ("GetReposownerrepoBranchesbranch", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}"),
+  ("GetReposownerrepoBranchesbranchProtection", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection"),
+  ("PutReposownerrepoBranchesbranchProtection", Methods::Put, "/repos/{owner}/{repo}/branches/{branch}/protection"),
+  ("DeleteReposownerrepoBranchesbranchProtection", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection"),
+  ("GetReposownerrepoBranchesbranchProtectionEnforceAdmins", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins"),
+  ("PostReposownerrepoBranchesbranchProtectionEnforceAdmins", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins"),
+  ("DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/enforce_admins"),
+  ("GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews"),
+  ("PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews", Methods::Patch, "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/required_pull_request_reviews"),
+  ("GetReposownerrepoBranchesbranchProtectionRequiredSignatures", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures"),
+  ("PostReposownerrepoBranchesbranchProtectionRequiredSignatures", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/required_signatures"),
+  ("GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks"),
+  ("PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks", Methods::Patch, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks"),
+  ("GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts"),
+  ("PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts"),
+  ("PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts", Methods::Put, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts"),
+  ("GetReposownerrepoBranchesbranchProtectionRestrictions", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRestrictions", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions"),
+  ("GetReposownerrepoBranchesbranchProtectionRestrictionsApps", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps"),
+  ("PostReposownerrepoBranchesbranchProtectionRestrictionsApps", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps"),
+  ("PutReposownerrepoBranchesbranchProtectionRestrictionsApps", Methods::Put, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps"),
+  ("GetReposownerrepoBranchesbranchProtectionRestrictionsTeams", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams"),
+  ("PostReposownerrepoBranchesbranchProtectionRestrictionsTeams", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams"),
+  ("PutReposownerrepoBranchesbranchProtectionRestrictionsTeams", Methods::Put, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams"),
+  ("GetReposownerrepoBranchesbranchProtectionRestrictionsUsers", Methods::Get, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users"),
+  ("PostReposownerrepoBranchesbranchProtectionRestrictionsUsers", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users"),
+  ("PutReposownerrepoBranchesbranchProtectionRestrictionsUsers", Methods::Put, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users"),
+  ("DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers", Methods::Delete, "/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users"),
+  ("PostReposownerrepoBranchesbranchRename", Methods::Post, "/repos/{owner}/{repo}/branches/{branch}/rename"),
+  ("PostReposownerrepoCheckRuns", Methods::Post, "/repos/{owner}/{repo}/check-runs"),
+  ("GetReposownerrepoCheckRunscheckRunId", Methods::Get, "/repos/{owner}/{repo}/check-runs/{check_run_id}"),
+  ("PatchReposownerrepoCheckRunscheckRunId", Methods::Patch, "/repos/{owner}/{repo}/check-runs/{check_run_id}"),
+  ("GetReposownerrepoCheckRunscheckRunIdAnnotations", Methods::Get, "/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations"),
+  ("PostReposownerrepoCheckRunscheckRunIdRerequest", Methods::Post, "/repos/{owner}/{repo}/check-runs/{check_run_id}/rerequest"),
+  ("PostReposownerrepoCheckSuites", Methods::Post, "/repos/{owner}/{repo}/check-suites"),
+  ("PatchReposownerrepoCheckSuitesPreferences", Methods::Patch, "/repos/{owner}/{repo}/check-suites/preferences"),
+  ("GetReposownerrepoCheckSuitescheckSuiteId", Methods::Get, "/repos/{owner}/{repo}/check-suites/{check_suite_id}"),
+  ("GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns", Methods::Get, "/repos/{owner}/{repo}/check-suites/{check_suite_id}/check-runs"),
+  ("PostReposownerrepoCheckSuitescheckSuiteIdRerequest", Methods::Post, "/repos/{owner}/{repo}/check-suites/{check_suite_id}/rerequest"),
+  ("GetReposownerrepoCodeScanningAlerts", Methods::Get, "/repos/{owner}/{repo}/code-scanning/alerts"),
+  ("GetReposownerrepoCodeScanningAlertsalertNumber", Methods::Get, "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}"),
+  ("PatchReposownerrepoCodeScanningAlertsalertNumber", Methods::Patch, "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}"),
+  ("GetReposownerrepoCodeScanningAlertsalertNumberInstances", Methods::Get, "/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances"),
+  ("GetReposownerrepoCodeScanningAnalyses", Methods::Get, "/repos/{owner}/{repo}/code-scanning/analyses"),
+  ("GetReposownerrepoCodeScanningAnalysesanalysisId", Methods::Get, "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}"),
+  ("DeleteReposownerrepoCodeScanningAnalysesanalysisId", Methods::Delete, "/repos/{owner}/{repo}/code-scanning/analyses/{analysis_id}"),
+  ("PostReposownerrepoCodeScanningSarifs", Methods::Post, "/repos/{owner}/{repo}/code-scanning/sarifs"),
+  ("GetReposownerrepoCodeScanningSarifssarifId", Methods::Get, "/repos/{owner}/{repo}/code-scanning/sarifs/{sarif_id}"),
+  ("GetReposownerrepoCodespaces", Methods::Get, "/repos/{owner}/{repo}/codespaces"),
+  ("PostReposownerrepoCodespaces", Methods::Post, "/repos/{owner}/{repo}/codespaces"),
+  ("GetReposownerrepoCodespacesMachines", Methods::Get, "/repos/{owner}/{repo}/codespaces/machines"),
+  ("GetReposownerrepoCollaborators", Methods::Get, "/repos/{owner}/{repo}/collaborators"),
+  ("GetReposownerrepoCollaboratorsusername", Methods::Get, "/repos/{owner}/{repo}/collaborators/{username}"),
+  ("PutReposownerrepoCollaboratorsusername", Methods::Put, "/repos/{owner}/{repo}/collaborators/{username}"),
+  ("DeleteReposownerrepoCollaboratorsusername", Methods::Delete, "/repos/{owner}/{repo}/collaborators/{username}"),
+  ("GetReposownerrepoCollaboratorsusernamePermission", Methods::Get, "/repos/{owner}/{repo}/collaborators/{username}/permission"),
+  ("GetReposownerrepoComments", Methods::Get, "/repos/{owner}/{repo}/comments"),
+  ("GetReposownerrepoCommentscommentId", Methods::Get, "/repos/{owner}/{repo}/comments/{comment_id}"),
+  ("PatchReposownerrepoCommentscommentId", Methods::Patch, "/repos/{owner}/{repo}/comments/{comment_id}"),
+  ("DeleteReposownerrepoCommentscommentId", Methods::Delete, "/repos/{owner}/{repo}/comments/{comment_id}"),
+  ("GetReposownerrepoCommentscommentIdReactions", Methods::Get, "/repos/{owner}/{repo}/comments/{comment_id}/reactions"),
+  ("PostReposownerrepoCommentscommentIdReactions", Methods::Post, "/repos/{owner}/{repo}/comments/{comment_id}/reactions"),
+  ("DeleteReposownerrepoCommentscommentIdReactionsreactionId", Methods::Delete, "/repos/{owner}/{repo}/comments/{comment_id}/reactions/{reaction_id}"),
+  ("GetReposownerrepoCommits", Methods::Get, "/repos/{owner}/{repo}/commits"),
+  ("GetReposownerrepoCommitscommitShaBranchesWhereHead", Methods::Get, "/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head"),
+  ("GetReposownerrepoCommitscommitShaComments", Methods::Get, "/repos/{owner}/{repo}/commits/{commit_sha}/comments"),
+  ("PostReposownerrepoCommitscommitShaComments", Methods::Post, "/repos/{owner}/{repo}/commits/{commit_sha}/comments"),
+  ("GetReposownerrepoCommitscommitShaPulls", Methods::Get, "/repos/{owner}/{repo}/commits/{commit_sha}/pulls"),
+  ("GetReposownerrepoCommitsref", Methods::Get, "/repos/{owner}/{repo}/commits/{ref}"),
+  ("GetReposownerrepoCommitsrefCheckRuns", Methods::Get, "/repos/{owner}/{repo}/commits/{ref}/check-runs"),
+  ("GetReposownerrepoCommitsrefCheckSuites", Methods::Get, "/repos/{owner}/{repo}/commits/{ref}/check-suites"),
+  ("GetReposownerrepoCommitsrefStatus", Methods::Get, "/repos/{owner}/{repo}/commits/{ref}/status"),
+  ("GetReposownerrepoCommitsrefStatuses", Methods::Get, "/repos/{owner}/{repo}/commits/{ref}/statuses"),
+  ("GetReposownerrepoCommunityProfile", Methods::Get, "/repos/{owner}/{repo}/community/profile"),
+  ("GetReposownerrepoComparebasehead", Methods::Get, "/repos/{owner}/{repo}/compare/{basehead}"),
+  ("GetReposownerrepoContentspath", Methods::Get, "/repos/{owner}/{repo}/contents/{path}"),
+  ("PutReposownerrepoContentspath", Methods::Put, "/repos/{owner}/{repo}/contents/{path}"),
+  ("DeleteReposownerrepoContentspath", Methods::Delete, "/repos/{owner}/{repo}/contents/{path}"),
+  ("GetReposownerrepoContributors", Methods::Get, "/repos/{owner}/{repo}/contributors"),
+  ("GetReposownerrepoDependabotSecrets", Methods::Get, "/repos/{owner}/{repo}/dependabot/secrets"),
+  ("GetReposownerrepoDependabotSecretsPublicKey", Methods::Get, "/repos/{owner}/{repo}/dependabot/secrets/public-key"),
+  ("GetReposownerrepoDependabotSecretssecretName", Methods::Get, "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}"),
+  ("PutReposownerrepoDependabotSecretssecretName", Methods::Put, "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}"),
+  ("DeleteReposownerrepoDependabotSecretssecretName", Methods::Delete, "/repos/{owner}/{repo}/dependabot/secrets/{secret_name}"),
+  ("GetReposownerrepoDeployments", Methods::Get, "/repos/{owner}/{repo}/deployments"),
+  ("PostReposownerrepoDeployments", Methods::Post, "/repos/{owner}/{repo}/deployments"),
+  ("GetReposownerrepoDeploymentsdeploymentId", Methods::Get, "/repos/{owner}/{repo}/deployments/{deployment_id}"),
+  ("DeleteReposownerrepoDeploymentsdeploymentId", Methods::Delete, "/repos/{owner}/{repo}/deployments/{deployment_id}"),
+  ("GetReposownerrepoDeploymentsdeploymentIdStatuses", Methods::Get, "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses"),
+  ("PostReposownerrepoDeploymentsdeploymentIdStatuses", Methods::Post, "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses"),
+  ("GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId", Methods::Get, "/repos/{owner}/{repo}/deployments/{deployment_id}/statuses/{status_id}"),
+  ("PostReposownerrepoDispatches", Methods::Post, "/repos/{owner}/{repo}/dispatches"),
+  ("GetReposownerrepoEnvironments", Methods::Get, "/repos/{owner}/{repo}/environments"),
+  ("GetReposownerrepoEnvironmentsenvironmentName", Methods::Get, "/repos/{owner}/{repo}/environments/{environment_name}"),
+  ("PutReposownerrepoEnvironmentsenvironmentName", Methods::Put, "/repos/{owner}/{repo}/environments/{environment_name}"),
+  ("DeleteReposownerrepoEnvironmentsenvironmentName", Methods::Delete, "/repos/{owner}/{repo}/environments/{environment_name}"),
+  ("GetReposownerrepoEvents", Methods::Get, "/repos/{owner}/{repo}/events"),
+  ("GetReposownerrepoForks", Methods::Get, "/repos/{owner}/{repo}/forks"),
+  ("PostReposownerrepoForks", Methods::Post, "/repos/{owner}/{repo}/forks"),
+  ("PostReposownerrepoGitBlobs", Methods::Post, "/repos/{owner}/{repo}/git/blobs"),
+  ("GetReposownerrepoGitBlobsfileSha", Methods::Get, "/repos/{owner}/{repo}/git/blobs/{file_sha}"),
+  ("PostReposownerrepoGitCommits", Methods::Post, "/repos/{owner}/{repo}/git/commits"),
+  ("GetReposownerrepoGitCommitscommitSha", Methods::Get, "/repos/{owner}/{repo}/git/commits/{commit_sha}"),
+  ("GetReposownerrepoGitMatchingRefsref", Methods::Get, "/repos/{owner}/{repo}/git/matching-refs/{ref}"),
+  ("GetReposownerrepoGitRefref", Methods::Get, "/repos/{owner}/{repo}/git/ref/{ref}"),
+  ("PostReposownerrepoGitRefs", Methods::Post, "/repos/{owner}/{repo}/git/refs"),
+  ("PatchReposownerrepoGitRefsref", Methods::Patch, "/repos/{owner}/{repo}/git/refs/{ref}"),
+  ("DeleteReposownerrepoGitRefsref", Methods::Delete, "/repos/{owner}/{repo}/git/refs/{ref}"),
+  ("PostReposownerrepoGitTags", Methods::Post, "/repos/{owner}/{repo}/git/tags"),
+  ("GetReposownerrepoGitTagstagSha", Methods::Get, "/repos/{owner}/{repo}/git/tags/{tag_sha}"),
+  ("PostReposownerrepoGitTrees", Methods::Post, "/repos/{owner}/{repo}/git/trees"),
+  ("GetReposownerrepoGitTreestreeSha", Methods::Get, "/repos/{owner}/{repo}/git/trees/{tree_sha}"),
+  ("GetReposownerrepoHooks", Methods::Get, "/repos/{owner}/{repo}/hooks"),
+  ("PostReposownerrepoHooks", Methods::Post, "/repos/{owner}/{repo}/hooks"),
+  ("GetReposownerrepoHookshookId", Methods::Get, "/repos/{owner}/{repo}/hooks/{hook_id}"),
+  ("PatchReposownerrepoHookshookId", Methods::Patch, "/repos/{owner}/{repo}/hooks/{hook_id}"),
+  ("DeleteReposownerrepoHookshookId", Methods::Delete, "/repos/{owner}/{repo}/hooks/{hook_id}"),
+  ("GetReposownerrepoHookshookIdConfig", Methods::Get, "/repos/{owner}/{repo}/hooks/{hook_id}/config"),
+  ("PatchReposownerrepoHookshookIdConfig", Methods::Patch, "/repos/{owner}/{repo}/hooks/{hook_id}/config"),
+  ("GetReposownerrepoHookshookIdDeliveries", Methods::Get, "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries"),
+  ("GetReposownerrepoHookshookIdDeliveriesdeliveryId", Methods::Get, "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}"),
+  ("PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts", Methods::Post, "/repos/{owner}/{repo}/hooks/{hook_id}/deliveries/{delivery_id}/attempts"),
+  ("PostReposownerrepoHookshookIdPings", Methods::Post, "/repos/{owner}/{repo}/hooks/{hook_id}/pings"),
+  ("PostReposownerrepoHookshookIdTests", Methods::Post, "/repos/{owner}/{repo}/hooks/{hook_id}/tests"),
+  ("GetReposownerrepoImport", Methods::Get, "/repos/{owner}/{repo}/import"),
+  ("PutReposownerrepoImport", Methods::Put, "/repos/{owner}/{repo}/import"),
+  ("PatchReposownerrepoImport", Methods::Patch, "/repos/{owner}/{repo}/import"),
+  ("DeleteReposownerrepoImport", Methods::Delete, "/repos/{owner}/{repo}/import"),
+  ("GetReposownerrepoImportAuthors", Methods::Get, "/repos/{owner}/{repo}/import/authors"),
+  ("PatchReposownerrepoImportAuthorsauthorId", Methods::Patch, "/repos/{owner}/{repo}/import/authors/{author_id}"),
+  ("GetReposownerrepoImportLargeFiles", Methods::Get, "/repos/{owner}/{repo}/import/large_files"),
+  ("PatchReposownerrepoImportLfs", Methods::Patch, "/repos/{owner}/{repo}/import/lfs"),
+  ("GetReposownerrepoInstallation", Methods::Get, "/repos/{owner}/{repo}/installation"),
+  ("GetReposownerrepoInteractionLimits", Methods::Get, "/repos/{owner}/{repo}/interaction-limits"),
+  ("PutReposownerrepoInteractionLimits", Methods::Put, "/repos/{owner}/{repo}/interaction-limits"),
+  ("DeleteReposownerrepoInteractionLimits", Methods::Delete, "/repos/{owner}/{repo}/interaction-limits"),
+  ("GetReposownerrepoInvitations", Methods::Get, "/repos/{owner}/{repo}/invitations"),
+  ("PatchReposownerrepoInvitationsinvitationId", Methods::Patch, "/repos/{owner}/{repo}/invitations/{invitation_id}"),
+  ("DeleteReposownerrepoInvitationsinvitationId", Methods::Delete, "/repos/{owner}/{repo}/invitations/{invitation_id}"),
+  ("GetReposownerrepoIssues", Methods::Get, "/repos/{owner}/{repo}/issues"),
+  ("PostReposownerrepoIssues", Methods::Post, "/repos/{owner}/{repo}/issues"),
+  ("GetReposownerrepoIssuesComments", Methods::Get, "/repos/{owner}/{repo}/issues/comments"),
+  ("GetReposownerrepoIssuesCommentscommentId", Methods::Get, "/repos/{owner}/{repo}/issues/comments/{comment_id}"),
+  ("PatchReposownerrepoIssuesCommentscommentId", Methods::Patch, "/repos/{owner}/{repo}/issues/comments/{comment_id}"),
+  ("DeleteReposownerrepoIssuesCommentscommentId", Methods::Delete, "/repos/{owner}/{repo}/issues/comments/{comment_id}"),
+  ("GetReposownerrepoIssuesCommentscommentIdReactions", Methods::Get, "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions"),
+  ("PostReposownerrepoIssuesCommentscommentIdReactions", Methods::Post, "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions"),
+  ("DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId", Methods::Delete, "/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions/{reaction_id}"),
+  ("GetReposownerrepoIssuesEvents", Methods::Get, "/repos/{owner}/{repo}/issues/events"),
+  ("GetReposownerrepoIssuesEventseventId", Methods::Get, "/repos/{owner}/{repo}/issues/events/{event_id}"),
+  ("GetReposownerrepoIssuesissueNumber", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}"),
+  ("PatchReposownerrepoIssuesissueNumber", Methods::Patch, "/repos/{owner}/{repo}/issues/{issue_number}"),
+  ("PostReposownerrepoIssuesissueNumberAssignees", Methods::Post, "/repos/{owner}/{repo}/issues/{issue_number}/assignees"),
+  ("DeleteReposownerrepoIssuesissueNumberAssignees", Methods::Delete, "/repos/{owner}/{repo}/issues/{issue_number}/assignees"),
+  ("GetReposownerrepoIssuesissueNumberComments", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}/comments"),
+  ("PostReposownerrepoIssuesissueNumberComments", Methods::Post, "/repos/{owner}/{repo}/issues/{issue_number}/comments"),
+  ("GetReposownerrepoIssuesissueNumberEvents", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}/events"),
+  ("GetReposownerrepoIssuesissueNumberLabels", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}/labels"),
+  ("PostReposownerrepoIssuesissueNumberLabels", Methods::Post, "/repos/{owner}/{repo}/issues/{issue_number}/labels"),
+  ("PutReposownerrepoIssuesissueNumberLabels", Methods::Put, "/repos/{owner}/{repo}/issues/{issue_number}/labels"),
+  ("DeleteReposownerrepoIssuesissueNumberLabels", Methods::Delete, "/repos/{owner}/{repo}/issues/{issue_number}/labels"),
+  ("DeleteReposownerrepoIssuesissueNumberLabelsname", Methods::Delete, "/repos/{owner}/{repo}/issues/{issue_number}/labels/{name}"),
+  ("PutReposownerrepoIssuesissueNumberLock", Methods::Put, "/repos/{owner}/{repo}/issues/{issue_number}/lock"),
+  ("DeleteReposownerrepoIssuesissueNumberLock", Methods::Delete, "/repos/{owner}/{repo}/issues/{issue_number}/lock"),
+  ("GetReposownerrepoIssuesissueNumberReactions", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}/reactions"),
+  ("PostReposownerrepoIssuesissueNumberReactions", Methods::Post, "/repos/{owner}/{repo}/issues/{issue_number}/reactions"),
+  ("DeleteReposownerrepoIssuesissueNumberReactionsreactionId", Methods::Delete, "/repos/{owner}/{repo}/issues/{issue_number}/reactions/{reaction_id}"),
+  ("GetReposownerrepoIssuesissueNumberTimeline", Methods::Get, "/repos/{owner}/{repo}/issues/{issue_number}/timeline"),
+  ("GetReposownerrepoKeys", Methods::Get, "/repos/{owner}/{repo}/keys"),
+  ("PostReposownerrepoKeys", Methods::Post, "/repos/{owner}/{repo}/keys"),
+  ("GetReposownerrepoKeyskeyId", Methods::Get, "/repos/{owner}/{repo}/keys/{key_id}"),
+  ("DeleteReposownerrepoKeyskeyId", Methods::Delete, "/repos/{owner}/{repo}/keys/{key_id}"),
+  ("GetReposownerrepoLabels", Methods::Get, "/repos/{owner}/{repo}/labels"),
+  ("PostReposownerrepoLabels", Methods::Post, "/repos/{owner}/{repo}/labels"),
+  ("GetReposownerrepoLabelsname", Methods::Get, "/repos/{owner}/{repo}/labels/{name}"),
+  ("PatchReposownerrepoLabelsname", Methods::Patch, "/repos/{owner}/{repo}/labels/{name}"),
+  ("DeleteReposownerrepoLabelsname", Methods::Delete, "/repos/{owner}/{repo}/labels/{name}"),
+  ("GetReposownerrepoLanguages", Methods::Get, "/repos/{owner}/{repo}/languages"),
+  ("PutReposownerrepoLfs", Methods::Put, "/repos/{owner}/{repo}/lfs"),
+  ("DeleteReposownerrepoLfs", Methods::Delete, "/repos/{owner}/{repo}/lfs"),
+  ("GetReposownerrepoLicense", Methods::Get, "/repos/{owner}/{repo}/license"),
+  ("PostReposownerrepoMergeUpstream", Methods::Post, "/repos/{owner}/{repo}/merge-upstream"),
+  ("PostReposownerrepoMerges", Methods::Post, "/repos/{owner}/{repo}/merges"),
+  ("GetReposownerrepoMilestones", Methods::Get, "/repos/{owner}/{repo}/milestones"),
+  ("PostReposownerrepoMilestones", Methods::Post, "/repos/{owner}/{repo}/milestones"),
+  ("GetReposownerrepoMilestonesmilestoneNumber", Methods::Get, "/repos/{owner}/{repo}/milestones/{milestone_number}"),
+  ("PatchReposownerrepoMilestonesmilestoneNumber", Methods::Patch, "/repos/{owner}/{repo}/milestones/{milestone_number}"),
+  ("DeleteReposownerrepoMilestonesmilestoneNumber", Methods::Delete, "/repos/{owner}/{repo}/milestones/{milestone_number}"),
+  ("GetReposownerrepoMilestonesmilestoneNumberLabels", Methods::Get, "/repos/{owner}/{repo}/milestones/{milestone_number}/labels"),
+  ("GetReposownerrepoNotifications", Methods::Get, "/repos/{owner}/{repo}/notifications"),
+  ("PutReposownerrepoNotifications", Methods::Put, "/repos/{owner}/{repo}/notifications"),
+  ("GetReposownerrepoPages", Methods::Get, "/repos/{owner}/{repo}/pages"),
+  ("PostReposownerrepoPages", Methods::Post, "/repos/{owner}/{repo}/pages"),
+  ("PutReposownerrepoPages", Methods::Put, "/repos/{owner}/{repo}/pages"),
+  ("DeleteReposownerrepoPages", Methods::Delete, "/repos/{owner}/{repo}/pages"),
+  ("GetReposownerrepoPagesBuilds", Methods::Get, "/repos/{owner}/{repo}/pages/builds"),
+  ("PostReposownerrepoPagesBuilds", Methods::Post, "/repos/{owner}/{repo}/pages/builds"),
+  ("GetReposownerrepoPagesBuildsLatest", Methods::Get, "/repos/{owner}/{repo}/pages/builds/latest"),
+  ("GetReposownerrepoPagesBuildsbuildId", Methods::Get, "/repos/{owner}/{repo}/pages/builds/{build_id}"),
+  ("GetReposownerrepoPagesHealth", Methods::Get, "/repos/{owner}/{repo}/pages/health"),
+  ("GetReposownerrepoProjects", Methods::Get, "/repos/{owner}/{repo}/projects"),
+  ("PostReposownerrepoProjects", Methods::Post, "/repos/{owner}/{repo}/projects"),
+  ("GetReposownerrepoPulls", Methods::Get, "/repos/{owner}/{repo}/pulls"),
+  ("PostReposownerrepoPulls", Methods::Post, "/repos/{owner}/{repo}/pulls"),
+  ("GetReposownerrepoPullsComments", Methods::Get, "/repos/{owner}/{repo}/pulls/comments"),
+  ("GetReposownerrepoPullsCommentscommentId", Methods::Get, "/repos/{owner}/{repo}/pulls/comments/{comment_id}"),
+  ("PatchReposownerrepoPullsCommentscommentId", Methods::Patch, "/repos/{owner}/{repo}/pulls/comments/{comment_id}"),
+  ("DeleteReposownerrepoPullsCommentscommentId", Methods::Delete, "/repos/{owner}/{repo}/pulls/comments/{comment_id}"),
+  ("GetReposownerrepoPullsCommentscommentIdReactions", Methods::Get, "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions"),
+  ("PostReposownerrepoPullsCommentscommentIdReactions", Methods::Post, "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions"),
+  ("DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId", Methods::Delete, "/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions/{reaction_id}"),
+  ("GetReposownerrepoPullspullNumber", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}"),
+  ("PatchReposownerrepoPullspullNumber", Methods::Patch, "/repos/{owner}/{repo}/pulls/{pull_number}"),
+  ("PostReposownerrepoPullspullNumberCodespaces", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/codespaces"),
+  ("GetReposownerrepoPullspullNumberComments", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/comments"),
+  ("PostReposownerrepoPullspullNumberComments", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/comments"),
+  ("PostReposownerrepoPullspullNumberCommentscommentIdReplies", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies"),
+  ("GetReposownerrepoPullspullNumberCommits", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/commits"),
+  ("GetReposownerrepoPullspullNumberFiles", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/files"),
+  ("GetReposownerrepoPullspullNumberMerge", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/merge"),
+  ("PutReposownerrepoPullspullNumberMerge", Methods::Put, "/repos/{owner}/{repo}/pulls/{pull_number}/merge"),
+  ("GetReposownerrepoPullspullNumberRequestedReviewers", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers"),
+  ("PostReposownerrepoPullspullNumberRequestedReviewers", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers"),
+  ("DeleteReposownerrepoPullspullNumberRequestedReviewers", Methods::Delete, "/repos/{owner}/{repo}/pulls/{pull_number}/requested_reviewers"),
+  ("GetReposownerrepoPullspullNumberReviews", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews"),
+  ("PostReposownerrepoPullspullNumberReviews", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews"),
+  ("GetReposownerrepoPullspullNumberReviewsreviewId", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}"),
+  ("PutReposownerrepoPullspullNumberReviewsreviewId", Methods::Put, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}"),
+  ("DeleteReposownerrepoPullspullNumberReviewsreviewId", Methods::Delete, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}"),
+  ("GetReposownerrepoPullspullNumberReviewsreviewIdComments", Methods::Get, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments"),
+  ("PutReposownerrepoPullspullNumberReviewsreviewIdDismissals", Methods::Put, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/dismissals"),
+  ("PostReposownerrepoPullspullNumberReviewsreviewIdEvents", Methods::Post, "/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events"),
+  ("PutReposownerrepoPullspullNumberUpdateBranch", Methods::Put, "/repos/{owner}/{repo}/pulls/{pull_number}/update-branch"),
+  ("GetReposownerrepoReadme", Methods::Get, "/repos/{owner}/{repo}/readme"),
+  ("GetReposownerrepoReadmedir", Methods::Get, "/repos/{owner}/{repo}/readme/{dir}"),
+  ("GetReposownerrepoReleases", Methods::Get, "/repos/{owner}/{repo}/releases"),
+  ("PostReposownerrepoReleases", Methods::Post, "/repos/{owner}/{repo}/releases"),
+  ("GetReposownerrepoReleasesAssetsassetId", Methods::Get, "/repos/{owner}/{repo}/releases/assets/{asset_id}"),
+  ("PatchReposownerrepoReleasesAssetsassetId", Methods::Patch, "/repos/{owner}/{repo}/releases/assets/{asset_id}"),
+  ("DeleteReposownerrepoReleasesAssetsassetId", Methods::Delete, "/repos/{owner}/{repo}/releases/assets/{asset_id}"),
+  ("PostReposownerrepoReleasesGenerateNotes", Methods::Post, "/repos/{owner}/{repo}/releases/generate-notes"),
+  ("GetReposownerrepoReleasesLatest", Methods::Get, "/repos/{owner}/{repo}/releases/latest"),
+  ("GetReposownerrepoReleasesTagstag", Methods::Get, "/repos/{owner}/{repo}/releases/tags/{tag}"),
+  ("GetReposownerrepoReleasesreleaseId", Methods::Get, "/repos/{owner}/{repo}/releases/{release_id}"),
+  ("PatchReposownerrepoReleasesreleaseId", Methods::Patch, "/repos/{owner}/{repo}/releases/{release_id}"),
+  ("DeleteReposownerrepoReleasesreleaseId", Methods::Delete, "/repos/{owner}/{repo}/releases/{release_id}"),
+  ("GetReposownerrepoReleasesreleaseIdAssets", Methods::Get, "/repos/{owner}/{repo}/releases/{release_id}/assets"),
+  ("PostReposownerrepoReleasesreleaseIdAssets", Methods::Post, "/repos/{owner}/{repo}/releases/{release_id}/assets"),
+  ("PostReposownerrepoReleasesreleaseIdReactions", Methods::Post, "/repos/{owner}/{repo}/releases/{release_id}/reactions"),
+  ("GetReposownerrepoSecretScanningAlerts", Methods::Get, "/repos/{owner}/{repo}/secret-scanning/alerts"),
+  ("GetReposownerrepoSecretScanningAlertsalertNumber", Methods::Get, "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}"),
+  ("PatchReposownerrepoSecretScanningAlertsalertNumber", Methods::Patch, "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}"),
+  ("GetReposownerrepoSecretScanningAlertsalertNumberLocations", Methods::Get, "/repos/{owner}/{repo}/secret-scanning/alerts/{alert_number}/locations"),
+  ("GetReposownerrepoStargazers", Methods::Get, "/repos/{owner}/{repo}/stargazers"),
+  ("GetReposownerrepoStatsCodeFrequency", Methods::Get, "/repos/{owner}/{repo}/stats/code_frequency"),
+  ("GetReposownerrepoStatsCommitActivity", Methods::Get, "/repos/{owner}/{repo}/stats/commit_activity"),
+  ("GetReposownerrepoStatsContributors", Methods::Get, "/repos/{owner}/{repo}/stats/contributors"),
+  ("GetReposownerrepoStatsParticipation", Methods::Get, "/repos/{owner}/{repo}/stats/participation"),
+  ("GetReposownerrepoStatsPunchCard", Methods::Get, "/repos/{owner}/{repo}/stats/punch_card"),
+  ("PostReposownerrepoStatusessha", Methods::Post, "/repos/{owner}/{repo}/statuses/{sha}"),
+  ("GetReposownerrepoSubscribers", Methods::Get, "/repos/{owner}/{repo}/subscribers"),
+  ("GetReposownerrepoSubscription", Methods::Get, "/repos/{owner}/{repo}/subscription"),
+  ("PutReposownerrepoSubscription", Methods::Put, "/repos/{owner}/{repo}/subscription"),
+  ("DeleteReposownerrepoSubscription", Methods::Delete, "/repos/{owner}/{repo}/subscription"),
+  ("GetReposownerrepoTags", Methods::Get, "/repos/{owner}/{repo}/tags"),
+  ("GetReposownerrepoTarballref", Methods::Get, "/repos/{owner}/{repo}/tarball/{ref}"),
+  ("GetReposownerrepoTeams", Methods::Get, "/repos/{owner}/{repo}/teams"),
+  ("GetReposownerrepoTopics", Methods::Get, "/repos/{owner}/{repo}/topics"),
+  ("PutReposownerrepoTopics", Methods::Put, "/repos/{owner}/{repo}/topics"),
+  ("GetReposownerrepoTrafficClones", Methods::Get, "/repos/{owner}/{repo}/traffic/clones"),
+  ("GetReposownerrepoTrafficPopularPaths", Methods::Get, "/repos/{owner}/{repo}/traffic/popular/paths"),
+  ("GetReposownerrepoTrafficPopularReferrers", Methods::Get, "/repos/{owner}/{repo}/traffic/popular/referrers"),
+  ("GetReposownerrepoTrafficViews", Methods::Get, "/repos/{owner}/{repo}/traffic/views"),
+  ("PostReposownerrepoTransfer", Methods::Post, "/repos/{owner}/{repo}/transfer"),
+  ("GetReposownerrepoVulnerabilityAlerts", Methods::Get, "/repos/{owner}/{repo}/vulnerability-alerts"),
+  ("PutReposownerrepoVulnerabilityAlerts", Methods::Put, "/repos/{owner}/{repo}/vulnerability-alerts"),
+  ("DeleteReposownerrepoVulnerabilityAlerts", Methods::Delete, "/repos/{owner}/{repo}/vulnerability-alerts"),
+  ("GetReposownerrepoZipballref", Methods::Get, "/repos/{owner}/{repo}/zipball/{ref}"),
+  ("PostRepostemplateOwnertemplateRepoGenerate", Methods::Post, "/repos/{template_owner}/{template_repo}/generate"),
+  ("GetRepositories", Methods::Get, "/repositories"),
+  ("GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets", Methods::Get, "/repositories/{repository_id}/environments/{environment_name}/secrets"),
+  ("GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey", Methods::Get, "/repositories/{repository_id}/environments/{environment_name}/secrets/public-key"),
+  ("GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName", Methods::Get, "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}"),
+  ("PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName", Methods::Put, "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}"),
+  ("DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName", Methods::Delete, "/repositories/{repository_id}/environments/{environment_name}/secrets/{secret_name}"),
+  ("GetScimV2EnterprisesenterpriseGroups", Methods::Get, "/scim/v2/enterprises/{enterprise}/Groups"),
+  ("PostScimV2EnterprisesenterpriseGroups", Methods::Post, "/scim/v2/enterprises/{enterprise}/Groups"),
+  ("GetScimV2EnterprisesenterpriseGroupsscimGroupId", Methods::Get, "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}"),
+  ("PutScimV2EnterprisesenterpriseGroupsscimGroupId", Methods::Put, "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}"),
+  ("PatchScimV2EnterprisesenterpriseGroupsscimGroupId", Methods::Patch, "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}"),
+  ("DeleteScimV2EnterprisesenterpriseGroupsscimGroupId", Methods::Delete, "/scim/v2/enterprises/{enterprise}/Groups/{scim_group_id}"),
+  ("GetScimV2EnterprisesenterpriseUsers", Methods::Get, "/scim/v2/enterprises/{enterprise}/Users"),
+  ("PostScimV2EnterprisesenterpriseUsers", Methods::Post, "/scim/v2/enterprises/{enterprise}/Users"),
+  ("GetScimV2EnterprisesenterpriseUsersscimUserId", Methods::Get, "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}"),
+  ("PutScimV2EnterprisesenterpriseUsersscimUserId", Methods::Put, "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}"),
+  ("PatchScimV2EnterprisesenterpriseUsersscimUserId", Methods::Patch, "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}"),
+  ("DeleteScimV2EnterprisesenterpriseUsersscimUserId", Methods::Delete, "/scim/v2/enterprises/{enterprise}/Users/{scim_user_id}"),
+  ("GetScimV2OrganizationsorgUsers", Methods::Get, "/scim/v2/organizations/{org}/Users"),
+  ("PostScimV2OrganizationsorgUsers", Methods::Post, "/scim/v2/organizations/{org}/Users"),
+  ("GetScimV2OrganizationsorgUsersscimUserId", Methods::Get, "/scim/v2/organizations/{org}/Users/{scim_user_id}"),
+  ("PutScimV2OrganizationsorgUsersscimUserId", Methods::Put, "/scim/v2/organizations/{org}/Users/{scim_user_id}"),
+  ("PatchScimV2OrganizationsorgUsersscimUserId", Methods::Patch, "/scim/v2/organizations/{org}/Users/{scim_user_id}"),
+  ("DeleteScimV2OrganizationsorgUsersscimUserId", Methods::Delete, "/scim/v2/organizations/{org}/Users/{scim_user_id}"),
+  ("GetSearchCode", Methods::Get, "/search/code"),
+  ("GetSearchCommits", Methods::Get, "/search/commits"),
+  ("GetSearchIssues", Methods::Get, "/search/issues"),
+  ("GetSearchLabels", Methods::Get, "/search/labels"),
+  ("GetSearchRepositories", Methods::Get, "/search/repositories"),
+  ("GetSearchTopics", Methods::Get, "/search/topics"),
+  ("GetSearchUsers", Methods::Get, "/search/users"),
+  ("GetTeamsteamId", Methods::Get, "/teams/{team_id}"),
+  ("PatchTeamsteamId", Methods::Patch, "/teams/{team_id}"),
+  ("DeleteTeamsteamId", Methods::Delete, "/teams/{team_id}"),
+  ("GetTeamsteamIdDiscussions", Methods::Get, "/teams/{team_id}/discussions"),
+  ("PostTeamsteamIdDiscussions", Methods::Post, "/teams/{team_id}/discussions"),
+  ("GetTeamsteamIdDiscussionsdiscussionNumber", Methods::Get, "/teams/{team_id}/discussions/{discussion_number}"),
+  ("PatchTeamsteamIdDiscussionsdiscussionNumber", Methods::Patch, "/teams/{team_id}/discussions/{discussion_number}"),
+  ("DeleteTeamsteamIdDiscussionsdiscussionNumber", Methods::Delete, "/teams/{team_id}/discussions/{discussion_number}"),
+  ("GetTeamsteamIdDiscussionsdiscussionNumberComments", Methods::Get, "/teams/{team_id}/discussions/{discussion_number}/comments"),
+  ("PostTeamsteamIdDiscussionsdiscussionNumberComments", Methods::Post, "/teams/{team_id}/discussions/{discussion_number}/comments"),
+  ("GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber", Methods::Get, "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber", Methods::Patch, "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber", Methods::Delete, "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}"),
+  ("GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions", Methods::Get, "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions"),
+  ("PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions", Methods::Post, "/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions"),
+  ("GetTeamsteamIdDiscussionsdiscussionNumberReactions", Methods::Get, "/teams/{team_id}/discussions/{discussion_number}/reactions"),
+  ("PostTeamsteamIdDiscussionsdiscussionNumberReactions", Methods::Post, "/teams/{team_id}/discussions/{discussion_number}/reactions"),
+  ("GetTeamsteamIdInvitations", Methods::Get, "/teams/{team_id}/invitations"),
+  ("GetTeamsteamIdMembers", Methods::Get, "/teams/{team_id}/members"),
+  ("GetTeamsteamIdMembersusername", Methods::Get, "/teams/{team_id}/members/{username}"),
+  ("PutTeamsteamIdMembersusername", Methods::Put, "/teams/{team_id}/members/{username}"),
+  ("DeleteTeamsteamIdMembersusername", Methods::Delete, "/teams/{team_id}/members/{username}"),
+  ("GetTeamsteamIdMembershipsusername", Methods::Get, "/teams/{team_id}/memberships/{username}"),
+  ("PutTeamsteamIdMembershipsusername", Methods::Put, "/teams/{team_id}/memberships/{username}"),
+  ("DeleteTeamsteamIdMembershipsusername", Methods::Delete, "/teams/{team_id}/memberships/{username}"),
+  ("GetTeamsteamIdProjects", Methods::Get, "/teams/{team_id}/projects"),
+  ("GetTeamsteamIdProjectsprojectId", Methods::Get, "/teams/{team_id}/projects/{project_id}"),
+  ("PutTeamsteamIdProjectsprojectId", Methods::Put, "/teams/{team_id}/projects/{project_id}"),
+  ("DeleteTeamsteamIdProjectsprojectId", Methods::Delete, "/teams/{team_id}/projects/{project_id}"),
+  ("GetTeamsteamIdRepos", Methods::Get, "/teams/{team_id}/repos"),
+  ("GetTeamsteamIdReposownerrepo", Methods::Get, "/teams/{team_id}/repos/{owner}/{repo}"),
+  ("PutTeamsteamIdReposownerrepo", Methods::Put, "/teams/{team_id}/repos/{owner}/{repo}"),
+  ("DeleteTeamsteamIdReposownerrepo", Methods::Delete, "/teams/{team_id}/repos/{owner}/{repo}"),
+  ("GetTeamsteamIdTeamSyncGroupMappings", Methods::Get, "/teams/{team_id}/team-sync/group-mappings"),
+  ("PatchTeamsteamIdTeamSyncGroupMappings", Methods::Patch, "/teams/{team_id}/team-sync/group-mappings"),
+  ("GetTeamsteamIdTeams", Methods::Get, "/teams/{team_id}/teams"),
+  ("GetUser", Methods::Get, "/user"),
+  ("PatchUser", Methods::Patch, "/user"),
+  ("GetUserBlocks", Methods::Get, "/user/blocks"),
+  ("GetUserBlocksusername", Methods::Get, "/user/blocks/{username}"),
+  ("PutUserBlocksusername", Methods::Put, "/user/blocks/{username}"),
+  ("DeleteUserBlocksusername", Methods::Delete, "/user/blocks/{username}"),
+  ("GetUserCodespaces", Methods::Get, "/user/codespaces"),
+  ("PostUserCodespaces", Methods::Post, "/user/codespaces"),
+  ("GetUserCodespacesSecrets", Methods::Get, "/user/codespaces/secrets"),
+  ("GetUserCodespacesSecretsPublicKey", Methods::Get, "/user/codespaces/secrets/public-key"),
+  ("GetUserCodespacesSecretssecretName", Methods::Get, "/user/codespaces/secrets/{secret_name}"),
+  ("PutUserCodespacesSecretssecretName", Methods::Put, "/user/codespaces/secrets/{secret_name}"),
+  ("DeleteUserCodespacesSecretssecretName", Methods::Delete, "/user/codespaces/secrets/{secret_name}"),
+  ("GetUserCodespacesSecretssecretNameRepositories", Methods::Get, "/user/codespaces/secrets/{secret_name}/repositories"),
+  ("PutUserCodespacesSecretssecretNameRepositories", Methods::Put, "/user/codespaces/secrets/{secret_name}/repositories"),
+  ("PutUserCodespacesSecretssecretNameRepositoriesrepositoryId", Methods::Put, "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}"),
+  ("DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId", Methods::Delete, "/user/codespaces/secrets/{secret_name}/repositories/{repository_id}"),
+  ("GetUserCodespacescodespaceName", Methods::Get, "/user/codespaces/{codespace_name}"),
+  ("PatchUserCodespacescodespaceName", Methods::Patch, "/user/codespaces/{codespace_name}"),
+  ("DeleteUserCodespacescodespaceName", Methods::Delete, "/user/codespaces/{codespace_name}"),
+  ("PostUserCodespacescodespaceNameExports", Methods::Post, "/user/codespaces/{codespace_name}/exports"),
+  ("GetUserCodespacescodespaceNameExportsexportId", Methods::Get, "/user/codespaces/{codespace_name}/exports/{export_id}"),
+  ("GetUserCodespacescodespaceNameMachines", Methods::Get, "/user/codespaces/{codespace_name}/machines"),
+  ("PostUserCodespacescodespaceNameStart", Methods::Post, "/user/codespaces/{codespace_name}/start"),
+  ("PostUserCodespacescodespaceNameStop", Methods::Post, "/user/codespaces/{codespace_name}/stop"),
+  ("PatchUserEmailVisibility", Methods::Patch, "/user/email/visibility"),
+  ("GetUserEmails", Methods::Get, "/user/emails"),
+  ("PostUserEmails", Methods::Post, "/user/emails"),
+  ("DeleteUserEmails", Methods::Delete, "/user/emails"),
+  ("GetUserFollowers", Methods::Get, "/user/followers"),
+  ("GetUserFollowing", Methods::Get, "/user/following"),
+  ("GetUserFollowingusername", Methods::Get, "/user/following/{username}"),
+  ("PutUserFollowingusername", Methods::Put, "/user/following/{username}"),
+  ("DeleteUserFollowingusername", Methods::Delete, "/user/following/{username}"),
+  ("GetUserGpgKeys", Methods::Get, "/user/gpg_keys"),
+  ("PostUserGpgKeys", Methods::Post, "/user/gpg_keys"),
+  ("GetUserGpgKeysgpgKeyId", Methods::Get, "/user/gpg_keys/{gpg_key_id}"),
+  ("DeleteUserGpgKeysgpgKeyId", Methods::Delete, "/user/gpg_keys/{gpg_key_id}"),
+  ("GetUserInstallations", Methods::Get, "/user/installations"),
+  ("GetUserInstallationsinstallationIdRepositories", Methods::Get, "/user/installations/{installation_id}/repositories"),
+  ("PutUserInstallationsinstallationIdRepositoriesrepositoryId", Methods::Put, "/user/installations/{installation_id}/repositories/{repository_id}"),
+  ("DeleteUserInstallationsinstallationIdRepositoriesrepositoryId", Methods::Delete, "/user/installations/{installation_id}/repositories/{repository_id}"),
+  ("GetUserInteractionLimits", Methods::Get, "/user/interaction-limits"),
+  ("PutUserInteractionLimits", Methods::Put, "/user/interaction-limits"),
+  ("DeleteUserInteractionLimits", Methods::Delete, "/user/interaction-limits"),
+  ("GetUserIssues", Methods::Get, "/user/issues"),
+  ("GetUserKeys", Methods::Get, "/user/keys"),
+  ("PostUserKeys", Methods::Post, "/user/keys"),
+  ("GetUserKeyskeyId", Methods::Get, "/user/keys/{key_id}"),
+  ("DeleteUserKeyskeyId", Methods::Delete, "/user/keys/{key_id}"),
+  ("GetUserMarketplacePurchases", Methods::Get, "/user/marketplace_purchases"),
+  ("GetUserMarketplacePurchasesStubbed", Methods::Get, "/user/marketplace_purchases/stubbed"),
+  ("GetUserMembershipsOrgs", Methods::Get, "/user/memberships/orgs"),
+  ("GetUserMembershipsOrgsorg", Methods::Get, "/user/memberships/orgs/{org}"),
+  ("PatchUserMembershipsOrgsorg", Methods::Patch, "/user/memberships/orgs/{org}"),
+  ("GetUserMigrations", Methods::Get, "/user/migrations"),
+  ("PostUserMigrations", Methods::Post, "/user/migrations"),
+  ("GetUserMigrationsmigrationId", Methods::Get, "/user/migrations/{migration_id}"),
+  ("GetUserMigrationsmigrationIdArchive", Methods::Get, "/user/migrations/{migration_id}/archive"),
+  ("DeleteUserMigrationsmigrationIdArchive", Methods::Delete, "/user/migrations/{migration_id}/archive"),
+  ("DeleteUserMigrationsmigrationIdReposrepoNameLock", Methods::Delete, "/user/migrations/{migration_id}/repos/{repo_name}/lock"),
+  ("GetUserMigrationsmigrationIdRepositories", Methods::Get, "/user/migrations/{migration_id}/repositories"),
+  ("GetUserOrgs", Methods::Get, "/user/orgs"),
+  ("GetUserPackages", Methods::Get, "/user/packages"),
+  ("GetUserPackagespackageTypepackageName", Methods::Get, "/user/packages/{package_type}/{package_name}"),
+  ("DeleteUserPackagespackageTypepackageName", Methods::Delete, "/user/packages/{package_type}/{package_name}"),
+  ("PostUserPackagespackageTypepackageNameRestore", Methods::Post, "/user/packages/{package_type}/{package_name}/restore"),
+  ("GetUserPackagespackageTypepackageNameVersions", Methods::Get, "/user/packages/{package_type}/{package_name}/versions"),
+  ("GetUserPackagespackageTypepackageNameVersionspackageVersionId", Methods::Get, "/user/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("DeleteUserPackagespackageTypepackageNameVersionspackageVersionId", Methods::Delete, "/user/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore", Methods::Post, "/user/packages/{package_type}/{package_name}/versions/{package_version_id}/restore"),
+  ("PostUserProjects", Methods::Post, "/user/projects"),
+  ("GetUserPublicEmails", Methods::Get, "/user/public_emails"),
+  ("GetUserRepos", Methods::Get, "/user/repos"),
+  ("PostUserRepos", Methods::Post, "/user/repos"),
+  ("GetUserRepositoryInvitations", Methods::Get, "/user/repository_invitations"),
+  ("PatchUserRepositoryInvitationsinvitationId", Methods::Patch, "/user/repository_invitations/{invitation_id}"),
+  ("DeleteUserRepositoryInvitationsinvitationId", Methods::Delete, "/user/repository_invitations/{invitation_id}"),
+  ("GetUserStarred", Methods::Get, "/user/starred"),
+  ("GetUserStarredownerrepo", Methods::Get, "/user/starred/{owner}/{repo}"),
+  ("PutUserStarredownerrepo", Methods::Put, "/user/starred/{owner}/{repo}"),
+  ("DeleteUserStarredownerrepo", Methods::Delete, "/user/starred/{owner}/{repo}"),
+  ("GetUserSubscriptions", Methods::Get, "/user/subscriptions"),
+  ("GetUserTeams", Methods::Get, "/user/teams"),
+  ("GetUsers", Methods::Get, "/users"),
+  ("GetUsersusername", Methods::Get, "/users/{username}"),
+  ("GetUsersusernameEvents", Methods::Get, "/users/{username}/events"),
+  ("GetUsersusernameEventsOrgsorg", Methods::Get, "/users/{username}/events/orgs/{org}"),
+  ("GetUsersusernameEventsPublic", Methods::Get, "/users/{username}/events/public"),
+  ("GetUsersusernameFollowers", Methods::Get, "/users/{username}/followers"),
+  ("GetUsersusernameFollowing", Methods::Get, "/users/{username}/following"),
+  ("GetUsersusernameFollowingtargetUser", Methods::Get, "/users/{username}/following/{target_user}"),
+  ("GetUsersusernameGists", Methods::Get, "/users/{username}/gists"),
+  ("GetUsersusernameGpgKeys", Methods::Get, "/users/{username}/gpg_keys"),
+  ("GetUsersusernameHovercard", Methods::Get, "/users/{username}/hovercard"),
+  ("GetUsersusernameInstallation", Methods::Get, "/users/{username}/installation"),
+  ("GetUsersusernameKeys", Methods::Get, "/users/{username}/keys"),
+  ("GetUsersusernameOrgs", Methods::Get, "/users/{username}/orgs"),
+  ("GetUsersusernamePackages", Methods::Get, "/users/{username}/packages"),
+  ("GetUsersusernamePackagespackageTypepackageName", Methods::Get, "/users/{username}/packages/{package_type}/{package_name}"),
+  ("DeleteUsersusernamePackagespackageTypepackageName", Methods::Delete, "/users/{username}/packages/{package_type}/{package_name}"),
+  ("PostUsersusernamePackagespackageTypepackageNameRestore", Methods::Post, "/users/{username}/packages/{package_type}/{package_name}/restore"),
+  ("GetUsersusernamePackagespackageTypepackageNameVersions", Methods::Get, "/users/{username}/packages/{package_type}/{package_name}/versions"),
+  ("GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId", Methods::Get, "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId", Methods::Delete, "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}"),
+  ("PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore", Methods::Post, "/users/{username}/packages/{package_type}/{package_name}/versions/{package_version_id}/restore"),
+  ("GetUsersusernameProjects", Methods::Get, "/users/{username}/projects"),
+  ("GetUsersusernameReceivedEvents", Methods::Get, "/users/{username}/received_events"),
+  ("GetUsersusernameReceivedEventsPublic", Methods::Get, "/users/{username}/received_events/public"),
+  ("GetUsersusernameRepos", Methods::Get, "/users/{username}/repos"),
+  ("GetUsersusernameSettingsBillingActions", Methods::Get, "/users/{username}/settings/billing/actions"),
+  ("GetUsersusernameSettingsBillingPackages", Methods::Get, "/users/{username}/settings/billing/packages"),
+  ("GetUsersusernameSettingsBillingSharedStorage", Methods::Get, "/users/{username}/settings/billing/shared-storage"),
+  ("GetUsersusernameStarred", Methods::Get, "/users/{username}/starred"),
+  ("GetUsersusernameSubscriptions", Methods::Get, "/users/{username}/subscriptions"),
+  ("GetZen", Methods::Get, "/zen")];
+    pub fn from_parts(name: &str, args: Vec<String>) -> Option<EndPoints> {
+        let mut args = args.into_iter();
+        let endpoint = match name { "Get" => EndPoints::Get(),
+  "GetApp" => EndPoints::GetApp(),
+  "PostAppManifestscodeConversions" => EndPoints::PostAppManifestscodeConversions(args.next()?),
+  "GetAppHookConfig" => EndPoints::GetAppHookConfig(),
+  "PatchAppHookConfig" => EndPoints::PatchAppHookConfig(),
+  "GetAppHookDeliveries" => EndPoints::GetAppHookDeliveries(),
+  "GetAppHookDeliveriesdeliveryId" => EndPoints::GetAppHookDeliveriesdeliveryId(args.next()?),
+  "PostAppHookDeliveriesdeliveryIdAttempts" => EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(args.next()?),
+  "GetAppInstallations" => EndPoints::GetAppInstallations(),
+  "GetAppInstallationsinstallationId" => EndPoints::GetAppInstallationsinstallationId(args.next()?),
+  "DeleteAppInstallationsinstallationId" => EndPoints::DeleteAppInstallationsinstallationId(args.next()?),
+  "PostAppInstallationsinstallationIdAccessTokens" => EndPoints::PostAppInstallationsinstallationIdAccessTokens(args.next()?),
+  "PutAppInstallationsinstallationIdSuspended" => EndPoints::PutAppInstallationsinstallationIdSuspended(args.next()?),
+  "DeleteAppInstallationsinstallationIdSuspended" => EndPoints::DeleteAppInstallationsinstallationIdSuspended(args.next()?),
+  "GetApplicationsGrants" => EndPoints::GetApplicationsGrants(),
+  "GetApplicationsGrantsgrantId" => EndPoints::GetApplicationsGrantsgrantId(args.next()?),
+  "DeleteApplicationsGrantsgrantId" => EndPoints::DeleteApplicationsGrantsgrantId(args.next()?),
+  "DeleteApplicationsclientIdGrant" => EndPoints::DeleteApplicationsclientIdGrant(args.next()?),
+  "PostApplicationsclientIdToken" => EndPoints::PostApplicationsclientIdToken(args.next()?),
+  "PatchApplicationsclientIdToken" => EndPoints::PatchApplicationsclientIdToken(args.next()?),
+  "DeleteApplicationsclientIdToken" => EndPoints::DeleteApplicationsclientIdToken(args.next()?),
+  "PostApplicationsclientIdTokenScoped" => EndPoints::PostApplicationsclientIdTokenScoped(args.next()?),
+  "GetAppsappSlug" => EndPoints::GetAppsappSlug(args.next()?),
+  "GetAuthorizations" => EndPoints::GetAuthorizations(),
+  "PostAuthorizations" => EndPoints::PostAuthorizations(),
+  "PutAuthorizationsClientsclientId" => EndPoints::PutAuthorizationsClientsclientId(args.next()?),
+  "PutAuthorizationsClientsclientIdfingerprint" => EndPoints::PutAuthorizationsClientsclientIdfingerprint(args.next()?,args.next()?),
+  "GetAuthorizationsauthorizationId" => EndPoints::GetAuthorizationsauthorizationId(args.next()?),
+  "PatchAuthorizationsauthorizationId" => EndPoints::PatchAuthorizationsauthorizationId(args.next()?),
+  "DeleteAuthorizationsauthorizationId" => EndPoints::DeleteAuthorizationsauthorizationId(args.next()?),
+  "GetCodesOfConduct" => EndPoints::GetCodesOfConduct(),
+  "GetCodesOfConductkey" => EndPoints::GetCodesOfConductkey(args.next()?),
+  "GetEmojis" => EndPoints::GetEmojis(),
+  "GetEnterprisesenterpriseActionsPermissions" => EndPoints::GetEnterprisesenterpriseActionsPermissions(args.next()?),
+  "PutEnterprisesenterpriseActionsPermissions" => EndPoints::PutEnterprisesenterpriseActionsPermissions(args.next()?),
+  "GetEnterprisesenterpriseActionsPermissionsOrganizations" => EndPoints::GetEnterprisesenterpriseActionsPermissionsOrganizations(args.next()?),
+  "PutEnterprisesenterpriseActionsPermissionsOrganizations" => EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizations(args.next()?),
+  "PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId" => EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId(args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId" => EndPoints::DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId(args.next()?,args.next()?),
+  "GetEnterprisesenterpriseActionsPermissionsSelectedActions" => EndPoints::GetEnterprisesenterpriseActionsPermissionsSelectedActions(args.next()?),
+  "PutEnterprisesenterpriseActionsPermissionsSelectedActions" => EndPoints::PutEnterprisesenterpriseActionsPermissionsSelectedActions(args.next()?),
+  "GetEnterprisesenterpriseActionsRunnerGroups" => EndPoints::GetEnterprisesenterpriseActionsRunnerGroups(args.next()?),
+  "PostEnterprisesenterpriseActionsRunnerGroups" => EndPoints::PostEnterprisesenterpriseActionsRunnerGroups(args.next()?),
+  "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId" => EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId" => EndPoints::PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId" => EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations" => EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(args.next()?,args.next()?),
+  "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations" => EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(args.next()?,args.next()?),
+  "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId" => EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(args.next()?,args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId" => EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(args.next()?,args.next()?,args.next()?),
+  "GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners" => EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(args.next()?,args.next()?),
+  "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners" => EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(args.next()?,args.next()?),
+  "PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId" => EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId" => EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "GetEnterprisesenterpriseActionsRunners" => EndPoints::GetEnterprisesenterpriseActionsRunners(args.next()?),
+  "GetEnterprisesenterpriseActionsRunnersDownloads" => EndPoints::GetEnterprisesenterpriseActionsRunnersDownloads(args.next()?),
+  "PostEnterprisesenterpriseActionsRunnersRegistrationToken" => EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(args.next()?),
+  "PostEnterprisesenterpriseActionsRunnersRemoveToken" => EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(args.next()?),
+  "GetEnterprisesenterpriseActionsRunnersrunnerId" => EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerId(args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnersrunnerId" => EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerId(args.next()?,args.next()?),
+  "GetEnterprisesenterpriseActionsRunnersrunnerIdLabels" => EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "PostEnterprisesenterpriseActionsRunnersrunnerIdLabels" => EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "PutEnterprisesenterpriseActionsRunnersrunnerIdLabels" => EndPoints::PutEnterprisesenterpriseActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels" => EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname" => EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname(args.next()?,args.next()?,args.next()?),
+  "GetEnterprisesenterpriseAuditLog" => EndPoints::GetEnterprisesenterpriseAuditLog(args.next()?),
+  "GetEnterprisesenterpriseSecretScanningAlerts" => EndPoints::GetEnterprisesenterpriseSecretScanningAlerts(args.next()?),
+  "GetEnterprisesenterpriseSettingsBillingActions" => EndPoints::GetEnterprisesenterpriseSettingsBillingActions(args.next()?),
+  "GetEnterprisesenterpriseSettingsBillingAdvancedSecurity" => EndPoints::GetEnterprisesenterpriseSettingsBillingAdvancedSecurity(args.next()?),
+  "GetEnterprisesenterpriseSettingsBillingPackages" => EndPoints::GetEnterprisesenterpriseSettingsBillingPackages(args.next()?),
+  "GetEnterprisesenterpriseSettingsBillingSharedStorage" => EndPoints::GetEnterprisesenterpriseSettingsBillingSharedStorage(args.next()?),
+  "GetEvents" => EndPoints::GetEvents(),
+  "GetFeeds" => EndPoints::GetFeeds(),
+  "GetGists" => EndPoints::GetGists(),
+  "PostGists" => EndPoints::PostGists(),
+  "GetGistsPublic" => EndPoints::GetGistsPublic(),
+  "GetGistsStarred" => EndPoints::GetGistsStarred(),
+  "GetGistsgistId" => EndPoints::GetGistsgistId(args.next()?),
+  "PatchGistsgistId" => EndPoints::PatchGistsgistId(args.next()?),
+  "DeleteGistsgistId" => EndPoints::DeleteGistsgistId(args.next()?),
+  "GetGistsgistIdComments" => EndPoints::GetGistsgistIdComments(args.next()?),
+  "PostGistsgistIdComments" => EndPoints::PostGistsgistIdComments(args.next()?),
+  "GetGistsgistIdCommentscommentId" => EndPoints::GetGistsgistIdCommentscommentId(args.next()?,args.next()?),
+  "PatchGistsgistIdCommentscommentId" => EndPoints::PatchGistsgistIdCommentscommentId(args.next()?,args.next()?),
+  "DeleteGistsgistIdCommentscommentId" => EndPoints::DeleteGistsgistIdCommentscommentId(args.next()?,args.next()?),
+  "GetGistsgistIdCommits" => EndPoints::GetGistsgistIdCommits(args.next()?),
+  "GetGistsgistIdForks" => EndPoints::GetGistsgistIdForks(args.next()?),
+  "PostGistsgistIdForks" => EndPoints::PostGistsgistIdForks(args.next()?),
+  "GetGistsgistIdStar" => EndPoints::GetGistsgistIdStar(args.next()?),
+  "PutGistsgistIdStar" => EndPoints::PutGistsgistIdStar(args.next()?),
+  "DeleteGistsgistIdStar" => EndPoints::DeleteGistsgistIdStar(args.next()?),
+  "GetGistsgistIdsha" => EndPoints::GetGistsgistIdsha(args.next()?,args.next()?),
+  "GetGitignoreTemplates" => EndPoints::GetGitignoreTemplates(),
+  "GetGitignoreTemplatesname" => EndPoints::GetGitignoreTemplatesname(args.next()?),
+  "GetInstallationRepositories" => EndPoints::GetInstallationRepositories(),
+  "DeleteInstallationToken" => EndPoints::DeleteInstallationToken(),
+  "GetIssues" => EndPoints::GetIssues(),
+  "GetLicenses" => EndPoints::GetLicenses(),
+  "GetLicenseslicense" => EndPoints::GetLicenseslicense(args.next()?),
+  "PostMarkdown" => EndPoints::PostMarkdown(),
+  "PostMarkdownRaw" => EndPoints::PostMarkdownRaw(),
+  "GetMarketplaceListingAccountsaccountId" => EndPoints::GetMarketplaceListingAccountsaccountId(args.next()?),
+  "GetMarketplaceListingPlans" => EndPoints::GetMarketplaceListingPlans(),
+  "GetMarketplaceListingPlansplanIdAccounts" => EndPoints::GetMarketplaceListingPlansplanIdAccounts(args.next()?),
+  "GetMarketplaceListingStubbedAccountsaccountId" => EndPoints::GetMarketplaceListingStubbedAccountsaccountId(args.next()?),
+  "GetMarketplaceListingStubbedPlans" => EndPoints::GetMarketplaceListingStubbedPlans(),
+  "GetMarketplaceListingStubbedPlansplanIdAccounts" => EndPoints::GetMarketplaceListingStubbedPlansplanIdAccounts(args.next()?),
+  "GetMeta" => EndPoints::GetMeta(),
+  "GetNetworksownerrepoEvents" => EndPoints::GetNetworksownerrepoEvents(args.next()?,args.next()?),
+  "GetNotifications" => EndPoints::GetNotifications(),
+  "PutNotifications" => EndPoints::PutNotifications(),
+  "GetNotificationsThreadsthreadId" => EndPoints::GetNotificationsThreadsthreadId(args.next()?),
+  "PatchNotificationsThreadsthreadId" => EndPoints::PatchNotificationsThreadsthreadId(args.next()?),
+  "GetNotificationsThreadsthreadIdSubscription" => EndPoints::GetNotificationsThreadsthreadIdSubscription(args.next()?),
+  "PutNotificationsThreadsthreadIdSubscription" => EndPoints::PutNotificationsThreadsthreadIdSubscription(args.next()?),
+  "DeleteNotificationsThreadsthreadIdSubscription" => EndPoints::DeleteNotificationsThreadsthreadIdSubscription(args.next()?),
+  "GetOctocat" => EndPoints::GetOctocat(),
+  "GetOrganizations" => EndPoints::GetOrganizations(),
+  "GetOrganizationsorganizationIdCustomRoles" => EndPoints::GetOrganizationsorganizationIdCustomRoles(args.next()?),
+  "GetOrganizationsorgTeamteamSlugExternalGroups" => EndPoints::GetOrganizationsorgTeamteamSlugExternalGroups(args.next()?,args.next()?),
+  "GetOrgsorg" => EndPoints::GetOrgsorg(args.next()?),
+  "PatchOrgsorg" => EndPoints::PatchOrgsorg(args.next()?),
+  "GetOrgsorgActionsPermissions" => EndPoints::GetOrgsorgActionsPermissions(args.next()?),
+  "PutOrgsorgActionsPermissions" => EndPoints::PutOrgsorgActionsPermissions(args.next()?),
+  "GetOrgsorgActionsPermissionsRepositories" => EndPoints::GetOrgsorgActionsPermissionsRepositories(args.next()?),
+  "PutOrgsorgActionsPermissionsRepositories" => EndPoints::PutOrgsorgActionsPermissionsRepositories(args.next()?),
+  "PutOrgsorgActionsPermissionsRepositoriesrepositoryId" => EndPoints::PutOrgsorgActionsPermissionsRepositoriesrepositoryId(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId" => EndPoints::DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId(args.next()?,args.next()?),
+  "GetOrgsorgActionsPermissionsSelectedActions" => EndPoints::GetOrgsorgActionsPermissionsSelectedActions(args.next()?),
+  "PutOrgsorgActionsPermissionsSelectedActions" => EndPoints::PutOrgsorgActionsPermissionsSelectedActions(args.next()?),
+  "GetOrgsorgActionsPermissionsWorkflow" => EndPoints::GetOrgsorgActionsPermissionsWorkflow(args.next()?),
+  "PutOrgsorgActionsPermissionsWorkflow" => EndPoints::PutOrgsorgActionsPermissionsWorkflow(args.next()?),
+  "GetOrgsorgActionsRunnerGroups" => EndPoints::GetOrgsorgActionsRunnerGroups(args.next()?),
+  "PostOrgsorgActionsRunnerGroups" => EndPoints::PostOrgsorgActionsRunnerGroups(args.next()?),
+  "GetOrgsorgActionsRunnerGroupsrunnerGroupId" => EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "PatchOrgsorgActionsRunnerGroupsrunnerGroupId" => EndPoints::PatchOrgsorgActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnerGroupsrunnerGroupId" => EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupId(args.next()?,args.next()?),
+  "GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories" => EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(args.next()?,args.next()?),
+  "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories" => EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(args.next()?,args.next()?),
+  "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId" => EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId" => EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners" => EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(args.next()?,args.next()?),
+  "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners" => EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(args.next()?,args.next()?),
+  "PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId" => EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId" => EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgActionsRunners" => EndPoints::GetOrgsorgActionsRunners(args.next()?),
+  "GetOrgsorgActionsRunnersDownloads" => EndPoints::GetOrgsorgActionsRunnersDownloads(args.next()?),
+  "PostOrgsorgActionsRunnersRegistrationToken" => EndPoints::PostOrgsorgActionsRunnersRegistrationToken(args.next()?),
+  "PostOrgsorgActionsRunnersRemoveToken" => EndPoints::PostOrgsorgActionsRunnersRemoveToken(args.next()?),
+  "GetOrgsorgActionsRunnersrunnerId" => EndPoints::GetOrgsorgActionsRunnersrunnerId(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnersrunnerId" => EndPoints::DeleteOrgsorgActionsRunnersrunnerId(args.next()?,args.next()?),
+  "GetOrgsorgActionsRunnersrunnerIdLabels" => EndPoints::GetOrgsorgActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "PostOrgsorgActionsRunnersrunnerIdLabels" => EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "PutOrgsorgActionsRunnersrunnerIdLabels" => EndPoints::PutOrgsorgActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnersrunnerIdLabels" => EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabels(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsRunnersrunnerIdLabelsname" => EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabelsname(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgActionsSecrets" => EndPoints::GetOrgsorgActionsSecrets(args.next()?),
+  "GetOrgsorgActionsSecretsPublicKey" => EndPoints::GetOrgsorgActionsSecretsPublicKey(args.next()?),
+  "GetOrgsorgActionsSecretssecretName" => EndPoints::GetOrgsorgActionsSecretssecretName(args.next()?,args.next()?),
+  "PutOrgsorgActionsSecretssecretName" => EndPoints::PutOrgsorgActionsSecretssecretName(args.next()?,args.next()?),
+  "DeleteOrgsorgActionsSecretssecretName" => EndPoints::DeleteOrgsorgActionsSecretssecretName(args.next()?,args.next()?),
+  "GetOrgsorgActionsSecretssecretNameRepositories" => EndPoints::GetOrgsorgActionsSecretssecretNameRepositories(args.next()?,args.next()?),
+  "PutOrgsorgActionsSecretssecretNameRepositories" => EndPoints::PutOrgsorgActionsSecretssecretNameRepositories(args.next()?,args.next()?),
+  "PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId" => EndPoints::PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId" => EndPoints::DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgAuditLog" => EndPoints::GetOrgsorgAuditLog(args.next()?),
+  "GetOrgsorgBlocks" => EndPoints::GetOrgsorgBlocks(args.next()?),
+  "GetOrgsorgBlocksusername" => EndPoints::GetOrgsorgBlocksusername(args.next()?,args.next()?),
+  "PutOrgsorgBlocksusername" => EndPoints::PutOrgsorgBlocksusername(args.next()?,args.next()?),
+  "DeleteOrgsorgBlocksusername" => EndPoints::DeleteOrgsorgBlocksusername(args.next()?,args.next()?),
+  "GetOrgsorgCodeScanningAlerts" => EndPoints::GetOrgsorgCodeScanningAlerts(args.next()?),
+  "GetOrgsorgCredentialAuthorizations" => EndPoints::GetOrgsorgCredentialAuthorizations(args.next()?),
+  "DeleteOrgsorgCredentialAuthorizationscredentialId" => EndPoints::DeleteOrgsorgCredentialAuthorizationscredentialId(args.next()?,args.next()?),
+  "GetOrgsorgDependabotSecrets" => EndPoints::GetOrgsorgDependabotSecrets(args.next()?),
+  "GetOrgsorgDependabotSecretsPublicKey" => EndPoints::GetOrgsorgDependabotSecretsPublicKey(args.next()?),
+  "GetOrgsorgDependabotSecretssecretName" => EndPoints::GetOrgsorgDependabotSecretssecretName(args.next()?,args.next()?),
+  "PutOrgsorgDependabotSecretssecretName" => EndPoints::PutOrgsorgDependabotSecretssecretName(args.next()?,args.next()?),
+  "DeleteOrgsorgDependabotSecretssecretName" => EndPoints::DeleteOrgsorgDependabotSecretssecretName(args.next()?,args.next()?),
+  "GetOrgsorgDependabotSecretssecretNameRepositories" => EndPoints::GetOrgsorgDependabotSecretssecretNameRepositories(args.next()?,args.next()?),
+  "PutOrgsorgDependabotSecretssecretNameRepositories" => EndPoints::PutOrgsorgDependabotSecretssecretNameRepositories(args.next()?,args.next()?),
+  "PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId" => EndPoints::PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId" => EndPoints::DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgEvents" => EndPoints::GetOrgsorgEvents(args.next()?),
+  "GetOrgsorgExternalGroupgroupId" => EndPoints::GetOrgsorgExternalGroupgroupId(args.next()?,args.next()?),
+  "GetOrgsorgExternalGroups" => EndPoints::GetOrgsorgExternalGroups(args.next()?),
+  "GetOrgsorgFailedInvitations" => EndPoints::GetOrgsorgFailedInvitations(args.next()?),
+  "GetOrgsorgHooks" => EndPoints::GetOrgsorgHooks(args.next()?),
+  "PostOrgsorgHooks" => EndPoints::PostOrgsorgHooks(args.next()?),
+  "GetOrgsorgHookshookId" => EndPoints::GetOrgsorgHookshookId(args.next()?,args.next()?),
+  "PatchOrgsorgHookshookId" => EndPoints::PatchOrgsorgHookshookId(args.next()?,args.next()?),
+  "DeleteOrgsorgHookshookId" => EndPoints::DeleteOrgsorgHookshookId(args.next()?,args.next()?),
+  "GetOrgsorgHookshookIdConfig" => EndPoints::GetOrgsorgHookshookIdConfig(args.next()?,args.next()?),
+  "PatchOrgsorgHookshookIdConfig" => EndPoints::PatchOrgsorgHookshookIdConfig(args.next()?,args.next()?),
+  "GetOrgsorgHookshookIdDeliveries" => EndPoints::GetOrgsorgHookshookIdDeliveries(args.next()?,args.next()?),
+  "GetOrgsorgHookshookIdDeliveriesdeliveryId" => EndPoints::GetOrgsorgHookshookIdDeliveriesdeliveryId(args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts" => EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgHookshookIdPings" => EndPoints::PostOrgsorgHookshookIdPings(args.next()?,args.next()?),
+  "GetOrgsorgInstallation" => EndPoints::GetOrgsorgInstallation(args.next()?),
+  "GetOrgsorgInstallations" => EndPoints::GetOrgsorgInstallations(args.next()?),
+  "GetOrgsorgInteractionLimits" => EndPoints::GetOrgsorgInteractionLimits(args.next()?),
+  "PutOrgsorgInteractionLimits" => EndPoints::PutOrgsorgInteractionLimits(args.next()?),
+  "DeleteOrgsorgInteractionLimits" => EndPoints::DeleteOrgsorgInteractionLimits(args.next()?),
+  "GetOrgsorgInvitations" => EndPoints::GetOrgsorgInvitations(args.next()?),
+  "PostOrgsorgInvitations" => EndPoints::PostOrgsorgInvitations(args.next()?),
+  "DeleteOrgsorgInvitationsinvitationId" => EndPoints::DeleteOrgsorgInvitationsinvitationId(args.next()?,args.next()?),
+  "GetOrgsorgInvitationsinvitationIdTeams" => EndPoints::GetOrgsorgInvitationsinvitationIdTeams(args.next()?,args.next()?),
+  "GetOrgsorgIssues" => EndPoints::GetOrgsorgIssues(args.next()?),
+  "GetOrgsorgMembers" => EndPoints::GetOrgsorgMembers(args.next()?),
+  "GetOrgsorgMembersusername" => EndPoints::GetOrgsorgMembersusername(args.next()?,args.next()?),
+  "DeleteOrgsorgMembersusername" => EndPoints::DeleteOrgsorgMembersusername(args.next()?,args.next()?),
+  "GetOrgsorgMembershipsusername" => EndPoints::GetOrgsorgMembershipsusername(args.next()?,args.next()?),
+  "PutOrgsorgMembershipsusername" => EndPoints::PutOrgsorgMembershipsusername(args.next()?,args.next()?),
+  "DeleteOrgsorgMembershipsusername" => EndPoints::DeleteOrgsorgMembershipsusername(args.next()?,args.next()?),
+  "GetOrgsorgMigrations" => EndPoints::GetOrgsorgMigrations(args.next()?),
+  "PostOrgsorgMigrations" => EndPoints::PostOrgsorgMigrations(args.next()?),
+  "GetOrgsorgMigrationsmigrationId" => EndPoints::GetOrgsorgMigrationsmigrationId(args.next()?,args.next()?),
+  "GetOrgsorgMigrationsmigrationIdArchive" => EndPoints::GetOrgsorgMigrationsmigrationIdArchive(args.next()?,args.next()?),
+  "DeleteOrgsorgMigrationsmigrationIdArchive" => EndPoints::DeleteOrgsorgMigrationsmigrationIdArchive(args.next()?,args.next()?),
+  "DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock" => EndPoints::DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgMigrationsmigrationIdRepositories" => EndPoints::GetOrgsorgMigrationsmigrationIdRepositories(args.next()?,args.next()?),
+  "GetOrgsorgOutsideCollaborators" => EndPoints::GetOrgsorgOutsideCollaborators(args.next()?),
+  "PutOrgsorgOutsideCollaboratorsusername" => EndPoints::PutOrgsorgOutsideCollaboratorsusername(args.next()?,args.next()?),
+  "DeleteOrgsorgOutsideCollaboratorsusername" => EndPoints::DeleteOrgsorgOutsideCollaboratorsusername(args.next()?,args.next()?),
+  "GetOrgsorgPackages" => EndPoints::GetOrgsorgPackages(args.next()?),
+  "GetOrgsorgPackagespackageTypepackageName" => EndPoints::GetOrgsorgPackagespackageTypepackageName(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgPackagespackageTypepackageName" => EndPoints::DeleteOrgsorgPackagespackageTypepackageName(args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgPackagespackageTypepackageNameRestore" => EndPoints::PostOrgsorgPackagespackageTypepackageNameRestore(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgPackagespackageTypepackageNameVersions" => EndPoints::GetOrgsorgPackagespackageTypepackageNameVersions(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore" => EndPoints::PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgProjects" => EndPoints::GetOrgsorgProjects(args.next()?),
+  "PostOrgsorgProjects" => EndPoints::PostOrgsorgProjects(args.next()?),
+  "GetOrgsorgPublicMembers" => EndPoints::GetOrgsorgPublicMembers(args.next()?),
+  "GetOrgsorgPublicMembersusername" => EndPoints::GetOrgsorgPublicMembersusername(args.next()?,args.next()?),
+  "PutOrgsorgPublicMembersusername" => EndPoints::PutOrgsorgPublicMembersusername(args.next()?,args.next()?),
+  "DeleteOrgsorgPublicMembersusername" => EndPoints::DeleteOrgsorgPublicMembersusername(args.next()?,args.next()?),
+  "GetOrgsorgRepos" => EndPoints::GetOrgsorgRepos(args.next()?),
+  "PostOrgsorgRepos" => EndPoints::PostOrgsorgRepos(args.next()?),
+  "GetOrgsorgSecretScanningAlerts" => EndPoints::GetOrgsorgSecretScanningAlerts(args.next()?),
+  "GetOrgsorgSettingsBillingActions" => EndPoints::GetOrgsorgSettingsBillingActions(args.next()?),
+  "GetOrgsorgSettingsBillingAdvancedSecurity" => EndPoints::GetOrgsorgSettingsBillingAdvancedSecurity(args.next()?),
+  "GetOrgsorgSettingsBillingPackages" => EndPoints::GetOrgsorgSettingsBillingPackages(args.next()?),
+  "GetOrgsorgSettingsBillingSharedStorage" => EndPoints::GetOrgsorgSettingsBillingSharedStorage(args.next()?),
+  "GetOrgsorgTeamSyncGroups" => EndPoints::GetOrgsorgTeamSyncGroups(args.next()?),
+  "GetOrgsorgTeams" => EndPoints::GetOrgsorgTeams(args.next()?),
+  "PostOrgsorgTeams" => EndPoints::PostOrgsorgTeams(args.next()?),
+  "GetOrgsorgTeamsteamSlug" => EndPoints::GetOrgsorgTeamsteamSlug(args.next()?,args.next()?),
+  "PatchOrgsorgTeamsteamSlug" => EndPoints::PatchOrgsorgTeamsteamSlug(args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlug" => EndPoints::DeleteOrgsorgTeamsteamSlug(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussions" => EndPoints::GetOrgsorgTeamsteamSlugDiscussions(args.next()?,args.next()?),
+  "PostOrgsorgTeamsteamSlugDiscussions" => EndPoints::PostOrgsorgTeamsteamSlugDiscussions(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber" => EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(args.next()?,args.next()?,args.next()?),
+  "PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber" => EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber" => EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments" => EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments" => EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions" => EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions" => EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId" => EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions" => EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(args.next()?,args.next()?,args.next()?),
+  "PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions" => EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId" => EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PatchOrgsorgTeamsteamSlugExternalGroups" => EndPoints::PatchOrgsorgTeamsteamSlugExternalGroups(args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugExternalGroups" => EndPoints::DeleteOrgsorgTeamsteamSlugExternalGroups(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugInvitations" => EndPoints::GetOrgsorgTeamsteamSlugInvitations(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugMembers" => EndPoints::GetOrgsorgTeamsteamSlugMembers(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugMembershipsusername" => EndPoints::GetOrgsorgTeamsteamSlugMembershipsusername(args.next()?,args.next()?,args.next()?),
+  "PutOrgsorgTeamsteamSlugMembershipsusername" => EndPoints::PutOrgsorgTeamsteamSlugMembershipsusername(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugMembershipsusername" => EndPoints::DeleteOrgsorgTeamsteamSlugMembershipsusername(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugProjects" => EndPoints::GetOrgsorgTeamsteamSlugProjects(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugProjectsprojectId" => EndPoints::GetOrgsorgTeamsteamSlugProjectsprojectId(args.next()?,args.next()?,args.next()?),
+  "PutOrgsorgTeamsteamSlugProjectsprojectId" => EndPoints::PutOrgsorgTeamsteamSlugProjectsprojectId(args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugProjectsprojectId" => EndPoints::DeleteOrgsorgTeamsteamSlugProjectsprojectId(args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugRepos" => EndPoints::GetOrgsorgTeamsteamSlugRepos(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugReposownerrepo" => EndPoints::GetOrgsorgTeamsteamSlugReposownerrepo(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PutOrgsorgTeamsteamSlugReposownerrepo" => EndPoints::PutOrgsorgTeamsteamSlugReposownerrepo(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteOrgsorgTeamsteamSlugReposownerrepo" => EndPoints::DeleteOrgsorgTeamsteamSlugReposownerrepo(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugTeamSyncGroupMappings" => EndPoints::GetOrgsorgTeamsteamSlugTeamSyncGroupMappings(args.next()?,args.next()?),
+  "PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings" => EndPoints::PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings(args.next()?,args.next()?),
+  "GetOrgsorgTeamsteamSlugTeams" => EndPoints::GetOrgsorgTeamsteamSlugTeams(args.next()?,args.next()?),
+  "GetProjectsColumnsCardscardId" => EndPoints::GetProjectsColumnsCardscardId(args.next()?),
+  "PatchProjectsColumnsCardscardId" => EndPoints::PatchProjectsColumnsCardscardId(args.next()?),
+  "DeleteProjectsColumnsCardscardId" => EndPoints::DeleteProjectsColumnsCardscardId(args.next()?),
+  "PostProjectsColumnsCardscardIdMoves" => EndPoints::PostProjectsColumnsCardscardIdMoves(args.next()?),
+  "GetProjectsColumnscolumnId" => EndPoints::GetProjectsColumnscolumnId(args.next()?),
+  "PatchProjectsColumnscolumnId" => EndPoints::PatchProjectsColumnscolumnId(args.next()?),
+  "DeleteProjectsColumnscolumnId" => EndPoints::DeleteProjectsColumnscolumnId(args.next()?),
+  "GetProjectsColumnscolumnIdCards" => EndPoints::GetProjectsColumnscolumnIdCards(args.next()?),
+  "PostProjectsColumnscolumnIdCards" => EndPoints::PostProjectsColumnscolumnIdCards(args.next()?),
+  "PostProjectsColumnscolumnIdMoves" => EndPoints::PostProjectsColumnscolumnIdMoves(args.next()?),
+  "GetProjectsprojectId" => EndPoints::GetProjectsprojectId(args.next()?),
+  "PatchProjectsprojectId" => EndPoints::PatchProjectsprojectId(args.next()?),
+  "DeleteProjectsprojectId" => EndPoints::DeleteProjectsprojectId(args.next()?),
+  "GetProjectsprojectIdCollaborators" => EndPoints::GetProjectsprojectIdCollaborators(args.next()?),
+  "PutProjectsprojectIdCollaboratorsusername" => EndPoints::PutProjectsprojectIdCollaboratorsusername(args.next()?,args.next()?),
+  "DeleteProjectsprojectIdCollaboratorsusername" => EndPoints::DeleteProjectsprojectIdCollaboratorsusername(args.next()?,args.next()?),
+  "GetProjectsprojectIdCollaboratorsusernamePermission" => EndPoints::GetProjectsprojectIdCollaboratorsusernamePermission(args.next()?,args.next()?),
+  "GetProjectsprojectIdColumns" => EndPoints::GetProjectsprojectIdColumns(args.next()?),
+  "PostProjectsprojectIdColumns" => EndPoints::PostProjectsprojectIdColumns(args.next()?),
+  "GetRateLimit" => EndPoints::GetRateLimit(),
+  "DeleteReactionsreactionId" => EndPoints::DeleteReactionsreactionId(args.next()?),
+  "GetReposownerrepo" => EndPoints::GetReposownerrepo(args.next()?,args.next()?),
+  "PatchReposownerrepo" => EndPoints::PatchReposownerrepo(args.next()?,args.next()?),
+  "DeleteReposownerrepo" => EndPoints::DeleteReposownerrepo(args.next()?,args.next()?),
+  "GetReposownerrepoActionsArtifacts" => EndPoints::GetReposownerrepoActionsArtifacts(args.next()?,args.next()?),
+  "GetReposownerrepoActionsArtifactsartifactId" => EndPoints::GetReposownerrepoActionsArtifactsartifactId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsArtifactsartifactId" => EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsArtifactsartifactIdarchiveFormat" => EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsJobsjobId" => EndPoints::GetReposownerrepoActionsJobsjobId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsJobsjobIdLogs" => EndPoints::GetReposownerrepoActionsJobsjobIdLogs(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsPermissions" => EndPoints::GetReposownerrepoActionsPermissions(args.next()?,args.next()?),
+  "PutReposownerrepoActionsPermissions" => EndPoints::PutReposownerrepoActionsPermissions(args.next()?,args.next()?),
+  "GetReposownerrepoActionsPermissionsSelectedActions" => EndPoints::GetReposownerrepoActionsPermissionsSelectedActions(args.next()?,args.next()?),
+  "PutReposownerrepoActionsPermissionsSelectedActions" => EndPoints::PutReposownerrepoActionsPermissionsSelectedActions(args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunners" => EndPoints::GetReposownerrepoActionsRunners(args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunnersDownloads" => EndPoints::GetReposownerrepoActionsRunnersDownloads(args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunnersRegistrationToken" => EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunnersRemoveToken" => EndPoints::PostReposownerrepoActionsRunnersRemoveToken(args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunnersrunnerId" => EndPoints::GetReposownerrepoActionsRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsRunnersrunnerId" => EndPoints::DeleteReposownerrepoActionsRunnersrunnerId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunnersrunnerIdLabels" => EndPoints::GetReposownerrepoActionsRunnersrunnerIdLabels(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunnersrunnerIdLabels" => EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoActionsRunnersrunnerIdLabels" => EndPoints::PutReposownerrepoActionsRunnersrunnerIdLabels(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsRunnersrunnerIdLabels" => EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabels(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsRunnersrunnerIdLabelsname" => EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabelsname(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRuns" => EndPoints::GetReposownerrepoActionsRuns(args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunId" => EndPoints::GetReposownerrepoActionsRunsrunId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsRunsrunId" => EndPoints::DeleteReposownerrepoActionsRunsrunId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdApprovals" => EndPoints::GetReposownerrepoActionsRunsrunIdApprovals(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunsrunIdApprove" => EndPoints::PostReposownerrepoActionsRunsrunIdApprove(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdArtifacts" => EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber" => EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs" => EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs" => EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunsrunIdCancel" => EndPoints::PostReposownerrepoActionsRunsrunIdCancel(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdJobs" => EndPoints::GetReposownerrepoActionsRunsrunIdJobs(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdLogs" => EndPoints::GetReposownerrepoActionsRunsrunIdLogs(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsRunsrunIdLogs" => EndPoints::DeleteReposownerrepoActionsRunsrunIdLogs(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdPendingDeployments" => EndPoints::GetReposownerrepoActionsRunsrunIdPendingDeployments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunsrunIdPendingDeployments" => EndPoints::PostReposownerrepoActionsRunsrunIdPendingDeployments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsRunsrunIdRerun" => EndPoints::PostReposownerrepoActionsRunsrunIdRerun(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsRunsrunIdTiming" => EndPoints::GetReposownerrepoActionsRunsrunIdTiming(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsSecrets" => EndPoints::GetReposownerrepoActionsSecrets(args.next()?,args.next()?),
+  "GetReposownerrepoActionsSecretsPublicKey" => EndPoints::GetReposownerrepoActionsSecretsPublicKey(args.next()?,args.next()?),
+  "GetReposownerrepoActionsSecretssecretName" => EndPoints::GetReposownerrepoActionsSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoActionsSecretssecretName" => EndPoints::PutReposownerrepoActionsSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoActionsSecretssecretName" => EndPoints::DeleteReposownerrepoActionsSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsWorkflows" => EndPoints::GetReposownerrepoActionsWorkflows(args.next()?,args.next()?),
+  "GetReposownerrepoActionsWorkflowsworkflowId" => EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoActionsWorkflowsworkflowIdDisable" => EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdDisable(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoActionsWorkflowsworkflowIdDispatches" => EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoActionsWorkflowsworkflowIdEnable" => EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdEnable(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsWorkflowsworkflowIdRuns" => EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoActionsWorkflowsworkflowIdTiming" => EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdTiming(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoAssignees" => EndPoints::GetReposownerrepoAssignees(args.next()?,args.next()?),
+  "GetReposownerrepoAssigneesassignee" => EndPoints::GetReposownerrepoAssigneesassignee(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoAutolinks" => EndPoints::GetReposownerrepoAutolinks(args.next()?,args.next()?),
+  "PostReposownerrepoAutolinks" => EndPoints::PostReposownerrepoAutolinks(args.next()?,args.next()?),
+  "GetReposownerrepoAutolinksautolinkId" => EndPoints::GetReposownerrepoAutolinksautolinkId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoAutolinksautolinkId" => EndPoints::DeleteReposownerrepoAutolinksautolinkId(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoAutomatedSecurityFixes" => EndPoints::PutReposownerrepoAutomatedSecurityFixes(args.next()?,args.next()?),
+  "DeleteReposownerrepoAutomatedSecurityFixes" => EndPoints::DeleteReposownerrepoAutomatedSecurityFixes(args.next()?,args.next()?),
+  "GetReposownerrepoBranches" => EndPoints::GetReposownerrepoBranches(args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranch" => EndPoints::GetReposownerrepoBranchesbranch(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtection" => EndPoints::GetReposownerrepoBranchesbranchProtection(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoBranchesbranchProtection" => EndPoints::PutReposownerrepoBranchesbranchProtection(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtection" => EndPoints::DeleteReposownerrepoBranchesbranchProtection(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionEnforceAdmins" => EndPoints::GetReposownerrepoBranchesbranchProtectionEnforceAdmins(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionEnforceAdmins" => EndPoints::PostReposownerrepoBranchesbranchProtectionEnforceAdmins(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews" => EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews" => EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRequiredSignatures" => EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredSignatures(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionRequiredSignatures" => EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredSignatures(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks" => EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks" => EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts" => EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts" => EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts" => EndPoints::PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRestrictions" => EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictions(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRestrictions" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictions(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRestrictionsApps" => EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsApps(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionRestrictionsApps" => EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsApps(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoBranchesbranchProtectionRestrictionsApps" => EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsApps(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRestrictionsTeams" => EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsTeams(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionRestrictionsTeams" => EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsTeams(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoBranchesbranchProtectionRestrictionsTeams" => EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsTeams(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoBranchesbranchProtectionRestrictionsUsers" => EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsUsers(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchProtectionRestrictionsUsers" => EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsUsers(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoBranchesbranchProtectionRestrictionsUsers" => EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsUsers(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers" => EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoBranchesbranchRename" => EndPoints::PostReposownerrepoBranchesbranchRename(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCheckRuns" => EndPoints::PostReposownerrepoCheckRuns(args.next()?,args.next()?),
+  "GetReposownerrepoCheckRunscheckRunId" => EndPoints::GetReposownerrepoCheckRunscheckRunId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoCheckRunscheckRunId" => EndPoints::PatchReposownerrepoCheckRunscheckRunId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCheckRunscheckRunIdAnnotations" => EndPoints::GetReposownerrepoCheckRunscheckRunIdAnnotations(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCheckRunscheckRunIdRerequest" => EndPoints::PostReposownerrepoCheckRunscheckRunIdRerequest(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCheckSuites" => EndPoints::PostReposownerrepoCheckSuites(args.next()?,args.next()?),
+  "PatchReposownerrepoCheckSuitesPreferences" => EndPoints::PatchReposownerrepoCheckSuitesPreferences(args.next()?,args.next()?),
+  "GetReposownerrepoCheckSuitescheckSuiteId" => EndPoints::GetReposownerrepoCheckSuitescheckSuiteId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns" => EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCheckSuitescheckSuiteIdRerequest" => EndPoints::PostReposownerrepoCheckSuitescheckSuiteIdRerequest(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningAlerts" => EndPoints::GetReposownerrepoCodeScanningAlerts(args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningAlertsalertNumber" => EndPoints::GetReposownerrepoCodeScanningAlertsalertNumber(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoCodeScanningAlertsalertNumber" => EndPoints::PatchReposownerrepoCodeScanningAlertsalertNumber(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningAlertsalertNumberInstances" => EndPoints::GetReposownerrepoCodeScanningAlertsalertNumberInstances(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningAnalyses" => EndPoints::GetReposownerrepoCodeScanningAnalyses(args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningAnalysesanalysisId" => EndPoints::GetReposownerrepoCodeScanningAnalysesanalysisId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoCodeScanningAnalysesanalysisId" => EndPoints::DeleteReposownerrepoCodeScanningAnalysesanalysisId(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCodeScanningSarifs" => EndPoints::PostReposownerrepoCodeScanningSarifs(args.next()?,args.next()?),
+  "GetReposownerrepoCodeScanningSarifssarifId" => EndPoints::GetReposownerrepoCodeScanningSarifssarifId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCodespaces" => EndPoints::GetReposownerrepoCodespaces(args.next()?,args.next()?),
+  "PostReposownerrepoCodespaces" => EndPoints::PostReposownerrepoCodespaces(args.next()?,args.next()?),
+  "GetReposownerrepoCodespacesMachines" => EndPoints::GetReposownerrepoCodespacesMachines(args.next()?,args.next()?),
+  "GetReposownerrepoCollaborators" => EndPoints::GetReposownerrepoCollaborators(args.next()?,args.next()?),
+  "GetReposownerrepoCollaboratorsusername" => EndPoints::GetReposownerrepoCollaboratorsusername(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoCollaboratorsusername" => EndPoints::PutReposownerrepoCollaboratorsusername(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoCollaboratorsusername" => EndPoints::DeleteReposownerrepoCollaboratorsusername(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCollaboratorsusernamePermission" => EndPoints::GetReposownerrepoCollaboratorsusernamePermission(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoComments" => EndPoints::GetReposownerrepoComments(args.next()?,args.next()?),
+  "GetReposownerrepoCommentscommentId" => EndPoints::GetReposownerrepoCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoCommentscommentId" => EndPoints::PatchReposownerrepoCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoCommentscommentId" => EndPoints::DeleteReposownerrepoCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommentscommentIdReactions" => EndPoints::GetReposownerrepoCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCommentscommentIdReactions" => EndPoints::PostReposownerrepoCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoCommentscommentIdReactionsreactionId" => EndPoints::DeleteReposownerrepoCommentscommentIdReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommits" => EndPoints::GetReposownerrepoCommits(args.next()?,args.next()?),
+  "GetReposownerrepoCommitscommitShaBranchesWhereHead" => EndPoints::GetReposownerrepoCommitscommitShaBranchesWhereHead(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitscommitShaComments" => EndPoints::GetReposownerrepoCommitscommitShaComments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoCommitscommitShaComments" => EndPoints::PostReposownerrepoCommitscommitShaComments(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitscommitShaPulls" => EndPoints::GetReposownerrepoCommitscommitShaPulls(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitsref" => EndPoints::GetReposownerrepoCommitsref(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitsrefCheckRuns" => EndPoints::GetReposownerrepoCommitsrefCheckRuns(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitsrefCheckSuites" => EndPoints::GetReposownerrepoCommitsrefCheckSuites(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitsrefStatus" => EndPoints::GetReposownerrepoCommitsrefStatus(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommitsrefStatuses" => EndPoints::GetReposownerrepoCommitsrefStatuses(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoCommunityProfile" => EndPoints::GetReposownerrepoCommunityProfile(args.next()?,args.next()?),
+  "GetReposownerrepoComparebasehead" => EndPoints::GetReposownerrepoComparebasehead(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoContentspath" => EndPoints::GetReposownerrepoContentspath(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoContentspath" => EndPoints::PutReposownerrepoContentspath(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoContentspath" => EndPoints::DeleteReposownerrepoContentspath(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoContributors" => EndPoints::GetReposownerrepoContributors(args.next()?,args.next()?),
+  "GetReposownerrepoDependabotSecrets" => EndPoints::GetReposownerrepoDependabotSecrets(args.next()?,args.next()?),
+  "GetReposownerrepoDependabotSecretsPublicKey" => EndPoints::GetReposownerrepoDependabotSecretsPublicKey(args.next()?,args.next()?),
+  "GetReposownerrepoDependabotSecretssecretName" => EndPoints::GetReposownerrepoDependabotSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoDependabotSecretssecretName" => EndPoints::PutReposownerrepoDependabotSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoDependabotSecretssecretName" => EndPoints::DeleteReposownerrepoDependabotSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoDeployments" => EndPoints::GetReposownerrepoDeployments(args.next()?,args.next()?),
+  "PostReposownerrepoDeployments" => EndPoints::PostReposownerrepoDeployments(args.next()?,args.next()?),
+  "GetReposownerrepoDeploymentsdeploymentId" => EndPoints::GetReposownerrepoDeploymentsdeploymentId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoDeploymentsdeploymentId" => EndPoints::DeleteReposownerrepoDeploymentsdeploymentId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoDeploymentsdeploymentIdStatuses" => EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatuses(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoDeploymentsdeploymentIdStatuses" => EndPoints::PostReposownerrepoDeploymentsdeploymentIdStatuses(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId" => EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoDispatches" => EndPoints::PostReposownerrepoDispatches(args.next()?,args.next()?),
+  "GetReposownerrepoEnvironments" => EndPoints::GetReposownerrepoEnvironments(args.next()?,args.next()?),
+  "GetReposownerrepoEnvironmentsenvironmentName" => EndPoints::GetReposownerrepoEnvironmentsenvironmentName(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoEnvironmentsenvironmentName" => EndPoints::PutReposownerrepoEnvironmentsenvironmentName(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoEnvironmentsenvironmentName" => EndPoints::DeleteReposownerrepoEnvironmentsenvironmentName(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoEvents" => EndPoints::GetReposownerrepoEvents(args.next()?,args.next()?),
+  "GetReposownerrepoForks" => EndPoints::GetReposownerrepoForks(args.next()?,args.next()?),
+  "PostReposownerrepoForks" => EndPoints::PostReposownerrepoForks(args.next()?,args.next()?),
+  "PostReposownerrepoGitBlobs" => EndPoints::PostReposownerrepoGitBlobs(args.next()?,args.next()?),
+  "GetReposownerrepoGitBlobsfileSha" => EndPoints::GetReposownerrepoGitBlobsfileSha(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoGitCommits" => EndPoints::PostReposownerrepoGitCommits(args.next()?,args.next()?),
+  "GetReposownerrepoGitCommitscommitSha" => EndPoints::GetReposownerrepoGitCommitscommitSha(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoGitMatchingRefsref" => EndPoints::GetReposownerrepoGitMatchingRefsref(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoGitRefref" => EndPoints::GetReposownerrepoGitRefref(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoGitRefs" => EndPoints::PostReposownerrepoGitRefs(args.next()?,args.next()?),
+  "PatchReposownerrepoGitRefsref" => EndPoints::PatchReposownerrepoGitRefsref(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoGitRefsref" => EndPoints::DeleteReposownerrepoGitRefsref(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoGitTags" => EndPoints::PostReposownerrepoGitTags(args.next()?,args.next()?),
+  "GetReposownerrepoGitTagstagSha" => EndPoints::GetReposownerrepoGitTagstagSha(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoGitTrees" => EndPoints::PostReposownerrepoGitTrees(args.next()?,args.next()?),
+  "GetReposownerrepoGitTreestreeSha" => EndPoints::GetReposownerrepoGitTreestreeSha(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoHooks" => EndPoints::GetReposownerrepoHooks(args.next()?,args.next()?),
+  "PostReposownerrepoHooks" => EndPoints::PostReposownerrepoHooks(args.next()?,args.next()?),
+  "GetReposownerrepoHookshookId" => EndPoints::GetReposownerrepoHookshookId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoHookshookId" => EndPoints::PatchReposownerrepoHookshookId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoHookshookId" => EndPoints::DeleteReposownerrepoHookshookId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoHookshookIdConfig" => EndPoints::GetReposownerrepoHookshookIdConfig(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoHookshookIdConfig" => EndPoints::PatchReposownerrepoHookshookIdConfig(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoHookshookIdDeliveries" => EndPoints::GetReposownerrepoHookshookIdDeliveries(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoHookshookIdDeliveriesdeliveryId" => EndPoints::GetReposownerrepoHookshookIdDeliveriesdeliveryId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts" => EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoHookshookIdPings" => EndPoints::PostReposownerrepoHookshookIdPings(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoHookshookIdTests" => EndPoints::PostReposownerrepoHookshookIdTests(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoImport" => EndPoints::GetReposownerrepoImport(args.next()?,args.next()?),
+  "PutReposownerrepoImport" => EndPoints::PutReposownerrepoImport(args.next()?,args.next()?),
+  "PatchReposownerrepoImport" => EndPoints::PatchReposownerrepoImport(args.next()?,args.next()?),
+  "DeleteReposownerrepoImport" => EndPoints::DeleteReposownerrepoImport(args.next()?,args.next()?),
+  "GetReposownerrepoImportAuthors" => EndPoints::GetReposownerrepoImportAuthors(args.next()?,args.next()?),
+  "PatchReposownerrepoImportAuthorsauthorId" => EndPoints::PatchReposownerrepoImportAuthorsauthorId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoImportLargeFiles" => EndPoints::GetReposownerrepoImportLargeFiles(args.next()?,args.next()?),
+  "PatchReposownerrepoImportLfs" => EndPoints::PatchReposownerrepoImportLfs(args.next()?,args.next()?),
+  "GetReposownerrepoInstallation" => EndPoints::GetReposownerrepoInstallation(args.next()?,args.next()?),
+  "GetReposownerrepoInteractionLimits" => EndPoints::GetReposownerrepoInteractionLimits(args.next()?,args.next()?),
+  "PutReposownerrepoInteractionLimits" => EndPoints::PutReposownerrepoInteractionLimits(args.next()?,args.next()?),
+  "DeleteReposownerrepoInteractionLimits" => EndPoints::DeleteReposownerrepoInteractionLimits(args.next()?,args.next()?),
+  "GetReposownerrepoInvitations" => EndPoints::GetReposownerrepoInvitations(args.next()?,args.next()?),
+  "PatchReposownerrepoInvitationsinvitationId" => EndPoints::PatchReposownerrepoInvitationsinvitationId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoInvitationsinvitationId" => EndPoints::DeleteReposownerrepoInvitationsinvitationId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssues" => EndPoints::GetReposownerrepoIssues(args.next()?,args.next()?),
+  "PostReposownerrepoIssues" => EndPoints::PostReposownerrepoIssues(args.next()?,args.next()?),
+  "GetReposownerrepoIssuesComments" => EndPoints::GetReposownerrepoIssuesComments(args.next()?,args.next()?),
+  "GetReposownerrepoIssuesCommentscommentId" => EndPoints::GetReposownerrepoIssuesCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoIssuesCommentscommentId" => EndPoints::PatchReposownerrepoIssuesCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesCommentscommentId" => EndPoints::DeleteReposownerrepoIssuesCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesCommentscommentIdReactions" => EndPoints::GetReposownerrepoIssuesCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoIssuesCommentscommentIdReactions" => EndPoints::PostReposownerrepoIssuesCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId" => EndPoints::DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesEvents" => EndPoints::GetReposownerrepoIssuesEvents(args.next()?,args.next()?),
+  "GetReposownerrepoIssuesEventseventId" => EndPoints::GetReposownerrepoIssuesEventseventId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumber" => EndPoints::GetReposownerrepoIssuesissueNumber(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoIssuesissueNumber" => EndPoints::PatchReposownerrepoIssuesissueNumber(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoIssuesissueNumberAssignees" => EndPoints::PostReposownerrepoIssuesissueNumberAssignees(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesissueNumberAssignees" => EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumberComments" => EndPoints::GetReposownerrepoIssuesissueNumberComments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoIssuesissueNumberComments" => EndPoints::PostReposownerrepoIssuesissueNumberComments(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumberEvents" => EndPoints::GetReposownerrepoIssuesissueNumberEvents(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumberLabels" => EndPoints::GetReposownerrepoIssuesissueNumberLabels(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoIssuesissueNumberLabels" => EndPoints::PostReposownerrepoIssuesissueNumberLabels(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoIssuesissueNumberLabels" => EndPoints::PutReposownerrepoIssuesissueNumberLabels(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesissueNumberLabels" => EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesissueNumberLabelsname" => EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoIssuesissueNumberLock" => EndPoints::PutReposownerrepoIssuesissueNumberLock(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesissueNumberLock" => EndPoints::DeleteReposownerrepoIssuesissueNumberLock(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumberReactions" => EndPoints::GetReposownerrepoIssuesissueNumberReactions(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoIssuesissueNumberReactions" => EndPoints::PostReposownerrepoIssuesissueNumberReactions(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoIssuesissueNumberReactionsreactionId" => EndPoints::DeleteReposownerrepoIssuesissueNumberReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoIssuesissueNumberTimeline" => EndPoints::GetReposownerrepoIssuesissueNumberTimeline(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoKeys" => EndPoints::GetReposownerrepoKeys(args.next()?,args.next()?),
+  "PostReposownerrepoKeys" => EndPoints::PostReposownerrepoKeys(args.next()?,args.next()?),
+  "GetReposownerrepoKeyskeyId" => EndPoints::GetReposownerrepoKeyskeyId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoKeyskeyId" => EndPoints::DeleteReposownerrepoKeyskeyId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoLabels" => EndPoints::GetReposownerrepoLabels(args.next()?,args.next()?),
+  "PostReposownerrepoLabels" => EndPoints::PostReposownerrepoLabels(args.next()?,args.next()?),
+  "GetReposownerrepoLabelsname" => EndPoints::GetReposownerrepoLabelsname(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoLabelsname" => EndPoints::PatchReposownerrepoLabelsname(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoLabelsname" => EndPoints::DeleteReposownerrepoLabelsname(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoLanguages" => EndPoints::GetReposownerrepoLanguages(args.next()?,args.next()?),
+  "PutReposownerrepoLfs" => EndPoints::PutReposownerrepoLfs(args.next()?,args.next()?),
+  "DeleteReposownerrepoLfs" => EndPoints::DeleteReposownerrepoLfs(args.next()?,args.next()?),
+  "GetReposownerrepoLicense" => EndPoints::GetReposownerrepoLicense(args.next()?,args.next()?),
+  "PostReposownerrepoMergeUpstream" => EndPoints::PostReposownerrepoMergeUpstream(args.next()?,args.next()?),
+  "PostReposownerrepoMerges" => EndPoints::PostReposownerrepoMerges(args.next()?,args.next()?),
+  "GetReposownerrepoMilestones" => EndPoints::GetReposownerrepoMilestones(args.next()?,args.next()?),
+  "PostReposownerrepoMilestones" => EndPoints::PostReposownerrepoMilestones(args.next()?,args.next()?),
+  "GetReposownerrepoMilestonesmilestoneNumber" => EndPoints::GetReposownerrepoMilestonesmilestoneNumber(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoMilestonesmilestoneNumber" => EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoMilestonesmilestoneNumber" => EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoMilestonesmilestoneNumberLabels" => EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoNotifications" => EndPoints::GetReposownerrepoNotifications(args.next()?,args.next()?),
+  "PutReposownerrepoNotifications" => EndPoints::PutReposownerrepoNotifications(args.next()?,args.next()?),
+  "GetReposownerrepoPages" => EndPoints::GetReposownerrepoPages(args.next()?,args.next()?),
+  "PostReposownerrepoPages" => EndPoints::PostReposownerrepoPages(args.next()?,args.next()?),
+  "PutReposownerrepoPages" => EndPoints::PutReposownerrepoPages(args.next()?,args.next()?),
+  "DeleteReposownerrepoPages" => EndPoints::DeleteReposownerrepoPages(args.next()?,args.next()?),
+  "GetReposownerrepoPagesBuilds" => EndPoints::GetReposownerrepoPagesBuilds(args.next()?,args.next()?),
+  "PostReposownerrepoPagesBuilds" => EndPoints::PostReposownerrepoPagesBuilds(args.next()?,args.next()?),
+  "GetReposownerrepoPagesBuildsLatest" => EndPoints::GetReposownerrepoPagesBuildsLatest(args.next()?,args.next()?),
+  "GetReposownerrepoPagesBuildsbuildId" => EndPoints::GetReposownerrepoPagesBuildsbuildId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPagesHealth" => EndPoints::GetReposownerrepoPagesHealth(args.next()?,args.next()?),
+  "GetReposownerrepoProjects" => EndPoints::GetReposownerrepoProjects(args.next()?,args.next()?),
+  "PostReposownerrepoProjects" => EndPoints::PostReposownerrepoProjects(args.next()?,args.next()?),
+  "GetReposownerrepoPulls" => EndPoints::GetReposownerrepoPulls(args.next()?,args.next()?),
+  "PostReposownerrepoPulls" => EndPoints::PostReposownerrepoPulls(args.next()?,args.next()?),
+  "GetReposownerrepoPullsComments" => EndPoints::GetReposownerrepoPullsComments(args.next()?,args.next()?),
+  "GetReposownerrepoPullsCommentscommentId" => EndPoints::GetReposownerrepoPullsCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoPullsCommentscommentId" => EndPoints::PatchReposownerrepoPullsCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoPullsCommentscommentId" => EndPoints::DeleteReposownerrepoPullsCommentscommentId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullsCommentscommentIdReactions" => EndPoints::GetReposownerrepoPullsCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullsCommentscommentIdReactions" => EndPoints::PostReposownerrepoPullsCommentscommentIdReactions(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId" => EndPoints::DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumber" => EndPoints::GetReposownerrepoPullspullNumber(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoPullspullNumber" => EndPoints::PatchReposownerrepoPullspullNumber(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberCodespaces" => EndPoints::PostReposownerrepoPullspullNumberCodespaces(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberComments" => EndPoints::GetReposownerrepoPullspullNumberComments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberComments" => EndPoints::PostReposownerrepoPullspullNumberComments(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberCommentscommentIdReplies" => EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberCommits" => EndPoints::GetReposownerrepoPullspullNumberCommits(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberFiles" => EndPoints::GetReposownerrepoPullspullNumberFiles(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberMerge" => EndPoints::GetReposownerrepoPullspullNumberMerge(args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoPullspullNumberMerge" => EndPoints::PutReposownerrepoPullspullNumberMerge(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberRequestedReviewers" => EndPoints::GetReposownerrepoPullspullNumberRequestedReviewers(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberRequestedReviewers" => EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoPullspullNumberRequestedReviewers" => EndPoints::DeleteReposownerrepoPullspullNumberRequestedReviewers(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberReviews" => EndPoints::GetReposownerrepoPullspullNumberReviews(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberReviews" => EndPoints::PostReposownerrepoPullspullNumberReviews(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberReviewsreviewId" => EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoPullspullNumberReviewsreviewId" => EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoPullspullNumberReviewsreviewId" => EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoPullspullNumberReviewsreviewIdComments" => EndPoints::GetReposownerrepoPullspullNumberReviewsreviewIdComments(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoPullspullNumberReviewsreviewIdDismissals" => EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoPullspullNumberReviewsreviewIdEvents" => EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PutReposownerrepoPullspullNumberUpdateBranch" => EndPoints::PutReposownerrepoPullspullNumberUpdateBranch(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoReadme" => EndPoints::GetReposownerrepoReadme(args.next()?,args.next()?),
+  "GetReposownerrepoReadmedir" => EndPoints::GetReposownerrepoReadmedir(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoReleases" => EndPoints::GetReposownerrepoReleases(args.next()?,args.next()?),
+  "PostReposownerrepoReleases" => EndPoints::PostReposownerrepoReleases(args.next()?,args.next()?),
+  "GetReposownerrepoReleasesAssetsassetId" => EndPoints::GetReposownerrepoReleasesAssetsassetId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoReleasesAssetsassetId" => EndPoints::PatchReposownerrepoReleasesAssetsassetId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoReleasesAssetsassetId" => EndPoints::DeleteReposownerrepoReleasesAssetsassetId(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoReleasesGenerateNotes" => EndPoints::PostReposownerrepoReleasesGenerateNotes(args.next()?,args.next()?),
+  "GetReposownerrepoReleasesLatest" => EndPoints::GetReposownerrepoReleasesLatest(args.next()?,args.next()?),
+  "GetReposownerrepoReleasesTagstag" => EndPoints::GetReposownerrepoReleasesTagstag(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoReleasesreleaseId" => EndPoints::GetReposownerrepoReleasesreleaseId(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoReleasesreleaseId" => EndPoints::PatchReposownerrepoReleasesreleaseId(args.next()?,args.next()?,args.next()?),
+  "DeleteReposownerrepoReleasesreleaseId" => EndPoints::DeleteReposownerrepoReleasesreleaseId(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoReleasesreleaseIdAssets" => EndPoints::GetReposownerrepoReleasesreleaseIdAssets(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoReleasesreleaseIdAssets" => EndPoints::PostReposownerrepoReleasesreleaseIdAssets(args.next()?,args.next()?,args.next()?),
+  "PostReposownerrepoReleasesreleaseIdReactions" => EndPoints::PostReposownerrepoReleasesreleaseIdReactions(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoSecretScanningAlerts" => EndPoints::GetReposownerrepoSecretScanningAlerts(args.next()?,args.next()?),
+  "GetReposownerrepoSecretScanningAlertsalertNumber" => EndPoints::GetReposownerrepoSecretScanningAlertsalertNumber(args.next()?,args.next()?,args.next()?),
+  "PatchReposownerrepoSecretScanningAlertsalertNumber" => EndPoints::PatchReposownerrepoSecretScanningAlertsalertNumber(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoSecretScanningAlertsalertNumberLocations" => EndPoints::GetReposownerrepoSecretScanningAlertsalertNumberLocations(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoStargazers" => EndPoints::GetReposownerrepoStargazers(args.next()?,args.next()?),
+  "GetReposownerrepoStatsCodeFrequency" => EndPoints::GetReposownerrepoStatsCodeFrequency(args.next()?,args.next()?),
+  "GetReposownerrepoStatsCommitActivity" => EndPoints::GetReposownerrepoStatsCommitActivity(args.next()?,args.next()?),
+  "GetReposownerrepoStatsContributors" => EndPoints::GetReposownerrepoStatsContributors(args.next()?,args.next()?),
+  "GetReposownerrepoStatsParticipation" => EndPoints::GetReposownerrepoStatsParticipation(args.next()?,args.next()?),
+  "GetReposownerrepoStatsPunchCard" => EndPoints::GetReposownerrepoStatsPunchCard(args.next()?,args.next()?),
+  "PostReposownerrepoStatusessha" => EndPoints::PostReposownerrepoStatusessha(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoSubscribers" => EndPoints::GetReposownerrepoSubscribers(args.next()?,args.next()?),
+  "GetReposownerrepoSubscription" => EndPoints::GetReposownerrepoSubscription(args.next()?,args.next()?),
+  "PutReposownerrepoSubscription" => EndPoints::PutReposownerrepoSubscription(args.next()?,args.next()?),
+  "DeleteReposownerrepoSubscription" => EndPoints::DeleteReposownerrepoSubscription(args.next()?,args.next()?),
+  "GetReposownerrepoTags" => EndPoints::GetReposownerrepoTags(args.next()?,args.next()?),
+  "GetReposownerrepoTarballref" => EndPoints::GetReposownerrepoTarballref(args.next()?,args.next()?,args.next()?),
+  "GetReposownerrepoTeams" => EndPoints::GetReposownerrepoTeams(args.next()?,args.next()?),
+  "GetReposownerrepoTopics" => EndPoints::GetReposownerrepoTopics(args.next()?,args.next()?),
+  "PutReposownerrepoTopics" => EndPoints::PutReposownerrepoTopics(args.next()?,args.next()?),
+  "GetReposownerrepoTrafficClones" => EndPoints::GetReposownerrepoTrafficClones(args.next()?,args.next()?),
+  "GetReposownerrepoTrafficPopularPaths" => EndPoints::GetReposownerrepoTrafficPopularPaths(args.next()?,args.next()?),
+  "GetReposownerrepoTrafficPopularReferrers" => EndPoints::GetReposownerrepoTrafficPopularReferrers(args.next()?,args.next()?),
+  "GetReposownerrepoTrafficViews" => EndPoints::GetReposownerrepoTrafficViews(args.next()?,args.next()?),
+  "PostReposownerrepoTransfer" => EndPoints::PostReposownerrepoTransfer(args.next()?,args.next()?),
+  "GetReposownerrepoVulnerabilityAlerts" => EndPoints::GetReposownerrepoVulnerabilityAlerts(args.next()?,args.next()?),
+  "PutReposownerrepoVulnerabilityAlerts" => EndPoints::PutReposownerrepoVulnerabilityAlerts(args.next()?,args.next()?),
+  "DeleteReposownerrepoVulnerabilityAlerts" => EndPoints::DeleteReposownerrepoVulnerabilityAlerts(args.next()?,args.next()?),
+  "GetReposownerrepoZipballref" => EndPoints::GetReposownerrepoZipballref(args.next()?,args.next()?,args.next()?),
+  "PostRepostemplateOwnertemplateRepoGenerate" => EndPoints::PostRepostemplateOwnertemplateRepoGenerate(args.next()?,args.next()?),
+  "GetRepositories" => EndPoints::GetRepositories(),
+  "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets" => EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets(args.next()?,args.next()?),
+  "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey" => EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey(args.next()?,args.next()?),
+  "GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName" => EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName" => EndPoints::PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName" => EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(args.next()?,args.next()?,args.next()?),
+  "GetScimV2EnterprisesenterpriseGroups" => EndPoints::GetScimV2EnterprisesenterpriseGroups(args.next()?),
+  "PostScimV2EnterprisesenterpriseGroups" => EndPoints::PostScimV2EnterprisesenterpriseGroups(args.next()?),
+  "GetScimV2EnterprisesenterpriseGroupsscimGroupId" => EndPoints::GetScimV2EnterprisesenterpriseGroupsscimGroupId(args.next()?,args.next()?),
+  "PutScimV2EnterprisesenterpriseGroupsscimGroupId" => EndPoints::PutScimV2EnterprisesenterpriseGroupsscimGroupId(args.next()?,args.next()?),
+  "PatchScimV2EnterprisesenterpriseGroupsscimGroupId" => EndPoints::PatchScimV2EnterprisesenterpriseGroupsscimGroupId(args.next()?,args.next()?),
+  "DeleteScimV2EnterprisesenterpriseGroupsscimGroupId" => EndPoints::DeleteScimV2EnterprisesenterpriseGroupsscimGroupId(args.next()?,args.next()?),
+  "GetScimV2EnterprisesenterpriseUsers" => EndPoints::GetScimV2EnterprisesenterpriseUsers(args.next()?),
+  "PostScimV2EnterprisesenterpriseUsers" => EndPoints::PostScimV2EnterprisesenterpriseUsers(args.next()?),
+  "GetScimV2EnterprisesenterpriseUsersscimUserId" => EndPoints::GetScimV2EnterprisesenterpriseUsersscimUserId(args.next()?,args.next()?),
+  "PutScimV2EnterprisesenterpriseUsersscimUserId" => EndPoints::PutScimV2EnterprisesenterpriseUsersscimUserId(args.next()?,args.next()?),
+  "PatchScimV2EnterprisesenterpriseUsersscimUserId" => EndPoints::PatchScimV2EnterprisesenterpriseUsersscimUserId(args.next()?,args.next()?),
+  "DeleteScimV2EnterprisesenterpriseUsersscimUserId" => EndPoints::DeleteScimV2EnterprisesenterpriseUsersscimUserId(args.next()?,args.next()?),
+  "GetScimV2OrganizationsorgUsers" => EndPoints::GetScimV2OrganizationsorgUsers(args.next()?),
+  "PostScimV2OrganizationsorgUsers" => EndPoints::PostScimV2OrganizationsorgUsers(args.next()?),
+  "GetScimV2OrganizationsorgUsersscimUserId" => EndPoints::GetScimV2OrganizationsorgUsersscimUserId(args.next()?,args.next()?),
+  "PutScimV2OrganizationsorgUsersscimUserId" => EndPoints::PutScimV2OrganizationsorgUsersscimUserId(args.next()?,args.next()?),
+  "PatchScimV2OrganizationsorgUsersscimUserId" => EndPoints::PatchScimV2OrganizationsorgUsersscimUserId(args.next()?,args.next()?),
+  "DeleteScimV2OrganizationsorgUsersscimUserId" => EndPoints::DeleteScimV2OrganizationsorgUsersscimUserId(args.next()?,args.next()?),
+  "GetSearchCode" => EndPoints::GetSearchCode(),
+  "GetSearchCommits" => EndPoints::GetSearchCommits(),
+  "GetSearchIssues" => EndPoints::GetSearchIssues(),
+  "GetSearchLabels" => EndPoints::GetSearchLabels(),
+  "GetSearchRepositories" => EndPoints::GetSearchRepositories(),
+  "GetSearchTopics" => EndPoints::GetSearchTopics(),
+  "GetSearchUsers" => EndPoints::GetSearchUsers(),
+  "GetTeamsteamId" => EndPoints::GetTeamsteamId(args.next()?),
+  "PatchTeamsteamId" => EndPoints::PatchTeamsteamId(args.next()?),
+  "DeleteTeamsteamId" => EndPoints::DeleteTeamsteamId(args.next()?),
+  "GetTeamsteamIdDiscussions" => EndPoints::GetTeamsteamIdDiscussions(args.next()?),
+  "PostTeamsteamIdDiscussions" => EndPoints::PostTeamsteamIdDiscussions(args.next()?),
+  "GetTeamsteamIdDiscussionsdiscussionNumber" => EndPoints::GetTeamsteamIdDiscussionsdiscussionNumber(args.next()?,args.next()?),
+  "PatchTeamsteamIdDiscussionsdiscussionNumber" => EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumber(args.next()?,args.next()?),
+  "DeleteTeamsteamIdDiscussionsdiscussionNumber" => EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumber(args.next()?,args.next()?),
+  "GetTeamsteamIdDiscussionsdiscussionNumberComments" => EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberComments(args.next()?,args.next()?),
+  "PostTeamsteamIdDiscussionsdiscussionNumberComments" => EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(args.next()?,args.next()?),
+  "GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?),
+  "PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?),
+  "DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber" => EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(args.next()?,args.next()?,args.next()?),
+  "GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions" => EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(args.next()?,args.next()?,args.next()?),
+  "PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions" => EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(args.next()?,args.next()?,args.next()?),
+  "GetTeamsteamIdDiscussionsdiscussionNumberReactions" => EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberReactions(args.next()?,args.next()?),
+  "PostTeamsteamIdDiscussionsdiscussionNumberReactions" => EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberReactions(args.next()?,args.next()?),
+  "GetTeamsteamIdInvitations" => EndPoints::GetTeamsteamIdInvitations(args.next()?),
+  "GetTeamsteamIdMembers" => EndPoints::GetTeamsteamIdMembers(args.next()?),
+  "GetTeamsteamIdMembersusername" => EndPoints::GetTeamsteamIdMembersusername(args.next()?,args.next()?),
+  "PutTeamsteamIdMembersusername" => EndPoints::PutTeamsteamIdMembersusername(args.next()?,args.next()?),
+  "DeleteTeamsteamIdMembersusername" => EndPoints::DeleteTeamsteamIdMembersusername(args.next()?,args.next()?),
+  "GetTeamsteamIdMembershipsusername" => EndPoints::GetTeamsteamIdMembershipsusername(args.next()?,args.next()?),
+  "PutTeamsteamIdMembershipsusername" => EndPoints::PutTeamsteamIdMembershipsusername(args.next()?,args.next()?),
+  "DeleteTeamsteamIdMembershipsusername" => EndPoints::DeleteTeamsteamIdMembershipsusername(args.next()?,args.next()?),
+  "GetTeamsteamIdProjects" => EndPoints::GetTeamsteamIdProjects(args.next()?),
+  "GetTeamsteamIdProjectsprojectId" => EndPoints::GetTeamsteamIdProjectsprojectId(args.next()?,args.next()?),
+  "PutTeamsteamIdProjectsprojectId" => EndPoints::PutTeamsteamIdProjectsprojectId(args.next()?,args.next()?),
+  "DeleteTeamsteamIdProjectsprojectId" => EndPoints::DeleteTeamsteamIdProjectsprojectId(args.next()?,args.next()?),
+  "GetTeamsteamIdRepos" => EndPoints::GetTeamsteamIdRepos(args.next()?),
+  "GetTeamsteamIdReposownerrepo" => EndPoints::GetTeamsteamIdReposownerrepo(args.next()?,args.next()?,args.next()?),
+  "PutTeamsteamIdReposownerrepo" => EndPoints::PutTeamsteamIdReposownerrepo(args.next()?,args.next()?,args.next()?),
+  "DeleteTeamsteamIdReposownerrepo" => EndPoints::DeleteTeamsteamIdReposownerrepo(args.next()?,args.next()?,args.next()?),
+  "GetTeamsteamIdTeamSyncGroupMappings" => EndPoints::GetTeamsteamIdTeamSyncGroupMappings(args.next()?),
+  "PatchTeamsteamIdTeamSyncGroupMappings" => EndPoints::PatchTeamsteamIdTeamSyncGroupMappings(args.next()?),
+  "GetTeamsteamIdTeams" => EndPoints::GetTeamsteamIdTeams(args.next()?),
+  "GetUser" => EndPoints::GetUser(),
+  "PatchUser" => EndPoints::PatchUser(),
+  "GetUserBlocks" => EndPoints::GetUserBlocks(),
+  "GetUserBlocksusername" => EndPoints::GetUserBlocksusername(args.next()?),
+  "PutUserBlocksusername" => EndPoints::PutUserBlocksusername(args.next()?),
+  "DeleteUserBlocksusername" => EndPoints::DeleteUserBlocksusername(args.next()?),
+  "GetUserCodespaces" => EndPoints::GetUserCodespaces(),
+  "PostUserCodespaces" => EndPoints::PostUserCodespaces(),
+  "GetUserCodespacesSecrets" => EndPoints::GetUserCodespacesSecrets(),
+  "GetUserCodespacesSecretsPublicKey" => EndPoints::GetUserCodespacesSecretsPublicKey(),
+  "GetUserCodespacesSecretssecretName" => EndPoints::GetUserCodespacesSecretssecretName(args.next()?),
+  "PutUserCodespacesSecretssecretName" => EndPoints::PutUserCodespacesSecretssecretName(args.next()?),
+  "DeleteUserCodespacesSecretssecretName" => EndPoints::DeleteUserCodespacesSecretssecretName(args.next()?),
+  "GetUserCodespacesSecretssecretNameRepositories" => EndPoints::GetUserCodespacesSecretssecretNameRepositories(args.next()?),
+  "PutUserCodespacesSecretssecretNameRepositories" => EndPoints::PutUserCodespacesSecretssecretNameRepositories(args.next()?),
+  "PutUserCodespacesSecretssecretNameRepositoriesrepositoryId" => EndPoints::PutUserCodespacesSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?),
+  "DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId" => EndPoints::DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId(args.next()?,args.next()?),
+  "GetUserCodespacescodespaceName" => EndPoints::GetUserCodespacescodespaceName(args.next()?),
+  "PatchUserCodespacescodespaceName" => EndPoints::PatchUserCodespacescodespaceName(args.next()?),
+  "DeleteUserCodespacescodespaceName" => EndPoints::DeleteUserCodespacescodespaceName(args.next()?),
+  "PostUserCodespacescodespaceNameExports" => EndPoints::PostUserCodespacescodespaceNameExports(args.next()?),
+  "GetUserCodespacescodespaceNameExportsexportId" => EndPoints::GetUserCodespacescodespaceNameExportsexportId(args.next()?,args.next()?),
+  "GetUserCodespacescodespaceNameMachines" => EndPoints::GetUserCodespacescodespaceNameMachines(args.next()?),
+  "PostUserCodespacescodespaceNameStart" => EndPoints::PostUserCodespacescodespaceNameStart(args.next()?),
+  "PostUserCodespacescodespaceNameStop" => EndPoints::PostUserCodespacescodespaceNameStop(args.next()?),
+  "PatchUserEmailVisibility" => EndPoints::PatchUserEmailVisibility(),
+  "GetUserEmails" => EndPoints::GetUserEmails(),
+  "PostUserEmails" => EndPoints::PostUserEmails(),
+  "DeleteUserEmails" => EndPoints::DeleteUserEmails(),
+  "GetUserFollowers" => EndPoints::GetUserFollowers(),
+  "GetUserFollowing" => EndPoints::GetUserFollowing(),
+  "GetUserFollowingusername" => EndPoints::GetUserFollowingusername(args.next()?),
+  "PutUserFollowingusername" => EndPoints::PutUserFollowingusername(args.next()?),
+  "DeleteUserFollowingusername" => EndPoints::DeleteUserFollowingusername(args.next()?),
+  "GetUserGpgKeys" => EndPoints::GetUserGpgKeys(),
+  "PostUserGpgKeys" => EndPoints::PostUserGpgKeys(),
+  "GetUserGpgKeysgpgKeyId" => EndPoints::GetUserGpgKeysgpgKeyId(args.next()?),
+  "DeleteUserGpgKeysgpgKeyId" => EndPoints::DeleteUserGpgKeysgpgKeyId(args.next()?),
+  "GetUserInstallations" => EndPoints::GetUserInstallations(),
+  "GetUserInstallationsinstallationIdRepositories" => EndPoints::GetUserInstallationsinstallationIdRepositories(args.next()?),
+  "PutUserInstallationsinstallationIdRepositoriesrepositoryId" => EndPoints::PutUserInstallationsinstallationIdRepositoriesrepositoryId(args.next()?,args.next()?),
+  "DeleteUserInstallationsinstallationIdRepositoriesrepositoryId" => EndPoints::DeleteUserInstallationsinstallationIdRepositoriesrepositoryId(args.next()?,args.next()?),
+  "GetUserInteractionLimits" => EndPoints::GetUserInteractionLimits(),
+  "PutUserInteractionLimits" => EndPoints::PutUserInteractionLimits(),
+  "DeleteUserInteractionLimits" => EndPoints::DeleteUserInteractionLimits(),
+  "GetUserIssues" => EndPoints::GetUserIssues(),
+  "GetUserKeys" => EndPoints::GetUserKeys(),
+  "PostUserKeys" => EndPoints::PostUserKeys(),
+  "GetUserKeyskeyId" => EndPoints::GetUserKeyskeyId(args.next()?),
+  "DeleteUserKeyskeyId" => EndPoints::DeleteUserKeyskeyId(args.next()?),
+  "GetUserMarketplacePurchases" => EndPoints::GetUserMarketplacePurchases(),
+  "GetUserMarketplacePurchasesStubbed" => EndPoints::GetUserMarketplacePurchasesStubbed(),
+  "GetUserMembershipsOrgs" => EndPoints::GetUserMembershipsOrgs(),
+  "GetUserMembershipsOrgsorg" => EndPoints::GetUserMembershipsOrgsorg(args.next()?),
+  "PatchUserMembershipsOrgsorg" => EndPoints::PatchUserMembershipsOrgsorg(args.next()?),
+  "GetUserMigrations" => EndPoints::GetUserMigrations(),
+  "PostUserMigrations" => EndPoints::PostUserMigrations(),
+  "GetUserMigrationsmigrationId" => EndPoints::GetUserMigrationsmigrationId(args.next()?),
+  "GetUserMigrationsmigrationIdArchive" => EndPoints::GetUserMigrationsmigrationIdArchive(args.next()?),
+  "DeleteUserMigrationsmigrationIdArchive" => EndPoints::DeleteUserMigrationsmigrationIdArchive(args.next()?),
+  "DeleteUserMigrationsmigrationIdReposrepoNameLock" => EndPoints::DeleteUserMigrationsmigrationIdReposrepoNameLock(args.next()?,args.next()?),
+  "GetUserMigrationsmigrationIdRepositories" => EndPoints::GetUserMigrationsmigrationIdRepositories(args.next()?),
+  "GetUserOrgs" => EndPoints::GetUserOrgs(),
+  "GetUserPackages" => EndPoints::GetUserPackages(),
+  "GetUserPackagespackageTypepackageName" => EndPoints::GetUserPackagespackageTypepackageName(args.next()?,args.next()?),
+  "DeleteUserPackagespackageTypepackageName" => EndPoints::DeleteUserPackagespackageTypepackageName(args.next()?,args.next()?),
+  "PostUserPackagespackageTypepackageNameRestore" => EndPoints::PostUserPackagespackageTypepackageNameRestore(args.next()?,args.next()?),
+  "GetUserPackagespackageTypepackageNameVersions" => EndPoints::GetUserPackagespackageTypepackageNameVersions(args.next()?,args.next()?),
+  "GetUserPackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::GetUserPackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?),
+  "DeleteUserPackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::DeleteUserPackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?),
+  "PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore" => EndPoints::PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore(args.next()?,args.next()?,args.next()?),
+  "PostUserProjects" => EndPoints::PostUserProjects(),
+  "GetUserPublicEmails" => EndPoints::GetUserPublicEmails(),
+  "GetUserRepos" => EndPoints::GetUserRepos(),
+  "PostUserRepos" => EndPoints::PostUserRepos(),
+  "GetUserRepositoryInvitations" => EndPoints::GetUserRepositoryInvitations(),
+  "PatchUserRepositoryInvitationsinvitationId" => EndPoints::PatchUserRepositoryInvitationsinvitationId(args.next()?),
+  "DeleteUserRepositoryInvitationsinvitationId" => EndPoints::DeleteUserRepositoryInvitationsinvitationId(args.next()?),
+  "GetUserStarred" => EndPoints::GetUserStarred(),
+  "GetUserStarredownerrepo" => EndPoints::GetUserStarredownerrepo(args.next()?,args.next()?),
+  "PutUserStarredownerrepo" => EndPoints::PutUserStarredownerrepo(args.next()?,args.next()?),
+  "DeleteUserStarredownerrepo" => EndPoints::DeleteUserStarredownerrepo(args.next()?,args.next()?),
+  "GetUserSubscriptions" => EndPoints::GetUserSubscriptions(),
+  "GetUserTeams" => EndPoints::GetUserTeams(),
+  "GetUsers" => EndPoints::GetUsers(),
+  "GetUsersusername" => EndPoints::GetUsersusername(args.next()?),
+  "GetUsersusernameEvents" => EndPoints::GetUsersusernameEvents(args.next()?),
+  "GetUsersusernameEventsOrgsorg" => EndPoints::GetUsersusernameEventsOrgsorg(args.next()?,args.next()?),
+  "GetUsersusernameEventsPublic" => EndPoints::GetUsersusernameEventsPublic(args.next()?),
+  "GetUsersusernameFollowers" => EndPoints::GetUsersusernameFollowers(args.next()?),
+  "GetUsersusernameFollowing" => EndPoints::GetUsersusernameFollowing(args.next()?),
+  "GetUsersusernameFollowingtargetUser" => EndPoints::GetUsersusernameFollowingtargetUser(args.next()?,args.next()?),
+  "GetUsersusernameGists" => EndPoints::GetUsersusernameGists(args.next()?),
+  "GetUsersusernameGpgKeys" => EndPoints::GetUsersusernameGpgKeys(args.next()?),
+  "GetUsersusernameHovercard" => EndPoints::GetUsersusernameHovercard(args.next()?),
+  "GetUsersusernameInstallation" => EndPoints::GetUsersusernameInstallation(args.next()?),
+  "GetUsersusernameKeys" => EndPoints::GetUsersusernameKeys(args.next()?),
+  "GetUsersusernameOrgs" => EndPoints::GetUsersusernameOrgs(args.next()?),
+  "GetUsersusernamePackages" => EndPoints::GetUsersusernamePackages(args.next()?),
+  "GetUsersusernamePackagespackageTypepackageName" => EndPoints::GetUsersusernamePackagespackageTypepackageName(args.next()?,args.next()?,args.next()?),
+  "DeleteUsersusernamePackagespackageTypepackageName" => EndPoints::DeleteUsersusernamePackagespackageTypepackageName(args.next()?,args.next()?,args.next()?),
+  "PostUsersusernamePackagespackageTypepackageNameRestore" => EndPoints::PostUsersusernamePackagespackageTypepackageNameRestore(args.next()?,args.next()?,args.next()?),
+  "GetUsersusernamePackagespackageTypepackageNameVersions" => EndPoints::GetUsersusernamePackagespackageTypepackageNameVersions(args.next()?,args.next()?,args.next()?),
+  "GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId" => EndPoints::DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(args.next()?,args.next()?,args.next()?,args.next()?),
+  "PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore" => EndPoints::PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore(args.next()?,args.next()?,args.next()?,args.next()?),
+  "GetUsersusernameProjects" => EndPoints::GetUsersusernameProjects(args.next()?),
+  "GetUsersusernameReceivedEvents" => EndPoints::GetUsersusernameReceivedEvents(args.next()?),
+  "GetUsersusernameReceivedEventsPublic" => EndPoints::GetUsersusernameReceivedEventsPublic(args.next()?),
+  "GetUsersusernameRepos" => EndPoints::GetUsersusernameRepos(args.next()?),
+  "GetUsersusernameSettingsBillingActions" => EndPoints::GetUsersusernameSettingsBillingActions(args.next()?),
+  "GetUsersusernameSettingsBillingPackages" => EndPoints::GetUsersusernameSettingsBillingPackages(args.next()?),
+  "GetUsersusernameSettingsBillingSharedStorage" => EndPoints::GetUsersusernameSettingsBillingSharedStorage(args.next()?),
+  "GetUsersusernameStarred" => EndPoints::GetUsersusernameStarred(args.next()?),
+  "GetUsersusernameSubscriptions" => EndPoints::GetUsersusernameSubscriptions(args.next()?),
+  "GetZen" => EndPoints::GetZen(),
+  _ => return None };
+        if args.next().is_some() {
+            return None;
+        }
+        Some(endpoint)
+    }
     pub fn path(&self) -> String {
         match self { EndPoints::Get() => "/".to_string(),
   EndPoints::GetApp() => "/app".to_string(),
@@ -10062,4 +14253,841 @@ impl EndPoints {
   EndPoints::GetUsersusernameSubscriptions(username) => format!("/users/{username}/subscriptions", username = username),
   EndPoints::GetZen() => "/zen".to_string()}
     }
+    pub fn args(&self) -> Vec<&str> {
+        match self { EndPoints::Get() => vec![],
+  EndPoints::GetApp() => vec![],
+  EndPoints::PostAppManifestscodeConversions(code) => vec![code.as_str()],
+  EndPoints::GetAppHookConfig() => vec![],
+  EndPoints::PatchAppHookConfig() => vec![],
+  EndPoints::GetAppHookDeliveries() => vec![],
+  EndPoints::GetAppHookDeliveriesdeliveryId(delivery_id) => vec![delivery_id.as_str()],
+  EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(delivery_id) => vec![delivery_id.as_str()],
+  EndPoints::GetAppInstallations() => vec![],
+  EndPoints::GetAppInstallationsinstallationId(installation_id) => vec![installation_id.as_str()],
+  EndPoints::DeleteAppInstallationsinstallationId(installation_id) => vec![installation_id.as_str()],
+  EndPoints::PostAppInstallationsinstallationIdAccessTokens(installation_id) => vec![installation_id.as_str()],
+  EndPoints::PutAppInstallationsinstallationIdSuspended(installation_id) => vec![installation_id.as_str()],
+  EndPoints::DeleteAppInstallationsinstallationIdSuspended(installation_id) => vec![installation_id.as_str()],
+  EndPoints::GetApplicationsGrants() => vec![],
+  EndPoints::GetApplicationsGrantsgrantId(grant_id) => vec![grant_id.as_str()],
+  EndPoints::DeleteApplicationsGrantsgrantId(grant_id) => vec![grant_id.as_str()],
+  EndPoints::DeleteApplicationsclientIdGrant(client_id) => vec![client_id.as_str()],
+  EndPoints::PostApplicationsclientIdToken(client_id) => vec![client_id.as_str()],
+  EndPoints::PatchApplicationsclientIdToken(client_id) => vec![client_id.as_str()],
+  EndPoints::DeleteApplicationsclientIdToken(client_id) => vec![client_id.as_str()],
+  EndPoints::PostApplicationsclientIdTokenScoped(client_id) => vec![client_id.as_str()],
+  EndPoints::GetAppsappSlug(app_slug) => vec![app_slug.as_str()],
+  EndPoints::GetAuthorizations() => vec![],
+  EndPoints::PostAuthorizations() => vec![],
+  EndPoints::PutAuthorizationsClientsclientId(client_id) => vec![client_id.as_str()],
+  EndPoints::PutAuthorizationsClientsclientIdfingerprint(client_id,fingerprint) => vec![client_id.as_str(), fingerprint.as_str()],
+  EndPoints::GetAuthorizationsauthorizationId(authorization_id) => vec![authorization_id.as_str()],
+  EndPoints::PatchAuthorizationsauthorizationId(authorization_id) => vec![authorization_id.as_str()],
+  EndPoints::DeleteAuthorizationsauthorizationId(authorization_id) => vec![authorization_id.as_str()],
+  EndPoints::GetCodesOfConduct() => vec![],
+  EndPoints::GetCodesOfConductkey(key) => vec![key.as_str()],
+  EndPoints::GetEmojis() => vec![],
+  EndPoints::GetEnterprisesenterpriseActionsPermissions(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsPermissions(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsOrganizations(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizations(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsOrganizationsorgId(enterprise,org_id) => vec![enterprise.as_str(), org_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsPermissionsOrganizationsorgId(enterprise,org_id) => vec![enterprise.as_str(), org_id.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsPermissionsSelectedActions(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsPermissionsSelectedActions(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroups(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PostEnterprisesenterpriseActionsRunnerGroups(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::PatchEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupId(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizations(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(enterprise,runner_group_id,org_id) => vec![enterprise.as_str(), runner_group_id.as_str(), org_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdOrganizationsorgId(enterprise,runner_group_id,org_id) => vec![enterprise.as_str(), runner_group_id.as_str(), org_id.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunners(enterprise,runner_group_id) => vec![enterprise.as_str(), runner_group_id.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(enterprise,runner_group_id,runner_id) => vec![enterprise.as_str(), runner_group_id.as_str(), runner_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(enterprise,runner_group_id,runner_id) => vec![enterprise.as_str(), runner_group_id.as_str(), runner_id.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunners(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnersDownloads(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerId(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerId(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::GetEnterprisesenterpriseActionsRunnersrunnerIdLabels(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::PutEnterprisesenterpriseActionsRunnersrunnerIdLabels(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabels(enterprise,runner_id) => vec![enterprise.as_str(), runner_id.as_str()],
+  EndPoints::DeleteEnterprisesenterpriseActionsRunnersrunnerIdLabelsname(enterprise,runner_id,name) => vec![enterprise.as_str(), runner_id.as_str(), name.as_str()],
+  EndPoints::GetEnterprisesenterpriseAuditLog(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseSecretScanningAlerts(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseSettingsBillingActions(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseSettingsBillingAdvancedSecurity(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseSettingsBillingPackages(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEnterprisesenterpriseSettingsBillingSharedStorage(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetEvents() => vec![],
+  EndPoints::GetFeeds() => vec![],
+  EndPoints::GetGists() => vec![],
+  EndPoints::PostGists() => vec![],
+  EndPoints::GetGistsPublic() => vec![],
+  EndPoints::GetGistsStarred() => vec![],
+  EndPoints::GetGistsgistId(gist_id) => vec![gist_id.as_str()],
+  EndPoints::PatchGistsgistId(gist_id) => vec![gist_id.as_str()],
+  EndPoints::DeleteGistsgistId(gist_id) => vec![gist_id.as_str()],
+  EndPoints::GetGistsgistIdComments(gist_id) => vec![gist_id.as_str()],
+  EndPoints::PostGistsgistIdComments(gist_id) => vec![gist_id.as_str()],
+  EndPoints::GetGistsgistIdCommentscommentId(gist_id,comment_id) => vec![gist_id.as_str(), comment_id.as_str()],
+  EndPoints::PatchGistsgistIdCommentscommentId(gist_id,comment_id) => vec![gist_id.as_str(), comment_id.as_str()],
+  EndPoints::DeleteGistsgistIdCommentscommentId(gist_id,comment_id) => vec![gist_id.as_str(), comment_id.as_str()],
+  EndPoints::GetGistsgistIdCommits(gist_id) => vec![gist_id.as_str()],
+  EndPoints::GetGistsgistIdForks(gist_id) => vec![gist_id.as_str()],
+  EndPoints::PostGistsgistIdForks(gist_id) => vec![gist_id.as_str()],
+  EndPoints::GetGistsgistIdStar(gist_id) => vec![gist_id.as_str()],
+  EndPoints::PutGistsgistIdStar(gist_id) => vec![gist_id.as_str()],
+  EndPoints::DeleteGistsgistIdStar(gist_id) => vec![gist_id.as_str()],
+  EndPoints::GetGistsgistIdsha(gist_id,sha) => vec![gist_id.as_str(), sha.as_str()],
+  EndPoints::GetGitignoreTemplates() => vec![],
+  EndPoints::GetGitignoreTemplatesname(name) => vec![name.as_str()],
+  EndPoints::GetInstallationRepositories() => vec![],
+  EndPoints::DeleteInstallationToken() => vec![],
+  EndPoints::GetIssues() => vec![],
+  EndPoints::GetLicenses() => vec![],
+  EndPoints::GetLicenseslicense(license) => vec![license.as_str()],
+  EndPoints::PostMarkdown() => vec![],
+  EndPoints::PostMarkdownRaw() => vec![],
+  EndPoints::GetMarketplaceListingAccountsaccountId(account_id) => vec![account_id.as_str()],
+  EndPoints::GetMarketplaceListingPlans() => vec![],
+  EndPoints::GetMarketplaceListingPlansplanIdAccounts(plan_id) => vec![plan_id.as_str()],
+  EndPoints::GetMarketplaceListingStubbedAccountsaccountId(account_id) => vec![account_id.as_str()],
+  EndPoints::GetMarketplaceListingStubbedPlans() => vec![],
+  EndPoints::GetMarketplaceListingStubbedPlansplanIdAccounts(plan_id) => vec![plan_id.as_str()],
+  EndPoints::GetMeta() => vec![],
+  EndPoints::GetNetworksownerrepoEvents(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetNotifications() => vec![],
+  EndPoints::PutNotifications() => vec![],
+  EndPoints::GetNotificationsThreadsthreadId(thread_id) => vec![thread_id.as_str()],
+  EndPoints::PatchNotificationsThreadsthreadId(thread_id) => vec![thread_id.as_str()],
+  EndPoints::GetNotificationsThreadsthreadIdSubscription(thread_id) => vec![thread_id.as_str()],
+  EndPoints::PutNotificationsThreadsthreadIdSubscription(thread_id) => vec![thread_id.as_str()],
+  EndPoints::DeleteNotificationsThreadsthreadIdSubscription(thread_id) => vec![thread_id.as_str()],
+  EndPoints::GetOctocat() => vec![],
+  EndPoints::GetOrganizations() => vec![],
+  EndPoints::GetOrganizationsorganizationIdCustomRoles(organization_id) => vec![organization_id.as_str()],
+  EndPoints::GetOrganizationsorgTeamteamSlugExternalGroups(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorg(org) => vec![org.as_str()],
+  EndPoints::PatchOrgsorg(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsPermissions(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgActionsPermissions(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsPermissionsRepositories(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgActionsPermissionsRepositories(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgActionsPermissionsRepositoriesrepositoryId(org,repository_id) => vec![org.as_str(), repository_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsPermissionsRepositoriesrepositoryId(org,repository_id) => vec![org.as_str(), repository_id.as_str()],
+  EndPoints::GetOrgsorgActionsPermissionsSelectedActions(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgActionsPermissionsSelectedActions(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsPermissionsWorkflow(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgActionsPermissionsWorkflow(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsRunnerGroups(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgActionsRunnerGroups(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupId(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::PatchOrgsorgActionsRunnerGroupsrunnerGroupId(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupId(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositories(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(org,runner_group_id,repository_id) => vec![org.as_str(), runner_group_id.as_str(), repository_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRepositoriesrepositoryId(org,runner_group_id,repository_id) => vec![org.as_str(), runner_group_id.as_str(), repository_id.as_str()],
+  EndPoints::GetOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunners(org,runner_group_id) => vec![org.as_str(), runner_group_id.as_str()],
+  EndPoints::PutOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(org,runner_group_id,runner_id) => vec![org.as_str(), runner_group_id.as_str(), runner_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnerGroupsrunnerGroupIdRunnersrunnerId(org,runner_group_id,runner_id) => vec![org.as_str(), runner_group_id.as_str(), runner_id.as_str()],
+  EndPoints::GetOrgsorgActionsRunners(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsRunnersDownloads(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgActionsRunnersRegistrationToken(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgActionsRunnersRemoveToken(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsRunnersrunnerId(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerId(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::GetOrgsorgActionsRunnersrunnerIdLabels(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::PutOrgsorgActionsRunnersrunnerIdLabels(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabels(org,runner_id) => vec![org.as_str(), runner_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsRunnersrunnerIdLabelsname(org,runner_id,name) => vec![org.as_str(), runner_id.as_str(), name.as_str()],
+  EndPoints::GetOrgsorgActionsSecrets(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsSecretsPublicKey(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgActionsSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgActionsSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::DeleteOrgsorgActionsSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::GetOrgsorgActionsSecretssecretNameRepositories(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositories(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(org,secret_name,repository_id) => vec![org.as_str(), secret_name.as_str(), repository_id.as_str()],
+  EndPoints::DeleteOrgsorgActionsSecretssecretNameRepositoriesrepositoryId(org,secret_name,repository_id) => vec![org.as_str(), secret_name.as_str(), repository_id.as_str()],
+  EndPoints::GetOrgsorgAuditLog(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgBlocks(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgBlocksusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::PutOrgsorgBlocksusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgBlocksusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgCodeScanningAlerts(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgCredentialAuthorizations(org) => vec![org.as_str()],
+  EndPoints::DeleteOrgsorgCredentialAuthorizationscredentialId(org,credential_id) => vec![org.as_str(), credential_id.as_str()],
+  EndPoints::GetOrgsorgDependabotSecrets(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgDependabotSecretsPublicKey(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgDependabotSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgDependabotSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::DeleteOrgsorgDependabotSecretssecretName(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::GetOrgsorgDependabotSecretssecretNameRepositories(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositories(org,secret_name) => vec![org.as_str(), secret_name.as_str()],
+  EndPoints::PutOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(org,secret_name,repository_id) => vec![org.as_str(), secret_name.as_str(), repository_id.as_str()],
+  EndPoints::DeleteOrgsorgDependabotSecretssecretNameRepositoriesrepositoryId(org,secret_name,repository_id) => vec![org.as_str(), secret_name.as_str(), repository_id.as_str()],
+  EndPoints::GetOrgsorgEvents(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgExternalGroupgroupId(org,group_id) => vec![org.as_str(), group_id.as_str()],
+  EndPoints::GetOrgsorgExternalGroups(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgFailedInvitations(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgHooks(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgHooks(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgHookshookId(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::PatchOrgsorgHookshookId(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::DeleteOrgsorgHookshookId(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::GetOrgsorgHookshookIdConfig(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::PatchOrgsorgHookshookIdConfig(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::GetOrgsorgHookshookIdDeliveries(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::GetOrgsorgHookshookIdDeliveriesdeliveryId(org,hook_id,delivery_id) => vec![org.as_str(), hook_id.as_str(), delivery_id.as_str()],
+  EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(org,hook_id,delivery_id) => vec![org.as_str(), hook_id.as_str(), delivery_id.as_str()],
+  EndPoints::PostOrgsorgHookshookIdPings(org,hook_id) => vec![org.as_str(), hook_id.as_str()],
+  EndPoints::GetOrgsorgInstallation(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgInstallations(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgInteractionLimits(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgInteractionLimits(org) => vec![org.as_str()],
+  EndPoints::DeleteOrgsorgInteractionLimits(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgInvitations(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgInvitations(org) => vec![org.as_str()],
+  EndPoints::DeleteOrgsorgInvitationsinvitationId(org,invitation_id) => vec![org.as_str(), invitation_id.as_str()],
+  EndPoints::GetOrgsorgInvitationsinvitationIdTeams(org,invitation_id) => vec![org.as_str(), invitation_id.as_str()],
+  EndPoints::GetOrgsorgIssues(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgMembers(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgMembersusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgMembersusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgMembershipsusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::PutOrgsorgMembershipsusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgMembershipsusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgMigrations(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgMigrations(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgMigrationsmigrationId(org,migration_id) => vec![org.as_str(), migration_id.as_str()],
+  EndPoints::GetOrgsorgMigrationsmigrationIdArchive(org,migration_id) => vec![org.as_str(), migration_id.as_str()],
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdArchive(org,migration_id) => vec![org.as_str(), migration_id.as_str()],
+  EndPoints::DeleteOrgsorgMigrationsmigrationIdReposrepoNameLock(org,migration_id,repo_name) => vec![org.as_str(), migration_id.as_str(), repo_name.as_str()],
+  EndPoints::GetOrgsorgMigrationsmigrationIdRepositories(org,migration_id) => vec![org.as_str(), migration_id.as_str()],
+  EndPoints::GetOrgsorgOutsideCollaborators(org) => vec![org.as_str()],
+  EndPoints::PutOrgsorgOutsideCollaboratorsusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgOutsideCollaboratorsusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgPackages(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgPackagespackageTypepackageName(org,package_type,package_name) => vec![org.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageName(org,package_type,package_name) => vec![org.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameRestore(org,package_type,package_name) => vec![org.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersions(org,package_type,package_name) => vec![org.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::GetOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(org,package_type,package_name,package_version_id) => vec![org.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::DeleteOrgsorgPackagespackageTypepackageNameVersionspackageVersionId(org,package_type,package_name,package_version_id) => vec![org.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::PostOrgsorgPackagespackageTypepackageNameVersionspackageVersionIdRestore(org,package_type,package_name,package_version_id) => vec![org.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::GetOrgsorgProjects(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgProjects(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgPublicMembers(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgPublicMembersusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::PutOrgsorgPublicMembersusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgPublicMembersusername(org,username) => vec![org.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgRepos(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgRepos(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgSecretScanningAlerts(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgSettingsBillingActions(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgSettingsBillingAdvancedSecurity(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgSettingsBillingPackages(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgSettingsBillingSharedStorage(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgTeamSyncGroups(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgTeams(org) => vec![org.as_str()],
+  EndPoints::PostOrgsorgTeams(org) => vec![org.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlug(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::PatchOrgsorgTeamsteamSlug(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlug(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussions(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussions(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumber(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(org,team_slug,discussion_number,comment_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::PatchOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(org,team_slug,discussion_number,comment_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumber(org,team_slug,discussion_number,comment_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(org,team_slug,discussion_number,comment_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactions(org,team_slug,discussion_number,comment_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberCommentscommentNumberReactionsreactionId(org,team_slug,discussion_number,comment_number,reaction_id) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), comment_number.as_str(), reaction_id.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactions(org,team_slug,discussion_number) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugDiscussionsdiscussionNumberReactionsreactionId(org,team_slug,discussion_number,reaction_id) => vec![org.as_str(), team_slug.as_str(), discussion_number.as_str(), reaction_id.as_str()],
+  EndPoints::PatchOrgsorgTeamsteamSlugExternalGroups(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugExternalGroups(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugInvitations(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugMembers(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugMembershipsusername(org,team_slug,username) => vec![org.as_str(), team_slug.as_str(), username.as_str()],
+  EndPoints::PutOrgsorgTeamsteamSlugMembershipsusername(org,team_slug,username) => vec![org.as_str(), team_slug.as_str(), username.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugMembershipsusername(org,team_slug,username) => vec![org.as_str(), team_slug.as_str(), username.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugProjects(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugProjectsprojectId(org,team_slug,project_id) => vec![org.as_str(), team_slug.as_str(), project_id.as_str()],
+  EndPoints::PutOrgsorgTeamsteamSlugProjectsprojectId(org,team_slug,project_id) => vec![org.as_str(), team_slug.as_str(), project_id.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugProjectsprojectId(org,team_slug,project_id) => vec![org.as_str(), team_slug.as_str(), project_id.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugRepos(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugReposownerrepo(org,team_slug,owner,repo) => vec![org.as_str(), team_slug.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::PutOrgsorgTeamsteamSlugReposownerrepo(org,team_slug,owner,repo) => vec![org.as_str(), team_slug.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::DeleteOrgsorgTeamsteamSlugReposownerrepo(org,team_slug,owner,repo) => vec![org.as_str(), team_slug.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugTeamSyncGroupMappings(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::PatchOrgsorgTeamsteamSlugTeamSyncGroupMappings(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetOrgsorgTeamsteamSlugTeams(org,team_slug) => vec![org.as_str(), team_slug.as_str()],
+  EndPoints::GetProjectsColumnsCardscardId(card_id) => vec![card_id.as_str()],
+  EndPoints::PatchProjectsColumnsCardscardId(card_id) => vec![card_id.as_str()],
+  EndPoints::DeleteProjectsColumnsCardscardId(card_id) => vec![card_id.as_str()],
+  EndPoints::PostProjectsColumnsCardscardIdMoves(card_id) => vec![card_id.as_str()],
+  EndPoints::GetProjectsColumnscolumnId(column_id) => vec![column_id.as_str()],
+  EndPoints::PatchProjectsColumnscolumnId(column_id) => vec![column_id.as_str()],
+  EndPoints::DeleteProjectsColumnscolumnId(column_id) => vec![column_id.as_str()],
+  EndPoints::GetProjectsColumnscolumnIdCards(column_id) => vec![column_id.as_str()],
+  EndPoints::PostProjectsColumnscolumnIdCards(column_id) => vec![column_id.as_str()],
+  EndPoints::PostProjectsColumnscolumnIdMoves(column_id) => vec![column_id.as_str()],
+  EndPoints::GetProjectsprojectId(project_id) => vec![project_id.as_str()],
+  EndPoints::PatchProjectsprojectId(project_id) => vec![project_id.as_str()],
+  EndPoints::DeleteProjectsprojectId(project_id) => vec![project_id.as_str()],
+  EndPoints::GetProjectsprojectIdCollaborators(project_id) => vec![project_id.as_str()],
+  EndPoints::PutProjectsprojectIdCollaboratorsusername(project_id,username) => vec![project_id.as_str(), username.as_str()],
+  EndPoints::DeleteProjectsprojectIdCollaboratorsusername(project_id,username) => vec![project_id.as_str(), username.as_str()],
+  EndPoints::GetProjectsprojectIdCollaboratorsusernamePermission(project_id,username) => vec![project_id.as_str(), username.as_str()],
+  EndPoints::GetProjectsprojectIdColumns(project_id) => vec![project_id.as_str()],
+  EndPoints::PostProjectsprojectIdColumns(project_id) => vec![project_id.as_str()],
+  EndPoints::GetRateLimit() => vec![],
+  EndPoints::DeleteReactionsreactionId(reaction_id) => vec![reaction_id.as_str()],
+  EndPoints::GetReposownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsArtifacts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsArtifactsartifactId(owner,repo,artifact_id) => vec![owner.as_str(), repo.as_str(), artifact_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsArtifactsartifactId(owner,repo,artifact_id) => vec![owner.as_str(), repo.as_str(), artifact_id.as_str()],
+  EndPoints::GetReposownerrepoActionsArtifactsartifactIdarchiveFormat(owner,repo,artifact_id,archive_format) => vec![owner.as_str(), repo.as_str(), artifact_id.as_str(), archive_format.as_str()],
+  EndPoints::GetReposownerrepoActionsJobsjobId(owner,repo,job_id) => vec![owner.as_str(), repo.as_str(), job_id.as_str()],
+  EndPoints::GetReposownerrepoActionsJobsjobIdLogs(owner,repo,job_id) => vec![owner.as_str(), repo.as_str(), job_id.as_str()],
+  EndPoints::GetReposownerrepoActionsPermissions(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoActionsPermissions(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsPermissionsSelectedActions(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoActionsPermissionsSelectedActions(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsRunners(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsRunnersDownloads(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoActionsRunnersRemoveToken(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsRunnersrunnerId(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerId(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunnersrunnerIdLabels(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::PutReposownerrepoActionsRunnersrunnerIdLabels(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabels(owner,repo,runner_id) => vec![owner.as_str(), repo.as_str(), runner_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsRunnersrunnerIdLabelsname(owner,repo,runner_id,name) => vec![owner.as_str(), repo.as_str(), runner_id.as_str(), name.as_str()],
+  EndPoints::GetReposownerrepoActionsRuns(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunId(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsRunsrunId(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdApprovals(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::PostReposownerrepoActionsRunsrunIdApprove(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdArtifacts(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumber(owner,repo,run_id,attempt_number) => vec![owner.as_str(), repo.as_str(), run_id.as_str(), attempt_number.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberJobs(owner,repo,run_id,attempt_number) => vec![owner.as_str(), repo.as_str(), run_id.as_str(), attempt_number.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdAttemptsattemptNumberLogs(owner,repo,run_id,attempt_number) => vec![owner.as_str(), repo.as_str(), run_id.as_str(), attempt_number.as_str()],
+  EndPoints::PostReposownerrepoActionsRunsrunIdCancel(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdJobs(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdLogs(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::DeleteReposownerrepoActionsRunsrunIdLogs(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdPendingDeployments(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::PostReposownerrepoActionsRunsrunIdPendingDeployments(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::PostReposownerrepoActionsRunsrunIdRerun(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsRunsrunIdTiming(owner,repo,run_id) => vec![owner.as_str(), repo.as_str(), run_id.as_str()],
+  EndPoints::GetReposownerrepoActionsSecrets(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsSecretsPublicKey(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::PutReposownerrepoActionsSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::DeleteReposownerrepoActionsSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::GetReposownerrepoActionsWorkflows(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowId(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdDisable(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::PostReposownerrepoActionsWorkflowsworkflowIdDispatches(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::PutReposownerrepoActionsWorkflowsworkflowIdEnable(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdRuns(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::GetReposownerrepoActionsWorkflowsworkflowIdTiming(owner,repo,workflow_id) => vec![owner.as_str(), repo.as_str(), workflow_id.as_str()],
+  EndPoints::GetReposownerrepoAssignees(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoAssigneesassignee(owner,repo,assignee) => vec![owner.as_str(), repo.as_str(), assignee.as_str()],
+  EndPoints::GetReposownerrepoAutolinks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoAutolinks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoAutolinksautolinkId(owner,repo,autolink_id) => vec![owner.as_str(), repo.as_str(), autolink_id.as_str()],
+  EndPoints::DeleteReposownerrepoAutolinksautolinkId(owner,repo,autolink_id) => vec![owner.as_str(), repo.as_str(), autolink_id.as_str()],
+  EndPoints::PutReposownerrepoAutomatedSecurityFixes(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoAutomatedSecurityFixes(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoBranches(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranch(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtection(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PutReposownerrepoBranchesbranchProtection(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtection(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionEnforceAdmins(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionEnforceAdmins(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionEnforceAdmins(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredPullRequestReviews(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredSignatures(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredSignatures(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredSignatures(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecks(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PatchReposownerrepoBranchesbranchProtectionRequiredStatusChecks(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecks(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRequiredStatusChecksContexts(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictions(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictions(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsApps(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsApps(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsApps(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsApps(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsTeams(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsTeams(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsTeams(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsTeams(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::GetReposownerrepoBranchesbranchProtectionRestrictionsUsers(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchProtectionRestrictionsUsers(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PutReposownerrepoBranchesbranchProtectionRestrictionsUsers(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::DeleteReposownerrepoBranchesbranchProtectionRestrictionsUsers(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoBranchesbranchRename(owner,repo,branch) => vec![owner.as_str(), repo.as_str(), branch.as_str()],
+  EndPoints::PostReposownerrepoCheckRuns(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCheckRunscheckRunId(owner,repo,check_run_id) => vec![owner.as_str(), repo.as_str(), check_run_id.as_str()],
+  EndPoints::PatchReposownerrepoCheckRunscheckRunId(owner,repo,check_run_id) => vec![owner.as_str(), repo.as_str(), check_run_id.as_str()],
+  EndPoints::GetReposownerrepoCheckRunscheckRunIdAnnotations(owner,repo,check_run_id) => vec![owner.as_str(), repo.as_str(), check_run_id.as_str()],
+  EndPoints::PostReposownerrepoCheckRunscheckRunIdRerequest(owner,repo,check_run_id) => vec![owner.as_str(), repo.as_str(), check_run_id.as_str()],
+  EndPoints::PostReposownerrepoCheckSuites(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoCheckSuitesPreferences(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteId(owner,repo,check_suite_id) => vec![owner.as_str(), repo.as_str(), check_suite_id.as_str()],
+  EndPoints::GetReposownerrepoCheckSuitescheckSuiteIdCheckRuns(owner,repo,check_suite_id) => vec![owner.as_str(), repo.as_str(), check_suite_id.as_str()],
+  EndPoints::PostReposownerrepoCheckSuitescheckSuiteIdRerequest(owner,repo,check_suite_id) => vec![owner.as_str(), repo.as_str(), check_suite_id.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningAlerts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumber(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::PatchReposownerrepoCodeScanningAlertsalertNumber(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningAlertsalertNumberInstances(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningAnalyses(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningAnalysesanalysisId(owner,repo,analysis_id) => vec![owner.as_str(), repo.as_str(), analysis_id.as_str()],
+  EndPoints::DeleteReposownerrepoCodeScanningAnalysesanalysisId(owner,repo,analysis_id) => vec![owner.as_str(), repo.as_str(), analysis_id.as_str()],
+  EndPoints::PostReposownerrepoCodeScanningSarifs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCodeScanningSarifssarifId(owner,repo,sarif_id) => vec![owner.as_str(), repo.as_str(), sarif_id.as_str()],
+  EndPoints::GetReposownerrepoCodespaces(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoCodespaces(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCodespacesMachines(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCollaborators(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCollaboratorsusername(owner,repo,username) => vec![owner.as_str(), repo.as_str(), username.as_str()],
+  EndPoints::PutReposownerrepoCollaboratorsusername(owner,repo,username) => vec![owner.as_str(), repo.as_str(), username.as_str()],
+  EndPoints::DeleteReposownerrepoCollaboratorsusername(owner,repo,username) => vec![owner.as_str(), repo.as_str(), username.as_str()],
+  EndPoints::GetReposownerrepoCollaboratorsusernamePermission(owner,repo,username) => vec![owner.as_str(), repo.as_str(), username.as_str()],
+  EndPoints::GetReposownerrepoComments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PatchReposownerrepoCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::GetReposownerrepoCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PostReposownerrepoCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoCommentscommentIdReactionsreactionId(owner,repo,comment_id,reaction_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str(), reaction_id.as_str()],
+  EndPoints::GetReposownerrepoCommits(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoCommitscommitShaBranchesWhereHead(owner,repo,commit_sha) => vec![owner.as_str(), repo.as_str(), commit_sha.as_str()],
+  EndPoints::GetReposownerrepoCommitscommitShaComments(owner,repo,commit_sha) => vec![owner.as_str(), repo.as_str(), commit_sha.as_str()],
+  EndPoints::PostReposownerrepoCommitscommitShaComments(owner,repo,commit_sha) => vec![owner.as_str(), repo.as_str(), commit_sha.as_str()],
+  EndPoints::GetReposownerrepoCommitscommitShaPulls(owner,repo,commit_sha) => vec![owner.as_str(), repo.as_str(), commit_sha.as_str()],
+  EndPoints::GetReposownerrepoCommitsref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoCommitsrefCheckRuns(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoCommitsrefCheckSuites(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoCommitsrefStatus(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoCommitsrefStatuses(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoCommunityProfile(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoComparebasehead(owner,repo,basehead) => vec![owner.as_str(), repo.as_str(), basehead.as_str()],
+  EndPoints::GetReposownerrepoContentspath(owner,repo,path) => vec![owner.as_str(), repo.as_str(), path.as_str()],
+  EndPoints::PutReposownerrepoContentspath(owner,repo,path) => vec![owner.as_str(), repo.as_str(), path.as_str()],
+  EndPoints::DeleteReposownerrepoContentspath(owner,repo,path) => vec![owner.as_str(), repo.as_str(), path.as_str()],
+  EndPoints::GetReposownerrepoContributors(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoDependabotSecrets(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoDependabotSecretsPublicKey(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoDependabotSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::PutReposownerrepoDependabotSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::DeleteReposownerrepoDependabotSecretssecretName(owner,repo,secret_name) => vec![owner.as_str(), repo.as_str(), secret_name.as_str()],
+  EndPoints::GetReposownerrepoDeployments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoDeployments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoDeploymentsdeploymentId(owner,repo,deployment_id) => vec![owner.as_str(), repo.as_str(), deployment_id.as_str()],
+  EndPoints::DeleteReposownerrepoDeploymentsdeploymentId(owner,repo,deployment_id) => vec![owner.as_str(), repo.as_str(), deployment_id.as_str()],
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatuses(owner,repo,deployment_id) => vec![owner.as_str(), repo.as_str(), deployment_id.as_str()],
+  EndPoints::PostReposownerrepoDeploymentsdeploymentIdStatuses(owner,repo,deployment_id) => vec![owner.as_str(), repo.as_str(), deployment_id.as_str()],
+  EndPoints::GetReposownerrepoDeploymentsdeploymentIdStatusesstatusId(owner,repo,deployment_id,status_id) => vec![owner.as_str(), repo.as_str(), deployment_id.as_str(), status_id.as_str()],
+  EndPoints::PostReposownerrepoDispatches(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoEnvironments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoEnvironmentsenvironmentName(owner,repo,environment_name) => vec![owner.as_str(), repo.as_str(), environment_name.as_str()],
+  EndPoints::PutReposownerrepoEnvironmentsenvironmentName(owner,repo,environment_name) => vec![owner.as_str(), repo.as_str(), environment_name.as_str()],
+  EndPoints::DeleteReposownerrepoEnvironmentsenvironmentName(owner,repo,environment_name) => vec![owner.as_str(), repo.as_str(), environment_name.as_str()],
+  EndPoints::GetReposownerrepoEvents(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoForks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoForks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoGitBlobs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoGitBlobsfileSha(owner,repo,file_sha) => vec![owner.as_str(), repo.as_str(), file_sha.as_str()],
+  EndPoints::PostReposownerrepoGitCommits(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoGitCommitscommitSha(owner,repo,commit_sha) => vec![owner.as_str(), repo.as_str(), commit_sha.as_str()],
+  EndPoints::GetReposownerrepoGitMatchingRefsref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoGitRefref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::PostReposownerrepoGitRefs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoGitRefsref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::DeleteReposownerrepoGitRefsref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::PostReposownerrepoGitTags(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoGitTagstagSha(owner,repo,tag_sha) => vec![owner.as_str(), repo.as_str(), tag_sha.as_str()],
+  EndPoints::PostReposownerrepoGitTrees(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoGitTreestreeSha(owner,repo,tree_sha) => vec![owner.as_str(), repo.as_str(), tree_sha.as_str()],
+  EndPoints::GetReposownerrepoHooks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoHooks(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoHookshookId(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::PatchReposownerrepoHookshookId(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::DeleteReposownerrepoHookshookId(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::GetReposownerrepoHookshookIdConfig(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::PatchReposownerrepoHookshookIdConfig(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::GetReposownerrepoHookshookIdDeliveries(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::GetReposownerrepoHookshookIdDeliveriesdeliveryId(owner,repo,hook_id,delivery_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str(), delivery_id.as_str()],
+  EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(owner,repo,hook_id,delivery_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str(), delivery_id.as_str()],
+  EndPoints::PostReposownerrepoHookshookIdPings(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::PostReposownerrepoHookshookIdTests(owner,repo,hook_id) => vec![owner.as_str(), repo.as_str(), hook_id.as_str()],
+  EndPoints::GetReposownerrepoImport(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoImport(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoImport(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoImport(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoImportAuthors(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoImportAuthorsauthorId(owner,repo,author_id) => vec![owner.as_str(), repo.as_str(), author_id.as_str()],
+  EndPoints::GetReposownerrepoImportLargeFiles(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoImportLfs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoInstallation(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoInteractionLimits(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoInteractionLimits(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoInteractionLimits(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoInvitations(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PatchReposownerrepoInvitationsinvitationId(owner,repo,invitation_id) => vec![owner.as_str(), repo.as_str(), invitation_id.as_str()],
+  EndPoints::DeleteReposownerrepoInvitationsinvitationId(owner,repo,invitation_id) => vec![owner.as_str(), repo.as_str(), invitation_id.as_str()],
+  EndPoints::GetReposownerrepoIssues(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoIssues(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoIssuesComments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoIssuesCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PatchReposownerrepoIssuesCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::GetReposownerrepoIssuesCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PostReposownerrepoIssuesCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesCommentscommentIdReactionsreactionId(owner,repo,comment_id,reaction_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str(), reaction_id.as_str()],
+  EndPoints::GetReposownerrepoIssuesEvents(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoIssuesEventseventId(owner,repo,event_id) => vec![owner.as_str(), repo.as_str(), event_id.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumber(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PatchReposownerrepoIssuesissueNumber(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PostReposownerrepoIssuesissueNumberAssignees(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesissueNumberAssignees(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumberComments(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PostReposownerrepoIssuesissueNumberComments(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumberEvents(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumberLabels(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PostReposownerrepoIssuesissueNumberLabels(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PutReposownerrepoIssuesissueNumberLabels(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(owner,repo,issue_number,name) => vec![owner.as_str(), repo.as_str(), issue_number.as_str(), name.as_str()],
+  EndPoints::PutReposownerrepoIssuesissueNumberLock(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesissueNumberLock(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumberReactions(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::PostReposownerrepoIssuesissueNumberReactions(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::DeleteReposownerrepoIssuesissueNumberReactionsreactionId(owner,repo,issue_number,reaction_id) => vec![owner.as_str(), repo.as_str(), issue_number.as_str(), reaction_id.as_str()],
+  EndPoints::GetReposownerrepoIssuesissueNumberTimeline(owner,repo,issue_number) => vec![owner.as_str(), repo.as_str(), issue_number.as_str()],
+  EndPoints::GetReposownerrepoKeys(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoKeys(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoKeyskeyId(owner,repo,key_id) => vec![owner.as_str(), repo.as_str(), key_id.as_str()],
+  EndPoints::DeleteReposownerrepoKeyskeyId(owner,repo,key_id) => vec![owner.as_str(), repo.as_str(), key_id.as_str()],
+  EndPoints::GetReposownerrepoLabels(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoLabels(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoLabelsname(owner,repo,name) => vec![owner.as_str(), repo.as_str(), name.as_str()],
+  EndPoints::PatchReposownerrepoLabelsname(owner,repo,name) => vec![owner.as_str(), repo.as_str(), name.as_str()],
+  EndPoints::DeleteReposownerrepoLabelsname(owner,repo,name) => vec![owner.as_str(), repo.as_str(), name.as_str()],
+  EndPoints::GetReposownerrepoLanguages(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoLfs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoLfs(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoLicense(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoMergeUpstream(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoMerges(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoMilestones(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoMilestones(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumber(owner,repo,milestone_number) => vec![owner.as_str(), repo.as_str(), milestone_number.as_str()],
+  EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(owner,repo,milestone_number) => vec![owner.as_str(), repo.as_str(), milestone_number.as_str()],
+  EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(owner,repo,milestone_number) => vec![owner.as_str(), repo.as_str(), milestone_number.as_str()],
+  EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(owner,repo,milestone_number) => vec![owner.as_str(), repo.as_str(), milestone_number.as_str()],
+  EndPoints::GetReposownerrepoNotifications(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoNotifications(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPages(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoPages(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoPages(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoPages(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPagesBuilds(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoPagesBuilds(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPagesBuildsLatest(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPagesBuildsbuildId(owner,repo,build_id) => vec![owner.as_str(), repo.as_str(), build_id.as_str()],
+  EndPoints::GetReposownerrepoPagesHealth(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoProjects(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoProjects(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPulls(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoPulls(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPullsComments(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoPullsCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PatchReposownerrepoPullsCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoPullsCommentscommentId(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::GetReposownerrepoPullsCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::PostReposownerrepoPullsCommentscommentIdReactions(owner,repo,comment_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str()],
+  EndPoints::DeleteReposownerrepoPullsCommentscommentIdReactionsreactionId(owner,repo,comment_id,reaction_id) => vec![owner.as_str(), repo.as_str(), comment_id.as_str(), reaction_id.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumber(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PatchReposownerrepoPullspullNumber(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberCodespaces(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberComments(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberComments(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(owner,repo,pull_number,comment_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), comment_id.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberCommits(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberFiles(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberMerge(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PutReposownerrepoPullspullNumberMerge(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberRequestedReviewers(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::DeleteReposownerrepoPullspullNumberRequestedReviewers(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberReviews(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberReviews(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::GetReposownerrepoPullspullNumberReviewsreviewIdComments(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(owner,repo,pull_number,review_id) => vec![owner.as_str(), repo.as_str(), pull_number.as_str(), review_id.as_str()],
+  EndPoints::PutReposownerrepoPullspullNumberUpdateBranch(owner,repo,pull_number) => vec![owner.as_str(), repo.as_str(), pull_number.as_str()],
+  EndPoints::GetReposownerrepoReadme(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoReadmedir(owner,repo,dir) => vec![owner.as_str(), repo.as_str(), dir.as_str()],
+  EndPoints::GetReposownerrepoReleases(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoReleases(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoReleasesAssetsassetId(owner,repo,asset_id) => vec![owner.as_str(), repo.as_str(), asset_id.as_str()],
+  EndPoints::PatchReposownerrepoReleasesAssetsassetId(owner,repo,asset_id) => vec![owner.as_str(), repo.as_str(), asset_id.as_str()],
+  EndPoints::DeleteReposownerrepoReleasesAssetsassetId(owner,repo,asset_id) => vec![owner.as_str(), repo.as_str(), asset_id.as_str()],
+  EndPoints::PostReposownerrepoReleasesGenerateNotes(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoReleasesLatest(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoReleasesTagstag(owner,repo,tag) => vec![owner.as_str(), repo.as_str(), tag.as_str()],
+  EndPoints::GetReposownerrepoReleasesreleaseId(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::PatchReposownerrepoReleasesreleaseId(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::DeleteReposownerrepoReleasesreleaseId(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::GetReposownerrepoReleasesreleaseIdAssets(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::PostReposownerrepoReleasesreleaseIdAssets(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::PostReposownerrepoReleasesreleaseIdReactions(owner,repo,release_id) => vec![owner.as_str(), repo.as_str(), release_id.as_str()],
+  EndPoints::GetReposownerrepoSecretScanningAlerts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumber(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::PatchReposownerrepoSecretScanningAlertsalertNumber(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::GetReposownerrepoSecretScanningAlertsalertNumberLocations(owner,repo,alert_number) => vec![owner.as_str(), repo.as_str(), alert_number.as_str()],
+  EndPoints::GetReposownerrepoStargazers(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoStatsCodeFrequency(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoStatsCommitActivity(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoStatsContributors(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoStatsParticipation(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoStatsPunchCard(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoStatusessha(owner,repo,sha) => vec![owner.as_str(), repo.as_str(), sha.as_str()],
+  EndPoints::GetReposownerrepoSubscribers(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoSubscription(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoSubscription(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoSubscription(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTags(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTarballref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::GetReposownerrepoTeams(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTopics(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoTopics(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTrafficClones(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTrafficPopularPaths(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTrafficPopularReferrers(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoTrafficViews(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PostReposownerrepoTransfer(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoVulnerabilityAlerts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutReposownerrepoVulnerabilityAlerts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteReposownerrepoVulnerabilityAlerts(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetReposownerrepoZipballref(owner,repo,aref) => vec![owner.as_str(), repo.as_str(), aref.as_str()],
+  EndPoints::PostRepostemplateOwnertemplateRepoGenerate(template_owner,template_repo) => vec![template_owner.as_str(), template_repo.as_str()],
+  EndPoints::GetRepositories() => vec![],
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecrets(repository_id,environment_name) => vec![repository_id.as_str(), environment_name.as_str()],
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretsPublicKey(repository_id,environment_name) => vec![repository_id.as_str(), environment_name.as_str()],
+  EndPoints::GetRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(repository_id,environment_name,secret_name) => vec![repository_id.as_str(), environment_name.as_str(), secret_name.as_str()],
+  EndPoints::PutRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(repository_id,environment_name,secret_name) => vec![repository_id.as_str(), environment_name.as_str(), secret_name.as_str()],
+  EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(repository_id,environment_name,secret_name) => vec![repository_id.as_str(), environment_name.as_str(), secret_name.as_str()],
+  EndPoints::GetScimV2EnterprisesenterpriseGroups(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PostScimV2EnterprisesenterpriseGroups(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetScimV2EnterprisesenterpriseGroupsscimGroupId(enterprise,scim_group_id) => vec![enterprise.as_str(), scim_group_id.as_str()],
+  EndPoints::PutScimV2EnterprisesenterpriseGroupsscimGroupId(enterprise,scim_group_id) => vec![enterprise.as_str(), scim_group_id.as_str()],
+  EndPoints::PatchScimV2EnterprisesenterpriseGroupsscimGroupId(enterprise,scim_group_id) => vec![enterprise.as_str(), scim_group_id.as_str()],
+  EndPoints::DeleteScimV2EnterprisesenterpriseGroupsscimGroupId(enterprise,scim_group_id) => vec![enterprise.as_str(), scim_group_id.as_str()],
+  EndPoints::GetScimV2EnterprisesenterpriseUsers(enterprise) => vec![enterprise.as_str()],
+  EndPoints::PostScimV2EnterprisesenterpriseUsers(enterprise) => vec![enterprise.as_str()],
+  EndPoints::GetScimV2EnterprisesenterpriseUsersscimUserId(enterprise,scim_user_id) => vec![enterprise.as_str(), scim_user_id.as_str()],
+  EndPoints::PutScimV2EnterprisesenterpriseUsersscimUserId(enterprise,scim_user_id) => vec![enterprise.as_str(), scim_user_id.as_str()],
+  EndPoints::PatchScimV2EnterprisesenterpriseUsersscimUserId(enterprise,scim_user_id) => vec![enterprise.as_str(), scim_user_id.as_str()],
+  EndPoints::DeleteScimV2EnterprisesenterpriseUsersscimUserId(enterprise,scim_user_id) => vec![enterprise.as_str(), scim_user_id.as_str()],
+  EndPoints::GetScimV2OrganizationsorgUsers(org) => vec![org.as_str()],
+  EndPoints::PostScimV2OrganizationsorgUsers(org) => vec![org.as_str()],
+  EndPoints::GetScimV2OrganizationsorgUsersscimUserId(org,scim_user_id) => vec![org.as_str(), scim_user_id.as_str()],
+  EndPoints::PutScimV2OrganizationsorgUsersscimUserId(org,scim_user_id) => vec![org.as_str(), scim_user_id.as_str()],
+  EndPoints::PatchScimV2OrganizationsorgUsersscimUserId(org,scim_user_id) => vec![org.as_str(), scim_user_id.as_str()],
+  EndPoints::DeleteScimV2OrganizationsorgUsersscimUserId(org,scim_user_id) => vec![org.as_str(), scim_user_id.as_str()],
+  EndPoints::GetSearchCode() => vec![],
+  EndPoints::GetSearchCommits() => vec![],
+  EndPoints::GetSearchIssues() => vec![],
+  EndPoints::GetSearchLabels() => vec![],
+  EndPoints::GetSearchRepositories() => vec![],
+  EndPoints::GetSearchTopics() => vec![],
+  EndPoints::GetSearchUsers() => vec![],
+  EndPoints::GetTeamsteamId(team_id) => vec![team_id.as_str()],
+  EndPoints::PatchTeamsteamId(team_id) => vec![team_id.as_str()],
+  EndPoints::DeleteTeamsteamId(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdDiscussions(team_id) => vec![team_id.as_str()],
+  EndPoints::PostTeamsteamIdDiscussions(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumber(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumber(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumber(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberComments(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(team_id,discussion_number,comment_number) => vec![team_id.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::PatchTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(team_id,discussion_number,comment_number) => vec![team_id.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::DeleteTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumber(team_id,discussion_number,comment_number) => vec![team_id.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(team_id,discussion_number,comment_number) => vec![team_id.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberCommentscommentNumberReactions(team_id,discussion_number,comment_number) => vec![team_id.as_str(), discussion_number.as_str(), comment_number.as_str()],
+  EndPoints::GetTeamsteamIdDiscussionsdiscussionNumberReactions(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberReactions(team_id,discussion_number) => vec![team_id.as_str(), discussion_number.as_str()],
+  EndPoints::GetTeamsteamIdInvitations(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdMembers(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdMembersusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::PutTeamsteamIdMembersusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::DeleteTeamsteamIdMembersusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::GetTeamsteamIdMembershipsusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::PutTeamsteamIdMembershipsusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::DeleteTeamsteamIdMembershipsusername(team_id,username) => vec![team_id.as_str(), username.as_str()],
+  EndPoints::GetTeamsteamIdProjects(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdProjectsprojectId(team_id,project_id) => vec![team_id.as_str(), project_id.as_str()],
+  EndPoints::PutTeamsteamIdProjectsprojectId(team_id,project_id) => vec![team_id.as_str(), project_id.as_str()],
+  EndPoints::DeleteTeamsteamIdProjectsprojectId(team_id,project_id) => vec![team_id.as_str(), project_id.as_str()],
+  EndPoints::GetTeamsteamIdRepos(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdReposownerrepo(team_id,owner,repo) => vec![team_id.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::PutTeamsteamIdReposownerrepo(team_id,owner,repo) => vec![team_id.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::DeleteTeamsteamIdReposownerrepo(team_id,owner,repo) => vec![team_id.as_str(), owner.as_str(), repo.as_str()],
+  EndPoints::GetTeamsteamIdTeamSyncGroupMappings(team_id) => vec![team_id.as_str()],
+  EndPoints::PatchTeamsteamIdTeamSyncGroupMappings(team_id) => vec![team_id.as_str()],
+  EndPoints::GetTeamsteamIdTeams(team_id) => vec![team_id.as_str()],
+  EndPoints::GetUser() => vec![],
+  EndPoints::PatchUser() => vec![],
+  EndPoints::GetUserBlocks() => vec![],
+  EndPoints::GetUserBlocksusername(username) => vec![username.as_str()],
+  EndPoints::PutUserBlocksusername(username) => vec![username.as_str()],
+  EndPoints::DeleteUserBlocksusername(username) => vec![username.as_str()],
+  EndPoints::GetUserCodespaces() => vec![],
+  EndPoints::PostUserCodespaces() => vec![],
+  EndPoints::GetUserCodespacesSecrets() => vec![],
+  EndPoints::GetUserCodespacesSecretsPublicKey() => vec![],
+  EndPoints::GetUserCodespacesSecretssecretName(secret_name) => vec![secret_name.as_str()],
+  EndPoints::PutUserCodespacesSecretssecretName(secret_name) => vec![secret_name.as_str()],
+  EndPoints::DeleteUserCodespacesSecretssecretName(secret_name) => vec![secret_name.as_str()],
+  EndPoints::GetUserCodespacesSecretssecretNameRepositories(secret_name) => vec![secret_name.as_str()],
+  EndPoints::PutUserCodespacesSecretssecretNameRepositories(secret_name) => vec![secret_name.as_str()],
+  EndPoints::PutUserCodespacesSecretssecretNameRepositoriesrepositoryId(secret_name,repository_id) => vec![secret_name.as_str(), repository_id.as_str()],
+  EndPoints::DeleteUserCodespacesSecretssecretNameRepositoriesrepositoryId(secret_name,repository_id) => vec![secret_name.as_str(), repository_id.as_str()],
+  EndPoints::GetUserCodespacescodespaceName(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::PatchUserCodespacescodespaceName(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::DeleteUserCodespacescodespaceName(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::PostUserCodespacescodespaceNameExports(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::GetUserCodespacescodespaceNameExportsexportId(codespace_name,export_id) => vec![codespace_name.as_str(), export_id.as_str()],
+  EndPoints::GetUserCodespacescodespaceNameMachines(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::PostUserCodespacescodespaceNameStart(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::PostUserCodespacescodespaceNameStop(codespace_name) => vec![codespace_name.as_str()],
+  EndPoints::PatchUserEmailVisibility() => vec![],
+  EndPoints::GetUserEmails() => vec![],
+  EndPoints::PostUserEmails() => vec![],
+  EndPoints::DeleteUserEmails() => vec![],
+  EndPoints::GetUserFollowers() => vec![],
+  EndPoints::GetUserFollowing() => vec![],
+  EndPoints::GetUserFollowingusername(username) => vec![username.as_str()],
+  EndPoints::PutUserFollowingusername(username) => vec![username.as_str()],
+  EndPoints::DeleteUserFollowingusername(username) => vec![username.as_str()],
+  EndPoints::GetUserGpgKeys() => vec![],
+  EndPoints::PostUserGpgKeys() => vec![],
+  EndPoints::GetUserGpgKeysgpgKeyId(gpg_key_id) => vec![gpg_key_id.as_str()],
+  EndPoints::DeleteUserGpgKeysgpgKeyId(gpg_key_id) => vec![gpg_key_id.as_str()],
+  EndPoints::GetUserInstallations() => vec![],
+  EndPoints::GetUserInstallationsinstallationIdRepositories(installation_id) => vec![installation_id.as_str()],
+  EndPoints::PutUserInstallationsinstallationIdRepositoriesrepositoryId(installation_id,repository_id) => vec![installation_id.as_str(), repository_id.as_str()],
+  EndPoints::DeleteUserInstallationsinstallationIdRepositoriesrepositoryId(installation_id,repository_id) => vec![installation_id.as_str(), repository_id.as_str()],
+  EndPoints::GetUserInteractionLimits() => vec![],
+  EndPoints::PutUserInteractionLimits() => vec![],
+  EndPoints::DeleteUserInteractionLimits() => vec![],
+  EndPoints::GetUserIssues() => vec![],
+  EndPoints::GetUserKeys() => vec![],
+  EndPoints::PostUserKeys() => vec![],
+  EndPoints::GetUserKeyskeyId(key_id) => vec![key_id.as_str()],
+  EndPoints::DeleteUserKeyskeyId(key_id) => vec![key_id.as_str()],
+  EndPoints::GetUserMarketplacePurchases() => vec![],
+  EndPoints::GetUserMarketplacePurchasesStubbed() => vec![],
+  EndPoints::GetUserMembershipsOrgs() => vec![],
+  EndPoints::GetUserMembershipsOrgsorg(org) => vec![org.as_str()],
+  EndPoints::PatchUserMembershipsOrgsorg(org) => vec![org.as_str()],
+  EndPoints::GetUserMigrations() => vec![],
+  EndPoints::PostUserMigrations() => vec![],
+  EndPoints::GetUserMigrationsmigrationId(migration_id) => vec![migration_id.as_str()],
+  EndPoints::GetUserMigrationsmigrationIdArchive(migration_id) => vec![migration_id.as_str()],
+  EndPoints::DeleteUserMigrationsmigrationIdArchive(migration_id) => vec![migration_id.as_str()],
+  EndPoints::DeleteUserMigrationsmigrationIdReposrepoNameLock(migration_id,repo_name) => vec![migration_id.as_str(), repo_name.as_str()],
+  EndPoints::GetUserMigrationsmigrationIdRepositories(migration_id) => vec![migration_id.as_str()],
+  EndPoints::GetUserOrgs() => vec![],
+  EndPoints::GetUserPackages() => vec![],
+  EndPoints::GetUserPackagespackageTypepackageName(package_type,package_name) => vec![package_type.as_str(), package_name.as_str()],
+  EndPoints::DeleteUserPackagespackageTypepackageName(package_type,package_name) => vec![package_type.as_str(), package_name.as_str()],
+  EndPoints::PostUserPackagespackageTypepackageNameRestore(package_type,package_name) => vec![package_type.as_str(), package_name.as_str()],
+  EndPoints::GetUserPackagespackageTypepackageNameVersions(package_type,package_name) => vec![package_type.as_str(), package_name.as_str()],
+  EndPoints::GetUserPackagespackageTypepackageNameVersionspackageVersionId(package_type,package_name,package_version_id) => vec![package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::DeleteUserPackagespackageTypepackageNameVersionspackageVersionId(package_type,package_name,package_version_id) => vec![package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::PostUserPackagespackageTypepackageNameVersionspackageVersionIdRestore(package_type,package_name,package_version_id) => vec![package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::PostUserProjects() => vec![],
+  EndPoints::GetUserPublicEmails() => vec![],
+  EndPoints::GetUserRepos() => vec![],
+  EndPoints::PostUserRepos() => vec![],
+  EndPoints::GetUserRepositoryInvitations() => vec![],
+  EndPoints::PatchUserRepositoryInvitationsinvitationId(invitation_id) => vec![invitation_id.as_str()],
+  EndPoints::DeleteUserRepositoryInvitationsinvitationId(invitation_id) => vec![invitation_id.as_str()],
+  EndPoints::GetUserStarred() => vec![],
+  EndPoints::GetUserStarredownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::PutUserStarredownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::DeleteUserStarredownerrepo(owner,repo) => vec![owner.as_str(), repo.as_str()],
+  EndPoints::GetUserSubscriptions() => vec![],
+  EndPoints::GetUserTeams() => vec![],
+  EndPoints::GetUsers() => vec![],
+  EndPoints::GetUsersusername(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameEvents(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameEventsOrgsorg(username,org) => vec![username.as_str(), org.as_str()],
+  EndPoints::GetUsersusernameEventsPublic(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameFollowers(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameFollowing(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameFollowingtargetUser(username,target_user) => vec![username.as_str(), target_user.as_str()],
+  EndPoints::GetUsersusernameGists(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameGpgKeys(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameHovercard(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameInstallation(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameKeys(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameOrgs(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernamePackages(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernamePackagespackageTypepackageName(username,package_type,package_name) => vec![username.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageName(username,package_type,package_name) => vec![username.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameRestore(username,package_type,package_name) => vec![username.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersions(username,package_type,package_name) => vec![username.as_str(), package_type.as_str(), package_name.as_str()],
+  EndPoints::GetUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(username,package_type,package_name,package_version_id) => vec![username.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::DeleteUsersusernamePackagespackageTypepackageNameVersionspackageVersionId(username,package_type,package_name,package_version_id) => vec![username.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::PostUsersusernamePackagespackageTypepackageNameVersionspackageVersionIdRestore(username,package_type,package_name,package_version_id) => vec![username.as_str(), package_type.as_str(), package_name.as_str(), package_version_id.as_str()],
+  EndPoints::GetUsersusernameProjects(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameReceivedEvents(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameReceivedEventsPublic(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameRepos(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameSettingsBillingActions(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameSettingsBillingPackages(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameSettingsBillingSharedStorage(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameStarred(username) => vec![username.as_str()],
+  EndPoints::GetUsersusernameSubscriptions(username) => vec![username.as_str()],
+  EndPoints::GetZen() => vec![]}
+    }
 }
@@ -0,0 +1,506 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+use crate::end_points::EndPoints;
+use crate::http::Response;
+use crate::policy::Denial;
+use crate::token::Sso;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The transport failed before a response was received.
+    Transport(BoxError),
+    /// GitHub answered with a non-success status.
+    GitHub(Box<GitHubError>),
+    /// A request or response body was not the JSON we expected.
+    Json(serde_json::Error),
+    /// A [`Policy`](crate::policy::Policy) refused the call before it was sent.
+    Policy(Denial),
+}
+
+impl Error {
+    pub fn transport<E: Into<BoxError>>(error: E) -> Self {
+        Error::Transport(error.into())
+    }
+
+    pub fn github(&self) -> Option<&GitHubError> {
+        match self {
+            Error::GitHub(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(e) => write!(f, "transport error: {}", e),
+            Error::GitHub(e) => e.fmt(f),
+            Error::Json(e) => write!(f, "invalid json: {}", e),
+            Error::Policy(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(e) => Some(e.as_ref()),
+            Error::GitHub(e) => Some(e.as_ref()),
+            Error::Json(e) => Some(e),
+            Error::Policy(e) => Some(e),
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
+
+impl From<Denial> for Error {
+    fn from(e: Denial) -> Self {
+        Error::Policy(e)
+    }
+}
+
+impl From<GitHubError> for Error {
+    fn from(e: GitHubError) -> Self {
+        Error::GitHub(Box::new(e))
+    }
+}
+
+/// What every [`GitHubError`] knows about the failed call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorDetails {
+    /// The operation that failed.
+    pub endpoint: EndPoints,
+    pub status: u16,
+    pub message: String,
+    pub documentation_url: Option<String>,
+}
+
+/// A non-2xx response, classified.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitHubError {
+    /// 404, also returned for private resources the token cannot see.
+    NotFound(ErrorDetails),
+    /// 401, missing, bad or expired credentials.
+    Unauthorized(ErrorDetails),
+    /// 403 that is not a primary rate limit.
+    Forbidden {
+        details: ErrorDetails,
+        reason: ForbiddenReason,
+    },
+    /// The primary rate limit is exhausted until `reset`.
+    RateLimited {
+        details: ErrorDetails,
+        reset: Option<SystemTime>,
+    },
+    /// 422 with the offending fields.
+    Validation {
+        details: ErrorDetails,
+        errors: Vec<FieldError>,
+    },
+    /// 409, e.g. a stale `sha` or a merge conflict.
+    Conflict(ErrorDetails),
+    /// 5xx.
+    Server(ErrorDetails),
+    /// Any other status, e.g. 400 or 410.
+    Other(ErrorDetails),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ForbiddenReason {
+    /// The organization enforces SAML SSO and the token is not authorized for it.
+    Sso {
+        url: Option<String>,
+    },
+    /// A secondary rate limit (formerly abuse detection) was hit.
+    Abuse {
+        retry_after: Option<Duration>,
+    },
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct FieldError {
+    pub resource: Option<String>,
+    pub field: Option<String>,
+    /// [`FieldErrorCode::Unknown`] when GitHub leaves it out.
+    #[serde(default)]
+    pub code: FieldErrorCode,
+    /// Set for `custom` errors.
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldErrorCode {
+    Missing,
+    MissingField,
+    Invalid,
+    AlreadyExists,
+    Unprocessable,
+    Custom,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    message: String,
+    documentation_url: Option<String>,
+    #[serde(default)]
+    errors: Vec<RawFieldError>,
+}
+
+/// Some endpoints put bare strings in `errors`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawFieldError {
+    Field(FieldError),
+    Message(String),
+}
+
+impl GitHubError {
+    /// Classifies `response`, `None` when it was successful.
+    pub fn from_response(endpoint: &EndPoints, response: &Response) -> Option<Self> {
+        if response.is_success() || response.status == 304 {
+            return None;
+        }
+        let body = serde_json::from_slice::<ErrorBody>(&response.body).ok();
+        let (message, documentation_url, errors) = match body {
+            Some(b) => (b.message, b.documentation_url, b.errors),
+            None => (
+                String::from_utf8_lossy(&response.body).into_owned(),
+                None,
+                Vec::new(),
+            ),
+        };
+        let details = ErrorDetails {
+            endpoint: endpoint.clone(),
+            status: response.status,
+            message,
+            documentation_url,
+        };
+        let headers = &response.headers;
+        let header_secs = |name| headers.get(name).and_then(|v| v.trim().parse::<u64>().ok());
+        Some(match response.status {
+            401 => GitHubError::Unauthorized(details),
+            403 | 429 if header_secs("x-ratelimit-remaining") == Some(0) => {
+                GitHubError::RateLimited {
+                    details,
+                    reset: header_secs("x-ratelimit-reset")
+                        .map(|s| UNIX_EPOCH + Duration::from_secs(s)),
+                }
+            }
+            403 | 429 => {
+                let retry_after = header_secs("retry-after").map(Duration::from_secs);
+                let lower = details.message.to_ascii_lowercase();
+                let reason = if response.status == 429
+                    || retry_after.is_some()
+                    || lower.contains("secondary rate limit")
+                    || lower.contains("abuse")
+                {
+                    ForbiddenReason::Abuse { retry_after }
+                } else if let Some(Sso::Required { url }) =
+                    headers.get("x-github-sso").and_then(Sso::parse)
+                {
+                    ForbiddenReason::Sso { url }
+                } else {
+                    ForbiddenReason::Other
+                };
+                GitHubError::Forbidden { details, reason }
+            }
+            404 => GitHubError::NotFound(details),
+            409 => GitHubError::Conflict(details),
+            422 => GitHubError::Validation {
+                details,
+                errors: errors
+                    .into_iter()
+                    .map(|e| match e {
+                        RawFieldError::Field(f) => f,
+                        RawFieldError::Message(message) => FieldError {
+                            resource: None,
+                            field: None,
+                            code: FieldErrorCode::Custom,
+                            message: Some(message),
+                        },
+                    })
+                    .collect(),
+            },
+            500..=599 => GitHubError::Server(details),
+            _ => GitHubError::Other(details),
+        })
+    }
+
+    pub fn details(&self) -> &ErrorDetails {
+        match self {
+            GitHubError::NotFound(d)
+            | GitHubError::Unauthorized(d)
+            | GitHubError::Conflict(d)
+            | GitHubError::Server(d)
+            | GitHubError::Other(d) => d,
+            GitHubError::Forbidden { details, .. }
+            | GitHubError::RateLimited { details, .. }
+            | GitHubError::Validation { details, .. } => details,
+        }
+    }
+
+    pub fn endpoint(&self) -> &EndPoints {
+        &self.details().endpoint
+    }
+
+    pub fn status(&self) -> u16 {
+        self.details().status
+    }
+}
+
+impl fmt::Display for GitHubError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = self.details();
+        write!(
+            f,
+            "{} {} ({:?}) failed with {}: {}",
+            d.endpoint.method().as_str(),
+            d.endpoint.path(),
+            d.endpoint,
+            d.status,
+            d.message
+        )?;
+        if let GitHubError::Validation { errors, .. } = self {
+            for e in errors {
+                write!(
+                    f,
+                    "; {}.{} {:?}",
+                    e.resource.as_deref().unwrap_or("?"),
+                    e.field.as_deref().unwrap_or("?"),
+                    e.code
+                )?;
+                if let Some(message) = &e.message {
+                    write!(f, " ({})", message)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for GitHubError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::Headers;
+
+    fn validation(body: &str) -> (ErrorDetails, Vec<FieldError>) {
+        let endpoint = EndPoints::PostReposownerrepoIssues("octocat".into(), "hello".into());
+        let response = Response {
+            status: 422,
+            headers: Headers::new(),
+            body: body.as_bytes().to_vec(),
+        };
+        match GitHubError::from_response(&endpoint, &response) {
+            Some(GitHubError::Validation { details, errors }) => (details, errors),
+            other => panic!("not a validation error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parses_a_real_validation_body() {
+        // As returned by POST /repos/{owner}/{repo}/releases for a taken tag,
+        // plus an entry without a code and a bare string.
+        let (details, errors) = validation(
+            r#"{
+              "message": "Validation Failed",
+              "errors": [
+                { "resource": "Release", "code": "already_exists", "field": "tag_name" },
+                { "resource": "Issue", "field": "title" },
+                { "resource": "Label", "code": "not_a_known_code", "field": "color" },
+                { "resource": "PullRequest", "code": "custom", "message": "No commits between main and main" },
+                "Head sha can't be blank"
+              ],
+              "documentation_url": "https://docs.github.com/rest/releases/releases#create-a-release",
+              "status": "422"
+            }"#,
+        );
+
+        assert_eq!(details.message, "Validation Failed");
+        assert_eq!(
+            details.documentation_url.as_deref(),
+            Some("https://docs.github.com/rest/releases/releases#create-a-release")
+        );
+        let codes: Vec<_> = errors.iter().map(|e| e.code.clone()).collect();
+        assert_eq!(
+            codes,
+            [
+                FieldErrorCode::AlreadyExists,
+                FieldErrorCode::Unknown,
+                FieldErrorCode::Unknown,
+                FieldErrorCode::Custom,
+                FieldErrorCode::Custom,
+            ]
+        );
+        assert_eq!(errors[0].field.as_deref(), Some("tag_name"));
+        assert_eq!(errors[1].resource.as_deref(), Some("Issue"));
+        assert_eq!(
+            errors[3].message.as_deref(),
+            Some("No commits between main and main")
+        );
+        assert_eq!(
+            errors[4].message.as_deref(),
+            Some("Head sha can't be blank")
+        );
+    }
+
+    #[test]
+    fn keeps_non_json_bodies_as_the_message() {
+        let (details, errors) = validation("Unprocessable");
+        assert_eq!(details.message, "Unprocessable");
+        assert!(errors.is_empty());
+    }
+
+    fn classify(status: u16, headers: &[(&str, &str)], body: &str) -> GitHubError {
+        let endpoint = EndPoints::GetReposownerrepo("acme".into(), "widgets".into());
+        let mut response = Response {
+            status,
+            headers: Headers::new(),
+            body: body.as_bytes().to_vec(),
+        };
+        for (name, value) in headers {
+            response.headers.insert(*name, *value);
+        }
+        GitHubError::from_response(&endpoint, &response).unwrap()
+    }
+
+    #[test]
+    fn classifies_not_found_unauthorized_and_server_errors() {
+        let error = classify(
+            404,
+            &[],
+            r#"{"message":"Not Found","documentation_url":"https://docs.github.com/rest"}"#,
+        );
+        assert!(matches!(error, GitHubError::NotFound(_)), "{:?}", error);
+        assert_eq!(error.details().message, "Not Found");
+        assert_eq!(error.status(), 404);
+
+        let error = classify(401, &[], r#"{"message":"Bad credentials"}"#);
+        assert!(matches!(error, GitHubError::Unauthorized(_)), "{:?}", error);
+        for status in [500, 502, 503] {
+            let error = classify(status, &[], "<html>Unicorn!</html>");
+            assert!(matches!(error, GitHubError::Server(_)), "{:?}", error);
+            assert_eq!(error.details().message, "<html>Unicorn!</html>");
+        }
+        assert!(matches!(classify(409, &[], ""), GitHubError::Conflict(_)));
+        assert!(matches!(classify(410, &[], ""), GitHubError::Other(_)));
+    }
+
+    #[test]
+    fn classifies_sso_enforcement() {
+        let error = classify(
+            403,
+            &[(
+                "X-GitHub-SSO",
+                "required; url=https://github.com/orgs/acme/sso?authorization_request=AZSCKtL4U8yX",
+            )],
+            r#"{"message":"Resource protected by organization SAML enforcement. You must grant your Personal Access token access to this organization."}"#,
+        );
+        assert_eq!(
+            error,
+            GitHubError::Forbidden {
+                details: error.details().clone(),
+                reason: ForbiddenReason::Sso {
+                    url: Some(
+                        "https://github.com/orgs/acme/sso?authorization_request=AZSCKtL4U8yX"
+                            .to_string()
+                    )
+                },
+            }
+        );
+        let plain = classify(
+            403,
+            &[],
+            r#"{"message":"Must have admin rights to Repository."}"#,
+        );
+        assert!(matches!(
+            plain,
+            GitHubError::Forbidden {
+                reason: ForbiddenReason::Other,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn classifies_secondary_rate_limits() {
+        let body = r#"{"message":"You have exceeded a secondary rate limit. Please wait a few minutes before you try again.","documentation_url":"https://docs.github.com/rest/overview/rate-limits-for-the-rest-api#about-secondary-rate-limits"}"#;
+
+        let with_retry = classify(403, &[("Retry-After", "60")], body);
+        assert!(matches!(
+            with_retry,
+            GitHubError::Forbidden {
+                reason: ForbiddenReason::Abuse {
+                    retry_after: Some(d)
+                },
+                ..
+            } if d == Duration::from_secs(60)
+        ));
+        let without = classify(403, &[("x-ratelimit-remaining", "4000")], body);
+        assert!(matches!(
+            without,
+            GitHubError::Forbidden {
+                reason: ForbiddenReason::Abuse { retry_after: None },
+                ..
+            }
+        ));
+        // Retry-After alone marks it, whatever the message.
+        assert!(matches!(
+            classify(403, &[("Retry-After", "5")], "{}"),
+            GitHubError::Forbidden {
+                reason: ForbiddenReason::Abuse { .. },
+                ..
+            }
+        ));
+        assert!(matches!(
+            classify(429, &[], "{}"),
+            GitHubError::Forbidden {
+                reason: ForbiddenReason::Abuse { retry_after: None },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn classifies_an_exhausted_primary_limit() {
+        for status in [403, 429] {
+            let error = classify(
+                status,
+                &[
+                    ("x-ratelimit-remaining", "0"),
+                    ("x-ratelimit-reset", "1700000000"),
+                    ("Retry-After", "60"),
+                ],
+                r#"{"message":"API rate limit exceeded for user ID 1."}"#,
+            );
+            match error {
+                GitHubError::RateLimited { details, reset } => {
+                    assert_eq!(details.status, status);
+                    assert_eq!(reset, Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000)));
+                }
+                other => panic!("not rate limited: {:?}", other),
+            }
+        }
+        assert!(matches!(
+            classify(403, &[("x-ratelimit-remaining", "0")], "{}"),
+            GitHubError::RateLimited { reset: None, .. }
+        ));
+    }
+}
@@ -0,0 +1,448 @@
+//! A programmable stand-in for api.github.com on localhost.
+//!
+//! Handlers are registered per [`EndPoints`] variant; incoming paths are routed
+//! back to their variant with [`EndPoints::from_request`].
+//!
+//! ```no_run
+//! use github_api_octocat::end_points::EndPoints;
+//! use github_api_octocat::fake::{json, FakeGitHub};
+//! use serde_json::json;
+//!
+//! let github = FakeGitHub::start().unwrap();
+//! github.on(EndPoints::PostReposownerrepoIssuesissueNumberLabels, |_| {
+//!     json(200, &json!([{ "name": "bug" }]))
+//! });
+//! github
+//!     .expect(EndPoints::PostReposownerrepoIssuesissueNumberLabels)
+//!     .times(1)
+//!     .with_json(json!({ "labels": ["bug"] }));
+//! // ... point the code under test at github.base() ...
+//! github.verify();
+//! ```
+
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::end_points::{EndPoints, Methods};
+use crate::error::Error;
+use crate::http::{ApiBase, Headers, Request, Response, Transport};
+use crate::server::{HttpServer, RawRequest};
+
+pub type Handler = Arc<dyn Fn(&Request) -> Response + Send + Sync>;
+
+/// Something that names an operation: an `EndPoints` variant constructor such as
+/// `EndPoints::GetReposownerrepo`, or the variant name as a string.
+pub trait Operation<Args> {
+    fn operation_name(&self) -> &'static str;
+}
+
+impl Operation<&'static str> for &'static str {
+    fn operation_name(&self) -> &'static str {
+        EndPoints::ROUTES
+            .iter()
+            .find(|(name, _, _)| name == self)
+            .map(|(name, _, _)| *name)
+            .unwrap_or_else(|| panic!("no operation named {}", self))
+    }
+}
+
+macro_rules! string_for {
+    ($arg:ident) => {
+        String
+    };
+}
+
+macro_rules! constructor_operation {
+    ($($arg:ident),*) => {
+        impl<F: Fn($(string_for!($arg)),*) -> EndPoints> Operation<($(string_for!($arg),)*)> for F {
+            fn operation_name(&self) -> &'static str {
+                $(let $arg = String::new();)*
+                self($($arg),*).name()
+            }
+        }
+    };
+}
+
+constructor_operation!();
+constructor_operation!(a);
+constructor_operation!(a, b);
+constructor_operation!(a, b, c);
+constructor_operation!(a, b, c, d);
+constructor_operation!(a, b, c, d, e);
+
+/// A response with a JSON body.
+pub fn json(status: u16, body: &impl Serialize) -> Response {
+    let mut headers = Headers::new();
+    headers.insert("Content-Type", "application/json; charset=utf-8");
+    Response {
+        status,
+        headers,
+        body: serde_json::to_vec(body).expect("serializable body"),
+    }
+}
+
+fn message(status: u16, message: &str) -> Response {
+    json(status, &serde_json::json!({ "message": message }))
+}
+
+struct Expectation {
+    operation: &'static str,
+    times: Option<usize>,
+    body: Option<Value>,
+}
+
+/// Returned by [`FakeGitHub::expect`], checked by [`FakeGitHub::verify`].
+pub struct ExpectationBuilder<'a> {
+    github: &'a FakeGitHub,
+    index: usize,
+}
+
+impl ExpectationBuilder<'_> {
+    /// How many matching calls there must be. Without it, at least one.
+    pub fn times(self, times: usize) -> Self {
+        self.github.shared.expectations.lock().unwrap()[self.index].times = Some(times);
+        self
+    }
+
+    /// Only count calls whose JSON body contains `body`, e.g. `{"labels": ["bug"]}`.
+    pub fn with_json(self, body: Value) -> Self {
+        self.github.shared.expectations.lock().unwrap()[self.index].body = Some(body);
+        self
+    }
+}
+
+#[derive(Default)]
+struct Shared {
+    handlers: Mutex<HashMap<&'static str, Handler>>,
+    calls: Mutex<Vec<Request>>,
+    unhandled: Mutex<Vec<String>>,
+    expectations: Mutex<Vec<Expectation>>,
+}
+
+impl Shared {
+    fn dispatch(&self, request: &Request) -> Response {
+        self.calls.lock().unwrap().push(request.clone());
+        // Cloned out so a handler can register handlers or make calls of its own.
+        let handler = self
+            .handlers
+            .lock()
+            .unwrap()
+            .get(request.endpoint.name())
+            .cloned();
+        match handler {
+            Some(handler) => handler(request),
+            None => {
+                self.unhandled.lock().unwrap().push(format!(
+                    "{} {} ({})",
+                    request.method().as_str(),
+                    request.endpoint.path(),
+                    request.endpoint.name()
+                ));
+                message(501, "no handler registered in FakeGitHub")
+            }
+        }
+    }
+
+    fn dispatch_raw(&self, raw: RawRequest) -> Response {
+        let endpoint = Methods::parse(&raw.method)
+            .and_then(|method| EndPoints::from_request(method, &raw.path));
+        let Some(endpoint) = endpoint else {
+            self.unhandled
+                .lock()
+                .unwrap()
+                .push(format!("{} {} (no such operation)", raw.method, raw.path));
+            return message(404, "Not Found");
+        };
+        self.dispatch(&Request {
+            endpoint,
+            query: raw.query,
+            headers: raw.headers,
+            body: (!raw.body.is_empty()).then_some(raw.body),
+        })
+    }
+}
+
+/// See the [module docs](self).
+pub struct FakeGitHub {
+    shared: Arc<Shared>,
+    server: HttpServer,
+}
+
+impl FakeGitHub {
+    /// Binds an ephemeral port on 127.0.0.1.
+    pub fn start() -> io::Result<Self> {
+        let shared = Arc::new(Shared::default());
+        let routed = shared.clone();
+        let server =
+            HttpServer::bind("127.0.0.1:0", Arc::new(move |raw| routed.dispatch_raw(raw)))?;
+        Ok(FakeGitHub { shared, server })
+    }
+
+    /// Hosts to point a client at. All three are the fake itself.
+    pub fn base(&self) -> ApiBase {
+        let url = format!("http://{}", self.server.addr());
+        ApiBase {
+            api: url.clone(),
+            uploads: url.clone(),
+            web: url,
+        }
+    }
+
+    /// Answers every call to `operation` with `handler`, replacing any earlier one.
+    pub fn on<A>(
+        &self,
+        operation: impl Operation<A>,
+        handler: impl Fn(&Request) -> Response + Send + Sync + 'static,
+    ) -> &Self {
+        self.shared
+            .handlers
+            .lock()
+            .unwrap()
+            .insert(operation.operation_name(), Arc::new(handler));
+        self
+    }
+
+    pub fn expect<A>(&self, operation: impl Operation<A>) -> ExpectationBuilder<'_> {
+        let mut expectations = self.shared.expectations.lock().unwrap();
+        expectations.push(Expectation {
+            operation: operation.operation_name(),
+            times: None,
+            body: None,
+        });
+        ExpectationBuilder {
+            github: self,
+            index: expectations.len() - 1,
+        }
+    }
+
+    /// Every call received so far for `operation`, in order.
+    pub fn calls<A>(&self, operation: impl Operation<A>) -> Vec<Request> {
+        let name = operation.operation_name();
+        self.shared
+            .calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.endpoint.name() == name)
+            .cloned()
+            .collect()
+    }
+
+    /// Panics on unhandled calls and unmet expectations. Also run on drop.
+    pub fn verify(&self) {
+        let mut failures: Vec<String> = self
+            .shared
+            .unhandled
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|call| format!("unhandled call {}", call))
+            .collect();
+        let calls = self.shared.calls.lock().unwrap();
+        for expectation in self.shared.expectations.lock().unwrap().iter() {
+            let matching = calls
+                .iter()
+                .filter(|r| r.endpoint.name() == expectation.operation)
+                .filter(|r| match &expectation.body {
+                    Some(wanted) => r
+                        .body
+                        .as_deref()
+                        .and_then(|b| serde_json::from_slice::<Value>(b).ok())
+                        .is_some_and(|actual| contains(&actual, wanted)),
+                    None => true,
+                })
+                .count();
+            let met = match expectation.times {
+                Some(times) => matching == times,
+                None => matching > 0,
+            };
+            if !met {
+                failures.push(format!(
+                    "expected {} call(s) to {}{}, got {}",
+                    expectation
+                        .times
+                        .map_or_else(|| "at least 1".to_string(), |t| t.to_string()),
+                    expectation.operation,
+                    expectation
+                        .body
+                        .as_ref()
+                        .map_or_else(String::new, |b| format!(" with {}", b)),
+                    matching
+                ));
+            }
+        }
+        // Not poisoned by the panic, so the fake stays usable after it is caught.
+        drop(calls);
+        if !failures.is_empty() {
+            panic!("FakeGitHub:\n  {}", failures.join("\n  "));
+        }
+    }
+}
+
+impl Transport for FakeGitHub {
+    /// Dispatches in-process, without going through the socket.
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        Ok(self.shared.dispatch(request))
+    }
+}
+
+impl Drop for FakeGitHub {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.verify();
+        }
+    }
+}
+
+/// Whether every field of `wanted` is present in `actual`; arrays must be equal.
+fn contains(actual: &Value, wanted: &Value) -> bool {
+    match (actual, wanted) {
+        (Value::Object(actual), Value::Object(wanted)) => wanted
+            .iter()
+            .all(|(k, v)| actual.get(k).is_some_and(|a| contains(a, v))),
+        (actual, wanted) => actual == wanted,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    use serde_json::json;
+
+    use super::*;
+
+    fn s(text: &str) -> String {
+        text.to_string()
+    }
+
+    /// The status line and body of a raw HTTP call to the fake.
+    fn http(github: &FakeGitHub, method: &str, path: &str) -> (String, String) {
+        let addr = github.base().api.trim_start_matches("http://").to_string();
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{} {} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+            method, path
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_string(), body.to_string())
+    }
+
+    #[test]
+    fn routes_paths_to_their_operation() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoIssuesissueNumber, |request| {
+            let EndPoints::GetReposownerrepoIssuesissueNumber(owner, repo, number) =
+                &request.endpoint
+            else {
+                unreachable!()
+            };
+            json(
+                200,
+                &json!({ "owner": owner, "repo": repo, "number": number }),
+            )
+        });
+
+        let (status, body) = http(&github, "GET", "/repos/octocat/hello/issues/3?state=all");
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        let body: Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            body,
+            json!({ "owner": "octocat", "repo": "hello", "number": "3" })
+        );
+        let calls = github.calls(EndPoints::GetReposownerrepoIssuesissueNumber);
+        assert_eq!(calls[0].query, [(s("state"), s("all"))]);
+    }
+
+    #[test]
+    fn answers_unknown_paths_and_unhandled_operations() {
+        let github = FakeGitHub::start().unwrap();
+
+        assert_eq!(
+            http(&github, "GET", "/no/such/thing").0,
+            "HTTP/1.1 404 Not Found"
+        );
+        let (status, _) = http(&github, "GET", "/repos/octocat/hello");
+        assert_eq!(status, "HTTP/1.1 501 Not Implemented");
+
+        let unhandled =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| github.verify())).unwrap_err();
+        let message = unhandled.downcast_ref::<String>().unwrap();
+        assert!(message.contains("unhandled call GET /no/such/thing (no such operation)"));
+        assert!(message.contains("unhandled call GET /repos/octocat/hello (GetReposownerrepo)"));
+        github.shared.unhandled.lock().unwrap().clear();
+    }
+
+    #[test]
+    fn later_handlers_replace_earlier_ones() {
+        let github = FakeGitHub::start().unwrap();
+        github
+            .on(EndPoints::GetZen, |_| json(200, &"first"))
+            .on("GetZen", |_| json(200, &"second"));
+
+        let response = github.send(&Request::new(EndPoints::GetZen())).unwrap();
+        assert_eq!(response.json::<String>().unwrap(), "second");
+    }
+
+    #[test]
+    fn handlers_can_call_back_into_the_fake() {
+        let github = Arc::new(FakeGitHub::start().unwrap());
+        github.on(EndPoints::GetReposownerrepo, |_| {
+            json(200, &json!({ "name": "hello" }))
+        });
+        let inner = Arc::downgrade(&github);
+        github.on(EndPoints::GetReposownerrepoIssues, move |_| {
+            let github = inner.upgrade().unwrap();
+            let repo = Request::new(EndPoints::GetReposownerrepo(s("octocat"), s("hello")));
+            // Registering from inside a handler used to deadlock.
+            github.on(EndPoints::GetZen, |_| json(200, &"zen"));
+            github.send(&repo).unwrap()
+        });
+
+        let issues = Request::new(EndPoints::GetReposownerrepoIssues(s("octocat"), s("hello")));
+        let response = github.send(&issues).unwrap();
+        assert_eq!(response.json::<Value>().unwrap()["name"], "hello");
+        assert_eq!(github.calls(EndPoints::GetReposownerrepo).len(), 1);
+        assert_eq!(
+            github
+                .send(&Request::new(EndPoints::GetZen()))
+                .unwrap()
+                .status,
+            200
+        );
+    }
+
+    #[test]
+    fn expectations_count_matching_bodies() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::PostReposownerrepoIssuesissueNumberLabels, |_| {
+            json(200, &json!([]))
+        });
+        github
+            .expect(EndPoints::PostReposownerrepoIssuesissueNumberLabels)
+            .times(1)
+            .with_json(json!({ "labels": ["bug"] }));
+        let add = |labels: Value| {
+            Request::new(EndPoints::PostReposownerrepoIssuesissueNumberLabels(
+                s("octocat"),
+                s("hello"),
+                s("1"),
+            ))
+            .json(&json!({ "labels": labels, "extra": true }))
+            .unwrap()
+        };
+
+        github.send(&add(json!(["bug"]))).unwrap();
+        github.send(&add(json!(["bug", "docs"]))).unwrap();
+        github.verify();
+    }
+}
@@ -0,0 +1,1103 @@
+//! The Git Data API: ref names, and committing many files at once.
+//!
+//! A [`GitRef`] is a validated ref name that knows which form each endpoint
+//! takes it in. A [`CommitBuilder`] uploads the new contents as blobs, builds a tree on top
+//! of the branch head's tree, commits it and fast-forwards the branch. If the
+//! branch moved in the meantime, the tree and commit are rebuilt on the new
+//! head and the update is tried again, so a change never overwrites someone
+//! else's.
+//!
+//! ```no_run
+//! use github_api_octocat::git::CommitBuilder;
+//! use github_api_octocat::http::Transport;
+//! # fn regenerate(transport: impl Transport) -> Result<(), github_api_octocat::git::CommitError> {
+//! let committed = CommitBuilder::new(transport, "octocat", "hello-world", "main")
+//!     .message("Regenerate clients")
+//!     .write("src/generated/client.rs", "// generated\n")
+//!     .write_executable("scripts/generate.sh", "#!/bin/sh\n")
+//!     .rename("src/old_client.rs", "src/client.rs")
+//!     .delete("src/unused.rs")
+//!     .commit()?;
+//! println!("{} after {} attempts", committed.sha, committed.attempts);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::contents::Person;
+use crate::end_points::EndPoints;
+use crate::error::{Error, GitHubError};
+use crate::http::{encode_path, Request, Transport};
+
+/// A ref name that passes `git check-ref-format`, such as `refs/heads/main`.
+///
+/// The Git refs endpoints want it without the leading `refs/`, creating a ref
+/// wants it in full; the methods building calls pick the right form.
+///
+/// ```
+/// use github_api_octocat::git::GitRef;
+///
+/// let main = GitRef::branch("main").unwrap();
+/// assert_eq!(main.full(), "refs/heads/main");
+/// assert_eq!(main.short(), "heads/main");
+/// assert_eq!(main.name(), "main");
+/// assert_eq!(main.get("octocat", "hello-world").path(), "/repos/octocat/hello-world/git/ref/heads/main");
+///
+/// assert_eq!("heads/main".parse::<GitRef>().unwrap(), main);
+/// assert_eq!("refs/tags/v1.0".parse::<GitRef>().unwrap(), GitRef::tag("v1.0").unwrap());
+/// assert!(GitRef::branch("feature..x").is_err());
+/// assert!(GitRef::branch("wip.lock").is_err());
+/// assert!("main".parse::<GitRef>().is_err(), "a branch or a tag?");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GitRef(String);
+
+impl GitRef {
+    /// `refs/heads/<name>`.
+    pub fn branch(name: &str) -> Result<Self, RefError> {
+        GitRef::full_name(format!("refs/heads/{}", name))
+    }
+
+    /// `refs/tags/<name>`.
+    pub fn tag(name: &str) -> Result<Self, RefError> {
+        GitRef::full_name(format!("refs/tags/{}", name))
+    }
+
+    /// Accepts the full form, `refs/heads/main`, or the form the refs
+    /// endpoints return, `heads/main`. A bare `main` is refused, as it could
+    /// be a branch or a tag.
+    pub fn parse(name: &str) -> Result<Self, RefError> {
+        if name.starts_with("refs/") {
+            return GitRef::full_name(name.to_string());
+        }
+        const SHORT: [&str; 4] = ["heads/", "tags/", "remotes/", "pull/"];
+        if SHORT.iter().any(|prefix| name.starts_with(prefix)) {
+            return GitRef::full_name(format!("refs/{}", name));
+        }
+        Err(RefError::new(
+            name,
+            "expected refs/..., heads/... or tags/...; use GitRef::branch or GitRef::tag for bare names",
+        ))
+    }
+
+    fn full_name(name: String) -> Result<Self, RefError> {
+        check_ref_format(&name)?;
+        Ok(GitRef(name))
+    }
+
+    /// `refs/heads/main`, as `PostReposownerrepoGitRefs` wants it.
+    pub fn full(&self) -> &str {
+        &self.0
+    }
+
+    /// `heads/main`, as the other Git refs endpoints and
+    /// `GetReposownerrepoCommitsref` want it.
+    pub fn short(&self) -> &str {
+        &self.0["refs/".len()..]
+    }
+
+    /// `main` for a branch, `v1.0` for a tag, the short form otherwise.
+    pub fn name(&self) -> &str {
+        self.branch_name()
+            .or_else(|| self.tag_name())
+            .unwrap_or_else(|| self.short())
+    }
+
+    pub fn branch_name(&self) -> Option<&str> {
+        self.0.strip_prefix("refs/heads/")
+    }
+
+    pub fn tag_name(&self) -> Option<&str> {
+        self.0.strip_prefix("refs/tags/")
+    }
+
+    pub fn get(&self, owner: &str, repo: &str) -> EndPoints {
+        EndPoints::GetReposownerrepoGitRefref(owner.to_string(), repo.to_string(), self.arg())
+    }
+
+    /// Lists this ref and every ref it is a prefix of, e.g. `heads/release`
+    /// matches `heads/release-1.0`.
+    pub fn matching(&self, owner: &str, repo: &str) -> EndPoints {
+        EndPoints::GetReposownerrepoGitMatchingRefsref(
+            owner.to_string(),
+            repo.to_string(),
+            self.arg(),
+        )
+    }
+
+    /// Creates the ref pointing at `sha`.
+    pub fn create(&self, owner: &str, repo: &str, sha: &str) -> Result<Request, Error> {
+        Request::new(EndPoints::PostReposownerrepoGitRefs(
+            owner.to_string(),
+            repo.to_string(),
+        ))
+        .json(&json!({ "ref": self.full(), "sha": sha }))
+    }
+
+    /// Moves the ref to `sha`, refusing anything but a fast forward unless `force`.
+    pub fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        force: bool,
+    ) -> Result<Request, Error> {
+        Request::new(EndPoints::PatchReposownerrepoGitRefsref(
+            owner.to_string(),
+            repo.to_string(),
+            self.arg(),
+        ))
+        .json(&json!({ "sha": sha, "force": force }))
+    }
+
+    pub fn delete(&self, owner: &str, repo: &str) -> EndPoints {
+        EndPoints::DeleteReposownerrepoGitRefsref(owner.to_string(), repo.to_string(), self.arg())
+    }
+
+    /// The commit the ref points at.
+    pub fn commit(&self, owner: &str, repo: &str) -> EndPoints {
+        EndPoints::GetReposownerrepoCommitsref(owner.to_string(), repo.to_string(), self.arg())
+    }
+
+    /// The short form, encoded for a path.
+    fn arg(&self) -> String {
+        encode_path(self.short())
+    }
+}
+
+impl std::str::FromStr for GitRef {
+    type Err = RefError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        GitRef::parse(name)
+    }
+}
+
+impl fmt::Display for GitRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A name `git check-ref-format` would refuse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefError {
+    pub name: String,
+    pub reason: &'static str,
+}
+
+impl RefError {
+    fn new(name: &str, reason: &'static str) -> Self {
+        RefError {
+            name: name.to_string(),
+            reason,
+        }
+    }
+}
+
+impl fmt::Display for RefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid ref name {:?}: {}", self.name, self.reason)
+    }
+}
+
+impl std::error::Error for RefError {}
+
+/// The rules of `git check-ref-format`, for a full name.
+fn check_ref_format(name: &str) -> Result<(), RefError> {
+    let fail = |reason| Err(RefError::new(name, reason));
+    if name == "@" {
+        return fail("cannot be @");
+    }
+    if name.ends_with('/') {
+        return fail("cannot end with /");
+    }
+    if name.ends_with('.') {
+        return fail("cannot end with .");
+    }
+    if name.contains("..") {
+        return fail("cannot contain ..");
+    }
+    if name.contains("@{") {
+        return fail("cannot contain @{");
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| c.is_ascii_control() || " ~^:?*[\\".contains(*c))
+    {
+        return fail(match c {
+            ' ' => "cannot contain spaces",
+            c if c.is_ascii_control() => "cannot contain control characters",
+            _ => "cannot contain ~ ^ : ? * [ or \\",
+        });
+    }
+    for component in name.split('/') {
+        if component.is_empty() {
+            return fail("cannot contain empty components");
+        }
+        if component.starts_with('.') {
+            return fail("components cannot start with .");
+        }
+        if component.ends_with(".lock") {
+            return fail("components cannot end with .lock");
+        }
+    }
+    Ok(())
+}
+
+/// The mode of a tree entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mode {
+    File,
+    Executable,
+    /// The blob holds the link target.
+    Symlink,
+}
+
+impl Mode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mode::File => "100644",
+            Mode::Executable => "100755",
+            Mode::Symlink => "120000",
+        }
+    }
+
+    fn parse(mode: &str) -> Option<Self> {
+        match mode {
+            "100644" => Some(Mode::File),
+            "100755" => Some(Mode::Executable),
+            "120000" => Some(Mode::Symlink),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CommitError {
+    Api(Error),
+    InvalidBranch(RefError),
+    /// A rename or mode change names a path that is not a file on the branch.
+    MissingPath(String),
+    /// The branch kept moving; given up after this many attempts.
+    Conflict {
+        attempts: usize,
+    },
+}
+
+impl fmt::Display for CommitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommitError::Api(e) => e.fmt(f),
+            CommitError::InvalidBranch(e) => e.fmt(f),
+            CommitError::MissingPath(path) => write!(f, "{} is not a file on the branch", path),
+            CommitError::Conflict { attempts } => {
+                write!(
+                    f,
+                    "the branch kept moving, gave up after {} attempts",
+                    attempts
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CommitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CommitError::Api(e) => Some(e),
+            CommitError::InvalidBranch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for CommitError {
+    fn from(e: Error) -> Self {
+        CommitError::Api(e)
+    }
+}
+
+impl From<RefError> for CommitError {
+    fn from(e: RefError) -> Self {
+        CommitError::InvalidBranch(e)
+    }
+}
+
+impl From<serde_json::Error> for CommitError {
+    fn from(e: serde_json::Error) -> Self {
+        CommitError::Api(e.into())
+    }
+}
+
+/// What [`CommitBuilder::commit`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Committed {
+    /// The new head of the branch; the old one when nothing changed.
+    pub sha: String,
+    pub tree: String,
+    pub parent: String,
+    /// `false` when the changes left the tree as it was and nothing was committed.
+    pub changed: bool,
+    pub attempts: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Change {
+    Write {
+        path: String,
+        content: Vec<u8>,
+        mode: Mode,
+    },
+    Delete(String),
+    Rename {
+        from: String,
+        to: String,
+    },
+    SetMode {
+        path: String,
+        mode: Mode,
+    },
+}
+
+#[derive(Deserialize)]
+struct Object {
+    sha: String,
+}
+
+#[derive(Deserialize)]
+struct RefResponse {
+    object: Object,
+}
+
+#[derive(Deserialize)]
+struct CommitResponse {
+    tree: Object,
+}
+
+#[derive(Deserialize)]
+struct Tree {
+    tree: Vec<TreeEntry>,
+}
+
+#[derive(Clone, Deserialize)]
+struct TreeEntry {
+    path: String,
+    mode: String,
+    #[serde(rename = "type")]
+    kind: String,
+    sha: String,
+}
+
+/// Tree listings by sha, fetched as lookups need them.
+type Trees = HashMap<String, Vec<TreeEntry>>;
+/// The mode and blob sha each touched path ends up with, `None` when deleted.
+type Entries = BTreeMap<String, Option<(Mode, String)>>;
+
+/// See the [module docs](self).
+#[derive(Debug)]
+pub struct CommitBuilder<T> {
+    transport: T,
+    owner: String,
+    repo: String,
+    branch: String,
+    message: String,
+    author: Option<Person>,
+    committer: Option<Person>,
+    changes: Vec<Change>,
+    max_attempts: usize,
+}
+
+impl<T: Transport> CommitBuilder<T> {
+    /// A commit on top of `branch`, which must exist.
+    pub fn new(
+        transport: T,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        branch: impl Into<String>,
+    ) -> Self {
+        CommitBuilder {
+            transport,
+            owner: owner.into(),
+            repo: repo.into(),
+            branch: branch.into(),
+            message: String::new(),
+            author: None,
+            committer: None,
+            changes: Vec::new(),
+            max_attempts: 3,
+        }
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Defaults to the authenticated user, or to the app's bot.
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(Person {
+            name: name.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    /// Defaults to the author.
+    pub fn committer(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.committer = Some(Person {
+            name: name.into(),
+            email: email.into(),
+        });
+        self
+    }
+
+    /// How often to rebuild the commit when the branch moved. 3 by default.
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Adds or replaces the file at `path`.
+    pub fn write(self, path: impl Into<String>, content: impl AsRef<[u8]>) -> Self {
+        self.write_mode(path, content, Mode::File)
+    }
+
+    pub fn write_executable(self, path: impl Into<String>, content: impl AsRef<[u8]>) -> Self {
+        self.write_mode(path, content, Mode::Executable)
+    }
+
+    /// Makes `path` a symlink to `target`.
+    pub fn symlink(self, path: impl Into<String>, target: impl AsRef<str>) -> Self {
+        self.write_mode(path, target.as_ref(), Mode::Symlink)
+    }
+
+    pub fn write_mode(
+        mut self,
+        path: impl Into<String>,
+        content: impl AsRef<[u8]>,
+        mode: Mode,
+    ) -> Self {
+        self.changes.push(Change::Write {
+            path: path.into(),
+            content: content.as_ref().to_vec(),
+            mode,
+        });
+        self
+    }
+
+    /// Removes `path`; nothing happens if there is no such file.
+    pub fn delete(mut self, path: impl Into<String>) -> Self {
+        self.changes.push(Change::Delete(path.into()));
+        self
+    }
+
+    /// Moves `from` to `to`, keeping its content and mode.
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.changes.push(Change::Rename {
+            from: from.into(),
+            to: to.into(),
+        });
+        self
+    }
+
+    /// Changes the mode of an existing file, e.g. to make it executable.
+    pub fn set_mode(mut self, path: impl Into<String>, mode: Mode) -> Self {
+        self.changes.push(Change::SetMode {
+            path: path.into(),
+            mode,
+        });
+        self
+    }
+
+    /// Commits the changes, in the order they were added, and moves the branch.
+    pub fn commit(&self) -> Result<Committed, CommitError> {
+        let branch = GitRef::branch(&self.branch)?;
+        let blobs = self.upload_blobs()?;
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            let parent = self.head(&branch)?;
+            let commit: CommitResponse =
+                self.get(EndPoints::GetReposownerrepoGitCommitscommitSha(
+                    self.owner.clone(),
+                    self.repo.clone(),
+                    parent.clone(),
+                ))?;
+            let base_tree = commit.tree.sha;
+            let entries = self.tree_entries(&base_tree, &blobs)?;
+            let tree = if entries.is_empty() {
+                base_tree.clone()
+            } else {
+                let body = json!({ "base_tree": base_tree, "tree": entries });
+                let tree: Object = self.post(
+                    EndPoints::PostReposownerrepoGitTrees(self.owner.clone(), self.repo.clone()),
+                    &body,
+                )?;
+                tree.sha
+            };
+            if tree == base_tree {
+                return Ok(Committed {
+                    sha: parent.clone(),
+                    tree,
+                    parent,
+                    changed: false,
+                    attempts,
+                });
+            }
+            let mut body = json!({ "message": self.message, "tree": tree, "parents": [parent] });
+            if let Some(author) = &self.author {
+                body["author"] = json!(author);
+            }
+            if let Some(committer) = &self.committer {
+                body["committer"] = json!(committer);
+            }
+            let created: Object = self.post(
+                EndPoints::PostReposownerrepoGitCommits(self.owner.clone(), self.repo.clone()),
+                &body,
+            )?;
+            let request = branch.update(&self.owner, &self.repo, &created.sha, false)?;
+            match self.transport.execute(&request) {
+                Ok(_) => {
+                    return Ok(Committed {
+                        sha: created.sha,
+                        tree,
+                        parent,
+                        changed: true,
+                        attempts,
+                    })
+                }
+                // Not a fast forward: someone pushed since the head was read.
+                Err(Error::GitHub(e))
+                    if matches!(
+                        *e,
+                        GitHubError::Validation { .. } | GitHubError::Conflict(_)
+                    ) && self.head(&branch)? != parent =>
+                {
+                    if attempts >= self.max_attempts {
+                        return Err(CommitError::Conflict { attempts });
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Uploads the content of every write, once for all attempts.
+    fn upload_blobs(&self) -> Result<HashMap<usize, String>, CommitError> {
+        let mut blobs = HashMap::new();
+        for (i, change) in self.changes.iter().enumerate() {
+            if let Change::Write { content, .. } = change {
+                let body = json!({ "content": STANDARD.encode(content), "encoding": "base64" });
+                let blob: Object = self.post(
+                    EndPoints::PostReposownerrepoGitBlobs(self.owner.clone(), self.repo.clone()),
+                    &body,
+                )?;
+                blobs.insert(i, blob.sha);
+            }
+        }
+        Ok(blobs)
+    }
+
+    /// The entries to lay over `base_tree`; a `null` sha deletes.
+    fn tree_entries(
+        &self,
+        base_tree: &str,
+        blobs: &HashMap<usize, String>,
+    ) -> Result<Vec<Value>, CommitError> {
+        let mut trees = HashMap::new();
+        let mut result: Entries = BTreeMap::new();
+        for (i, change) in self.changes.iter().enumerate() {
+            match change {
+                Change::Write { path, mode, .. } => {
+                    result.insert(path.clone(), Some((*mode, blobs[&i].clone())));
+                }
+                Change::Delete(path) => self.remove(&mut trees, base_tree, &mut result, path)?,
+                Change::Rename { from, to } => {
+                    let entry = self
+                        .current(&mut trees, base_tree, &result, from)?
+                        .ok_or_else(|| CommitError::MissingPath(from.clone()))?;
+                    self.remove(&mut trees, base_tree, &mut result, from)?;
+                    result.insert(to.clone(), Some(entry));
+                }
+                Change::SetMode { path, mode } => {
+                    let (_, sha) = self
+                        .current(&mut trees, base_tree, &result, path)?
+                        .ok_or_else(|| CommitError::MissingPath(path.clone()))?;
+                    result.insert(path.clone(), Some((*mode, sha)));
+                }
+            }
+        }
+        Ok(result
+            .into_iter()
+            .map(|(path, entry)| match entry {
+                Some((mode, sha)) => {
+                    json!({ "path": path, "mode": mode.as_str(), "type": "blob", "sha": sha })
+                }
+                None => json!({ "path": path, "mode": "100644", "type": "blob", "sha": null }),
+            })
+            .collect())
+    }
+
+    /// `path` as earlier changes left it.
+    fn current(
+        &self,
+        trees: &mut Trees,
+        base_tree: &str,
+        result: &Entries,
+        path: &str,
+    ) -> Result<Option<(Mode, String)>, CommitError> {
+        match result.get(path) {
+            Some(entry) => Ok(entry.clone()),
+            None => self.lookup(trees, base_tree, path),
+        }
+    }
+
+    /// Deletes `path` if the base tree has it, or drops an earlier write of it.
+    fn remove(
+        &self,
+        trees: &mut Trees,
+        base_tree: &str,
+        result: &mut Entries,
+        path: &str,
+    ) -> Result<(), CommitError> {
+        if self.lookup(trees, base_tree, path)?.is_some() {
+            result.insert(path.to_string(), None);
+        } else {
+            result.remove(path);
+        }
+        Ok(())
+    }
+
+    /// The mode and blob sha of `path` in `tree`, walking one directory at a time.
+    fn lookup(
+        &self,
+        trees: &mut Trees,
+        tree: &str,
+        path: &str,
+    ) -> Result<Option<(Mode, String)>, CommitError> {
+        let mut tree = tree.to_string();
+        let mut segments = path.split('/').filter(|s| !s.is_empty()).peekable();
+        while let Some(segment) = segments.next() {
+            if !trees.contains_key(&tree) {
+                let listing: Tree = self.get(EndPoints::GetReposownerrepoGitTreestreeSha(
+                    self.owner.clone(),
+                    self.repo.clone(),
+                    tree.clone(),
+                ))?;
+                trees.insert(tree.clone(), listing.tree);
+            }
+            let Some(entry) = trees[&tree].iter().find(|e| e.path == segment).cloned() else {
+                return Ok(None);
+            };
+            match (segments.peek(), entry.kind.as_str()) {
+                (None, "blob") => return Ok(Mode::parse(&entry.mode).map(|m| (m, entry.sha))),
+                (Some(_), "tree") => tree = entry.sha,
+                _ => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+
+    fn head(&self, branch: &GitRef) -> Result<String, CommitError> {
+        let head: RefResponse = self.get(branch.get(&self.owner, &self.repo))?;
+        Ok(head.object.sha)
+    }
+
+    fn get<R: serde::de::DeserializeOwned>(&self, endpoint: EndPoints) -> Result<R, CommitError> {
+        Ok(self.transport.execute(&Request::new(endpoint))?.json()?)
+    }
+
+    fn post<R: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: EndPoints,
+        body: &Value,
+    ) -> Result<R, CommitError> {
+        let request = Request::new(endpoint).json(body)?;
+        Ok(self.transport.execute(&request)?.json()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::fake::{json, FakeGitHub};
+    use crate::http::Response;
+
+    fn blob(path: &str, mode: &str, sha: &str) -> Value {
+        json!({ "path": path, "mode": mode, "type": "blob", "sha": sha })
+    }
+
+    fn tree(path: &str, sha: &str) -> Value {
+        json!({ "path": path, "mode": "040000", "type": "tree", "sha": sha })
+    }
+
+    /// A branch whose head `c1` has the tree `t0`:
+    ///
+    /// ```text
+    /// README.md
+    /// scripts/build.sh
+    /// src/lib.rs
+    /// src/old/mod.rs
+    /// ```
+    fn repository() -> FakeGitHub {
+        let github = FakeGitHub::start().unwrap();
+        github
+            .on(EndPoints::GetReposownerrepoGitRefref, |_| {
+                json(
+                    200,
+                    &json!({ "ref": "refs/heads/main", "object": { "sha": "c1" } }),
+                )
+            })
+            .on(EndPoints::GetReposownerrepoGitCommitscommitSha, |_| {
+                json(200, &json!({ "sha": "c1", "tree": { "sha": "t0" } }))
+            })
+            .on(EndPoints::GetReposownerrepoGitTreestreeSha, |request| {
+                let entries = match request.endpoint.args()[2] {
+                    "t0" => vec![
+                        blob("README.md", "100644", "b-readme"),
+                        tree("scripts", "t-scripts"),
+                        tree("src", "t-src"),
+                    ],
+                    "t-scripts" => vec![blob("build.sh", "100644", "b-build")],
+                    "t-src" => vec![blob("lib.rs", "100644", "b-lib"), tree("old", "t-old")],
+                    "t-old" => vec![blob("mod.rs", "100644", "b-mod")],
+                    _ => return json(404, &json!({ "message": "Not Found" })),
+                };
+                json(
+                    200,
+                    &json!({ "sha": request.endpoint.args()[2], "tree": entries }),
+                )
+            })
+            .on(EndPoints::PostReposownerrepoGitBlobs, |_| {
+                json(201, &json!({ "sha": "b-new" }))
+            })
+            .on(EndPoints::PostReposownerrepoGitTrees, |_| {
+                json(201, &json!({ "sha": "t1" }))
+            })
+            .on(EndPoints::PostReposownerrepoGitCommits, |_| {
+                json(201, &json!({ "sha": "c-new" }))
+            })
+            .on(EndPoints::PatchReposownerrepoGitRefsref, |_| {
+                json(200, &json!({ "object": { "sha": "c-new" } }))
+            });
+        github
+    }
+
+    fn not_a_fast_forward() -> Response {
+        json(
+            422,
+            &json!({
+                "message": "Update is not a fast forward",
+                "documentation_url": "https://docs.github.com/rest/git/refs#update-a-reference",
+            }),
+        )
+    }
+
+    fn reason(name: &str) -> &'static str {
+        check_ref_format(name).unwrap_err().reason
+    }
+
+    #[test]
+    fn accepts_ordinary_ref_names() {
+        for name in [
+            "refs/heads/main",
+            "refs/heads/feature/x-1.2",
+            "refs/tags/v1.0.0",
+            "refs/heads/user@host",
+            "refs/heads/locked.lockfile",
+        ] {
+            assert_eq!(check_ref_format(name), Ok(()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn refuses_control_characters() {
+        assert_eq!(
+            reason("refs/heads/a\tb"),
+            "cannot contain control characters"
+        );
+        assert_eq!(
+            reason("refs/heads/a\u{7f}b"),
+            "cannot contain control characters"
+        );
+        assert_eq!(reason("refs/heads/a b"), "cannot contain spaces");
+        for name in ["a~1", "a^", "a:b", "a?", "a*", "a[b", "a\\b"] {
+            assert_eq!(
+                reason(&format!("refs/heads/{}", name)),
+                "cannot contain ~ ^ : ? * [ or \\",
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn refuses_at_brace_and_a_lone_at() {
+        assert_eq!(reason("refs/heads/main@{1}"), "cannot contain @{");
+        assert_eq!(reason("@"), "cannot be @");
+    }
+
+    #[test]
+    fn refuses_components_ending_in_lock() {
+        assert_eq!(
+            reason("refs/heads/main.lock"),
+            "components cannot end with .lock"
+        );
+        assert_eq!(
+            reason("refs/heads/wip.lock/x"),
+            "components cannot end with .lock"
+        );
+    }
+
+    #[test]
+    fn refuses_components_starting_with_a_dot() {
+        assert_eq!(
+            reason("refs/heads/.hidden"),
+            "components cannot start with ."
+        );
+        assert_eq!(
+            reason("refs/heads/a/.b/c"),
+            "components cannot start with ."
+        );
+        assert_eq!(reason("refs/heads/a..b"), "cannot contain ..");
+        assert_eq!(reason("refs/heads/a."), "cannot end with .");
+    }
+
+    #[test]
+    fn refuses_empty_components() {
+        assert_eq!(
+            reason("refs/heads//main"),
+            "cannot contain empty components"
+        );
+        assert_eq!(
+            reason("/refs/heads/main"),
+            "cannot contain empty components"
+        );
+        assert_eq!(reason("refs/heads/main/"), "cannot end with /");
+    }
+
+    #[test]
+    fn branch_and_tag_names_are_checked_in_full() {
+        let error = GitRef::branch("main.lock").unwrap_err();
+        assert_eq!(error.name, "refs/heads/main.lock");
+        assert_eq!(
+            error.to_string(),
+            "invalid ref name \"refs/heads/main.lock\": components cannot end with .lock"
+        );
+        assert!(GitRef::tag("v1@{0}").is_err());
+        assert_eq!(GitRef::branch("@").unwrap().full(), "refs/heads/@");
+    }
+
+    #[test]
+    fn renames_deletes_and_mode_changes_on_nested_paths() {
+        let github = repository();
+        github
+            .expect(EndPoints::PostReposownerrepoGitTrees)
+            .times(1)
+            .with_json(json!({
+                "base_tree": "t0",
+                "tree": [
+                    { "path": "README.md", "mode": "100644", "type": "blob", "sha": null },
+                    blob("docs/guide.md", "100644", "b-new"),
+                    blob("scripts/build.sh", "100755", "b-build"),
+                    blob("src/new/mod.rs", "100644", "b-mod"),
+                    { "path": "src/old/mod.rs", "mode": "100644", "type": "blob", "sha": null },
+                ],
+            }));
+        github
+            .expect(EndPoints::PostReposownerrepoGitCommits)
+            .times(1)
+            .with_json(json!({
+                "message": "Reorganize",
+                "tree": "t1",
+                "parents": ["c1"],
+                "author": { "name": "Mona", "email": "mona@example.com" },
+            }));
+        github
+            .expect(EndPoints::PatchReposownerrepoGitRefsref)
+            .times(1)
+            .with_json(json!({ "sha": "c-new", "force": false }));
+
+        let committed = CommitBuilder::new(&github, "acme", "api", "main")
+            .message("Reorganize")
+            .author("Mona", "mona@example.com")
+            .rename("src/old/mod.rs", "src/new/mod.rs")
+            .delete("README.md")
+            .set_mode("scripts/build.sh", Mode::Executable)
+            .write("docs/guide.md", "# Guide\n")
+            // Neither ends up in the tree: one never existed, one is written and dropped.
+            .delete("missing.txt")
+            .write("scratch.txt", "tmp")
+            .delete("scratch.txt")
+            .commit()
+            .unwrap();
+
+        assert_eq!(
+            committed,
+            Committed {
+                sha: "c-new".to_string(),
+                tree: "t1".to_string(),
+                parent: "c1".to_string(),
+                changed: true,
+                attempts: 1,
+            }
+        );
+        assert_eq!(
+            github.calls(EndPoints::PatchReposownerrepoGitRefsref)[0]
+                .endpoint
+                .path(),
+            "/repos/acme/api/git/refs/heads/main"
+        );
+        // Every tree on the way is listed once, however many paths go through it.
+        assert_eq!(
+            github
+                .calls(EndPoints::GetReposownerrepoGitTreestreeSha)
+                .len(),
+            4
+        );
+    }
+
+    #[test]
+    fn a_path_that_is_not_a_file_cannot_be_renamed_or_changed() {
+        let github = repository();
+
+        let result = CommitBuilder::new(&github, "acme", "api", "main")
+            .rename("src/old", "src/new")
+            .commit();
+        assert!(matches!(result, Err(CommitError::MissingPath(p)) if p == "src/old"));
+
+        let result = CommitBuilder::new(&github, "acme", "api", "main")
+            .set_mode("src/old/missing.rs", Mode::Executable)
+            .commit();
+        assert!(matches!(result, Err(CommitError::MissingPath(p)) if p == "src/old/missing.rs"));
+        assert!(github
+            .calls(EndPoints::PostReposownerrepoGitTrees)
+            .is_empty());
+    }
+
+    #[test]
+    fn an_unchanged_tree_is_not_committed() {
+        let github = repository();
+        github.on(EndPoints::PostReposownerrepoGitTrees, |_| {
+            json(201, &json!({ "sha": "t0" }))
+        });
+
+        // Rewriting a file with its current content gives the same tree back.
+        let committed = CommitBuilder::new(&github, "acme", "api", "main")
+            .write("src/lib.rs", "// unchanged\n")
+            .commit()
+            .unwrap();
+        assert!(!committed.changed);
+        assert_eq!(committed.sha, "c1");
+        assert_eq!(committed.tree, "t0");
+
+        // With nothing to lay over the base tree, no tree is even created.
+        let committed = CommitBuilder::new(&github, "acme", "api", "main")
+            .delete("missing.txt")
+            .commit()
+            .unwrap();
+        assert!(!committed.changed);
+        assert_eq!(github.calls(EndPoints::PostReposownerrepoGitTrees).len(), 1);
+        assert!(github
+            .calls(EndPoints::PostReposownerrepoGitCommits)
+            .is_empty());
+        assert!(github
+            .calls(EndPoints::PatchReposownerrepoGitRefsref)
+            .is_empty());
+    }
+
+    #[test]
+    fn rebuilds_the_commit_when_the_branch_moved() {
+        let github = repository();
+        // c1 on the first read; c2 once the update has been turned down.
+        let reads = Arc::new(AtomicUsize::new(0));
+        let counted = reads.clone();
+        github.on(EndPoints::GetReposownerrepoGitRefref, move |_| {
+            let head = if counted.fetch_add(1, Ordering::SeqCst) == 0 {
+                "c1"
+            } else {
+                "c2"
+            };
+            json(200, &json!({ "object": { "sha": head } }))
+        });
+        let updates = Arc::new(AtomicUsize::new(0));
+        let counted = updates.clone();
+        github.on(EndPoints::PatchReposownerrepoGitRefsref, move |_| {
+            if counted.fetch_add(1, Ordering::SeqCst) == 0 {
+                not_a_fast_forward()
+            } else {
+                json(200, &json!({ "object": { "sha": "c-new" } }))
+            }
+        });
+
+        let committed = CommitBuilder::new(&github, "acme", "api", "main")
+            .message("Regenerate")
+            .write("src/lib.rs", "// regenerated\n")
+            .commit()
+            .unwrap();
+
+        assert_eq!(committed.attempts, 2);
+        assert_eq!(committed.parent, "c2");
+        let commits = github.calls(EndPoints::PostReposownerrepoGitCommits);
+        assert_eq!(commits.len(), 2);
+        assert_eq!(
+            serde_json::from_slice::<Value>(commits[1].body.as_deref().unwrap()).unwrap()
+                ["parents"],
+            json!(["c2"])
+        );
+        // The blob is uploaded once for all attempts.
+        assert_eq!(github.calls(EndPoints::PostReposownerrepoGitBlobs).len(), 1);
+    }
+
+    #[test]
+    fn gives_up_when_the_branch_keeps_moving() {
+        let github = repository();
+        let reads = Arc::new(AtomicUsize::new(0));
+        let counted = reads.clone();
+        github.on(EndPoints::GetReposownerrepoGitRefref, move |_| {
+            let head = format!("c{}", counted.fetch_add(1, Ordering::SeqCst));
+            json(200, &json!({ "object": { "sha": head } }))
+        });
+        github.on(EndPoints::PatchReposownerrepoGitRefsref, |_| {
+            not_a_fast_forward()
+        });
+
+        let result = CommitBuilder::new(&github, "acme", "api", "main")
+            .max_attempts(2)
+            .write("src/lib.rs", "// regenerated\n")
+            .commit();
+        assert!(matches!(result, Err(CommitError::Conflict { attempts: 2 })));
+        assert_eq!(
+            github.calls(EndPoints::PatchReposownerrepoGitRefsref).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn a_rejected_update_on_an_unmoved_branch_is_not_retried() {
+        let github = repository();
+        github.on(EndPoints::PatchReposownerrepoGitRefsref, |_| {
+            not_a_fast_forward()
+        });
+
+        let result = CommitBuilder::new(&github, "acme", "api", "main")
+            .write("src/lib.rs", "// regenerated\n")
+            .commit();
+        assert!(matches!(result, Err(CommitError::Api(Error::GitHub(_)))));
+        assert_eq!(
+            github.calls(EndPoints::PatchReposownerrepoGitRefsref).len(),
+            1
+        );
+    }
+}
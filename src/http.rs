@@ -0,0 +1,328 @@
+use std::io::Read;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::end_points::{EndPoints, Methods};
+use crate::error::{Error, GitHubError};
+
+impl Methods {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Methods::Get => "GET",
+            Methods::Post => "POST",
+            Methods::Patch => "PATCH",
+            Methods::Delete => "DELETE",
+            Methods::Put => "PUT",
+        }
+    }
+
+    pub fn parse(method: &str) -> Option<Self> {
+        match method.to_ascii_uppercase().as_str() {
+            "GET" => Some(Methods::Get),
+            "POST" => Some(Methods::Post),
+            "PATCH" => Some(Methods::Patch),
+            "DELETE" => Some(Methods::Delete),
+            "PUT" => Some(Methods::Put),
+            _ => None,
+        }
+    }
+}
+
+/// Hosts of a GitHub deployment, either github.com or an Enterprise Server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiBase {
+    /// Base of the REST API, e.g. `https://api.github.com`.
+    pub api: String,
+    /// Base used for release asset uploads.
+    pub uploads: String,
+    /// The web UI, e.g. `https://github.com`.
+    pub web: String,
+}
+
+impl ApiBase {
+    pub fn github() -> Self {
+        ApiBase {
+            api: "https://api.github.com".to_string(),
+            uploads: "https://uploads.github.com".to_string(),
+            web: "https://github.com".to_string(),
+        }
+    }
+
+    /// GitHub Enterprise Server reachable at `https://{host}`.
+    pub fn enterprise(host: &str) -> Self {
+        let host = host.trim_end_matches('/');
+        ApiBase {
+            api: format!("https://{}/api/v3", host),
+            uploads: format!("https://{}/api/uploads", host),
+            web: format!("https://{}", host),
+        }
+    }
+
+    /// Where to send `request`: the uploads host for release assets, the API
+    /// otherwise.
+    pub fn url(&self, request: &Request) -> String {
+        let base = match request.endpoint {
+            EndPoints::PostReposownerrepoReleasesreleaseIdAssets(..) => &self.uploads,
+            _ => &self.api,
+        };
+        let mut url = format!("{}{}", base.trim_end_matches('/'), request.endpoint.path());
+        if !request.query.is_empty() {
+            url.push('?');
+            url.push_str(&encode_query(&request.query));
+        }
+        url
+    }
+}
+
+impl Default for ApiBase {
+    fn default() -> Self {
+        ApiBase::github()
+    }
+}
+
+/// Header list with case-insensitive lookups.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Headers(Vec<(String, String)>);
+
+impl Headers {
+    pub fn new() -> Self {
+        Headers::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Replaces every existing value of `name`.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.remove(&name);
+        self.0.push((name, value.into()));
+    }
+
+    pub fn append(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.push((name.into(), value.into()));
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|(k, _)| !k.eq_ignore_ascii_case(name));
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Headers {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Headers(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub endpoint: EndPoints,
+    pub query: Vec<(String, String)>,
+    pub headers: Headers,
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    pub fn new(endpoint: EndPoints) -> Self {
+        Request {
+            endpoint,
+            query: Vec::new(),
+            headers: Headers::new(),
+            body: None,
+        }
+    }
+
+    pub fn method(&self) -> Methods {
+        self.endpoint.method()
+    }
+
+    pub fn query(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.query.push((key.into(), value.to_string()));
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub fn json<T: Serialize + ?Sized>(mut self, body: &T) -> Result<Self, Error> {
+        self.body = Some(serde_json::to_vec(body)?);
+        self.headers.insert("Content-Type", "application/json");
+        Ok(self)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub headers: Headers,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+
+    /// Turns non-2xx responses into an [`Error::GitHub`] for `endpoint`.
+    pub fn error_for_status(self, endpoint: &EndPoints) -> Result<Self, Error> {
+        match GitHubError::from_response(endpoint, &self) {
+            Some(e) => Err(e.into()),
+            None => Ok(self),
+        }
+    }
+}
+
+/// Anything that can put a [`Request`] on the wire and hand back the [`Response`].
+///
+/// The crate does not ship an HTTP stack; wrap whichever client you already use.
+pub trait Transport {
+    fn send(&self, request: &Request) -> Result<Response, Error>;
+
+    /// Sends `request` and classifies error statuses.
+    fn execute(&self, request: &Request) -> Result<Response, Error> {
+        self.send(request)?.error_for_status(&request.endpoint)
+    }
+
+    /// Sends `request` with the `len` bytes read from `body` instead of
+    /// `request.body`, for release assets too large to hold in memory.
+    ///
+    /// The default reads the body into memory and calls [`Transport::send`];
+    /// transports that can stream a request body should override it.
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        let mut buffer = Vec::with_capacity(usize::try_from(len).unwrap_or_default());
+        body.take(len)
+            .read_to_end(&mut buffer)
+            .map_err(Error::transport)?;
+        let mut request = request.clone();
+        request.body = Some(buffer);
+        self.send(&request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        (**self).send(request)
+    }
+
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        (**self).send_body(request, body, len)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        (**self).send(request)
+    }
+
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        (**self).send_body(request, body, len)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for std::sync::Arc<T> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        (**self).send(request)
+    }
+
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        (**self).send_body(request, body, len)
+    }
+}
+
+pub fn encode_query(query: &[(String, String)]) -> String {
+    query
+        .iter()
+        .map(|(k, v)| format!("{}={}", encode_component(k), encode_component(v)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters.
+pub fn encode_component(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for b in input.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// Percent-encodes each segment of a path parameter such as a file path or a
+/// ref name, keeping the slashes between them.
+pub fn encode_path(path: &str) -> String {
+    path.trim_matches('/')
+        .split('/')
+        .map(encode_component)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Reverses [`encode_component`]; malformed escapes are kept as they are.
+pub fn decode_component(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
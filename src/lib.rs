@@ -1 +1,28 @@
+pub mod app_manifest;
+pub mod cache;
+pub mod cassette;
+pub mod clock;
+pub mod contents;
+pub mod curl;
 pub mod end_points;
+pub mod error;
+pub mod fake;
+pub mod git;
+pub mod http;
+pub mod middleware;
+pub mod models;
+pub mod policy;
+pub mod rate_limit;
+pub mod releases;
+pub mod retry;
+pub mod routing;
+mod server;
+pub mod simulator;
+#[cfg(any(feature = "tracing", feature = "metrics"))]
+pub mod telemetry;
+pub mod throttle;
+pub mod token;
+mod util;
+pub mod webhooks;
+
+pub use error::Error;
@@ -0,0 +1,296 @@
+//! Layers around request execution, for the cross-cutting parts every caller
+//! wants: a User-Agent, audit logs, secret redaction, policy checks.
+//!
+//! A [`Middleware`] sees the typed [`Request`], including its [`EndPoints`](crate::end_points::EndPoints)
+//! variant, and decides whether and how to call the rest of the chain.
+//!
+//! ```no_run
+//! use github_api_octocat::http::{Request, Response, Transport};
+//! use github_api_octocat::middleware::{Chain, Next, UserAgent};
+//! use github_api_octocat::Error;
+//! # fn chain<T: Transport + Send + Sync>(transport: T) {
+//! let transport = Chain::new(transport)
+//!     .layer(UserAgent::new("acme-bot/1.0"))
+//!     .layer(|request: Request, next: Next<'_>| -> Result<Response, Error> {
+//!         let name = request.endpoint.name();
+//!         let response = next.run(request)?;
+//!         eprintln!("{} -> {}", name, response.status);
+//!         Ok(response)
+//!     });
+//! # }
+//! ```
+
+use std::fmt;
+use std::io::Read;
+
+use crate::error::Error;
+use crate::http::{Request, Response, Transport};
+
+pub trait Middleware: Send + Sync {
+    /// Handles `request`, usually by passing it on with [`Next::run`]. Returning
+    /// without calling `next` short-circuits the chain.
+    fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error>;
+}
+
+impl<F> Middleware for F
+where
+    F: Fn(Request, Next<'_>) -> Result<Response, Error> + Send + Sync,
+{
+    fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        self(request, next)
+    }
+}
+
+/// The layers after the current one, ending in the transport.
+pub struct Next<'a> {
+    layers: &'a [Box<dyn Middleware>],
+    transport: &'a dyn Transport,
+    /// A body for [`Transport::send_body`], which layers do not see.
+    body: Option<(&'a mut dyn Read, u64)>,
+}
+
+impl Next<'_> {
+    pub fn run(self, request: Request) -> Result<Response, Error> {
+        match self.layers.split_first() {
+            Some((layer, layers)) => layer.handle(
+                request,
+                Next {
+                    layers,
+                    transport: self.transport,
+                    body: self.body,
+                },
+            ),
+            None => match self.body {
+                Some((body, len)) => self.transport.send_body(&request, body, len),
+                None => self.transport.send(&request),
+            },
+        }
+    }
+}
+
+/// A [`Transport`] that runs every request through its layers, outermost first.
+pub struct Chain<T> {
+    inner: T,
+    layers: Vec<Box<dyn Middleware>>,
+}
+
+impl<T> Chain<T> {
+    pub fn new(inner: T) -> Self {
+        Chain {
+            inner,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Adds `middleware` inside the layers added so far.
+    pub fn layer(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.layers.push(Box::new(middleware));
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Chain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chain")
+            .field("inner", &self.inner)
+            .field("layers", &self.layers.len())
+            .finish()
+    }
+}
+
+impl<T: Transport> Transport for Chain<T> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        Next {
+            layers: &self.layers,
+            transport: &self.inner,
+            body: None,
+        }
+        .run(request.clone())
+    }
+
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        Next {
+            layers: &self.layers,
+            transport: &self.inner,
+            body: Some((body, len)),
+        }
+        .run(request.clone())
+    }
+}
+
+/// Sets `User-Agent` on requests that do not carry one. GitHub rejects requests without it.
+#[derive(Debug, Clone)]
+pub struct UserAgent(String);
+
+impl UserAgent {
+    pub fn new(user_agent: impl Into<String>) -> Self {
+        UserAgent(user_agent.into())
+    }
+}
+
+impl Middleware for UserAgent {
+    fn handle(&self, mut request: Request, next: Next<'_>) -> Result<Response, Error> {
+        if request.headers.get("user-agent").is_none() {
+            request.headers.insert("User-Agent", self.0.as_str());
+        }
+        next.run(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::end_points::EndPoints;
+    use crate::fake::{self, FakeGitHub};
+
+    /// A layer that logs `name` on the way in and out.
+    fn logging(name: &'static str, log: &Arc<Mutex<Vec<String>>>) -> impl Middleware {
+        let log = Arc::clone(log);
+        move |request: Request, next: Next<'_>| -> Result<Response, Error> {
+            log.lock().unwrap().push(format!("{} in", name));
+            let response = next.run(request);
+            log.lock().unwrap().push(format!("{} out", name));
+            response
+        }
+    }
+
+    /// Records what reaches [`Transport::send_body`] and refuses plain sends.
+    #[derive(Default)]
+    struct Streaming(Mutex<Option<(Request, Vec<u8>, u64)>>);
+
+    impl Transport for Streaming {
+        fn send(&self, _: &Request) -> Result<Response, Error> {
+            panic!("the body was buffered instead of streamed");
+        }
+
+        fn send_body(
+            &self,
+            request: &Request,
+            body: &mut dyn Read,
+            len: u64,
+        ) -> Result<Response, Error> {
+            let mut bytes = Vec::new();
+            body.read_to_end(&mut bytes).unwrap();
+            *self.0.lock().unwrap() = Some((request.clone(), bytes, len));
+            Ok(fake::json(201, &json!({})))
+        }
+    }
+
+    #[test]
+    fn layers_run_outermost_first() {
+        let github = FakeGitHub::start().unwrap();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let inner = Arc::clone(&log);
+        github.on(EndPoints::GetZen, move |_| {
+            inner.lock().unwrap().push("transport".to_string());
+            fake::json(200, &json!("Keep it logically awesome."))
+        });
+        let chain = Chain::new(&github)
+            .layer(logging("outer", &log))
+            .layer(logging("inner", &log));
+
+        chain.execute(&Request::new(EndPoints::GetZen())).unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "outer in",
+                "inner in",
+                "transport",
+                "inner out",
+                "outer out"
+            ]
+        );
+    }
+
+    #[test]
+    fn a_layer_can_short_circuit_the_chain() {
+        let github = FakeGitHub::start().unwrap();
+        let chain = Chain::new(&github).layer(|request: Request, _: Next<'_>| {
+            Err(Error::transport(format!(
+                "{} is offline",
+                request.endpoint.name()
+            )))
+        });
+
+        let error = chain.send(&Request::new(EndPoints::GetZen())).unwrap_err();
+        assert_eq!(error.to_string(), "transport error: GetZen is offline");
+        assert!(github.calls(EndPoints::GetZen).is_empty());
+    }
+
+    #[test]
+    fn a_layer_can_rewrite_the_response() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetZen, |_| fake::json(502, &json!({})));
+        let chain = Chain::new(&github).layer(|request: Request, next: Next<'_>| {
+            let mut response = next.run(request)?;
+            if response.status == 502 {
+                response.status = 200;
+                response.body = b"\"Cached wisdom\"".to_vec();
+            }
+            Ok(response)
+        });
+
+        let response = chain.execute(&Request::new(EndPoints::GetZen())).unwrap();
+        assert_eq!(response.json::<String>().unwrap(), "Cached wisdom");
+        assert_eq!(github.calls(EndPoints::GetZen).len(), 1);
+    }
+
+    #[test]
+    fn user_agent_is_set_unless_the_request_has_one() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetZen, |_| fake::json(200, &json!("")));
+        let chain = Chain::new(&github).layer(UserAgent::new("acme-bot/1.0"));
+
+        chain.send(&Request::new(EndPoints::GetZen())).unwrap();
+        chain
+            .send(&Request::new(EndPoints::GetZen()).header("user-agent", "curl/8.0"))
+            .unwrap();
+        let agents: Vec<_> = github
+            .calls(EndPoints::GetZen)
+            .iter()
+            .map(|call| call.headers.get("User-Agent").unwrap().to_string())
+            .collect();
+        assert_eq!(agents, ["acme-bot/1.0", "curl/8.0"]);
+    }
+
+    #[test]
+    fn send_body_streams_through_the_layers() {
+        let transport = Streaming::default();
+        let seen = Arc::new(Mutex::new(None));
+        let layer_seen = Arc::clone(&seen);
+        let chain = Chain::new(&transport)
+            .layer(UserAgent::new("acme-bot/1.0"))
+            .layer(move |request: Request, next: Next<'_>| {
+                *layer_seen.lock().unwrap() = Some(request.body.is_some());
+                next.run(request)
+            });
+        let endpoint = EndPoints::PostReposownerrepoReleasesreleaseIdAssets(
+            "octocat".to_string(),
+            "hello".to_string(),
+            "1".to_string(),
+        );
+
+        let mut body: &[u8] = b"release bytes";
+        let response = chain
+            .send_body(&Request::new(endpoint), &mut body, 13)
+            .unwrap();
+        assert_eq!(response.status, 201);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            Some(false),
+            "layers do not see the body"
+        );
+        let (request, bytes, len) = transport.0.lock().unwrap().take().unwrap();
+        assert_eq!(request.headers.get("user-agent"), Some("acme-bot/1.0"));
+        assert_eq!((bytes.as_slice(), len), (&b"release bytes"[..], 13));
+    }
+}
@@ -0,0 +1,364 @@
+//! The resources GitHub returns, shared by REST responses and webhook payloads.
+//!
+//! Only the commonly used fields are modelled; unknown fields are ignored, so a
+//! [`Response::json`](crate::http::Response::json) into these types keeps working
+//! as GitHub adds more. Timestamps are kept as the ISO 8601 strings GitHub sends.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    pub login: String,
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    #[serde(default)]
+    pub avatar_url: String,
+    #[serde(default)]
+    pub html_url: String,
+    /// `User`, `Organization` or `Bot`.
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    #[serde(default)]
+    pub site_admin: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Repository {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub name: String,
+    /// `owner/name`.
+    pub full_name: String,
+    pub owner: User,
+    #[serde(default)]
+    pub private: bool,
+    #[serde(default)]
+    pub html_url: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub fork: bool,
+    #[serde(default)]
+    pub url: String,
+    pub default_branch: Option<String>,
+    #[serde(default)]
+    pub archived: bool,
+    pub visibility: Option<String>,
+    /// A timestamp in REST responses, but Unix seconds in `push` payloads.
+    pub created_at: Option<serde_json::Value>,
+    pub updated_at: Option<String>,
+    /// Like `created_at`.
+    pub pushed_at: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Label {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub name: String,
+    /// Hex without the `#`, e.g. `d73a4a`.
+    #[serde(default)]
+    pub color: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub default: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum State {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Milestone {
+    pub id: u64,
+    pub number: u64,
+    pub title: String,
+    pub description: Option<String>,
+    pub state: State,
+    #[serde(default)]
+    pub open_issues: u64,
+    #[serde(default)]
+    pub closed_issues: u64,
+    pub due_on: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Issue {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: Option<User>,
+    pub state: State,
+    /// `completed`, `not_planned` or `reopened`.
+    pub state_reason: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<User>,
+    pub milestone: Option<Milestone>,
+    #[serde(default)]
+    pub locked: bool,
+    #[serde(default)]
+    pub comments: u64,
+    /// Present when the issue is a pull request.
+    pub pull_request: Option<serde_json::Value>,
+    #[serde(default)]
+    pub html_url: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub closed_at: Option<String>,
+}
+
+impl Issue {
+    /// GitHub returns pull requests from the issues endpoints too.
+    pub fn is_pull_request(&self) -> bool {
+        self.pull_request.is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub body: Option<String>,
+    pub user: Option<User>,
+    #[serde(default)]
+    pub html_url: String,
+    /// e.g. `OWNER`, `MEMBER`, `CONTRIBUTOR`, `NONE`.
+    pub author_association: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+/// The `head` or `base` of a pull request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Branch {
+    /// `owner:branch`.
+    pub label: Option<String>,
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub sha: String,
+    pub user: Option<User>,
+    /// `None` when the fork was deleted.
+    pub repo: Option<Repository>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PullRequest {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: Option<User>,
+    pub state: State,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub locked: bool,
+    pub head: Branch,
+    pub base: Branch,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    #[serde(default)]
+    pub assignees: Vec<User>,
+    #[serde(default)]
+    pub requested_reviewers: Vec<User>,
+    pub milestone: Option<Milestone>,
+    /// Only set on single-pull responses and webhook payloads.
+    pub merged: Option<bool>,
+    pub mergeable: Option<bool>,
+    pub merge_commit_sha: Option<String>,
+    pub merged_by: Option<User>,
+    #[serde(default)]
+    pub html_url: String,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    pub closed_at: Option<String>,
+    pub merged_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GitActor {
+    pub name: String,
+    pub email: Option<String>,
+    /// Only in `push` payloads.
+    pub username: Option<String>,
+}
+
+/// A commit as listed in a `push` payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PushCommit {
+    pub id: String,
+    pub tree_id: Option<String>,
+    pub message: String,
+    pub timestamp: Option<String>,
+    #[serde(default)]
+    pub url: String,
+    pub author: GitActor,
+    pub committer: GitActor,
+    #[serde(default)]
+    pub distinct: bool,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub name: String,
+    pub head_sha: String,
+    /// `queued`, `in_progress`, `completed`, ...
+    pub status: String,
+    /// `success`, `failure`, `neutral`, ... once completed.
+    pub conclusion: Option<String>,
+    pub external_id: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
+    pub details_url: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    pub app: Option<App>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub name: Option<String>,
+    pub workflow_id: u64,
+    pub run_number: u64,
+    #[serde(default = "first_attempt")]
+    pub run_attempt: u64,
+    /// e.g. `push`, `pull_request`, `workflow_dispatch`.
+    pub event: String,
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    /// `queued`, `in_progress`, `completed`, ...
+    pub status: Option<String>,
+    pub conclusion: Option<String>,
+    #[serde(default)]
+    pub html_url: String,
+    pub actor: Option<User>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+fn first_attempt() -> u64 {
+    1
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct App {
+    pub id: u64,
+    pub slug: Option<String>,
+    pub name: String,
+    pub owner: Option<User>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Installation {
+    pub id: u64,
+    /// The user or organization the app is installed on. Missing from the
+    /// `installation` reference other events carry.
+    pub account: Option<User>,
+    pub app_id: Option<u64>,
+    /// `all` or `selected`.
+    pub repository_selection: Option<String>,
+    #[serde(default)]
+    pub permissions: std::collections::BTreeMap<String, String>,
+    #[serde(default)]
+    pub events: Vec<String>,
+    pub suspended_at: Option<String>,
+}
+
+/// A repository or organization webhook.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: u64,
+    /// Always `web` for webhooks.
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub events: Vec<String>,
+    pub config: WebhookConfig,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: Option<String>,
+    /// `json` or `form`.
+    pub content_type: Option<String>,
+    /// `"0"` or `"1"`; some responses use a number.
+    pub insecure_ssl: Option<serde_json::Value>,
+    /// Masked as `********` in responses when set.
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Release {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub tag_name: String,
+    /// The branch or commit the tag is created from, if it does not exist yet.
+    #[serde(default)]
+    pub target_commitish: String,
+    pub name: Option<String>,
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+    pub author: Option<User>,
+    #[serde(default)]
+    pub assets: Vec<ReleaseAsset>,
+    #[serde(default)]
+    pub html_url: String,
+    /// A URI template, e.g. `https://uploads.github.com/.../assets{?name,label}`.
+    #[serde(default)]
+    pub upload_url: String,
+    pub created_at: Option<String>,
+    /// `None` for drafts.
+    pub published_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseAsset {
+    pub id: u64,
+    #[serde(default)]
+    pub node_id: String,
+    pub name: String,
+    pub label: Option<String>,
+    #[serde(default)]
+    pub content_type: String,
+    /// `uploaded`, or `open` for an upload that never finished.
+    #[serde(default)]
+    pub state: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(default)]
+    pub download_count: u64,
+    #[serde(default)]
+    pub browser_download_url: String,
+    pub uploader: Option<User>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
@@ -0,0 +1,508 @@
+//! Which calls a token may make, checked before anything is sent.
+//!
+//! A [`Policy`] is a default decision plus allow and deny rules. A call is denied
+//! if any deny rule matches it, otherwise allowed if any allow rule matches, and
+//! otherwise gets the default. Install it as a [`Middleware`] so nothing reaches
+//! the transport without being checked.
+//!
+//! ```toml
+//! default = "deny"
+//!
+//! [[allow]]
+//! name = "triage"
+//! tags = ["issues", "reactions"]
+//! repos = ["acme/*"]
+//!
+//! [[deny]]
+//! operations = ["DeleteReposownerrepo", "PutReposownerrepoBranchesbranchProtection"]
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::end_points::{EndPoints, Methods};
+use crate::error::Error;
+use crate::http::{Request, Response};
+use crate::middleware::{Middleware, Next};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Decision {
+    #[default]
+    Allow,
+    Deny,
+}
+
+/// Matches a call when every non-empty criterion does; within one criterion any
+/// entry may match. A rule with no criteria matches everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rule {
+    /// Shown in denials.
+    pub name: Option<String>,
+    /// `EndPoints` variant names, e.g. `DeleteReposownerrepo`.
+    pub operations: Vec<String>,
+    /// API reference tags, e.g. `issues`. See [`EndPoints::tag`].
+    pub tags: Vec<String>,
+    /// HTTP methods, e.g. `GET`.
+    pub methods: Vec<String>,
+    /// `owner/repo` globs where `*` matches any run of characters and `?` one
+    /// character, e.g. `acme/*` or `*/docs-?`. Calls on an organization or owner
+    /// alone match patterns whose repo part is `*`; calls on neither never match.
+    /// While any rule has repos, calls whose owner or repo is not a plain name,
+    /// such as `acme/../other`, are denied. So are calls that name the repository
+    /// or organization by numeric id, like `/repositories/{repository_id}/...`,
+    /// when a deny rule with repos matches them otherwise, since the id cannot be
+    /// checked against a pattern. Calls scoped by something else that belongs to
+    /// an organization, such as a team or project id, are not matched by repos.
+    pub repos: Vec<String>,
+}
+
+impl Rule {
+    pub fn new() -> Self {
+        Rule::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn operation(mut self, operation: impl Into<String>) -> Self {
+        self.operations.push(operation.into());
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn method(mut self, method: Methods) -> Self {
+        self.methods.push(method.as_str().to_string());
+        self
+    }
+
+    pub fn repo(mut self, pattern: impl Into<String>) -> Self {
+        self.repos.push(pattern.into());
+        self
+    }
+
+    pub fn matches(&self, endpoint: &EndPoints) -> bool {
+        self.matches_call(endpoint) && (self.repos.is_empty() || self.matches_repo(endpoint))
+    }
+
+    /// Whether every criterion but `repos` matches.
+    fn matches_call(&self, endpoint: &EndPoints) -> bool {
+        let any = |list: &[String], value: &str| {
+            list.is_empty() || list.iter().any(|v| v.eq_ignore_ascii_case(value))
+        };
+        any(&self.operations, endpoint.name())
+            && any(&self.tags, endpoint.tag())
+            && any(&self.methods, endpoint.method().as_str())
+    }
+
+    fn matches_repo(&self, endpoint: &EndPoints) -> bool {
+        let Some((owner, repo)) = scope(endpoint) else {
+            return false;
+        };
+        self.repos.iter().any(|pattern| {
+            let (owner_pattern, repo_pattern) = pattern.split_once('/').unwrap_or((pattern, "*"));
+            glob(owner_pattern, owner)
+                && match repo {
+                    Some(repo) => glob(repo_pattern, repo),
+                    None => repo_pattern == "*",
+                }
+        })
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        for operation in &self.operations {
+            if !EndPoints::ROUTES
+                .iter()
+                .any(|(name, _, _)| name == operation)
+            {
+                return Err(format!("unknown operation {}", operation));
+            }
+        }
+        for tag in &self.tags {
+            if !known_tags().iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                return Err(format!("unknown tag {}", tag));
+            }
+        }
+        for method in &self.methods {
+            if Methods::parse(method).is_none() {
+                return Err(format!("unknown method {}", method));
+            }
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> String {
+        match &self.name {
+            Some(name) => format!("rule {:?}", name),
+            None => "an unnamed rule".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Policy {
+    /// Applies to calls no rule matches.
+    pub default: Decision,
+    pub allow: Vec<Rule>,
+    pub deny: Vec<Rule>,
+}
+
+impl Policy {
+    /// Everything not explicitly denied is allowed.
+    pub fn allow_all() -> Self {
+        Policy::default()
+    }
+
+    /// Nothing not explicitly allowed is allowed.
+    pub fn deny_all() -> Self {
+        Policy {
+            default: Decision::Deny,
+            ..Policy::default()
+        }
+    }
+
+    pub fn allow(mut self, rule: Rule) -> Self {
+        self.allow.push(rule);
+        self
+    }
+
+    pub fn deny(mut self, rule: Rule) -> Self {
+        self.deny.push(rule);
+        self
+    }
+
+    /// Parses a policy, rejecting unknown operations, tags and methods so a typo
+    /// cannot silently turn a deny rule into a no-op.
+    pub fn from_toml(text: &str) -> io::Result<Self> {
+        let policy: Policy =
+            toml::from_str(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        for rule in policy.allow.iter().chain(&policy.deny) {
+            rule.validate()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+        Ok(policy)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Policy::from_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn check(&self, endpoint: &EndPoints) -> Result<(), Denial> {
+        let denial = |reason| {
+            Err(Denial {
+                operation: endpoint.name(),
+                method: endpoint.method(),
+                path: endpoint.path(),
+                reason,
+            })
+        };
+        let repo_rules = self
+            .allow
+            .iter()
+            .chain(&self.deny)
+            .any(|r| !r.repos.is_empty());
+        if repo_rules && !scope_is_plain(endpoint) {
+            return denial("cannot tell which repository the call is on".to_string());
+        }
+        if scoped_by_id(endpoint) {
+            if let Some(rule) = self
+                .deny
+                .iter()
+                .find(|r| !r.repos.is_empty() && r.matches_call(endpoint))
+            {
+                return denial(format!(
+                    "cannot tell which repository the call is on, and {} may deny it",
+                    rule.describe()
+                ));
+            }
+        }
+        if let Some(rule) = self.deny.iter().find(|r| r.matches(endpoint)) {
+            return denial(format!("denied by {}", rule.describe()));
+        }
+        if self.allow.iter().any(|r| r.matches(endpoint)) {
+            return Ok(());
+        }
+        match self.default {
+            Decision::Allow => Ok(()),
+            Decision::Deny => denial("not allowed by any rule".to_string()),
+        }
+    }
+}
+
+impl Middleware for Policy {
+    fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        self.check(&request.endpoint)?;
+        next.run(request)
+    }
+}
+
+/// A call refused by a [`Policy`]. Nothing was sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Denial {
+    /// The `EndPoints` variant name.
+    pub operation: &'static str,
+    pub method: Methods,
+    pub path: String,
+    pub reason: String,
+}
+
+impl fmt::Display for Denial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "policy forbids {} {} ({}): {}",
+            self.method.as_str(),
+            self.path,
+            self.operation,
+            self.reason
+        )
+    }
+}
+
+impl std::error::Error for Denial {}
+
+/// The owner or organization, and repository, a call is on.
+fn scope(endpoint: &EndPoints) -> Option<(&str, Option<&str>)> {
+    let params: Vec<(&str, &str)> = endpoint
+        .template()
+        .split('/')
+        .filter_map(|s| s.strip_prefix('{').and_then(|p| p.strip_suffix('}')))
+        .zip(endpoint.args())
+        .collect();
+    let param = |name| params.iter().find(|(n, _)| *n == name).map(|(_, v)| *v);
+    let owner = param("owner").or_else(|| param("org"))?;
+    Some((owner, param("repo")))
+}
+
+/// Whether a call names its repository or organization by id rather than by name.
+fn scoped_by_id(endpoint: &EndPoints) -> bool {
+    endpoint
+        .template()
+        .split('/')
+        .any(|s| s == "{repository_id}" || s == "{organization_id}")
+}
+
+/// Every [`EndPoints::tag`], for validating rules.
+fn known_tags() -> &'static [&'static str] {
+    static TAGS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    TAGS.get_or_init(|| {
+        let mut tags: Vec<&'static str> = EndPoints::ROUTES
+            .iter()
+            .filter_map(|(name, _, template)| {
+                let params = template.matches('{').count();
+                EndPoints::from_parts(name, vec![String::new(); params])
+            })
+            .map(|endpoint| endpoint.tag())
+            .collect();
+        tags.sort_unstable();
+        tags.dedup();
+        tags
+    })
+}
+
+/// Whether the owner and repository of a call, if any, are names a rule can
+/// be matched against rather than something that changes the path.
+fn scope_is_plain(endpoint: &EndPoints) -> bool {
+    let plain = |name: &str| {
+        !name.is_empty()
+            && name != "."
+            && name != ".."
+            && !name.contains(['/', '\\', '?', '#', '%'])
+    };
+    match scope(endpoint) {
+        Some((owner, repo)) => plain(owner) && repo.is_none_or(plain),
+        None => true,
+    }
+}
+
+/// Case-insensitive glob with `*` and `?`.
+fn glob(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, t));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match star {
+                Some((star_p, star_t)) => {
+                    p = star_p + 1;
+                    t = star_t + 1;
+                    star = Some((star_p, star_t + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(owner: &str, repo: &str) -> EndPoints {
+        EndPoints::GetReposownerrepo(owner.to_string(), repo.to_string())
+    }
+
+    #[test]
+    fn allows_only_matching_repos() {
+        let policy = Policy::deny_all().allow(Rule::new().repo("acme/*"));
+
+        assert!(policy.check(&repo("acme", "widgets")).is_ok());
+        assert!(policy.check(&repo("ACME", "widgets")).is_ok());
+        assert!(policy.check(&repo("other", "widgets")).is_err());
+        // An organization alone matches a `*` repo part.
+        assert!(policy
+            .check(&EndPoints::GetOrgsorg("acme".to_string()))
+            .is_ok());
+        // Neither owner nor organization: no repo rule matches.
+        assert!(policy.check(&EndPoints::GetZen()).is_err());
+    }
+
+    #[test]
+    fn deny_rules_win() {
+        let policy = Policy::allow_all()
+            .deny(
+                Rule::new()
+                    .name("no deletes")
+                    .operation("DeleteReposownerrepo"),
+            )
+            .deny(Rule::new().repo("acme/secret-?"));
+
+        let denial = policy
+            .check(&EndPoints::DeleteReposownerrepo(
+                "acme".to_string(),
+                "widgets".to_string(),
+            ))
+            .unwrap_err();
+        assert_eq!(denial.reason, "denied by rule \"no deletes\"");
+        assert!(policy.check(&repo("acme", "secret-1")).is_err());
+        assert!(policy.check(&repo("acme", "secret-10")).is_ok());
+        assert!(policy.check(&EndPoints::GetZen()).is_ok());
+    }
+
+    #[test]
+    fn values_that_change_the_path_are_denied() {
+        let policy = Policy::allow_all().deny(Rule::new().repo("acme/secret"));
+
+        for (owner, name) in [
+            ("acme", "x/../secret"),
+            ("acme/secret", "x"),
+            ("acme", ""),
+            ("..", "secret"),
+            ("acme", "secret%2F.."),
+        ] {
+            let denial = policy.check(&repo(owner, name)).unwrap_err();
+            assert_eq!(denial.reason, "cannot tell which repository the call is on");
+        }
+        assert!(policy.check(&repo("acme", "public")).is_ok());
+        // Without repo rules nothing needs to be told apart.
+        assert!(Policy::allow_all().check(&repo("acme", "x/..")).is_ok());
+    }
+
+    #[test]
+    fn repo_is_read_from_the_variant_not_the_path() {
+        // Taken whole, however many segments the rendered path has.
+        let endpoint = EndPoints::GetReposownerrepoBranchesbranchProtection(
+            "acme".to_string(),
+            "widgets".to_string(),
+            "release/v1".to_string(),
+        );
+        let policy = Policy::deny_all().allow(Rule::new().repo("acme/widgets"));
+
+        assert!(policy.check(&endpoint).is_ok());
+        assert!(Policy::allow_all()
+            .deny(Rule::new().repo("acme/*"))
+            .check(&endpoint)
+            .is_err());
+    }
+
+    #[test]
+    fn parses_and_validates_toml() {
+        let policy = Policy::from_toml(
+            r#"
+            default = "deny"
+
+            [[allow]]
+            tags = ["issues"]
+            repos = ["acme/*"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(policy.default, Decision::Deny);
+        assert!(policy
+            .check(&EndPoints::GetReposownerrepoIssues(
+                "acme".to_string(),
+                "widgets".to_string()
+            ))
+            .is_ok());
+
+        let typo = Policy::from_toml("[[deny]]\noperations = [\"DeleteRepo\"]\n").unwrap_err();
+        assert!(typo.to_string().contains("unknown operation DeleteRepo"));
+    }
+
+    #[test]
+    fn unknown_tags_are_rejected() {
+        let policy = Policy::from_toml("[[deny]]\ntags = [\"Issues\", \"git\"]\n").unwrap();
+        assert!(policy
+            .check(&EndPoints::GetReposownerrepoIssues(
+                "acme".to_string(),
+                "widgets".to_string()
+            ))
+            .is_err());
+
+        let typo = Policy::from_toml("[[deny]]\ntags = [\"issue\"]\n").unwrap_err();
+        assert!(typo.to_string().contains("unknown tag issue"));
+        assert!(known_tags().contains(&"pulls"));
+    }
+
+    #[test]
+    fn calls_by_repository_id_cannot_slip_past_repo_denies() {
+        let secret =
+            EndPoints::DeleteRepositoriesrepositoryIdEnvironmentsenvironmentNameSecretssecretName(
+                "42".to_string(),
+                "production".to_string(),
+                "TOKEN".to_string(),
+            );
+        let denial = Policy::allow_all()
+            .deny(Rule::new().name("secret").repo("acme/secret"))
+            .check(&secret)
+            .unwrap_err();
+        assert_eq!(
+            denial.reason,
+            "cannot tell which repository the call is on, and rule \"secret\" may deny it"
+        );
+        // Only deny rules that would otherwise match count.
+        assert!(Policy::allow_all()
+            .deny(Rule::new().tag("issues").repo("acme/secret"))
+            .check(&secret)
+            .is_ok());
+        // An allow rule on repos cannot match it, so the default applies.
+        assert!(Policy::deny_all()
+            .allow(Rule::new().repo("acme/*"))
+            .check(&secret)
+            .is_err());
+        assert!(Policy::allow_all().check(&secret).is_ok());
+    }
+}
@@ -0,0 +1,425 @@
+//! Primary rate limits, tracked per resource bucket.
+//!
+//! See <https://docs.github.com/rest/reference/rate-limit>.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::Deserialize;
+
+use crate::clock::{Clock, SystemClock};
+use crate::end_points::EndPoints;
+use crate::error::Error;
+use crate::http::{Headers, Request, Response, Transport};
+
+/// A quota bucket, as named by `X-RateLimit-Resource`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitResource {
+    Core,
+    Search,
+    /// `/search/code` has its own, smaller bucket.
+    CodeSearch,
+    Graphql,
+    IntegrationManifest,
+    SourceImport,
+    CodeScanningUpload,
+    ActionsRunnerRegistration,
+    Scim,
+    DependencySnapshots,
+    AuditLog,
+    Other(String),
+}
+
+impl RateLimitResource {
+    pub fn parse(name: &str) -> Self {
+        match name {
+            "core" => RateLimitResource::Core,
+            "search" => RateLimitResource::Search,
+            "code_search" => RateLimitResource::CodeSearch,
+            "graphql" => RateLimitResource::Graphql,
+            "integration_manifest" => RateLimitResource::IntegrationManifest,
+            "source_import" => RateLimitResource::SourceImport,
+            "code_scanning_upload" => RateLimitResource::CodeScanningUpload,
+            "actions_runner_registration" => RateLimitResource::ActionsRunnerRegistration,
+            "scim" => RateLimitResource::Scim,
+            "dependency_snapshots" => RateLimitResource::DependencySnapshots,
+            "audit_log" => RateLimitResource::AuditLog,
+            other => RateLimitResource::Other(other.to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            RateLimitResource::Core => "core",
+            RateLimitResource::Search => "search",
+            RateLimitResource::CodeSearch => "code_search",
+            RateLimitResource::Graphql => "graphql",
+            RateLimitResource::IntegrationManifest => "integration_manifest",
+            RateLimitResource::SourceImport => "source_import",
+            RateLimitResource::CodeScanningUpload => "code_scanning_upload",
+            RateLimitResource::ActionsRunnerRegistration => "actions_runner_registration",
+            RateLimitResource::Scim => "scim",
+            RateLimitResource::DependencySnapshots => "dependency_snapshots",
+            RateLimitResource::AuditLog => "audit_log",
+            RateLimitResource::Other(name) => name,
+        }
+    }
+}
+
+impl EndPoints {
+    /// The bucket a call to this endpoint is counted against.
+    ///
+    /// `GetRateLimit` is reported as `Core` but is not counted at all.
+    pub fn rate_limit_resource(&self) -> RateLimitResource {
+        match self {
+            EndPoints::GetSearchCode() => RateLimitResource::CodeSearch,
+            EndPoints::GetSearchCommits()
+            | EndPoints::GetSearchIssues()
+            | EndPoints::GetSearchLabels()
+            | EndPoints::GetSearchRepositories()
+            | EndPoints::GetSearchTopics()
+            | EndPoints::GetSearchUsers() => RateLimitResource::Search,
+            EndPoints::PostAppManifestscodeConversions(..) => {
+                RateLimitResource::IntegrationManifest
+            }
+            EndPoints::PostReposownerrepoCodeScanningSarifs(..) => {
+                RateLimitResource::CodeScanningUpload
+            }
+            EndPoints::GetReposownerrepoImport(..)
+            | EndPoints::PutReposownerrepoImport(..)
+            | EndPoints::PatchReposownerrepoImport(..)
+            | EndPoints::DeleteReposownerrepoImport(..)
+            | EndPoints::GetReposownerrepoImportAuthors(..)
+            | EndPoints::PatchReposownerrepoImportAuthorsauthorId(..)
+            | EndPoints::GetReposownerrepoImportLargeFiles(..)
+            | EndPoints::PatchReposownerrepoImportLfs(..) => RateLimitResource::SourceImport,
+            EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(..)
+            | EndPoints::PostOrgsorgActionsRunnersRegistrationToken(..)
+            | EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(..) => {
+                RateLimitResource::ActionsRunnerRegistration
+            }
+            EndPoints::GetEnterprisesenterpriseAuditLog(..) | EndPoints::GetOrgsorgAuditLog(..) => {
+                RateLimitResource::AuditLog
+            }
+            e if e.template().starts_with("/scim/v2/") => RateLimitResource::Scim,
+            _ => RateLimitResource::Core,
+        }
+    }
+}
+
+/// State of one bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    #[serde(default)]
+    pub used: u64,
+    #[serde(deserialize_with = "epoch_seconds")]
+    pub reset: SystemTime,
+}
+
+impl RateLimit {
+    /// Reads the `X-RateLimit-*` headers, `None` if any is missing.
+    pub fn from_headers(headers: &Headers) -> Option<Self> {
+        let number = |name| headers.get(name)?.trim().parse::<u64>().ok();
+        let remaining = number("x-ratelimit-remaining")?;
+        let limit = number("x-ratelimit-limit")?;
+        Some(RateLimit {
+            limit,
+            remaining,
+            used: number("x-ratelimit-used").unwrap_or(limit - remaining.min(limit)),
+            reset: UNIX_EPOCH + Duration::from_secs(number("x-ratelimit-reset")?),
+        })
+    }
+}
+
+fn epoch_seconds<'de, D: serde::Deserializer<'de>>(d: D) -> Result<SystemTime, D::Error> {
+    Ok(UNIX_EPOCH + Duration::from_secs(u64::deserialize(d)?))
+}
+
+#[derive(Deserialize)]
+struct RateLimitOverview {
+    resources: HashMap<String, RateLimit>,
+}
+
+/// Remembers every bucket's quota and holds calls back while their bucket is empty.
+#[derive(Debug, Default)]
+pub struct RateLimiter<C = SystemClock> {
+    clock: C,
+    buckets: Mutex<HashMap<RateLimitResource, RateLimit>>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        RateLimiter::default()
+    }
+}
+
+impl<C: Clock> RateLimiter<C> {
+    pub fn with_clock(clock: C) -> Self {
+        RateLimiter {
+            clock,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn get(&self, resource: &RateLimitResource) -> Option<RateLimit> {
+        self.buckets.lock().unwrap().get(resource).copied()
+    }
+
+    /// Records the headers of a response to `endpoint`.
+    pub fn observe(&self, endpoint: &EndPoints, headers: &Headers) {
+        let Some(limit) = RateLimit::from_headers(headers) else {
+            return;
+        };
+        let resource = headers
+            .get("x-ratelimit-resource")
+            .map(RateLimitResource::parse)
+            .unwrap_or_else(|| endpoint.rate_limit_resource());
+        self.buckets.lock().unwrap().insert(resource, limit);
+    }
+
+    /// Records every bucket from a `GetRateLimit` response body.
+    pub fn observe_overview(&self, body: &[u8]) -> Result<(), Error> {
+        let overview: RateLimitOverview = serde_json::from_slice(body)?;
+        let mut buckets = self.buckets.lock().unwrap();
+        for (name, limit) in overview.resources {
+            buckets.insert(RateLimitResource::parse(&name), limit);
+        }
+        Ok(())
+    }
+
+    /// How long a call to `endpoint` would have to wait right now.
+    pub fn delay(&self, endpoint: &EndPoints) -> Option<Duration> {
+        if *endpoint == EndPoints::GetRateLimit() {
+            return None;
+        }
+        let limit = self.get(&endpoint.rate_limit_resource())?;
+        if limit.remaining > 0 {
+            return None;
+        }
+        limit.reset.duration_since(self.clock.now()).ok()
+    }
+
+    /// Waits until the bucket of `endpoint` has quota left, then counts the call against it.
+    pub fn acquire(&self, endpoint: &EndPoints) {
+        if let Some(wait) = self.delay(endpoint) {
+            self.clock.sleep(wait);
+        }
+        if *endpoint == EndPoints::GetRateLimit() {
+            return;
+        }
+        let resource = endpoint.rate_limit_resource();
+        let now = self.clock.now();
+        let mut buckets = self.buckets.lock().unwrap();
+        match buckets.get_mut(&resource) {
+            // The window rolled over, the next response tells us the new quota.
+            Some(limit) if limit.reset <= now => {
+                buckets.remove(&resource);
+            }
+            Some(limit) => {
+                limit.remaining = limit.remaining.saturating_sub(1);
+                limit.used += 1;
+            }
+            None => {}
+        }
+    }
+}
+
+/// A [`Transport`] that runs every call through a [`RateLimiter`].
+#[derive(Debug)]
+pub struct RateLimited<T, C = SystemClock> {
+    inner: T,
+    limiter: RateLimiter<C>,
+}
+
+impl<T> RateLimited<T> {
+    pub fn new(inner: T) -> Self {
+        RateLimited {
+            inner,
+            limiter: RateLimiter::new(),
+        }
+    }
+}
+
+impl<T, C: Clock> RateLimited<T, C> {
+    pub fn with_limiter(inner: T, limiter: RateLimiter<C>) -> Self {
+        RateLimited { inner, limiter }
+    }
+
+    pub fn limiter(&self) -> &RateLimiter<C> {
+        &self.limiter
+    }
+}
+
+impl<T: Transport, C: Clock> Transport for RateLimited<T, C> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        self.limiter.acquire(&request.endpoint);
+        let response = self.inner.send(request)?;
+        self.limiter.observe(&request.endpoint, &response.headers);
+        if request.endpoint == EndPoints::GetRateLimit() && response.is_success() {
+            self.limiter.observe_overview(&response.body)?;
+        }
+        Ok(response)
+    }
+
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        self.limiter.acquire(&request.endpoint);
+        let response = self.inner.send_body(request, body, len)?;
+        self.limiter.observe(&request.endpoint, &response.headers);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::clock::FakeClock;
+    use crate::fake::{json, FakeGitHub};
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn clock() -> FakeClock {
+        FakeClock::new(UNIX_EPOCH + Duration::from_secs(NOW))
+    }
+
+    fn headers(remaining: u64, reset: u64, resource: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("X-RateLimit-Limit", "5000");
+        headers.insert("X-RateLimit-Remaining", remaining.to_string());
+        headers.insert("X-RateLimit-Reset", reset.to_string());
+        headers.insert("X-RateLimit-Resource", resource);
+        headers
+    }
+
+    fn zen() -> EndPoints {
+        EndPoints::GetZen()
+    }
+
+    #[test]
+    fn endpoints_map_to_their_bucket() {
+        assert_eq!(
+            EndPoints::GetSearchCode().rate_limit_resource(),
+            RateLimitResource::CodeSearch
+        );
+        assert_eq!(
+            EndPoints::GetSearchIssues().rate_limit_resource(),
+            RateLimitResource::Search
+        );
+        assert_eq!(
+            EndPoints::GetScimV2EnterprisesenterpriseGroups("acme".to_string())
+                .rate_limit_resource(),
+            RateLimitResource::Scim
+        );
+        assert_eq!(
+            EndPoints::GetReposownerrepo("scim".to_string(), "v2".to_string())
+                .rate_limit_resource(),
+            RateLimitResource::Core
+        );
+        assert_eq!(zen().rate_limit_resource(), RateLimitResource::Core);
+    }
+
+    #[test]
+    fn reads_limits_from_headers() {
+        let limit = RateLimit::from_headers(&headers(4990, NOW + 60, "core")).unwrap();
+        assert_eq!((limit.limit, limit.remaining, limit.used), (5000, 4990, 10));
+        assert_eq!(limit.reset, UNIX_EPOCH + Duration::from_secs(NOW + 60));
+        assert!(RateLimit::from_headers(&Headers::new()).is_none());
+    }
+
+    #[test]
+    fn waits_for_an_empty_bucket_to_reset() {
+        let clock = clock();
+        let limiter = RateLimiter::with_clock(clock.clone());
+        limiter.observe(&zen(), &headers(1, NOW + 30, "core"));
+
+        limiter.acquire(&zen());
+        assert_eq!(limiter.get(&RateLimitResource::Core).unwrap().remaining, 0);
+        assert!(clock.sleeps().is_empty());
+        assert_eq!(limiter.delay(&zen()), Some(Duration::from_secs(30)));
+        // Other buckets are not held back.
+        assert_eq!(limiter.delay(&EndPoints::GetSearchIssues()), None);
+
+        limiter.acquire(&zen());
+        assert_eq!(clock.sleeps(), [Duration::from_secs(30)]);
+        // The window rolled over; the next response sets the new quota.
+        assert_eq!(limiter.get(&RateLimitResource::Core), None);
+        limiter.acquire(&zen());
+        assert_eq!(clock.sleeps().len(), 1);
+    }
+
+    #[test]
+    fn a_reset_in_the_past_does_not_wait() {
+        let clock = clock();
+        let limiter = RateLimiter::with_clock(clock.clone());
+        limiter.observe(&zen(), &headers(0, NOW - 1, "core"));
+
+        assert_eq!(limiter.delay(&zen()), None);
+        limiter.acquire(&zen());
+        assert!(clock.sleeps().is_empty());
+    }
+
+    #[test]
+    fn rate_limit_calls_are_never_counted_or_held() {
+        let clock = clock();
+        let limiter = RateLimiter::with_clock(clock.clone());
+        limiter.observe(&zen(), &headers(0, NOW + 30, "core"));
+
+        assert_eq!(limiter.delay(&EndPoints::GetRateLimit()), None);
+        limiter.acquire(&EndPoints::GetRateLimit());
+        assert!(clock.sleeps().is_empty());
+    }
+
+    #[test]
+    fn transport_learns_every_bucket_from_the_overview() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetRateLimit, |_| {
+            json(
+                200,
+                &json!({ "resources": {
+                    "core": { "limit": 5000, "remaining": 0, "used": 5000, "reset": NOW + 10 },
+                    "search": { "limit": 30, "remaining": 29, "reset": NOW + 60 },
+                    "code_search": { "limit": 10, "remaining": 10, "reset": NOW + 60 }
+                }}),
+            )
+        });
+        github.on(EndPoints::GetSearchCode, |_| {
+            let mut response = json(200, &json!({ "items": [] }));
+            response.headers = headers(9, NOW + 60, "code_search");
+            response
+        });
+        let clock = clock();
+        let transport = RateLimited::with_limiter(&github, RateLimiter::with_clock(clock.clone()));
+
+        transport
+            .send(&Request::new(EndPoints::GetRateLimit()))
+            .unwrap();
+        let limiter = transport.limiter();
+        assert_eq!(
+            limiter.get(&RateLimitResource::Search).unwrap().remaining,
+            29
+        );
+        assert_eq!(limiter.delay(&zen()), Some(Duration::from_secs(10)));
+
+        transport
+            .send_body(&Request::new(EndPoints::GetSearchCode()), &mut &b""[..], 0)
+            .unwrap();
+        assert_eq!(
+            limiter
+                .get(&RateLimitResource::CodeSearch)
+                .unwrap()
+                .remaining,
+            9
+        );
+        assert!(clock.sleeps().is_empty());
+    }
+}
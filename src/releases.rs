@@ -0,0 +1,848 @@
+//! Publishing a release together with its assets.
+//!
+//! A [`ReleasePublisher`] finds the release for a tag, or creates it as a
+//! draft, uploads the assets to the uploads host (replacing any with the same
+//! name) and only then publishes it, so nobody sees a release with half its
+//! assets. Running it again for the same tag updates the release in place.
+//!
+//! On a release that is already published, a replacement is uploaded under a
+//! temporary name first, and only then swapped for the old asset. Between the
+//! delete and the rename, a moment, downloads of that name fail.
+//!
+//! ```no_run
+//! use github_api_octocat::http::Transport;
+//! use github_api_octocat::releases::{Asset, ReleasePublisher};
+//! # fn release(transport: impl Transport) -> Result<(), github_api_octocat::releases::ReleaseError> {
+//! let published = ReleasePublisher::new(transport, "octocat", "hello-world", "v1.2.0")
+//!     .target_commitish("main")
+//!     .generate_notes(true)
+//!     .asset(Asset::path("target/dist/hello-x86_64-linux.tar.gz").label("Linux (x86_64)"))
+//!     .asset(Asset::path("target/dist/SHA256SUMS"))
+//!     .publish()?;
+//! println!("{}", published.release.html_url);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Assets read from a file are handed to [`Transport::send_body`], which
+//! streams them if the transport supports it.
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde_json::{json, Map};
+
+use crate::end_points::EndPoints;
+use crate::error::{Error, GitHubError};
+use crate::http::{encode_path, Request, Transport};
+use crate::models::{Release, ReleaseAsset};
+
+const PER_PAGE: usize = 100;
+/// Appended to the name of a replacement while the old asset is still live.
+const STAGING_SUFFIX: &str = ".uploading";
+
+#[derive(Debug)]
+pub enum ReleaseError {
+    Api(Error),
+    /// An asset file could not be read.
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl fmt::Display for ReleaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReleaseError::Api(e) => e.fmt(f),
+            ReleaseError::Io { path, error } => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for ReleaseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReleaseError::Api(e) => Some(e),
+            ReleaseError::Io { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<Error> for ReleaseError {
+    fn from(e: Error) -> Self {
+        ReleaseError::Api(e)
+    }
+}
+
+impl From<serde_json::Error> for ReleaseError {
+    fn from(e: serde_json::Error) -> Self {
+        ReleaseError::Api(e.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    Path(PathBuf),
+    Bytes(Vec<u8>),
+}
+
+/// A file to attach to the release.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    source: Source,
+    name: String,
+    label: Option<String>,
+    content_type: Option<String>,
+}
+
+impl Asset {
+    /// The file at `path`, named after it.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Asset {
+            source: Source::Path(path),
+            name,
+            label: None,
+            content_type: None,
+        }
+    }
+
+    pub fn bytes(name: impl Into<String>, content: impl Into<Vec<u8>>) -> Self {
+        Asset {
+            source: Source::Bytes(content.into()),
+            name: name.into(),
+            label: None,
+            content_type: None,
+        }
+    }
+
+    /// The file name on the release. GitHub replaces characters it does not
+    /// allow, such as spaces, with `.`.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Shown instead of the name on the release page.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Guessed from the name with [`content_type_for`] otherwise.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+/// The MIME type for a file name, by extension. `application/octet-stream`
+/// when unknown.
+///
+/// ```
+/// use github_api_octocat::releases::content_type_for;
+///
+/// assert_eq!(content_type_for("hello-linux.tar.gz"), "application/gzip");
+/// assert_eq!(content_type_for("hello.ZIP"), "application/zip");
+/// assert_eq!(content_type_for("SHA256SUMS"), "text/plain");
+/// assert_eq!(content_type_for("hello"), "application/octet-stream");
+/// ```
+pub fn content_type_for(name: &str) -> &'static str {
+    if name.to_ascii_uppercase().ends_with("SUMS") {
+        return "text/plain";
+    }
+    let extension = name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "gz" | "tgz" => "application/gzip",
+        "zip" => "application/zip",
+        "tar" => "application/x-tar",
+        "xz" | "txz" => "application/x-xz",
+        "bz2" | "tbz" => "application/x-bzip2",
+        "zst" => "application/zstd",
+        "7z" => "application/x-7z-compressed",
+        "deb" => "application/vnd.debian.binary-package",
+        "rpm" => "application/x-rpm",
+        "apk" => "application/vnd.android.package-archive",
+        "dmg" => "application/x-apple-diskimage",
+        "pkg" => "application/x-newton-compatible-pkg",
+        "exe" => "application/vnd.microsoft.portable-executable",
+        "msi" => "application/x-msi",
+        "jar" => "application/java-archive",
+        "wasm" => "application/wasm",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "asc" | "sig" => "application/pgp-signature",
+        "txt" | "sha256" | "sha512" | "sha256sum" | "sha512sum" => "text/plain",
+        "md" => "text/markdown",
+        "html" | "htm" => "text/html",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "svg" => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+/// What [`ReleasePublisher::publish`] did.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Published {
+    pub release: Release,
+    /// Whether the release did not exist before.
+    pub created: bool,
+    /// The uploaded assets, in the order they were added.
+    pub assets: Vec<ReleaseAsset>,
+    /// Names of the assets that replaced an existing one.
+    pub replaced: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Notes {
+    name: String,
+    body: String,
+}
+
+/// See the [module docs](self).
+#[derive(Debug)]
+pub struct ReleasePublisher<T> {
+    transport: T,
+    owner: String,
+    repo: String,
+    tag: String,
+    target_commitish: Option<String>,
+    name: Option<String>,
+    body: Option<String>,
+    draft: bool,
+    prerelease: Option<bool>,
+    generate_notes: bool,
+    previous_tag: Option<String>,
+    assets: Vec<Asset>,
+}
+
+impl<T: Transport> ReleasePublisher<T> {
+    pub fn new(
+        transport: T,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+        tag: impl Into<String>,
+    ) -> Self {
+        ReleasePublisher {
+            transport,
+            owner: owner.into(),
+            repo: repo.into(),
+            tag: tag.into(),
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: false,
+            prerelease: None,
+            generate_notes: false,
+            previous_tag: None,
+            assets: Vec::new(),
+        }
+    }
+
+    /// The branch or commit to tag, if the tag does not exist yet. The default
+    /// branch otherwise.
+    pub fn target_commitish(mut self, target: impl Into<String>) -> Self {
+        self.target_commitish = Some(target.into());
+        self
+    }
+
+    /// The release title. The tag, or the generated title, by default.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The release notes; generated notes follow them.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Leaves the release as a draft.
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = draft;
+        self
+    }
+
+    /// Left as it is on an existing release unless set.
+    pub fn prerelease(mut self, prerelease: bool) -> Self {
+        self.prerelease = Some(prerelease);
+        self
+    }
+
+    /// Asks `PostReposownerrepoReleasesGenerateNotes` for notes listing the
+    /// pull requests merged since the previous release.
+    pub fn generate_notes(mut self, generate_notes: bool) -> Self {
+        self.generate_notes = generate_notes;
+        self
+    }
+
+    /// The release to generate notes from. The latest one by default.
+    pub fn previous_tag(mut self, tag: impl Into<String>) -> Self {
+        self.previous_tag = Some(tag.into());
+        self
+    }
+
+    pub fn asset(mut self, asset: Asset) -> Self {
+        self.assets.push(asset);
+        self
+    }
+
+    /// Creates or updates the release, uploads the assets, then publishes it
+    /// unless it is to stay a draft.
+    pub fn publish(&self) -> Result<Published, ReleaseError> {
+        // Fail before changing anything if a file is missing.
+        for asset in &self.assets {
+            if let Source::Path(path) = &asset.source {
+                fs::metadata(path).map_err(|error| ReleaseError::Io {
+                    path: path.clone(),
+                    error,
+                })?;
+            }
+        }
+        let mut fields = Map::new();
+        if let Some(prerelease) = self.prerelease {
+            fields.insert("prerelease".to_string(), json!(prerelease));
+        }
+        if let Some(target) = &self.target_commitish {
+            fields.insert("target_commitish".to_string(), json!(target));
+        }
+        let notes = if self.generate_notes {
+            Some(self.notes()?)
+        } else {
+            None
+        };
+        let name = self
+            .name
+            .clone()
+            .or_else(|| notes.as_ref().map(|notes| notes.name.clone()));
+        if let Some(name) = name {
+            fields.insert("name".to_string(), json!(name));
+        }
+        let body = match (&self.body, notes) {
+            (Some(body), Some(notes)) => Some(format!("{}\n\n{}", body, notes.body)),
+            (Some(body), None) => Some(body.clone()),
+            (None, notes) => notes.map(|notes| notes.body),
+        };
+        if let Some(body) = body {
+            fields.insert("body".to_string(), json!(body));
+        }
+
+        let (release, created) = match self.find()? {
+            Some(release) if fields.is_empty() => (release, false),
+            Some(release) => {
+                let release: Release = self.send(
+                    Request::new(EndPoints::PatchReposownerrepoReleasesreleaseId(
+                        self.owner.clone(),
+                        self.repo.clone(),
+                        release.id.to_string(),
+                    ))
+                    .json(&fields)?,
+                )?;
+                (release, false)
+            }
+            None => {
+                let mut fields = fields.clone();
+                fields.insert("tag_name".to_string(), json!(self.tag));
+                // Published once the assets are there.
+                fields.insert("draft".to_string(), json!(true));
+                let release: Release = self.send(
+                    Request::new(EndPoints::PostReposownerrepoReleases(
+                        self.owner.clone(),
+                        self.repo.clone(),
+                    ))
+                    .json(&fields)?,
+                )?;
+                (release, true)
+            }
+        };
+
+        let existing = self.list_assets(release.id)?;
+        let mut published = Published {
+            release,
+            created,
+            assets: Vec::new(),
+            replaced: Vec::new(),
+        };
+        let id = published.release.id;
+        for asset in &self.assets {
+            // Including uploads that never finished, which still hold the name.
+            let old = existing.iter().find(|old| old.name == asset.name);
+            let uploaded = match old {
+                Some(old) if !published.release.draft => {
+                    let staging = format!("{}{}", asset.name, STAGING_SUFFIX);
+                    // Left behind by a run that failed halfway.
+                    if let Some(stale) = existing.iter().find(|stale| stale.name == staging) {
+                        self.delete_asset(stale.id)?;
+                    }
+                    let uploaded = self.upload(id, asset, &staging)?;
+                    self.delete_asset(old.id)?;
+                    self.rename_asset(uploaded.id, &asset.name)?
+                }
+                Some(old) => {
+                    self.delete_asset(old.id)?;
+                    self.upload(id, asset, &asset.name)?
+                }
+                None => self.upload(id, asset, &asset.name)?,
+            };
+            if old.is_some() {
+                published.replaced.push(asset.name.clone());
+            }
+            published.assets.push(uploaded);
+        }
+
+        if published.release.draft != self.draft {
+            published.release = self.send(
+                Request::new(EndPoints::PatchReposownerrepoReleasesreleaseId(
+                    self.owner.clone(),
+                    self.repo.clone(),
+                    published.release.id.to_string(),
+                ))
+                .json(&json!({ "draft": self.draft }))?,
+            )?;
+        }
+        Ok(published)
+    }
+
+    /// The release for the tag. Drafts are not found by tag, so they are
+    /// looked for among all releases.
+    fn find(&self) -> Result<Option<Release>, ReleaseError> {
+        let request = Request::new(EndPoints::GetReposownerrepoReleasesTagstag(
+            self.owner.clone(),
+            self.repo.clone(),
+            encode_path(&self.tag),
+        ));
+        match self.send(request) {
+            Ok(release) => return Ok(Some(release)),
+            Err(ReleaseError::Api(Error::GitHub(e))) if matches!(*e, GitHubError::NotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+        for page in 1.. {
+            let request = Request::new(EndPoints::GetReposownerrepoReleases(
+                self.owner.clone(),
+                self.repo.clone(),
+            ))
+            .query("per_page", PER_PAGE)
+            .query("page", page);
+            let releases: Vec<Release> = self.send(request)?;
+            let last = releases.len() < PER_PAGE;
+            if let Some(draft) = releases
+                .into_iter()
+                .find(|release| release.draft && release.tag_name == self.tag)
+            {
+                return Ok(Some(draft));
+            }
+            if last {
+                break;
+            }
+        }
+        Ok(None)
+    }
+
+    fn notes(&self) -> Result<Notes, ReleaseError> {
+        let mut body = json!({ "tag_name": self.tag });
+        if let Some(target) = &self.target_commitish {
+            body["target_commitish"] = json!(target);
+        }
+        if let Some(previous) = &self.previous_tag {
+            body["previous_tag_name"] = json!(previous);
+        }
+        self.send(
+            Request::new(EndPoints::PostReposownerrepoReleasesGenerateNotes(
+                self.owner.clone(),
+                self.repo.clone(),
+            ))
+            .json(&body)?,
+        )
+    }
+
+    fn list_assets(&self, release_id: u64) -> Result<Vec<ReleaseAsset>, ReleaseError> {
+        let mut assets = Vec::new();
+        for page in 1.. {
+            let request = Request::new(EndPoints::GetReposownerrepoReleasesreleaseIdAssets(
+                self.owner.clone(),
+                self.repo.clone(),
+                release_id.to_string(),
+            ))
+            .query("per_page", PER_PAGE)
+            .query("page", page);
+            let batch: Vec<ReleaseAsset> = self.send(request)?;
+            let last = batch.len() < PER_PAGE;
+            assets.extend(batch);
+            if last {
+                break;
+            }
+        }
+        Ok(assets)
+    }
+
+    fn delete_asset(&self, asset_id: u64) -> Result<(), ReleaseError> {
+        let request = Request::new(EndPoints::DeleteReposownerrepoReleasesAssetsassetId(
+            self.owner.clone(),
+            self.repo.clone(),
+            asset_id.to_string(),
+        ));
+        self.transport.execute(&request)?;
+        Ok(())
+    }
+
+    fn rename_asset(&self, asset_id: u64, name: &str) -> Result<ReleaseAsset, ReleaseError> {
+        self.send(
+            Request::new(EndPoints::PatchReposownerrepoReleasesAssetsassetId(
+                self.owner.clone(),
+                self.repo.clone(),
+                asset_id.to_string(),
+            ))
+            .json(&json!({ "name": name }))?,
+        )
+    }
+
+    /// Uploads `asset` as `name`.
+    fn upload(
+        &self,
+        release_id: u64,
+        asset: &Asset,
+        name: &str,
+    ) -> Result<ReleaseAsset, ReleaseError> {
+        let content_type = asset
+            .content_type
+            .clone()
+            .unwrap_or_else(|| content_type_for(&asset.name).to_string());
+        let mut request = Request::new(EndPoints::PostReposownerrepoReleasesreleaseIdAssets(
+            self.owner.clone(),
+            self.repo.clone(),
+            release_id.to_string(),
+        ))
+        .query("name", name)
+        .header("Content-Type", content_type);
+        if let Some(label) = &asset.label {
+            request = request.query("label", label);
+        }
+        let response = match &asset.source {
+            Source::Path(path) => {
+                let io_error = |error| ReleaseError::Io {
+                    path: path.clone(),
+                    error,
+                };
+                let mut file = File::open(path).map_err(io_error)?;
+                let len = file.metadata().map_err(io_error)?.len();
+                self.transport.send_body(&request, &mut file, len)?
+            }
+            Source::Bytes(bytes) => {
+                self.transport
+                    .send_body(&request, &mut bytes.as_slice(), bytes.len() as u64)?
+            }
+        };
+        Ok(response.error_for_status(&request.endpoint)?.json()?)
+    }
+
+    fn send<R: serde::de::DeserializeOwned>(&self, request: Request) -> Result<R, ReleaseError> {
+        Ok(self.transport.execute(&request)?.json()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::cache::ConditionalCache;
+    use crate::cassette::Recorder;
+    use crate::clock::FakeClock;
+    use crate::fake::{json, FakeGitHub};
+    use crate::http::Response;
+    use crate::middleware::{Chain, UserAgent};
+    use crate::rate_limit::{RateLimited, RateLimiter};
+    use crate::retry::{Retry, RetryPolicy};
+    use crate::throttle::{Throttle, ThrottleConfig};
+
+    type Log = Arc<Mutex<Vec<String>>>;
+
+    fn release(id: u64, tag: &str, draft: bool) -> Value {
+        json!({ "id": id, "tag_name": tag, "draft": draft })
+    }
+
+    fn query<'a>(request: &'a Request, key: &str) -> &'a str {
+        request
+            .query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+            .unwrap_or_default()
+    }
+
+    /// A fake with `existing` as the release for `v1.0.0`, holding `assets`.
+    /// Asset calls are logged as `delete <id>`, `upload <name>` and
+    /// `rename <id> <name>`.
+    fn github(existing: Option<Value>, assets: Value) -> (FakeGitHub, Log) {
+        let github = FakeGitHub::start().unwrap();
+        let log = Log::default();
+        match existing {
+            Some(found) => github.on(EndPoints::GetReposownerrepoReleasesTagstag, move |_| {
+                json(200, &found)
+            }),
+            None => github
+                .on(EndPoints::GetReposownerrepoReleasesTagstag, |_| {
+                    json(404, &json!({ "message": "Not Found" }))
+                })
+                .on(EndPoints::GetReposownerrepoReleases, |_| {
+                    json(200, &json!([]))
+                })
+                .on(EndPoints::PostReposownerrepoReleases, |_| {
+                    json(201, &release(1, "v1.0.0", true))
+                }),
+        };
+        github
+            .on(
+                EndPoints::GetReposownerrepoReleasesreleaseIdAssets,
+                move |_| json(200, &assets),
+            )
+            .on(EndPoints::PatchReposownerrepoReleasesreleaseId, |request| {
+                let body: Value = serde_json::from_slice(request.body.as_deref().unwrap()).unwrap();
+                json(200, &release(1, "v1.0.0", body["draft"] == json!(true)))
+            });
+        let logged = log.clone();
+        github.on(
+            EndPoints::DeleteReposownerrepoReleasesAssetsassetId,
+            move |request| {
+                let EndPoints::DeleteReposownerrepoReleasesAssetsassetId(_, _, id) =
+                    &request.endpoint
+                else {
+                    unreachable!()
+                };
+                logged.lock().unwrap().push(format!("delete {}", id));
+                Response {
+                    status: 204,
+                    headers: Default::default(),
+                    body: Vec::new(),
+                }
+            },
+        );
+        let logged = log.clone();
+        github.on(
+            EndPoints::PostReposownerrepoReleasesreleaseIdAssets,
+            move |request| {
+                let name = query(request, "name");
+                logged.lock().unwrap().push(format!("upload {}", name));
+                json(201, &json!({ "id": 100, "name": name }))
+            },
+        );
+        let logged = log.clone();
+        github.on(
+            EndPoints::PatchReposownerrepoReleasesAssetsassetId,
+            move |request| {
+                let EndPoints::PatchReposownerrepoReleasesAssetsassetId(_, _, id) =
+                    &request.endpoint
+                else {
+                    unreachable!()
+                };
+                let body: Value = serde_json::from_slice(request.body.as_deref().unwrap()).unwrap();
+                let name = body["name"].as_str().unwrap();
+                logged
+                    .lock()
+                    .unwrap()
+                    .push(format!("rename {} {}", id, name));
+                json(200, &json!({ "id": 100, "name": name }))
+            },
+        );
+        (github, log)
+    }
+
+    fn logged(log: &Log) -> Vec<String> {
+        log.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn creates_a_draft_uploads_then_publishes() {
+        let (github, log) = github(None, json!([]));
+        github
+            .expect(EndPoints::PostReposownerrepoReleases)
+            .times(1)
+            .with_json(json!({ "tag_name": "v1.0.0", "draft": true }));
+        github
+            .expect(EndPoints::PatchReposownerrepoReleasesreleaseId)
+            .times(1)
+            .with_json(json!({ "draft": false }));
+
+        let published = ReleasePublisher::new(&github, "octocat", "hello", "v1.0.0")
+            .asset(Asset::bytes("app.tar.gz", "archive"))
+            .publish()
+            .unwrap();
+
+        assert!(published.created);
+        assert!(!published.release.draft);
+        assert_eq!(published.assets[0].name, "app.tar.gz");
+        assert!(published.replaced.is_empty());
+        assert_eq!(logged(&log), ["upload app.tar.gz"]);
+        let upload = &github.calls(EndPoints::PostReposownerrepoReleasesreleaseIdAssets)[0];
+        assert_eq!(upload.body.as_deref(), Some(&b"archive"[..]));
+        assert_eq!(upload.headers.get("content-type"), Some("application/gzip"));
+    }
+
+    #[test]
+    fn finds_drafts_past_the_first_page() {
+        let (github, _) = github(None, json!([]));
+        github.on(EndPoints::GetReposownerrepoReleases, |request| {
+            let releases: Vec<Value> = match query(request, "page") {
+                "1" => (0..PER_PAGE as u64)
+                    .map(|i| release(100 + i, &format!("v0.{}", i), false))
+                    .collect(),
+                _ => vec![release(7, "v0.9.0", true), release(1, "v1.0.0", true)],
+            };
+            json(200, &releases)
+        });
+        github
+            .expect(EndPoints::PostReposownerrepoReleases)
+            .times(0);
+
+        let published = ReleasePublisher::new(&github, "octocat", "hello", "v1.0.0")
+            .draft(true)
+            .publish()
+            .unwrap();
+
+        assert!(!published.created);
+        assert_eq!(published.release.id, 1);
+        assert_eq!(github.calls(EndPoints::GetReposownerrepoReleases).len(), 2);
+    }
+
+    #[test]
+    fn replaces_draft_assets_in_place() {
+        let assets = json!([{ "id": 7, "name": "app.tar.gz" }]);
+        let (github, log) = github(Some(release(1, "v1.0.0", true)), assets);
+
+        let published = ReleasePublisher::new(&github, "octocat", "hello", "v1.0.0")
+            .asset(Asset::bytes("app.tar.gz", "new"))
+            .publish()
+            .unwrap();
+
+        assert_eq!(logged(&log), ["delete 7", "upload app.tar.gz"]);
+        assert_eq!(published.replaced, ["app.tar.gz"]);
+    }
+
+    #[test]
+    fn swaps_assets_on_a_published_release() {
+        let assets = json!([
+            { "id": 7, "name": "app.tar.gz" },
+            { "id": 8, "name": "app.tar.gz.uploading" },
+            { "id": 9, "name": "SHA256SUMS" },
+        ]);
+        let (github, log) = github(Some(release(1, "v1.0.0", false)), assets);
+        github
+            .expect(EndPoints::PatchReposownerrepoReleasesreleaseId)
+            .times(0);
+
+        let published = ReleasePublisher::new(&github, "octocat", "hello", "v1.0.0")
+            .asset(Asset::bytes("app.tar.gz", "new"))
+            .publish()
+            .unwrap();
+
+        assert_eq!(
+            logged(&log),
+            [
+                "delete 8",
+                "upload app.tar.gz.uploading",
+                "delete 7",
+                "rename 100 app.tar.gz"
+            ]
+        );
+        assert_eq!(published.assets[0].name, "app.tar.gz");
+        assert_eq!(published.replaced, ["app.tar.gz"]);
+    }
+
+    #[test]
+    fn fails_before_changing_anything_when_a_file_is_missing() {
+        let (github, _) = github(None, json!([]));
+        github
+            .expect(EndPoints::PostReposownerrepoReleases)
+            .times(0);
+
+        let error = ReleasePublisher::new(&github, "octocat", "hello", "v1.0.0")
+            .asset(Asset::path("/nonexistent/app.tar.gz"))
+            .publish()
+            .unwrap_err();
+
+        assert!(matches!(error, ReleaseError::Io { .. }), "{:?}", error);
+    }
+
+    /// Counts bodies handed to `send_body`, which reach it only if every
+    /// layer above forwards them.
+    struct Streaming {
+        github: FakeGitHub,
+        streamed: Mutex<Vec<Vec<u8>>>,
+    }
+
+    impl Transport for Streaming {
+        fn send(&self, request: &Request) -> Result<Response, Error> {
+            self.github.send(request)
+        }
+
+        fn send_body(
+            &self,
+            request: &Request,
+            body: &mut dyn Read,
+            len: u64,
+        ) -> Result<Response, Error> {
+            let mut buffer = Vec::new();
+            body.take(len).read_to_end(&mut buffer).unwrap();
+            self.streamed.lock().unwrap().push(buffer.clone());
+            let mut request = request.clone();
+            request.body = Some(buffer);
+            self.github.send(&request)
+        }
+    }
+
+    #[test]
+    fn streams_uploads_through_a_layered_stack() {
+        let (github, log) = github(None, json!([]));
+        let bottom = Arc::new(Streaming {
+            github,
+            streamed: Mutex::new(Vec::new()),
+        });
+        let clock = FakeClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        let transport = Chain::new(Retry::with_clock(
+            Throttle::with_clock(
+                RateLimited::with_limiter(
+                    ConditionalCache::new(Recorder::new(bottom.clone())),
+                    RateLimiter::with_clock(clock.clone()),
+                ),
+                ThrottleConfig::default(),
+                clock.clone(),
+            ),
+            RetryPolicy::default(),
+            clock,
+        ))
+        .layer(UserAgent::new("releases-test"));
+
+        ReleasePublisher::new(transport, "octocat", "hello", "v1.0.0")
+            .asset(Asset::bytes("app.tar.gz", "archive"))
+            .asset(Asset::bytes("SHA256SUMS", "sums"))
+            .publish()
+            .unwrap();
+
+        assert_eq!(
+            *bottom.streamed.lock().unwrap(),
+            [b"archive".to_vec(), b"sums".to_vec()]
+        );
+        assert_eq!(logged(&log), ["upload app.tar.gz", "upload SHA256SUMS"]);
+        let upload = &bottom
+            .github
+            .calls(EndPoints::PostReposownerrepoReleasesreleaseIdAssets)[0];
+        assert_eq!(upload.headers.get("user-agent"), Some("releases-test"));
+    }
+}
@@ -0,0 +1,346 @@
+//! Retrying transient failures without duplicating writes.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Read;
+use std::time::Duration;
+
+use crate::clock::{Clock, SystemClock};
+use crate::end_points::{EndPoints, Methods};
+use crate::error::Error;
+use crate::http::{Request, Response, Transport};
+
+impl EndPoints {
+    /// Whether sending the same call twice has the same effect as sending it once.
+    ///
+    /// Everything but POST is, plus the POSTs that only render, add to a set,
+    /// hand out a fresh token or create content-addressed git objects.
+    pub fn is_idempotent(&self) -> bool {
+        match self {
+            EndPoints::PostMarkdown()
+            | EndPoints::PostMarkdownRaw()
+            | EndPoints::PostReposownerrepoGitBlobs(..)
+            | EndPoints::PostReposownerrepoGitTrees(..)
+            | EndPoints::PostReposownerrepoIssuesissueNumberLabels(..)
+            | EndPoints::PostReposownerrepoIssuesissueNumberAssignees(..)
+            | EndPoints::PostReposownerrepoPullspullNumberRequestedReviewers(..)
+            | EndPoints::PostEnterprisesenterpriseActionsRunnersrunnerIdLabels(..)
+            | EndPoints::PostOrgsorgActionsRunnersrunnerIdLabels(..)
+            | EndPoints::PostReposownerrepoActionsRunnersrunnerIdLabels(..)
+            | EndPoints::PostAppInstallationsinstallationIdAccessTokens(..)
+            | EndPoints::PostEnterprisesenterpriseActionsRunnersRegistrationToken(..)
+            | EndPoints::PostEnterprisesenterpriseActionsRunnersRemoveToken(..)
+            | EndPoints::PostOrgsorgActionsRunnersRegistrationToken(..)
+            | EndPoints::PostOrgsorgActionsRunnersRemoveToken(..)
+            | EndPoints::PostReposownerrepoActionsRunnersRegistrationToken(..)
+            | EndPoints::PostReposownerrepoActionsRunnersRemoveToken(..) => true,
+            e => e.method() != Methods::Post,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every further one.
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    /// Also retry calls for which [`EndPoints::is_idempotent`] is false.
+    pub retry_non_idempotent: bool,
+    /// Response statuses considered transient.
+    pub retry_statuses: Vec<u16>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            retry_non_idempotent: false,
+            retry_statuses: vec![500, 502, 503, 504],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Upper bound of the delay before retry number `retry`, starting at 0.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay)
+    }
+
+    fn should_retry(&self, outcome: &Result<Response, Error>) -> bool {
+        match outcome {
+            Ok(response) => self.retry_statuses.contains(&response.status),
+            Err(Error::Transport(_)) => true,
+            Err(_) => false,
+        }
+    }
+}
+
+/// A [`Transport`] that retries transient failures with exponential backoff and full jitter.
+#[derive(Debug)]
+pub struct Retry<T, C = SystemClock> {
+    inner: T,
+    policy: RetryPolicy,
+    clock: C,
+}
+
+impl<T> Retry<T> {
+    pub fn new(inner: T, policy: RetryPolicy) -> Self {
+        Retry::with_clock(inner, policy, SystemClock)
+    }
+}
+
+impl<T, C: Clock> Retry<T, C> {
+    pub fn with_clock(inner: T, policy: RetryPolicy, clock: C) -> Self {
+        Retry {
+            inner,
+            policy,
+            clock,
+        }
+    }
+}
+
+impl<T: Transport, C: Clock> Retry<T, C> {
+    /// Sends `request` with retries even if it is not idempotent, for callers
+    /// who know a duplicate is harmless.
+    pub fn send_retrying(&self, request: &Request) -> Result<Response, Error> {
+        self.run(request, true)
+    }
+
+    fn run(&self, request: &Request, allowed: bool) -> Result<Response, Error> {
+        let mut retry = 0;
+        loop {
+            let outcome = self.inner.send(request);
+            if !allowed || retry >= self.policy.max_retries || !self.policy.should_retry(&outcome) {
+                return outcome;
+            }
+            let mut delay = jitter(self.policy.backoff(retry));
+            if let Ok(response) = &outcome {
+                if let Some(after) = response
+                    .headers
+                    .get("retry-after")
+                    .and_then(|v| v.trim().parse().ok())
+                {
+                    delay = delay.max(Duration::from_secs(after));
+                }
+            }
+            self.clock.sleep(delay);
+            retry += 1;
+        }
+    }
+}
+
+impl<T: Transport, C: Clock> Transport for Retry<T, C> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let allowed = self.policy.retry_non_idempotent || request.endpoint.is_idempotent();
+        self.run(request, allowed)
+    }
+
+    /// Sent once: the body has been read by the first attempt.
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        self.inner.send_body(request, body, len)
+    }
+}
+
+/// A uniformly random duration in `[0, max]`.
+fn jitter(max: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    let nanos = max.as_nanos() as u64;
+    Duration::from_nanos(random % nanos.saturating_add(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::UNIX_EPOCH;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::clock::FakeClock;
+    use crate::fake::{json, FakeGitHub};
+
+    fn s(text: &str) -> String {
+        text.to_string()
+    }
+
+    fn clock() -> FakeClock {
+        FakeClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    }
+
+    /// Answers `operation` with 503 `failures` times, then with 200.
+    fn flaky(github: &FakeGitHub, operation: EndPoints, failures: usize, retry_after: &str) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let retry_after = retry_after.to_string();
+        github.on(operation.name(), move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) < failures {
+                let mut response = json(503, &json!({ "message": "Service Unavailable" }));
+                if !retry_after.is_empty() {
+                    response.headers.insert("Retry-After", retry_after.clone());
+                }
+                response
+            } else {
+                json(200, &json!({}))
+            }
+        });
+    }
+
+    #[test]
+    fn everything_but_post_is_idempotent() {
+        assert!(EndPoints::GetReposownerrepoIssues(s("acme"), s("api")).is_idempotent());
+        assert!(
+            EndPoints::PutReposownerrepoContentspath(s("acme"), s("api"), s("README.md"))
+                .is_idempotent()
+        );
+        assert!(EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+            s("acme"),
+            s("api"),
+            s("1"),
+            s("bug")
+        )
+        .is_idempotent());
+        assert!(
+            EndPoints::PatchReposownerrepoIssuesissueNumber(s("acme"), s("api"), s("1"))
+                .is_idempotent()
+        );
+        assert!(!EndPoints::PostReposownerrepoIssues(s("acme"), s("api")).is_idempotent());
+        assert!(!EndPoints::PostReposownerrepoIssuesissueNumberComments(
+            s("acme"),
+            s("api"),
+            s("1")
+        )
+        .is_idempotent());
+        assert!(!EndPoints::PostReposownerrepoGitCommits(s("acme"), s("api")).is_idempotent());
+    }
+
+    #[test]
+    fn posts_that_cannot_duplicate_are_idempotent() {
+        for endpoint in [
+            EndPoints::PostMarkdown(),
+            EndPoints::PostReposownerrepoGitBlobs(s("acme"), s("api")),
+            EndPoints::PostReposownerrepoGitTrees(s("acme"), s("api")),
+            EndPoints::PostReposownerrepoIssuesissueNumberLabels(s("acme"), s("api"), s("1")),
+            EndPoints::PostAppInstallationsinstallationIdAccessTokens(s("42")),
+            EndPoints::PostOrgsorgActionsRunnersRegistrationToken(s("acme")),
+        ] {
+            assert!(endpoint.is_idempotent(), "{}", endpoint.name());
+        }
+    }
+
+    #[test]
+    fn jitter_stays_within_zero_and_max() {
+        assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+        assert!(jitter(Duration::from_nanos(1)) <= Duration::from_nanos(1));
+        let max = Duration::from_millis(500);
+        let delays: Vec<Duration> = (0..1000).map(|_| jitter(max)).collect();
+        assert!(delays.iter().all(|d| *d <= max));
+        // Full jitter: spread over the range rather than stuck at one end.
+        assert!(delays.iter().any(|d| *d < max / 2));
+        assert!(delays.iter().any(|d| *d > max / 2));
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(10), Duration::from_secs(30));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn retries_idempotent_calls_with_jittered_backoff() {
+        let github = FakeGitHub::start().unwrap();
+        let read = EndPoints::GetReposownerrepoIssues(s("acme"), s("api"));
+        flaky(&github, read.clone(), 2, "");
+        let clock = clock();
+        let retry = Retry::with_clock(&github, RetryPolicy::default(), clock.clone());
+
+        assert_eq!(retry.send(&Request::new(read.clone())).unwrap().status, 200);
+        let sleeps = clock.sleeps();
+        assert_eq!(sleeps.len(), 2);
+        assert!(sleeps[0] <= Duration::from_millis(500));
+        assert!(sleeps[1] <= Duration::from_secs(1));
+        assert_eq!(github.calls(read.name()).len(), 3);
+    }
+
+    #[test]
+    fn retry_after_sets_a_lower_bound() {
+        let github = FakeGitHub::start().unwrap();
+        let read = EndPoints::GetReposownerrepoIssues(s("acme"), s("api"));
+        flaky(&github, read.clone(), 1, " 7 ");
+        let clock = clock();
+        let retry = Retry::with_clock(&github, RetryPolicy::default(), clock.clone());
+
+        retry.send(&Request::new(read)).unwrap();
+        assert_eq!(clock.sleeps(), [Duration::from_secs(7)]);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries() {
+        let github = FakeGitHub::start().unwrap();
+        let read = EndPoints::GetReposownerrepoIssues(s("acme"), s("api"));
+        flaky(&github, read.clone(), usize::MAX, "");
+        let clock = clock();
+        let policy = RetryPolicy {
+            max_retries: 2,
+            ..RetryPolicy::default()
+        };
+        let retry = Retry::with_clock(&github, policy, clock.clone());
+
+        assert_eq!(retry.send(&Request::new(read.clone())).unwrap().status, 503);
+        assert_eq!(github.calls(read.name()).len(), 3);
+        assert_eq!(clock.sleeps().len(), 2);
+    }
+
+    #[test]
+    fn non_idempotent_calls_are_sent_once_unless_asked() {
+        let github = FakeGitHub::start().unwrap();
+        let create = EndPoints::PostReposownerrepoIssues(s("acme"), s("api"));
+        flaky(&github, create.clone(), 1, "");
+        let clock = clock();
+        let retry = Retry::with_clock(&github, RetryPolicy::default(), clock.clone());
+
+        assert_eq!(
+            retry.send(&Request::new(create.clone())).unwrap().status,
+            503
+        );
+        assert!(clock.sleeps().is_empty());
+
+        flaky(&github, create.clone(), 1, "");
+        assert_eq!(
+            retry
+                .send_retrying(&Request::new(create.clone()))
+                .unwrap()
+                .status,
+            200
+        );
+        assert_eq!(github.calls(create.name()).len(), 3);
+    }
+
+    #[test]
+    fn other_statuses_are_not_retried() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoIssues, |_| {
+            json(404, &json!({ "message": "Not Found" }))
+        });
+        let clock = clock();
+        let retry = Retry::with_clock(&github, RetryPolicy::default(), clock.clone());
+
+        let read = Request::new(EndPoints::GetReposownerrepoIssues(s("acme"), s("api")));
+        assert_eq!(retry.send(&read).unwrap().status, 404);
+        assert!(clock.sleeps().is_empty());
+    }
+}
@@ -0,0 +1,73 @@
+//! Reverse routing: from a method and a concrete path back to the [`EndPoints`] call.
+
+use crate::end_points::{EndPoints, Methods};
+use crate::http::decode_component;
+
+/// Parameters that may span several path segments, like `heads/feature/x` or `docs/README.md`.
+const MULTI_SEGMENT: [&str; 5] = ["path", "ref", "branch", "basehead", "dir"];
+
+impl EndPoints {
+    /// Finds the call that [`EndPoints::path`] would render as `path`.
+    ///
+    /// When several templates match, the one with the most literal segments wins,
+    /// so `/gists/starred` is `GetGistsStarred` rather than `GetGistsgistId`.
+    pub fn from_request(method: Methods, path: &str) -> Option<EndPoints> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        let segments = split(path);
+        EndPoints::ROUTES
+            .iter()
+            .filter(|(_, m, _)| *m == method)
+            .filter_map(|(name, _, template)| {
+                let template = split(template);
+                let args = match_segments(&template, &segments)?;
+                let literals = template.iter().filter(|s| !s.starts_with('{')).count();
+                Some((literals, name, args))
+            })
+            .max_by_key(|(literals, _, _)| *literals)
+            .and_then(|(_, name, args)| EndPoints::from_parts(name, args))
+    }
+
+    /// The path parameters of this call by template name, e.g. `("owner", "octocat")`.
+    /// Read from the variant's fields, so values containing `/` come back whole.
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        split(self.template())
+            .into_iter()
+            .filter_map(|s| s.strip_prefix('{').and_then(|p| p.strip_suffix('}')))
+            .zip(self.args())
+            .map(|(name, value)| (name, value.to_string()))
+            .collect()
+    }
+}
+
+fn split(path: &str) -> Vec<&str> {
+    path.split('/').filter(|s| !s.is_empty()).collect()
+}
+
+fn match_segments(template: &[&str], path: &[&str]) -> Option<Vec<String>> {
+    let Some((first, template_rest)) = template.split_first() else {
+        return path.is_empty().then(Vec::new);
+    };
+    let Some(param) = first.strip_prefix('{').and_then(|p| p.strip_suffix('}')) else {
+        let (segment, path_rest) = path.split_first()?;
+        return if segment == first {
+            match_segments(template_rest, path_rest)
+        } else {
+            None
+        };
+    };
+    let max = if MULTI_SEGMENT.contains(&param) {
+        path.len() - template_rest.len().min(path.len())
+    } else {
+        1
+    };
+    (1..=max.min(path.len())).find_map(|take| {
+        let mut rest = match_segments(template_rest, &path[take..])?;
+        let value = path[..take]
+            .iter()
+            .map(|s| decode_component(s))
+            .collect::<Vec<_>>()
+            .join("/");
+        rest.insert(0, value);
+        Some(rest)
+    })
+}
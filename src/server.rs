@@ -0,0 +1,333 @@
+//! Just enough HTTP/1.1 to serve localhost test traffic and webhook
+//! deliveries: one request per connection, `Content-Length` bodies only.
+//!
+//! Every connection gets a thread, so [`Limits`] bounds how many there are,
+//! how long each may sit idle and how much it may send.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::http::{decode_component, Headers, Response};
+
+/// Largest body accepted, GitHub's webhook payload cap.
+const MAX_BODY: usize = 25 * 1024 * 1024;
+/// Longest request or header line.
+const MAX_LINE: u64 = 8 * 1024;
+const MAX_HEADERS: usize = 100;
+/// The most a body buffer starts with; it grows with what the client actually
+/// sends, not with what its `Content-Length` claims.
+const CHUNK: usize = 64 * 1024;
+
+/// Bounds on what clients can hold on to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Limits {
+    /// Connections served at once; more are answered `503` and closed.
+    pub max_connections: usize,
+    /// How long a read may wait for the client before the connection is dropped.
+    pub read_timeout: Duration,
+    pub max_body: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_connections: 64,
+            read_timeout: Duration::from_secs(10),
+            max_body: MAX_BODY,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RawRequest {
+    pub method: String,
+    pub path: String,
+    pub query: Vec<(String, String)>,
+    pub headers: Headers,
+    pub body: Vec<u8>,
+}
+
+pub(crate) type RawHandler = Arc<dyn Fn(RawRequest) -> Response + Send + Sync>;
+
+/// Accept loop on its own thread, stopped on drop.
+#[derive(Debug)]
+pub(crate) struct HttpServer {
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl HttpServer {
+    pub fn bind(addr: impl ToSocketAddrs, handler: RawHandler) -> io::Result<Self> {
+        HttpServer::bind_with(addr, handler, Limits::default())
+    }
+
+    pub fn bind_with(
+        addr: impl ToSocketAddrs,
+        handler: RawHandler,
+        limits: Limits,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let stop = shutdown.clone();
+        let active = Arc::new(AtomicUsize::new(0));
+        let thread = std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                if active.fetch_add(1, Ordering::SeqCst) >= limits.max_connections {
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    let _ = stream.set_write_timeout(Some(Duration::from_secs(1)));
+                    let _ = write_response(&stream, &busy());
+                    continue;
+                }
+                let handler = handler.clone();
+                let active = active.clone();
+                std::thread::spawn(move || {
+                    let _ = serve_connection(stream, &*handler, &limits);
+                    active.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        Ok(HttpServer {
+            addr,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+}
+
+impl Drop for HttpServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        // Wake the blocking accept so the loop sees the flag.
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn busy() -> Response {
+    Response {
+        status: 503,
+        headers: Headers::new(),
+        body: b"too many connections".to_vec(),
+    }
+}
+
+fn serve_connection(
+    stream: TcpStream,
+    handler: &(dyn Fn(RawRequest) -> Response + Send + Sync),
+    limits: &Limits,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(limits.read_timeout))?;
+    stream.set_write_timeout(Some(limits.read_timeout))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader, limits.max_body) {
+        Ok(Some(request)) => handler(request),
+        Ok(None) => return Ok(()),
+        // The client went quiet; there is nobody to answer.
+        Err(e)
+            if matches!(
+                e.kind(),
+                io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+            ) =>
+        {
+            return Err(e)
+        }
+        Err(e) => Response {
+            status: 400,
+            headers: Headers::new(),
+            body: e.to_string().into_bytes(),
+        },
+    };
+    write_response(stream, &response)
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Reads one line of at most [`MAX_LINE`] bytes, `0` at the end of input.
+fn read_line(reader: &mut impl BufRead, line: &mut String) -> io::Result<usize> {
+    let read = reader.take(MAX_LINE).read_line(line)?;
+    if read as u64 == MAX_LINE && !line.ends_with('\n') {
+        return Err(invalid("line too long"));
+    }
+    Ok(read)
+}
+
+pub(crate) fn read_request(
+    reader: &mut impl BufRead,
+    max_body: usize,
+) -> io::Result<Option<RawRequest>> {
+    let mut line = String::new();
+    if read_line(reader, &mut line)? == 0 {
+        return Ok(None);
+    }
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Err(invalid("bad request line"));
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+    let mut headers = Headers::new();
+    loop {
+        line.clear();
+        read_line(reader, &mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if headers.iter().count() >= MAX_HEADERS {
+            return Err(invalid("too many headers"));
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            headers.append(name.trim(), value.trim());
+        }
+    }
+    let length = headers
+        .get("content-length")
+        .map(|l| l.parse::<usize>())
+        .transpose()
+        .map_err(|_| invalid("bad content-length"))?
+        .unwrap_or(0);
+    if length > max_body {
+        return Err(invalid("body too large"));
+    }
+    let mut body = Vec::with_capacity(length.min(CHUNK));
+    reader.take(length as u64).read_to_end(&mut body)?;
+    if body.len() < length {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "body shorter than content-length",
+        ));
+    }
+    let (path, query) = match target.split_once('?') {
+        Some((path, query)) => (path.to_string(), parse_query(query)),
+        None => (target, Vec::new()),
+    };
+    Ok(Some(RawRequest {
+        method,
+        path,
+        query,
+        headers,
+        body,
+    }))
+}
+
+pub(crate) fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let decode = |s: &str| decode_component(&s.replace('+', " "));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+pub(crate) fn write_response(mut stream: impl Write, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        reason(response.status)
+    )?;
+    for (name, value) in response.headers.iter() {
+        if !name.eq_ignore_ascii_case("content-length") && !name.eq_ignore_ascii_case("connection")
+        {
+            write!(stream, "{}: {}\r\n", name, value)?;
+        }
+    }
+    write!(
+        stream,
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        501 => "Not Implemented",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(raw: &[u8], max_body: usize) -> io::Result<Option<RawRequest>> {
+        read_request(&mut &raw[..], max_body)
+    }
+
+    #[test]
+    fn reads_a_request() {
+        let request = read(
+            b"POST /hooks?a=1&b=x+y HTTP/1.1\r\nContent-Length: 2\r\n\r\nhi",
+            10,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            (request.method.as_str(), request.path.as_str()),
+            ("POST", "/hooks")
+        );
+        assert_eq!(request.query[1], ("b".to_string(), "x y".to_string()));
+        assert_eq!(request.body, b"hi");
+    }
+
+    #[test]
+    fn rejects_bodies_over_the_limit_before_reading_them() {
+        let error = read(b"POST / HTTP/1.1\r\nContent-Length: 26214400\r\n\r\n", 1024).unwrap_err();
+        assert_eq!(error.to_string(), "body too large");
+    }
+
+    #[test]
+    fn rejects_bodies_shorter_than_claimed() {
+        let error = read(b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nhi", 1024).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn rejects_overlong_lines_and_header_floods() {
+        let mut long = b"GET /".to_vec();
+        long.resize(long.len() + MAX_LINE as usize, b'a');
+        assert_eq!(read(&long, 0).unwrap_err().to_string(), "line too long");
+
+        let mut flood = b"GET / HTTP/1.1\r\n".to_vec();
+        for i in 0..=MAX_HEADERS {
+            flood.extend(format!("X-{}: 1\r\n", i).as_bytes());
+        }
+        assert_eq!(read(&flood, 0).unwrap_err().to_string(), "too many headers");
+    }
+}
@@ -0,0 +1,2047 @@
+//! A stateful, in-memory GitHub: repositories, issues, labels, milestones,
+//! comments, pull requests and reviews.
+//!
+//! Unlike the stubs of [`FakeGitHub`], state carries over between calls: an issue
+//! created with `PostReposownerrepoIssues` shows up in `GetReposownerrepoIssues`.
+//! Lists paginate with `per_page`, `page` and a `Link` header, and missing
+//! resources are `404`s with GitHub's error bodies.
+//!
+//! ```no_run
+//! use github_api_octocat::end_points::EndPoints;
+//! use github_api_octocat::http::{Request, Transport};
+//! use github_api_octocat::simulator::Simulator;
+//! use serde_json::json;
+//!
+//! let github = Simulator::new().repo("octocat", "hello-world");
+//! let create = Request::new(EndPoints::PostReposownerrepoIssues(
+//!     "octocat".to_string(),
+//!     "hello-world".to_string(),
+//! ))
+//! .json(&json!({ "title": "Found a bug", "labels": ["bug"] }))
+//! .unwrap();
+//! github.execute(&create).unwrap();
+//! ```
+
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use crate::end_points::EndPoints;
+use crate::error::Error;
+use crate::fake::{json, FakeGitHub};
+use crate::http::{encode_component, ApiBase, Headers, Request, Response, Transport};
+use crate::util::{civil_from_days, djb2};
+
+const DEFAULT_PER_PAGE: usize = 30;
+const MAX_PER_PAGE: usize = 100;
+const DOCUMENTATION_URL: &str = "https://docs.github.com/rest";
+
+/// Operations the simulator implements. Everything else is answered with `501`.
+const OPERATIONS: &[&str] = &[
+    "GetUserRepos",
+    "PostUserRepos",
+    "GetOrgsorgRepos",
+    "PostOrgsorgRepos",
+    "GetReposownerrepo",
+    "PatchReposownerrepo",
+    "DeleteReposownerrepo",
+    "GetReposownerrepoIssues",
+    "PostReposownerrepoIssues",
+    "GetReposownerrepoIssuesissueNumber",
+    "PatchReposownerrepoIssuesissueNumber",
+    "GetReposownerrepoIssuesComments",
+    "GetReposownerrepoIssuesCommentscommentId",
+    "PatchReposownerrepoIssuesCommentscommentId",
+    "DeleteReposownerrepoIssuesCommentscommentId",
+    "GetReposownerrepoIssuesissueNumberComments",
+    "PostReposownerrepoIssuesissueNumberComments",
+    "GetReposownerrepoIssuesissueNumberLabels",
+    "PostReposownerrepoIssuesissueNumberLabels",
+    "PutReposownerrepoIssuesissueNumberLabels",
+    "DeleteReposownerrepoIssuesissueNumberLabels",
+    "DeleteReposownerrepoIssuesissueNumberLabelsname",
+    "GetReposownerrepoLabels",
+    "PostReposownerrepoLabels",
+    "GetReposownerrepoLabelsname",
+    "PatchReposownerrepoLabelsname",
+    "DeleteReposownerrepoLabelsname",
+    "GetReposownerrepoMilestones",
+    "PostReposownerrepoMilestones",
+    "GetReposownerrepoMilestonesmilestoneNumber",
+    "PatchReposownerrepoMilestonesmilestoneNumber",
+    "DeleteReposownerrepoMilestonesmilestoneNumber",
+    "GetReposownerrepoMilestonesmilestoneNumberLabels",
+    "GetReposownerrepoPulls",
+    "PostReposownerrepoPulls",
+    "GetReposownerrepoPullspullNumber",
+    "PatchReposownerrepoPullspullNumber",
+    "GetReposownerrepoPullspullNumberMerge",
+    "PutReposownerrepoPullspullNumberMerge",
+    "GetReposownerrepoPullspullNumberReviews",
+    "PostReposownerrepoPullspullNumberReviews",
+    "GetReposownerrepoPullspullNumberReviewsreviewId",
+    "PutReposownerrepoPullspullNumberReviewsreviewId",
+    "DeleteReposownerrepoPullspullNumberReviewsreviewId",
+    "PostReposownerrepoPullspullNumberReviewsreviewIdEvents",
+    "PutReposownerrepoPullspullNumberReviewsreviewIdDismissals",
+];
+
+/// A response either way; `Err` short-circuits with `?`.
+type Reply = Result<Response, Response>;
+
+#[derive(Debug, Clone)]
+struct Label {
+    id: u64,
+    name: String,
+    color: String,
+    description: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Milestone {
+    id: u64,
+    number: u64,
+    title: String,
+    description: Option<String>,
+    state: String,
+    due_on: Option<String>,
+    created_at: String,
+    closed_at: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PullRequest {
+    head: String,
+    base: String,
+    draft: bool,
+    merged: bool,
+    merge_commit_sha: Option<String>,
+}
+
+/// Issues and pull requests share one numbering, as on GitHub.
+#[derive(Debug, Clone)]
+struct Issue {
+    id: u64,
+    number: u64,
+    title: String,
+    body: Option<String>,
+    user: String,
+    state: String,
+    state_reason: Option<String>,
+    /// Label names; rendered from the repository's labels so renames show up.
+    labels: Vec<String>,
+    milestone: Option<u64>,
+    created_at: String,
+    updated_at: String,
+    closed_at: Option<String>,
+    pull: Option<PullRequest>,
+}
+
+#[derive(Debug, Clone)]
+struct Comment {
+    id: u64,
+    issue_number: u64,
+    body: String,
+    user: String,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Debug, Clone)]
+struct Review {
+    id: u64,
+    pull_number: u64,
+    body: String,
+    state: String,
+    user: String,
+    submitted_at: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct Repo {
+    id: u64,
+    owner: String,
+    name: String,
+    private: bool,
+    description: Option<String>,
+    default_branch: String,
+    issues: Vec<Issue>,
+    labels: Vec<Label>,
+    milestones: Vec<Milestone>,
+    comments: Vec<Comment>,
+    reviews: Vec<Review>,
+}
+
+impl Repo {
+    fn new(id: u64, owner: &str, name: &str) -> Self {
+        Repo {
+            id,
+            owner: owner.to_string(),
+            name: name.to_string(),
+            private: false,
+            description: None,
+            default_branch: "main".to_string(),
+            issues: Vec::new(),
+            labels: Vec::new(),
+            milestones: Vec::new(),
+            comments: Vec::new(),
+            reviews: Vec::new(),
+        }
+    }
+
+    fn issue(&mut self, number: &str) -> Result<&mut Issue, Response> {
+        let number = parse_number(number)?;
+        self.issues
+            .iter_mut()
+            .find(|i| i.number == number)
+            .ok_or_else(not_found)
+    }
+
+    fn pull(&mut self, number: &str) -> Result<&mut Issue, Response> {
+        let issue = self.issue(number)?;
+        if issue.pull.is_none() {
+            return Err(not_found());
+        }
+        Ok(issue)
+    }
+
+    fn label(&self, name: &str) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|l| l.name.eq_ignore_ascii_case(name))
+    }
+
+    fn milestone(&mut self, number: &str) -> Result<&mut Milestone, Response> {
+        let number = parse_number(number)?;
+        self.milestones
+            .iter_mut()
+            .find(|m| m.number == number)
+            .ok_or_else(not_found)
+    }
+
+    fn comment(&mut self, id: &str) -> Result<&mut Comment, Response> {
+        let id = parse_number(id)?;
+        self.comments
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or_else(not_found)
+    }
+
+    fn review(&mut self, pull_number: &str, id: &str) -> Result<&mut Review, Response> {
+        let (pull_number, id) = (parse_number(pull_number)?, parse_number(id)?);
+        self.reviews
+            .iter_mut()
+            .find(|r| r.id == id && r.pull_number == pull_number)
+            .ok_or_else(not_found)
+    }
+
+    fn next_number(&self) -> u64 {
+        self.issues.iter().map(|i| i.number).max().unwrap_or(0) + 1
+    }
+
+    /// Canonical names for `names`, creating labels that do not exist yet like
+    /// GitHub does when labels are added to an issue.
+    fn ensure_labels(&mut self, names: &[String], next_id: impl Fn() -> u64) -> Vec<String> {
+        names
+            .iter()
+            .map(|name| match self.label(name) {
+                Some(label) => label.name.clone(),
+                None => {
+                    self.labels.push(Label {
+                        id: next_id(),
+                        name: name.clone(),
+                        color: "ededed".to_string(),
+                        description: None,
+                    });
+                    name.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    repos: Vec<Repo>,
+}
+
+impl State {
+    fn repo(&mut self, owner: &str, name: &str) -> Result<&mut Repo, Response> {
+        self.repos
+            .iter_mut()
+            .find(|r| r.owner.eq_ignore_ascii_case(owner) && r.name.eq_ignore_ascii_case(name))
+            .ok_or_else(not_found)
+    }
+
+    fn create_repo(&mut self, id: u64, owner: &str, body: &Value) -> Result<&Repo, Response> {
+        let name =
+            text(body, "name").ok_or_else(|| invalid("Repository", "name", "missing_field"))?;
+        if self.repo(owner, &name).is_ok() {
+            return Err(invalid("Repository", "name", "already_exists"));
+        }
+        let mut repo = Repo::new(id, owner, &name);
+        repo.private = body["private"].as_bool().unwrap_or(false);
+        repo.description = text(body, "description");
+        self.repos.push(repo);
+        Ok(self.repos.last().expect("just pushed"))
+    }
+}
+
+/// See the [module docs](self).
+#[derive(Debug)]
+pub struct Simulator {
+    login: String,
+    base: ApiBase,
+    state: Mutex<State>,
+    last_id: AtomicU64,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Simulator::new()
+    }
+}
+
+impl Simulator {
+    /// An empty GitHub, called as the user `octocat`.
+    pub fn new() -> Self {
+        Simulator::with_login("octocat")
+    }
+
+    /// An empty GitHub, called as `login`. It authors everything created through the API.
+    pub fn with_login(login: impl Into<String>) -> Self {
+        Simulator {
+            login: login.into(),
+            base: ApiBase::github(),
+            state: Mutex::new(State::default()),
+            last_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds an empty repository without going through the API.
+    pub fn repo(self, owner: &str, name: &str) -> Self {
+        let repo = Repo::new(self.next_id(), owner, name);
+        self.state.lock().unwrap().repos.push(repo);
+        self
+    }
+
+    /// Answers `request` from, and applies it to, the simulated state.
+    pub fn handle(&self, request: &Request) -> Response {
+        let mut state = self.state.lock().unwrap();
+        match self.route(&mut state, request) {
+            Ok(response) | Err(response) => response,
+        }
+    }
+
+    /// Serves the simulator on localhost. URLs in responses point at the fake.
+    pub fn serve(mut self) -> io::Result<FakeGitHub> {
+        let github = FakeGitHub::start()?;
+        self.base = github.base();
+        let simulator = Arc::new(self);
+        for operation in OPERATIONS {
+            let simulator = simulator.clone();
+            github.on(*operation, move |request| simulator.handle(request));
+        }
+        Ok(github)
+    }
+
+    fn next_id(&self) -> u64 {
+        self.last_id.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn route(&self, state: &mut State, request: &Request) -> Reply {
+        let body = request_body(request)?;
+        match &request.endpoint {
+            EndPoints::GetUserRepos() => {
+                let repos = state
+                    .repos
+                    .iter()
+                    .filter(|r| r.owner == self.login)
+                    .map(|r| self.render_repo(r))
+                    .collect();
+                self.page(request, repos)
+            }
+            EndPoints::GetOrgsorgRepos(org) => {
+                let repos = state
+                    .repos
+                    .iter()
+                    .filter(|r| r.owner.eq_ignore_ascii_case(org))
+                    .map(|r| self.render_repo(r))
+                    .collect();
+                self.page(request, repos)
+            }
+            EndPoints::PostUserRepos() => {
+                let repo = state.create_repo(self.next_id(), &self.login, &body)?;
+                created(self.render_repo(repo))
+            }
+            EndPoints::PostOrgsorgRepos(org) => {
+                let repo = state.create_repo(self.next_id(), org, &body)?;
+                created(self.render_repo(repo))
+            }
+            EndPoints::GetReposownerrepo(owner, repo) => {
+                ok(self.render_repo(state.repo(owner, repo)?))
+            }
+            EndPoints::PatchReposownerrepo(owner, repo) => {
+                state.repo(owner, repo)?;
+                let name = text(&body, "name");
+                if let Some(name) = &name {
+                    // Renaming to another case of its own name is fine.
+                    let taken = state.repos.iter().any(|r| {
+                        r.owner.eq_ignore_ascii_case(owner)
+                            && r.name.eq_ignore_ascii_case(name)
+                            && !r.name.eq_ignore_ascii_case(repo)
+                    });
+                    if taken {
+                        return Err(invalid("Repository", "name", "already_exists"));
+                    }
+                }
+                let repo = state.repo(owner, repo)?;
+                if let Some(name) = name {
+                    repo.name = name;
+                }
+                if body.get("description").is_some() {
+                    repo.description = text(&body, "description");
+                }
+                if let Some(private) = body["private"].as_bool() {
+                    repo.private = private;
+                }
+                if let Some(branch) = text(&body, "default_branch") {
+                    repo.default_branch = branch;
+                }
+                ok(self.render_repo(repo))
+            }
+            EndPoints::DeleteReposownerrepo(owner, repo) => {
+                state.repo(owner, repo)?;
+                state.repos.retain(|r| {
+                    !(r.owner.eq_ignore_ascii_case(owner) && r.name.eq_ignore_ascii_case(repo))
+                });
+                Ok(no_content())
+            }
+
+            EndPoints::GetReposownerrepoIssues(owner, repo) => {
+                let repo = state.repo(owner, repo)?;
+                let state_filter = param(request, "state").unwrap_or("open");
+                let labels: Vec<&str> = param(request, "labels")
+                    .map(|l| {
+                        l.split(',')
+                            .map(str::trim)
+                            .filter(|l| !l.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let milestone = param(request, "milestone");
+                let mut issues: Vec<&Issue> = repo
+                    .issues
+                    .iter()
+                    .filter(|i| state_filter == "all" || i.state == state_filter)
+                    .filter(|i| {
+                        labels
+                            .iter()
+                            .all(|l| i.labels.iter().any(|have| have.eq_ignore_ascii_case(l)))
+                    })
+                    .filter(|i| match milestone {
+                        None => true,
+                        Some("*") => i.milestone.is_some(),
+                        Some("none") => i.milestone.is_none(),
+                        Some(number) => {
+                            i.milestone.map(|m| m.to_string()).as_deref() == Some(number)
+                        }
+                    })
+                    .collect();
+                sort_newest_first(request, &mut issues);
+                let issues = issues
+                    .into_iter()
+                    .map(|i| self.render_issue(repo, i))
+                    .collect();
+                self.page(request, issues)
+            }
+            EndPoints::PostReposownerrepoIssues(owner, repo) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let title = text(&body, "title")
+                    .ok_or_else(|| invalid("Issue", "title", "missing_field"))?;
+                let milestone = match body.get("milestone") {
+                    None | Some(Value::Null) => None,
+                    Some(number) => Some(existing_milestone(repo, number)?),
+                };
+                let labels = repo.ensure_labels(&names(&body["labels"]), || self.next_id());
+                let now = timestamp();
+                let issue = Issue {
+                    id,
+                    number: repo.next_number(),
+                    title,
+                    body: text(&body, "body"),
+                    user: self.login.clone(),
+                    state: "open".to_string(),
+                    state_reason: None,
+                    labels,
+                    milestone,
+                    created_at: now.clone(),
+                    updated_at: now,
+                    closed_at: None,
+                    pull: None,
+                };
+                // Stored first so the milestone's counts include it.
+                repo.issues.push(issue);
+                created(self.render_issue(repo, repo.issues.last().unwrap()))
+            }
+            EndPoints::GetReposownerrepoIssuesissueNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let issue = repo.issue(number)?.clone();
+                ok(self.render_issue(repo, &issue))
+            }
+            EndPoints::PatchReposownerrepoIssuesissueNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                // A missing issue must not leave new labels behind.
+                repo.issue(number)?;
+                let milestone = match body.get("milestone") {
+                    None => None,
+                    Some(Value::Null) => Some(None),
+                    Some(number) => Some(Some(existing_milestone(repo, number)?)),
+                };
+                let labels = body
+                    .get("labels")
+                    .map(|labels| repo.ensure_labels(&names(labels), || self.next_id()));
+                let issue = repo.issue(number)?;
+                if let Some(title) = text(&body, "title") {
+                    issue.title = title;
+                }
+                if body.get("body").is_some() {
+                    issue.body = text(&body, "body");
+                }
+                if let Some(new_state) = text(&body, "state") {
+                    set_state(issue, &new_state, text(&body, "state_reason"))?;
+                }
+                if let Some(labels) = labels {
+                    issue.labels = labels;
+                }
+                if let Some(milestone) = milestone {
+                    issue.milestone = milestone;
+                }
+                issue.updated_at = timestamp();
+                let issue = issue.clone();
+                ok(self.render_issue(repo, &issue))
+            }
+
+            EndPoints::GetReposownerrepoIssuesComments(owner, repo) => {
+                let repo = state.repo(owner, repo)?;
+                let comments = repo
+                    .comments
+                    .iter()
+                    .map(|c| self.render_comment(repo, c))
+                    .collect();
+                self.page(request, comments)
+            }
+            EndPoints::GetReposownerrepoIssuesissueNumberComments(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let number = repo.issue(number)?.number;
+                let comments = repo
+                    .comments
+                    .iter()
+                    .filter(|c| c.issue_number == number)
+                    .map(|c| self.render_comment(repo, c))
+                    .collect();
+                self.page(request, comments)
+            }
+            EndPoints::PostReposownerrepoIssuesissueNumberComments(owner, repo, number) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let text_body = text(&body, "body")
+                    .ok_or_else(|| invalid("IssueComment", "body", "missing_field"))?;
+                let issue = repo.issue(number)?;
+                let now = timestamp();
+                issue.updated_at = now.clone();
+                let comment = Comment {
+                    id,
+                    issue_number: issue.number,
+                    body: text_body,
+                    user: self.login.clone(),
+                    created_at: now.clone(),
+                    updated_at: now,
+                };
+                let rendered = self.render_comment(repo, &comment);
+                repo.comments.push(comment);
+                created(rendered)
+            }
+            EndPoints::GetReposownerrepoIssuesCommentscommentId(owner, repo, id) => {
+                let repo = state.repo(owner, repo)?;
+                let comment = repo.comment(id)?.clone();
+                ok(self.render_comment(repo, &comment))
+            }
+            EndPoints::PatchReposownerrepoIssuesCommentscommentId(owner, repo, id) => {
+                let repo = state.repo(owner, repo)?;
+                let text_body = text(&body, "body")
+                    .ok_or_else(|| invalid("IssueComment", "body", "missing_field"))?;
+                let comment = repo.comment(id)?;
+                comment.body = text_body;
+                comment.updated_at = timestamp();
+                let comment = comment.clone();
+                ok(self.render_comment(repo, &comment))
+            }
+            EndPoints::DeleteReposownerrepoIssuesCommentscommentId(owner, repo, id) => {
+                let repo = state.repo(owner, repo)?;
+                let id = repo.comment(id)?.id;
+                repo.comments.retain(|c| c.id != id);
+                Ok(no_content())
+            }
+
+            EndPoints::GetReposownerrepoIssuesissueNumberLabels(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let labels = repo.issue(number)?.labels.clone();
+                let labels = self.render_labels(repo, &labels);
+                self.page(request, labels)
+            }
+            EndPoints::PostReposownerrepoIssuesissueNumberLabels(owner, repo, number)
+            | EndPoints::PutReposownerrepoIssuesissueNumberLabels(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                repo.issue(number)?;
+                let wanted = match &body {
+                    Value::Array(_) => names(&body),
+                    _ => names(&body["labels"]),
+                };
+                let wanted = repo.ensure_labels(&wanted, || self.next_id());
+                let replace = matches!(
+                    request.endpoint,
+                    EndPoints::PutReposownerrepoIssuesissueNumberLabels(..)
+                );
+                let issue = repo.issue(number)?;
+                if replace {
+                    issue.labels.clear();
+                }
+                for label in wanted {
+                    if !issue.labels.contains(&label) {
+                        issue.labels.push(label);
+                    }
+                }
+                issue.updated_at = timestamp();
+                let labels = issue.labels.clone();
+                ok(Value::Array(self.render_labels(repo, &labels)))
+            }
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabels(owner, repo, number) => {
+                let issue = state.repo(owner, repo)?.issue(number)?;
+                issue.labels.clear();
+                issue.updated_at = timestamp();
+                Ok(no_content())
+            }
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+                owner,
+                repo,
+                number,
+                name,
+            ) => {
+                let repo = state.repo(owner, repo)?;
+                let issue = repo.issue(number)?;
+                let before = issue.labels.len();
+                issue.labels.retain(|l| !l.eq_ignore_ascii_case(name));
+                if issue.labels.len() == before {
+                    return Err(json(
+                        404,
+                        &json!({
+                            "message": "Label does not exist",
+                            "documentation_url": DOCUMENTATION_URL,
+                        }),
+                    ));
+                }
+                issue.updated_at = timestamp();
+                let labels = issue.labels.clone();
+                ok(Value::Array(self.render_labels(repo, &labels)))
+            }
+
+            EndPoints::GetReposownerrepoLabels(owner, repo) => {
+                let repo = state.repo(owner, repo)?;
+                let labels = repo
+                    .labels
+                    .iter()
+                    .map(|l| self.render_label(repo, l))
+                    .collect();
+                self.page(request, labels)
+            }
+            EndPoints::PostReposownerrepoLabels(owner, repo) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let name =
+                    text(&body, "name").ok_or_else(|| invalid("Label", "name", "missing_field"))?;
+                if repo.label(&name).is_some() {
+                    return Err(invalid("Label", "name", "already_exists"));
+                }
+                let label = Label {
+                    id,
+                    name,
+                    color: text(&body, "color")
+                        .map(|c| c.trim_start_matches('#').to_string())
+                        .unwrap_or_else(|| "ededed".to_string()),
+                    description: text(&body, "description"),
+                };
+                let rendered = self.render_label(repo, &label);
+                repo.labels.push(label);
+                created(rendered)
+            }
+            EndPoints::GetReposownerrepoLabelsname(owner, repo, name) => {
+                let repo = state.repo(owner, repo)?;
+                let label = repo.label(name).ok_or_else(not_found)?;
+                ok(self.render_label(repo, label))
+            }
+            EndPoints::PatchReposownerrepoLabelsname(owner, repo, name) => {
+                let repo = state.repo(owner, repo)?;
+                let old = repo.label(name).ok_or_else(not_found)?.name.clone();
+                let new_name = text(&body, "new_name").unwrap_or_else(|| old.clone());
+                if !new_name.eq_ignore_ascii_case(&old) && repo.label(&new_name).is_some() {
+                    return Err(invalid("Label", "name", "already_exists"));
+                }
+                let label = repo
+                    .labels
+                    .iter_mut()
+                    .find(|l| l.name == old)
+                    .expect("found above");
+                label.name = new_name.clone();
+                if let Some(color) = text(&body, "color") {
+                    label.color = color.trim_start_matches('#').to_string();
+                }
+                if body.get("description").is_some() {
+                    label.description = text(&body, "description");
+                }
+                for issue in &mut repo.issues {
+                    for label in issue.labels.iter_mut().filter(|l| **l == old) {
+                        *label = new_name.clone();
+                    }
+                }
+                let label = repo.label(&new_name).expect("renamed above");
+                ok(self.render_label(repo, label))
+            }
+            EndPoints::DeleteReposownerrepoLabelsname(owner, repo, name) => {
+                let repo = state.repo(owner, repo)?;
+                let old = repo.label(name).ok_or_else(not_found)?.name.clone();
+                repo.labels.retain(|l| l.name != old);
+                for issue in &mut repo.issues {
+                    issue.labels.retain(|l| *l != old);
+                }
+                Ok(no_content())
+            }
+
+            EndPoints::GetReposownerrepoMilestones(owner, repo) => {
+                let repo = state.repo(owner, repo)?;
+                let state_filter = param(request, "state").unwrap_or("open");
+                let milestones = repo
+                    .milestones
+                    .iter()
+                    .filter(|m| state_filter == "all" || m.state == state_filter)
+                    .map(|m| self.render_milestone(repo, m))
+                    .collect();
+                self.page(request, milestones)
+            }
+            EndPoints::PostReposownerrepoMilestones(owner, repo) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let title = text(&body, "title")
+                    .ok_or_else(|| invalid("Milestone", "title", "missing_field"))?;
+                if repo.milestones.iter().any(|m| m.title == title) {
+                    return Err(invalid("Milestone", "title", "already_exists"));
+                }
+                let milestone = Milestone {
+                    id,
+                    number: repo.milestones.iter().map(|m| m.number).max().unwrap_or(0) + 1,
+                    title,
+                    description: text(&body, "description"),
+                    state: text(&body, "state").unwrap_or_else(|| "open".to_string()),
+                    due_on: text(&body, "due_on"),
+                    created_at: timestamp(),
+                    closed_at: None,
+                };
+                let rendered = self.render_milestone(repo, &milestone);
+                repo.milestones.push(milestone);
+                created(rendered)
+            }
+            EndPoints::GetReposownerrepoMilestonesmilestoneNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let milestone = repo.milestone(number)?.clone();
+                ok(self.render_milestone(repo, &milestone))
+            }
+            EndPoints::PatchReposownerrepoMilestonesmilestoneNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let milestone = repo.milestone(number)?;
+                if let Some(title) = text(&body, "title") {
+                    milestone.title = title;
+                }
+                if body.get("description").is_some() {
+                    milestone.description = text(&body, "description");
+                }
+                if body.get("due_on").is_some() {
+                    milestone.due_on = text(&body, "due_on");
+                }
+                if let Some(new_state) = text(&body, "state") {
+                    milestone.closed_at = (new_state == "closed").then(timestamp);
+                    milestone.state = new_state;
+                }
+                let milestone = milestone.clone();
+                ok(self.render_milestone(repo, &milestone))
+            }
+            EndPoints::DeleteReposownerrepoMilestonesmilestoneNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let number = repo.milestone(number)?.number;
+                repo.milestones.retain(|m| m.number != number);
+                for issue in repo
+                    .issues
+                    .iter_mut()
+                    .filter(|i| i.milestone == Some(number))
+                {
+                    issue.milestone = None;
+                }
+                Ok(no_content())
+            }
+            EndPoints::GetReposownerrepoMilestonesmilestoneNumberLabels(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let number = repo.milestone(number)?.number;
+                let mut labels: Vec<String> = Vec::new();
+                for issue in repo.issues.iter().filter(|i| i.milestone == Some(number)) {
+                    for label in &issue.labels {
+                        if !labels.contains(label) {
+                            labels.push(label.clone());
+                        }
+                    }
+                }
+                let labels = self.render_labels(repo, &labels);
+                self.page(request, labels)
+            }
+
+            EndPoints::GetReposownerrepoPulls(owner, repo) => {
+                let repo = state.repo(owner, repo)?;
+                let state_filter = param(request, "state").unwrap_or("open");
+                let mut pulls: Vec<&Issue> = repo
+                    .issues
+                    .iter()
+                    .filter(|i| i.pull.is_some())
+                    .filter(|i| state_filter == "all" || i.state == state_filter)
+                    .filter(|i| {
+                        param(request, "base")
+                            .is_none_or(|b| i.pull.as_ref().is_some_and(|p| p.base == b))
+                    })
+                    .collect();
+                sort_newest_first(request, &mut pulls);
+                let pulls = pulls
+                    .into_iter()
+                    .map(|i| self.render_pull(repo, i))
+                    .collect();
+                self.page(request, pulls)
+            }
+            EndPoints::PostReposownerrepoPulls(owner, repo) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let title = text(&body, "title")
+                    .ok_or_else(|| invalid("PullRequest", "title", "missing_field"))?;
+                let head = text(&body, "head")
+                    .ok_or_else(|| invalid("PullRequest", "head", "missing_field"))?;
+                let base = text(&body, "base")
+                    .ok_or_else(|| invalid("PullRequest", "base", "missing_field"))?;
+                if head == base {
+                    return Err(invalid("PullRequest", "head", "invalid"));
+                }
+                let now = timestamp();
+                let issue = Issue {
+                    id,
+                    number: repo.next_number(),
+                    title,
+                    body: text(&body, "body"),
+                    user: self.login.clone(),
+                    state: "open".to_string(),
+                    state_reason: None,
+                    labels: Vec::new(),
+                    milestone: None,
+                    created_at: now.clone(),
+                    updated_at: now,
+                    closed_at: None,
+                    pull: Some(PullRequest {
+                        head,
+                        base,
+                        draft: body["draft"].as_bool().unwrap_or(false),
+                        merged: false,
+                        merge_commit_sha: None,
+                    }),
+                };
+                let rendered = self.render_pull(repo, &issue);
+                repo.issues.push(issue);
+                created(rendered)
+            }
+            EndPoints::GetReposownerrepoPullspullNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let pull = repo.pull(number)?.clone();
+                ok(self.render_pull(repo, &pull))
+            }
+            EndPoints::PatchReposownerrepoPullspullNumber(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let issue = repo.pull(number)?;
+                if let Some(title) = text(&body, "title") {
+                    issue.title = title;
+                }
+                if body.get("body").is_some() {
+                    issue.body = text(&body, "body");
+                }
+                if let Some(new_state) = text(&body, "state") {
+                    if issue.pull.as_ref().is_some_and(|p| p.merged) {
+                        return Err(invalid("PullRequest", "state", "invalid"));
+                    }
+                    set_state(issue, &new_state, None)?;
+                }
+                if let (Some(base), Some(pull)) = (text(&body, "base"), issue.pull.as_mut()) {
+                    pull.base = base;
+                }
+                issue.updated_at = timestamp();
+                let issue = issue.clone();
+                ok(self.render_pull(repo, &issue))
+            }
+            EndPoints::GetReposownerrepoPullspullNumberMerge(owner, repo, number) => {
+                let pull = state.repo(owner, repo)?.pull(number)?;
+                if pull.pull.as_ref().is_some_and(|p| p.merged) {
+                    Ok(no_content())
+                } else {
+                    Err(not_found())
+                }
+            }
+            EndPoints::PutReposownerrepoPullspullNumberMerge(owner, repo, number) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let issue = repo.pull(number)?;
+                if issue.state != "open" {
+                    return Err(json(
+                        405,
+                        &json!({
+                            "message": "Pull Request is not mergeable",
+                            "documentation_url": DOCUMENTATION_URL,
+                        }),
+                    ));
+                }
+                let sha = format!("{:040x}", id);
+                let now = timestamp();
+                issue.state = "closed".to_string();
+                issue.closed_at = Some(now.clone());
+                issue.updated_at = now;
+                let pull = issue.pull.as_mut().expect("checked by Repo::pull");
+                pull.merged = true;
+                pull.merge_commit_sha = Some(sha.clone());
+                ok(
+                    json!({ "sha": sha, "merged": true, "message": "Pull Request successfully merged" }),
+                )
+            }
+
+            EndPoints::GetReposownerrepoPullspullNumberReviews(owner, repo, number) => {
+                let repo = state.repo(owner, repo)?;
+                let number = repo.pull(number)?.number;
+                let reviews = repo
+                    .reviews
+                    .iter()
+                    .filter(|r| r.pull_number == number)
+                    .map(|r| self.render_review(repo, r))
+                    .collect();
+                self.page(request, reviews)
+            }
+            EndPoints::PostReposownerrepoPullspullNumberReviews(owner, repo, number) => {
+                let (repo, id) = (state.repo(owner, repo)?, self.next_id());
+                let issue = repo.pull(number)?;
+                let (review_state, submitted_at) = match text(&body, "event") {
+                    None => ("PENDING".to_string(), None),
+                    Some(event) => (review_state(&event, &body)?, Some(timestamp())),
+                };
+                if issue.user == self.login
+                    && review_state != "COMMENTED"
+                    && review_state != "PENDING"
+                {
+                    return Err(invalid("PullRequestReview", "event", "invalid"));
+                }
+                let review = Review {
+                    id,
+                    pull_number: issue.number,
+                    body: text(&body, "body").unwrap_or_default(),
+                    state: review_state,
+                    user: self.login.clone(),
+                    submitted_at,
+                };
+                let rendered = self.render_review(repo, &review);
+                repo.reviews.push(review);
+                ok(rendered)
+            }
+            EndPoints::GetReposownerrepoPullspullNumberReviewsreviewId(owner, repo, number, id) => {
+                let repo = state.repo(owner, repo)?;
+                let review = repo.review(number, id)?.clone();
+                ok(self.render_review(repo, &review))
+            }
+            EndPoints::PutReposownerrepoPullspullNumberReviewsreviewId(owner, repo, number, id) => {
+                let repo = state.repo(owner, repo)?;
+                let text_body = text(&body, "body")
+                    .ok_or_else(|| invalid("PullRequestReview", "body", "missing_field"))?;
+                let review = repo.review(number, id)?;
+                review.body = text_body;
+                let review = review.clone();
+                ok(self.render_review(repo, &review))
+            }
+            EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(
+                owner,
+                repo,
+                number,
+                id,
+            ) => {
+                let repo = state.repo(owner, repo)?;
+                let review = repo.review(number, id)?.clone();
+                if review.state != "PENDING" {
+                    return Err(json(
+                        422,
+                        &json!({
+                            "message": "Can not delete a non-pending pull request review",
+                            "documentation_url": DOCUMENTATION_URL,
+                        }),
+                    ));
+                }
+                repo.reviews.retain(|r| r.id != review.id);
+                ok(self.render_review(repo, &review))
+            }
+            EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(
+                owner,
+                repo,
+                number,
+                id,
+            ) => {
+                let repo = state.repo(owner, repo)?;
+                let event = text(&body, "event")
+                    .ok_or_else(|| invalid("PullRequestReview", "event", "missing_field"))?;
+                let new_state = review_state(&event, &body)?;
+                // The same rule as submitting straight away.
+                if repo.pull(number)?.user == self.login && new_state != "COMMENTED" {
+                    return Err(invalid("PullRequestReview", "event", "invalid"));
+                }
+                let review = repo.review(number, id)?;
+                if review.state != "PENDING" {
+                    return Err(invalid("PullRequestReview", "state", "invalid"));
+                }
+                review.state = new_state;
+                review.submitted_at = Some(timestamp());
+                if let Some(text_body) = text(&body, "body") {
+                    review.body = text_body;
+                }
+                let review = review.clone();
+                ok(self.render_review(repo, &review))
+            }
+            EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(
+                owner,
+                repo,
+                number,
+                id,
+            ) => {
+                let repo = state.repo(owner, repo)?;
+                text(&body, "message")
+                    .ok_or_else(|| invalid("PullRequestReview", "message", "missing_field"))?;
+                let review = repo.review(number, id)?;
+                if review.state != "APPROVED" && review.state != "CHANGES_REQUESTED" {
+                    return Err(invalid("PullRequestReview", "state", "invalid"));
+                }
+                review.state = "DISMISSED".to_string();
+                let review = review.clone();
+                ok(self.render_review(repo, &review))
+            }
+
+            other => Err(json(
+                501,
+                &json!({
+                    "message": format!("{} is not simulated", other.name()),
+                    "documentation_url": DOCUMENTATION_URL,
+                }),
+            )),
+        }
+    }
+
+    /// One page of `items`, selected by `per_page` and `page`, with a `Link` header.
+    fn page(&self, request: &Request, items: Vec<Value>) -> Reply {
+        let per_page = param(request, "per_page")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(DEFAULT_PER_PAGE)
+            .clamp(1, MAX_PER_PAGE);
+        let page = param(request, "page")
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(1usize)
+            .max(1);
+        let last = items.len().div_ceil(per_page).max(1);
+        let items: Vec<Value> = items
+            .into_iter()
+            .skip((page - 1) * per_page)
+            .take(per_page)
+            .collect();
+        let mut response = json(200, &items);
+        let mut links = Vec::new();
+        if page > 1 {
+            links.push((page - 1, "prev"));
+        }
+        if page < last {
+            links.push((page + 1, "next"));
+            links.push((last, "last"));
+        }
+        if page > 1 {
+            links.push((1, "first"));
+        }
+        if !links.is_empty() {
+            let links: Vec<String> = links
+                .into_iter()
+                .map(|(page, rel)| {
+                    let mut linked = request.clone();
+                    linked.query.retain(|(k, _)| k != "page");
+                    linked.query.push(("page".to_string(), page.to_string()));
+                    format!("<{}>; rel=\"{}\"", self.base.url(&linked), rel)
+                })
+                .collect();
+            response.headers.insert("Link", links.join(", "));
+        }
+        Ok(response)
+    }
+
+    fn api(&self) -> &str {
+        self.base.api.trim_end_matches('/')
+    }
+
+    fn web(&self) -> &str {
+        self.base.web.trim_end_matches('/')
+    }
+
+    fn render_user(&self, login: &str) -> Value {
+        // Stable across runs so fixtures can refer to it.
+        let id = djb2(login) % 100_000_000;
+        json!({
+            "login": login,
+            "id": id,
+            "type": "User",
+            "url": format!("{}/users/{}", self.api(), login),
+            "html_url": format!("{}/{}", self.web(), login),
+        })
+    }
+
+    fn render_repo(&self, repo: &Repo) -> Value {
+        json!({
+            "id": repo.id,
+            "name": repo.name,
+            "full_name": format!("{}/{}", repo.owner, repo.name),
+            "owner": self.render_user(&repo.owner),
+            "private": repo.private,
+            "description": repo.description,
+            "default_branch": repo.default_branch,
+            "has_issues": true,
+            "open_issues_count": repo.issues.iter().filter(|i| i.state == "open").count(),
+            "url": format!("{}/repos/{}/{}", self.api(), repo.owner, repo.name),
+            "html_url": format!("{}/{}/{}", self.web(), repo.owner, repo.name),
+        })
+    }
+
+    fn render_label(&self, repo: &Repo, label: &Label) -> Value {
+        json!({
+            "id": label.id,
+            "name": label.name,
+            "color": label.color,
+            "description": label.description,
+            "default": false,
+            "url": format!(
+                "{}/repos/{}/{}/labels/{}",
+                self.api(),
+                repo.owner,
+                repo.name,
+                encode_component(&label.name)
+            ),
+        })
+    }
+
+    fn render_labels(&self, repo: &Repo, names: &[String]) -> Vec<Value> {
+        names
+            .iter()
+            .filter_map(|name| repo.label(name))
+            .map(|label| self.render_label(repo, label))
+            .collect()
+    }
+
+    fn render_milestone(&self, repo: &Repo, milestone: &Milestone) -> Value {
+        let issues = repo
+            .issues
+            .iter()
+            .filter(|i| i.milestone == Some(milestone.number));
+        let open = issues.clone().filter(|i| i.state == "open").count();
+        let closed = issues.count() - open;
+        json!({
+            "id": milestone.id,
+            "number": milestone.number,
+            "title": milestone.title,
+            "description": milestone.description,
+            "state": milestone.state,
+            "due_on": milestone.due_on,
+            "open_issues": open,
+            "closed_issues": closed,
+            "creator": self.render_user(&self.login),
+            "created_at": milestone.created_at,
+            "closed_at": milestone.closed_at,
+            "url": format!(
+                "{}/repos/{}/{}/milestones/{}",
+                self.api(),
+                repo.owner,
+                repo.name,
+                milestone.number
+            ),
+        })
+    }
+
+    fn render_issue(&self, repo: &Repo, issue: &Issue) -> Value {
+        let url = format!(
+            "{}/repos/{}/{}/issues/{}",
+            self.api(),
+            repo.owner,
+            repo.name,
+            issue.number
+        );
+        let mut value = json!({
+            "id": issue.id,
+            "number": issue.number,
+            "title": issue.title,
+            "body": issue.body,
+            "user": self.render_user(&issue.user),
+            "state": issue.state,
+            "state_reason": issue.state_reason,
+            "labels": self.render_labels(repo, &issue.labels),
+            "milestone": issue
+                .milestone
+                .and_then(|n| repo.milestones.iter().find(|m| m.number == n))
+                .map(|m| self.render_milestone(repo, m)),
+            "comments": repo.comments.iter().filter(|c| c.issue_number == issue.number).count(),
+            "created_at": issue.created_at,
+            "updated_at": issue.updated_at,
+            "closed_at": issue.closed_at,
+            "url": url,
+            "html_url": format!("{}/{}/{}/issues/{}", self.web(), repo.owner, repo.name, issue.number),
+        });
+        if issue.pull.is_some() {
+            value["pull_request"] = json!({
+                "url": format!("{}/repos/{}/{}/pulls/{}", self.api(), repo.owner, repo.name, issue.number),
+                "html_url": format!("{}/{}/{}/pull/{}", self.web(), repo.owner, repo.name, issue.number),
+            });
+        }
+        value
+    }
+
+    fn render_pull(&self, repo: &Repo, issue: &Issue) -> Value {
+        let mut value = self.render_issue(repo, issue);
+        let pull = issue.pull.as_ref().expect("a pull request");
+        let object = value.as_object_mut().expect("an object");
+        object.remove("pull_request");
+        object.remove("comments");
+        object.insert(
+            "url".to_string(),
+            json!(format!(
+                "{}/repos/{}/{}/pulls/{}",
+                self.api(),
+                repo.owner,
+                repo.name,
+                issue.number
+            )),
+        );
+        object.insert(
+            "html_url".to_string(),
+            json!(format!(
+                "{}/{}/{}/pull/{}",
+                self.web(),
+                repo.owner,
+                repo.name,
+                issue.number
+            )),
+        );
+        object.insert(
+            "head".to_string(),
+            json!({
+                "ref": pull.head,
+                "label": format!("{}:{}", repo.owner, pull.head),
+                "sha": branch_sha(repo, &pull.head),
+            }),
+        );
+        object.insert(
+            "base".to_string(),
+            json!({
+                "ref": pull.base,
+                "label": format!("{}:{}", repo.owner, pull.base),
+                "sha": branch_sha(repo, &pull.base),
+            }),
+        );
+        object.insert("draft".to_string(), json!(pull.draft));
+        object.insert("merged".to_string(), json!(pull.merged));
+        object.insert("merge_commit_sha".to_string(), json!(pull.merge_commit_sha));
+        value
+    }
+
+    fn render_comment(&self, repo: &Repo, comment: &Comment) -> Value {
+        json!({
+            "id": comment.id,
+            "body": comment.body,
+            "user": self.render_user(&comment.user),
+            "created_at": comment.created_at,
+            "updated_at": comment.updated_at,
+            "url": format!("{}/repos/{}/{}/issues/comments/{}", self.api(), repo.owner, repo.name, comment.id),
+            "issue_url": format!("{}/repos/{}/{}/issues/{}", self.api(), repo.owner, repo.name, comment.issue_number),
+        })
+    }
+
+    fn render_review(&self, repo: &Repo, review: &Review) -> Value {
+        json!({
+            "id": review.id,
+            "body": review.body,
+            "state": review.state,
+            "user": self.render_user(&review.user),
+            "submitted_at": review.submitted_at,
+            "pull_request_url": format!("{}/repos/{}/{}/pulls/{}", self.api(), repo.owner, repo.name, review.pull_number),
+        })
+    }
+}
+
+impl Transport for Simulator {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        Ok(self.handle(request))
+    }
+}
+
+fn param<'a>(request: &'a Request, key: &str) -> Option<&'a str> {
+    request
+        .query
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+fn request_body(request: &Request) -> Result<Value, Response> {
+    match request.body.as_deref() {
+        None | Some([]) => Ok(json!({})),
+        Some(body) => serde_json::from_slice(body).map_err(|_| {
+            json(
+                400,
+                &json!({
+                    "message": "Problems parsing JSON",
+                    "documentation_url": DOCUMENTATION_URL,
+                }),
+            )
+        }),
+    }
+}
+
+fn text(body: &Value, key: &str) -> Option<String> {
+    body.get(key)?.as_str().map(str::to_string)
+}
+
+/// Label names given either as strings or as `{"name": ...}` objects.
+fn names(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|labels| {
+            labels
+                .iter()
+                .filter_map(|l| l.as_str().or_else(|| l["name"].as_str()))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_number(number: &str) -> Result<u64, Response> {
+    number.parse().map_err(|_| not_found())
+}
+
+fn existing_milestone(repo: &Repo, number: &Value) -> Result<u64, Response> {
+    number
+        .as_u64()
+        .filter(|n| repo.milestones.iter().any(|m| m.number == *n))
+        .ok_or_else(|| invalid("Issue", "milestone", "invalid"))
+}
+
+fn set_state(issue: &mut Issue, new_state: &str, reason: Option<String>) -> Result<(), Response> {
+    match new_state {
+        "open" => {
+            issue.closed_at = None;
+            issue.state_reason = reason.filter(|r| r == "reopened");
+        }
+        "closed" => {
+            if issue.state != "closed" {
+                issue.closed_at = Some(timestamp());
+            }
+            issue.state_reason = Some(reason.unwrap_or_else(|| "completed".to_string()));
+        }
+        _ => return Err(invalid("Issue", "state", "invalid")),
+    }
+    issue.state = new_state.to_string();
+    Ok(())
+}
+
+fn review_state(event: &str, body: &Value) -> Result<String, Response> {
+    let state = match event {
+        "APPROVE" => "APPROVED",
+        "REQUEST_CHANGES" => "CHANGES_REQUESTED",
+        "COMMENT" => "COMMENTED",
+        _ => return Err(invalid("PullRequestReview", "event", "invalid")),
+    };
+    if state != "APPROVED" && text(body, "body").is_none_or(|b| b.is_empty()) {
+        return Err(invalid("PullRequestReview", "body", "missing_field"));
+    }
+    Ok(state.to_string())
+}
+
+/// Newest first, or oldest first with `direction=asc`, like GitHub's default `sort=created`.
+fn sort_newest_first(request: &Request, issues: &mut [&Issue]) {
+    issues.sort_by_key(|i| std::cmp::Reverse(i.number));
+    if param(request, "direction") == Some("asc") {
+        issues.reverse();
+    }
+}
+
+fn ok(value: Value) -> Reply {
+    Ok(json(200, &value))
+}
+
+fn created(value: Value) -> Reply {
+    Ok(json(201, &value))
+}
+
+fn no_content() -> Response {
+    Response {
+        status: 204,
+        headers: Headers::new(),
+        body: Vec::new(),
+    }
+}
+
+fn not_found() -> Response {
+    json(
+        404,
+        &json!({ "message": "Not Found", "documentation_url": DOCUMENTATION_URL }),
+    )
+}
+
+fn invalid(resource: &str, field: &str, code: &str) -> Response {
+    json(
+        422,
+        &json!({
+            "message": "Validation Failed",
+            "errors": [{ "resource": resource, "field": field, "code": code }],
+            "documentation_url": DOCUMENTATION_URL,
+        }),
+    )
+}
+
+/// Branches have no commits here; each gets a fixed, made-up head.
+fn branch_sha(repo: &Repo, branch: &str) -> String {
+    let key = format!("{}/{}:{}", repo.owner, repo.name, branch);
+    let hash = djb2(&key);
+    format!(
+        "{:016x}{:016x}{:08x}",
+        hash,
+        hash.rotate_left(21),
+        hash as u32
+    )
+}
+
+fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64);
+    let (days, rest) = (secs.div_euclid(86_400), secs.rem_euclid(86_400));
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(github: &Simulator, endpoint: EndPoints, body: Value) -> Response {
+        github.handle(&Request::new(endpoint).json(&body).unwrap())
+    }
+
+    fn s(text: &str) -> String {
+        text.to_string()
+    }
+
+    fn label_names(github: &Simulator) -> Vec<String> {
+        let labels: Vec<Value> = github
+            .handle(&Request::new(EndPoints::GetReposownerrepoLabels(
+                s("octocat"),
+                s("hello"),
+            )))
+            .json()
+            .unwrap();
+        labels
+            .iter()
+            .map(|l| l["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn renaming_onto_an_existing_repo_is_rejected() {
+        let github = Simulator::new()
+            .repo("octocat", "hello")
+            .repo("octocat", "world");
+
+        let taken = call(
+            &github,
+            EndPoints::PatchReposownerrepo(s("octocat"), s("hello")),
+            json!({ "name": "World" }),
+        );
+        assert_eq!(taken.status, 422);
+        let error: Value = taken.json().unwrap();
+        assert_eq!(error["errors"][0]["code"], "already_exists");
+
+        let recased = call(
+            &github,
+            EndPoints::PatchReposownerrepo(s("octocat"), s("hello")),
+            json!({ "name": "Hello" }),
+        );
+        assert_eq!(recased.status, 200);
+        let renamed = call(
+            &github,
+            EndPoints::PatchReposownerrepo(s("octocat"), s("Hello")),
+            json!({ "name": "hello-world" }),
+        );
+        assert_eq!(renamed.status, 200);
+        let moved = github.handle(&Request::new(EndPoints::GetReposownerrepo(
+            s("octocat"),
+            s("hello-world"),
+        )));
+        assert_eq!(moved.status, 200);
+    }
+
+    #[test]
+    fn patching_a_missing_issue_creates_no_labels() {
+        let github = Simulator::new().repo("octocat", "hello");
+
+        let missing = call(
+            &github,
+            EndPoints::PatchReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("7")),
+            json!({ "labels": ["bug"] }),
+        );
+        assert_eq!(missing.status, 404);
+        assert!(label_names(&github).is_empty());
+
+        call(
+            &github,
+            EndPoints::PostReposownerrepoIssues(s("octocat"), s("hello")),
+            json!({ "title": "Found a bug" }),
+        );
+        let labelled = call(
+            &github,
+            EndPoints::PatchReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+            json!({ "labels": ["bug"] }),
+        );
+        assert_eq!(labelled.status, 200);
+        assert_eq!(label_names(&github), ["bug"]);
+    }
+
+    fn get(github: &Simulator, endpoint: EndPoints) -> Response {
+        github.handle(&Request::new(endpoint))
+    }
+
+    fn issues(owner: &str, repo: &str) -> EndPoints {
+        EndPoints::GetReposownerrepoIssues(s(owner), s(repo))
+    }
+
+    fn numbers(response: &Response) -> Vec<u64> {
+        let items: Vec<Value> = response.json().unwrap();
+        items
+            .iter()
+            .map(|i| i["number"].as_u64().unwrap())
+            .collect()
+    }
+
+    fn open_issue(github: &Simulator, body: Value) -> Value {
+        let response = call(
+            github,
+            EndPoints::PostReposownerrepoIssues(s("octocat"), s("hello")),
+            body,
+        );
+        assert_eq!(
+            response.status,
+            201,
+            "{}",
+            String::from_utf8_lossy(&response.body)
+        );
+        response.json().unwrap()
+    }
+
+    fn open_pull(github: &Simulator) -> Value {
+        let response = call(
+            github,
+            EndPoints::PostReposownerrepoPulls(s("octocat"), s("hello")),
+            json!({ "title": "Fix the bug", "head": "fix", "base": "main" }),
+        );
+        assert_eq!(response.status, 201);
+        response.json().unwrap()
+    }
+
+    #[test]
+    fn created_repos_and_issues_show_up_in_their_lists() {
+        let github = Simulator::new();
+        let created = call(
+            &github,
+            EndPoints::PostUserRepos(),
+            json!({ "name": "hello", "description": "Hi" }),
+        );
+        assert_eq!(created.status, 201);
+        let org_repo = call(
+            &github,
+            EndPoints::PostOrgsorgRepos(s("acme")),
+            json!({ "name": "api" }),
+        );
+        assert_eq!(org_repo.status, 201);
+
+        let mine: Vec<Value> = get(&github, EndPoints::GetUserRepos()).json().unwrap();
+        assert_eq!(mine.len(), 1);
+        assert_eq!(mine[0]["full_name"], "octocat/hello");
+        assert_eq!(mine[0]["description"], "Hi");
+        let acme: Vec<Value> = get(&github, EndPoints::GetOrgsorgRepos(s("Acme")))
+            .json()
+            .unwrap();
+        assert_eq!(acme[0]["full_name"], "acme/api");
+
+        let issue = open_issue(
+            &github,
+            json!({ "title": "Found a bug", "body": "It breaks" }),
+        );
+        assert_eq!(issue["number"], 1);
+        assert_eq!(issue["user"]["login"], "octocat");
+        assert_eq!(numbers(&get(&github, issues("octocat", "hello"))), [1]);
+        let fetched: Value = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(fetched, issue);
+        let repo: Value = get(
+            &github,
+            EndPoints::GetReposownerrepo(s("octocat"), s("hello")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(repo["open_issues_count"], 1);
+    }
+
+    #[test]
+    fn lists_close_and_filter_by_state() {
+        let github = Simulator::new().repo("octocat", "hello");
+        open_issue(&github, json!({ "title": "One" }));
+        open_issue(&github, json!({ "title": "Two" }));
+        let closed = call(
+            &github,
+            EndPoints::PatchReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+            json!({ "state": "closed", "state_reason": "not_planned" }),
+        );
+        let closed: Value = closed.json().unwrap();
+        assert_eq!(closed["state_reason"], "not_planned");
+        assert!(closed["closed_at"].is_string());
+
+        assert_eq!(numbers(&get(&github, issues("octocat", "hello"))), [2]);
+        let all = Request::new(issues("octocat", "hello"))
+            .query("state", "all")
+            .query("direction", "asc");
+        assert_eq!(numbers(&github.handle(&all)), [1, 2]);
+    }
+
+    #[test]
+    fn lists_paginate_with_link_headers() {
+        let github = Simulator::new().repo("octocat", "hello");
+        for n in 1..=5 {
+            open_issue(&github, json!({ "title": format!("Issue {}", n) }));
+        }
+        let page = |page: &str| {
+            github.handle(
+                &Request::new(issues("octocat", "hello"))
+                    .query("per_page", 2)
+                    .query("page", page),
+            )
+        };
+        let url = |page: u32| {
+            format!(
+                "https://api.github.com/repos/octocat/hello/issues?per_page=2&page={}",
+                page
+            )
+        };
+
+        let first = page("1");
+        assert_eq!(numbers(&first), [5, 4]);
+        assert_eq!(
+            first.headers.get("link").unwrap(),
+            format!("<{}>; rel=\"next\", <{}>; rel=\"last\"", url(2), url(3))
+        );
+        let middle = page("2");
+        assert_eq!(numbers(&middle), [3, 2]);
+        assert_eq!(
+            middle.headers.get("link").unwrap(),
+            format!(
+                "<{}>; rel=\"prev\", <{}>; rel=\"next\", <{}>; rel=\"last\", <{}>; rel=\"first\"",
+                url(1),
+                url(3),
+                url(3),
+                url(1)
+            )
+        );
+        let last = page("3");
+        assert_eq!(numbers(&last), [1]);
+        let link = last.headers.get("link").unwrap();
+        assert!(!link.contains("rel=\"next\"") && !link.contains("rel=\"last\""));
+        assert!(numbers(&page("4")).is_empty());
+
+        // Everything fits on the default page of 30, so there is no Link.
+        let all = get(&github, issues("octocat", "hello"));
+        assert_eq!(numbers(&all).len(), 5);
+        assert_eq!(all.headers.get("link"), None);
+        let clamped = github.handle(&Request::new(issues("octocat", "hello")).query("per_page", 0));
+        assert_eq!(numbers(&clamped), [5]);
+    }
+
+    #[test]
+    fn missing_and_invalid_requests_get_githubs_error_bodies() {
+        let github = Simulator::new().repo("octocat", "hello");
+
+        let missing = get(
+            &github,
+            EndPoints::GetReposownerrepo(s("octocat"), s("nope")),
+        );
+        assert_eq!(missing.status, 404);
+        assert_eq!(
+            missing.json::<Value>().unwrap(),
+            json!({ "message": "Not Found", "documentation_url": DOCUMENTATION_URL })
+        );
+        let not_a_number = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("x")),
+        );
+        assert_eq!(not_a_number.status, 404);
+
+        let untitled = call(
+            &github,
+            EndPoints::PostReposownerrepoIssues(s("octocat"), s("hello")),
+            json!({ "body": "no title" }),
+        );
+        assert_eq!(untitled.status, 422);
+        assert_eq!(
+            untitled.json::<Value>().unwrap(),
+            json!({
+                "message": "Validation Failed",
+                "errors": [{ "resource": "Issue", "field": "title", "code": "missing_field" }],
+                "documentation_url": DOCUMENTATION_URL,
+            })
+        );
+        let error = untitled
+            .error_for_status(&EndPoints::PostReposownerrepoIssues(
+                s("octocat"),
+                s("hello"),
+            ))
+            .unwrap_err();
+        assert!(matches!(
+            error.github(),
+            Some(crate::error::GitHubError::Validation { .. })
+        ));
+
+        let mut garbled = Request::new(EndPoints::PostReposownerrepoIssues(
+            s("octocat"),
+            s("hello"),
+        ));
+        garbled.body = Some(b"{not json".to_vec());
+        assert_eq!(github.handle(&garbled).status, 400);
+        let unsimulated = get(&github, EndPoints::GetZen());
+        assert_eq!(unsimulated.status, 501);
+    }
+
+    #[test]
+    fn issues_carry_labels_and_milestones() {
+        let github = Simulator::new().repo("octocat", "hello");
+        let label = call(
+            &github,
+            EndPoints::PostReposownerrepoLabels(s("octocat"), s("hello")),
+            json!({ "name": "bug", "color": "d73a4a" }),
+        );
+        assert_eq!(label.status, 201);
+        let milestone = call(
+            &github,
+            EndPoints::PostReposownerrepoMilestones(s("octocat"), s("hello")),
+            json!({ "title": "v1.0" }),
+        );
+        assert_eq!(milestone.json::<Value>().unwrap()["number"], 1);
+
+        // Label names match case-insensitively; unknown ones are created.
+        let issue = open_issue(
+            &github,
+            json!({ "title": "Crash", "labels": ["BUG", "crash"], "milestone": 1 }),
+        );
+        assert_eq!(issue["labels"][0]["name"], "bug");
+        assert_eq!(issue["labels"][0]["color"], "d73a4a");
+        assert_eq!(issue["labels"][1]["name"], "crash");
+        assert_eq!(issue["milestone"]["title"], "v1.0");
+        assert_eq!(issue["milestone"]["open_issues"], 1);
+        assert_eq!(label_names(&github), ["bug", "crash"]);
+        open_issue(&github, json!({ "title": "Idea" }));
+
+        let filtered = Request::new(issues("octocat", "hello")).query("labels", "bug,crash");
+        assert_eq!(numbers(&github.handle(&filtered)), [1]);
+        let filtered = Request::new(issues("octocat", "hello")).query("milestone", "none");
+        assert_eq!(numbers(&github.handle(&filtered)), [2]);
+
+        let added = call(
+            &github,
+            EndPoints::PostReposownerrepoIssuesissueNumberLabels(s("octocat"), s("hello"), s("2")),
+            json!({ "labels": ["enhancement"] }),
+        );
+        assert_eq!(added.json::<Value>().unwrap()[0]["name"], "enhancement");
+        let replaced = call(
+            &github,
+            EndPoints::PutReposownerrepoIssuesissueNumberLabels(s("octocat"), s("hello"), s("1")),
+            json!(["crash"]),
+        );
+        assert_eq!(
+            replaced.json::<Value>().unwrap().as_array().unwrap().len(),
+            1
+        );
+        let removed = get(
+            &github,
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+                s("octocat"),
+                s("hello"),
+                s("1"),
+                s("bug"),
+            ),
+        );
+        assert_eq!(removed.status, 404);
+        assert_eq!(
+            removed.json::<Value>().unwrap()["message"],
+            "Label does not exist"
+        );
+
+        let no_such_milestone = call(
+            &github,
+            EndPoints::PostReposownerrepoIssues(s("octocat"), s("hello")),
+            json!({ "title": "Later", "milestone": 9 }),
+        );
+        assert_eq!(no_such_milestone.status, 422);
+
+        call(
+            &github,
+            EndPoints::PatchReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+            json!({ "state": "closed" }),
+        );
+        let milestone: Value = get(
+            &github,
+            EndPoints::GetReposownerrepoMilestonesmilestoneNumber(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(milestone["open_issues"], 0);
+        assert_eq!(milestone["closed_issues"], 1);
+    }
+
+    #[test]
+    fn comments_are_listed_per_issue_and_per_repository() {
+        let github = Simulator::new().repo("octocat", "hello");
+        open_issue(&github, json!({ "title": "One" }));
+        open_issue(&github, json!({ "title": "Two" }));
+        let comment = |number: &str, body: &str| {
+            call(
+                &github,
+                EndPoints::PostReposownerrepoIssuesissueNumberComments(
+                    s("octocat"),
+                    s("hello"),
+                    s(number),
+                ),
+                json!({ "body": body }),
+            )
+        };
+
+        let first: Value = comment("1", "Me too").json().unwrap();
+        comment("2", "Not me");
+        assert_eq!(comment("3", "Nobody").status, 404);
+        assert_eq!(comment("1", "").status, 201);
+        let empty = call(
+            &github,
+            EndPoints::PostReposownerrepoIssuesissueNumberComments(
+                s("octocat"),
+                s("hello"),
+                s("1"),
+            ),
+            json!({}),
+        );
+        assert_eq!(empty.status, 422);
+
+        let on_one: Vec<Value> = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesissueNumberComments(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(on_one.len(), 2);
+        assert_eq!(on_one[0]["body"], "Me too");
+        let issue: Value = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(issue["comments"], 2);
+
+        let id = first["id"].to_string();
+        let edited = call(
+            &github,
+            EndPoints::PatchReposownerrepoIssuesCommentscommentId(
+                s("octocat"),
+                s("hello"),
+                id.clone(),
+            ),
+            json!({ "body": "Me too!" }),
+        );
+        assert_eq!(edited.json::<Value>().unwrap()["body"], "Me too!");
+        let deleted = get(
+            &github,
+            EndPoints::DeleteReposownerrepoIssuesCommentscommentId(
+                s("octocat"),
+                s("hello"),
+                id.clone(),
+            ),
+        );
+        assert_eq!(deleted.status, 204);
+        let gone = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesCommentscommentId(s("octocat"), s("hello"), id),
+        );
+        assert_eq!(gone.status, 404);
+        let all: Vec<Value> = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesComments(s("octocat"), s("hello")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(all.len(), 2);
+    }
+
+    #[test]
+    fn pull_requests_go_through_review_and_merge() {
+        let github = Simulator::new().repo("octocat", "hello");
+        let pull = open_pull(&github);
+        assert_eq!(pull["number"], 1);
+        assert_eq!(pull["head"]["ref"], "fix");
+        assert_eq!(pull["merged"], false);
+        // A pull request is an issue too.
+        let as_issue: Value = get(
+            &github,
+            EndPoints::GetReposownerrepoIssuesissueNumber(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert!(as_issue["pull_request"]["url"].is_string());
+        let review = |body: Value| {
+            call(
+                &github,
+                EndPoints::PostReposownerrepoPullspullNumberReviews(
+                    s("octocat"),
+                    s("hello"),
+                    s("1"),
+                ),
+                body,
+            )
+        };
+
+        // Authors can comment on their own pull request but not approve it.
+        assert_eq!(review(json!({ "event": "APPROVE" })).status, 422);
+        assert_eq!(
+            review(json!({ "event": "COMMENT" })).status,
+            422,
+            "needs a body"
+        );
+        let commented: Value = review(json!({ "event": "COMMENT", "body": "Note" }))
+            .json()
+            .unwrap();
+        assert_eq!(commented["state"], "COMMENTED");
+        let pending: Value = review(json!({ "body": "Draft" })).json().unwrap();
+        assert_eq!(pending["state"], "PENDING");
+        let submit = |id: &Value, body: Value| {
+            call(
+                &github,
+                EndPoints::PostReposownerrepoPullspullNumberReviewsreviewIdEvents(
+                    s("octocat"),
+                    s("hello"),
+                    s("1"),
+                    id.to_string(),
+                ),
+                body,
+            )
+        };
+        assert_eq!(
+            submit(&pending["id"], json!({ "event": "APPROVE" })).status,
+            422
+        );
+
+        // Someone else opened it.
+        github.state.lock().unwrap().repos[0].issues[0].user = s("hubot");
+        let approved = submit(&pending["id"], json!({ "event": "APPROVE" }));
+        assert_eq!(approved.json::<Value>().unwrap()["state"], "APPROVED");
+        assert_eq!(
+            submit(&pending["id"], json!({ "event": "APPROVE" })).status,
+            422
+        );
+        let undeletable = get(
+            &github,
+            EndPoints::DeleteReposownerrepoPullspullNumberReviewsreviewId(
+                s("octocat"),
+                s("hello"),
+                s("1"),
+                pending["id"].to_string(),
+            ),
+        );
+        assert_eq!(undeletable.status, 422);
+        let changes: Value = review(json!({ "event": "REQUEST_CHANGES", "body": "Tests?" }))
+            .json()
+            .unwrap();
+        let dismissed = call(
+            &github,
+            EndPoints::PutReposownerrepoPullspullNumberReviewsreviewIdDismissals(
+                s("octocat"),
+                s("hello"),
+                s("1"),
+                changes["id"].to_string(),
+            ),
+            json!({ "message": "Added" }),
+        );
+        assert_eq!(dismissed.json::<Value>().unwrap()["state"], "DISMISSED");
+        let states: Vec<Value> = get(
+            &github,
+            EndPoints::GetReposownerrepoPullspullNumberReviews(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        let states: Vec<&str> = states
+            .iter()
+            .map(|r| r["state"].as_str().unwrap())
+            .collect();
+        assert_eq!(states, ["COMMENTED", "APPROVED", "DISMISSED"]);
+
+        let merge =
+            EndPoints::GetReposownerrepoPullspullNumberMerge(s("octocat"), s("hello"), s("1"));
+        assert_eq!(get(&github, merge.clone()).status, 404);
+        let merged = get(
+            &github,
+            EndPoints::PutReposownerrepoPullspullNumberMerge(s("octocat"), s("hello"), s("1")),
+        );
+        assert_eq!(merged.json::<Value>().unwrap()["merged"], true);
+        assert_eq!(get(&github, merge).status, 204);
+        let again = get(
+            &github,
+            EndPoints::PutReposownerrepoPullspullNumberMerge(s("octocat"), s("hello"), s("1")),
+        );
+        assert_eq!(again.status, 405);
+        let pull: Value = get(
+            &github,
+            EndPoints::GetReposownerrepoPullspullNumber(s("octocat"), s("hello"), s("1")),
+        )
+        .json()
+        .unwrap();
+        assert_eq!(pull["state"], "closed");
+        assert_eq!(pull["merged"], true);
+        let open = get(
+            &github,
+            EndPoints::GetReposownerrepoPulls(s("octocat"), s("hello")),
+        );
+        assert!(numbers(&open).is_empty());
+    }
+}
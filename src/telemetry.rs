@@ -0,0 +1,290 @@
+//! Per-operation spans and metrics, as a [`Middleware`].
+//!
+//! With the `tracing` feature every request runs inside a `github.request` span
+//! whose `operation` is the `EndPoints` variant name, plus `template`, `method`,
+//! `status`, `rate_limit_remaining` and `duration_ms`.
+//!
+//! With the `metrics` feature it records:
+//!
+//! - `github_requests_total`, a counter labelled `operation`, `template`, `method`, `status`
+//! - `github_request_duration_seconds`, a histogram labelled `operation`, `template`, `method`
+//! - `github_rate_limit_remaining`, a gauge labelled `resource`
+//!
+//! Labels use the path template, never the concrete path, so the number of series
+//! is bounded by the number of operations.
+
+use std::time::Instant;
+
+use crate::error::Error;
+use crate::http::{Request, Response};
+use crate::middleware::{Middleware, Next};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Telemetry;
+
+impl Telemetry {
+    pub fn new() -> Self {
+        Telemetry
+    }
+}
+
+impl Middleware for Telemetry {
+    fn handle(&self, request: Request, next: Next<'_>) -> Result<Response, Error> {
+        let operation = request.endpoint.name();
+        let template = request.endpoint.template();
+        let method = request.method().as_str();
+
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "github.request",
+            operation,
+            template,
+            method,
+            status = tracing::field::Empty,
+            rate_limit_remaining = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+        );
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let started = Instant::now();
+        let result = next.run(request);
+        let elapsed = started.elapsed();
+
+        let status = match &result {
+            Ok(response) => response.status.to_string(),
+            Err(_) => "error".to_string(),
+        };
+        let remaining = result.as_ref().ok().and_then(|response| {
+            response
+                .headers
+                .get("x-ratelimit-remaining")?
+                .trim()
+                .parse::<u64>()
+                .ok()
+        });
+
+        #[cfg(feature = "tracing")]
+        {
+            span.record("status", status.as_str());
+            span.record("duration_ms", elapsed.as_millis() as u64);
+            if let Some(remaining) = remaining {
+                span.record("rate_limit_remaining", remaining);
+            }
+            if let Err(error) = &result {
+                tracing::warn!(%error, "request failed");
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::counter!(
+                "github_requests_total",
+                "operation" => operation,
+                "template" => template,
+                "method" => method,
+                "status" => status.clone(),
+            )
+            .increment(1);
+            metrics::histogram!(
+                "github_request_duration_seconds",
+                "operation" => operation,
+                "template" => template,
+                "method" => method,
+            )
+            .record(elapsed.as_secs_f64());
+            if let (Some(remaining), Ok(response)) = (remaining, &result) {
+                let resource = response
+                    .headers
+                    .get("x-ratelimit-resource")
+                    .unwrap_or("core")
+                    .to_string();
+                metrics::gauge!("github_rate_limit_remaining", "resource" => resource)
+                    .set(remaining as f64);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::end_points::EndPoints;
+    use crate::fake::{self, FakeGitHub};
+    use crate::http::Transport;
+    use crate::middleware::Chain;
+
+    fn issue(number: &str) -> Request {
+        Request::new(EndPoints::GetReposownerrepoIssuesissueNumber(
+            "octocat".to_string(),
+            "hello".to_string(),
+            number.to_string(),
+        ))
+    }
+
+    fn github() -> FakeGitHub {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoIssuesissueNumber, |_| {
+            let mut response = fake::json(200, &json!({ "number": 1 }));
+            response.headers.insert("X-RateLimit-Remaining", "4999");
+            response.headers.insert("X-RateLimit-Resource", "core");
+            response
+        });
+        github
+    }
+
+    #[test]
+    fn passes_the_response_through() {
+        let github = github();
+        let chain = Chain::new(&github).layer(Telemetry::new());
+
+        let response = chain.send(&issue("1")).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.headers.get("x-ratelimit-remaining"), Some("4999"));
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn metrics_are_labelled_with_the_template() {
+        use std::sync::Mutex;
+
+        use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, SharedString, Unit};
+
+        /// Keeps the key of every metric registered, as `name{label=value,...}`.
+        #[derive(Default)]
+        struct Keys(Mutex<Vec<String>>);
+
+        impl Keys {
+            fn push(&self, key: &Key) {
+                let labels: Vec<String> = key
+                    .labels()
+                    .map(|label| format!("{}={}", label.key(), label.value()))
+                    .collect();
+                let key = format!("{}{{{}}}", key.name(), labels.join(","));
+                self.0.lock().unwrap().push(key);
+            }
+        }
+
+        impl metrics::Recorder for Keys {
+            fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+            fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+            fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+                self.push(key);
+                Counter::noop()
+            }
+
+            fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+                self.push(key);
+                Gauge::noop()
+            }
+
+            fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+                self.push(key);
+                Histogram::noop()
+            }
+        }
+
+        let github = github();
+        let chain = Chain::new(&github).layer(Telemetry::new());
+        let keys = Keys::default();
+        metrics::with_local_recorder(&keys, || {
+            chain.send(&issue("1")).unwrap();
+            chain.send(&issue("2")).unwrap();
+        });
+
+        let labels = "operation=GetReposownerrepoIssuesissueNumber,\
+                      template=/repos/{owner}/{repo}/issues/{issue_number},method=GET";
+        let keys = keys.0.into_inner().unwrap();
+        assert_eq!(
+            keys[..3],
+            [
+                format!("github_requests_total{{{},status=200}}", labels),
+                format!("github_request_duration_seconds{{{}}}", labels),
+                "github_rate_limit_remaining{resource=core}".to_string(),
+            ]
+        );
+        // The issue number never becomes a label.
+        assert_eq!(keys[..3], keys[3..]);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn the_span_carries_the_template_and_outcome() {
+        use std::fmt;
+        use std::sync::{Arc, Mutex};
+
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Keeps the fields recorded on any span.
+        #[derive(Clone, Default)]
+        struct Fields(Arc<Mutex<Vec<(String, String)>>>);
+
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                let mut fields = self.0.lock().unwrap();
+                fields.push((field.name().to_string(), value.to_string()));
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                let mut fields = self.0.lock().unwrap();
+                fields.push((field.name().to_string(), format!("{:?}", value)));
+            }
+        }
+
+        impl Subscriber for Fields {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                assert_eq!(span.metadata().name(), "github.request");
+                span.record(&mut self.clone());
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut self.clone());
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let github = github();
+        let chain = Chain::new(&github).layer(Telemetry::new());
+        let fields = Fields::default();
+        tracing::subscriber::with_default(fields.clone(), || {
+            chain.send(&issue("1")).unwrap();
+        });
+
+        let fields = fields.0.lock().unwrap();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(
+            field("operation"),
+            Some("GetReposownerrepoIssuesissueNumber")
+        );
+        assert_eq!(
+            field("template"),
+            Some("/repos/{owner}/{repo}/issues/{issue_number}")
+        );
+        assert_eq!(field("method"), Some("GET"));
+        assert_eq!(field("status"), Some("200"));
+        assert_eq!(field("rate_limit_remaining"), Some("4999"));
+        assert!(field("duration_ms").is_some());
+    }
+}
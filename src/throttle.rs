@@ -0,0 +1,442 @@
+//! Pacing of mutating calls to stay clear of secondary rate limits.
+//!
+//! See <https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits>.
+
+use std::collections::VecDeque;
+use std::io::Read;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use crate::clock::{Clock, SystemClock};
+use crate::end_points::{EndPoints, Methods};
+use crate::error::{Error, ForbiddenReason, GitHubError};
+use crate::http::{Request, Response, Transport};
+
+/// How heavily GitHub's secondary limits weigh a call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WriteClass {
+    Read,
+    /// Any POST, PATCH, PUT or DELETE.
+    Mutation,
+    /// Creates content that notifies people: issues, comments, pull requests and the like.
+    ContentCreation,
+}
+
+impl EndPoints {
+    pub fn write_class(&self) -> WriteClass {
+        match self {
+            EndPoints::PostGists()
+            | EndPoints::PostGistsgistIdComments(..)
+            | EndPoints::PostGistsgistIdForks(..)
+            | EndPoints::PostOrgsorgProjects(..)
+            | EndPoints::PostOrgsorgRepos(..)
+            | EndPoints::PostOrgsorgTeamsteamSlugDiscussions(..)
+            | EndPoints::PostOrgsorgTeamsteamSlugDiscussionsdiscussionNumberComments(..)
+            | EndPoints::PostProjectsColumnscolumnIdCards(..)
+            | EndPoints::PostProjectsprojectIdColumns(..)
+            | EndPoints::PostReposownerrepoCommitscommitShaComments(..)
+            | EndPoints::PostReposownerrepoForks(..)
+            | EndPoints::PostReposownerrepoIssues(..)
+            | EndPoints::PostReposownerrepoIssuesissueNumberComments(..)
+            | EndPoints::PostReposownerrepoProjects(..)
+            | EndPoints::PostReposownerrepoPulls(..)
+            | EndPoints::PostReposownerrepoPullspullNumberComments(..)
+            | EndPoints::PostReposownerrepoPullspullNumberCommentscommentIdReplies(..)
+            | EndPoints::PostReposownerrepoPullspullNumberReviews(..)
+            | EndPoints::PostReposownerrepoReleases(..)
+            | EndPoints::PostRepostemplateOwnertemplateRepoGenerate(..)
+            | EndPoints::PostTeamsteamIdDiscussions(..)
+            | EndPoints::PostTeamsteamIdDiscussionsdiscussionNumberComments(..)
+            | EndPoints::PostUserProjects()
+            | EndPoints::PostUserRepos() => WriteClass::ContentCreation,
+            e if e.method() == Methods::Get => WriteClass::Read,
+            _ => WriteClass::Mutation,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ThrottleConfig {
+    /// Minimum gap between two mutations. GitHub asks for at least a second.
+    pub mutation_interval: Duration,
+    /// Minimum gap between two content-creating calls.
+    pub content_interval: Duration,
+    /// Content-creating calls allowed in any sliding minute.
+    pub content_per_minute: usize,
+    /// Wait used when a secondary limit response has no `Retry-After`.
+    pub default_retry_after: Duration,
+    /// How often a call rejected by a secondary limit is sent again.
+    pub max_retries: u32,
+}
+
+impl Default for ThrottleConfig {
+    fn default() -> Self {
+        ThrottleConfig {
+            mutation_interval: Duration::from_secs(1),
+            content_interval: Duration::from_secs(1),
+            content_per_minute: 80,
+            default_retry_after: Duration::from_secs(60),
+            max_retries: 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ThrottleMetrics {
+    /// Calls that had to wait before being sent.
+    pub waits: u64,
+    pub total_wait: Duration,
+    /// Responses rejected by a secondary rate limit.
+    pub secondary_limit_hits: u64,
+    pub retries: u64,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    last_mutation: Option<SystemTime>,
+    recent_content: VecDeque<SystemTime>,
+    blocked_until: Option<SystemTime>,
+    metrics: ThrottleMetrics,
+}
+
+/// A [`Transport`] that spaces out writes and backs off on `Retry-After`.
+#[derive(Debug)]
+pub struct Throttle<T, C = SystemClock> {
+    inner: T,
+    clock: C,
+    config: ThrottleConfig,
+    state: Mutex<State>,
+}
+
+impl<T> Throttle<T> {
+    pub fn new(inner: T) -> Self {
+        Throttle::with_clock(inner, ThrottleConfig::default(), SystemClock)
+    }
+}
+
+impl<T, C: Clock> Throttle<T, C> {
+    pub fn with_clock(inner: T, config: ThrottleConfig, clock: C) -> Self {
+        Throttle {
+            inner,
+            clock,
+            config,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    pub fn metrics(&self) -> ThrottleMetrics {
+        self.state.lock().unwrap().metrics
+    }
+
+    /// Blocks until `endpoint` may be called and books the slot.
+    fn wait_for_slot(&self, endpoint: &EndPoints) {
+        let class = endpoint.write_class();
+        loop {
+            let now = self.clock.now();
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let wait = self.required_wait(&mut state, class, now);
+                if wait.is_zero() {
+                    if class != WriteClass::Read {
+                        state.last_mutation = Some(now);
+                    }
+                    if class == WriteClass::ContentCreation {
+                        state.recent_content.push_back(now);
+                    }
+                    return;
+                }
+                state.metrics.waits += 1;
+                state.metrics.total_wait += wait;
+                wait
+            };
+            self.clock.sleep(wait);
+        }
+    }
+
+    /// Blocks everything until the `Retry-After` of a secondary rate limit
+    /// response. Returns whether `response` was one.
+    fn note_secondary_limit(&self, endpoint: &EndPoints, response: &Response) -> bool {
+        let retry_after = match GitHubError::from_response(endpoint, response) {
+            Some(GitHubError::Forbidden {
+                reason: ForbiddenReason::Abuse { retry_after },
+                ..
+            }) => retry_after.unwrap_or(self.config.default_retry_after),
+            _ => return false,
+        };
+        let mut state = self.state.lock().unwrap();
+        state.metrics.secondary_limit_hits += 1;
+        state.blocked_until = Some(self.clock.now() + retry_after);
+        true
+    }
+
+    fn required_wait(&self, state: &mut State, class: WriteClass, now: SystemTime) -> Duration {
+        let until = |t: SystemTime| t.duration_since(now).unwrap_or_default();
+        let mut wait = state.blocked_until.map(until).unwrap_or_default();
+        if class == WriteClass::Read {
+            return wait;
+        }
+        if let Some(last) = state.last_mutation {
+            wait = wait.max(until(last + self.config.mutation_interval));
+        }
+        if class == WriteClass::ContentCreation {
+            let minute = Duration::from_secs(60);
+            while state
+                .recent_content
+                .front()
+                .is_some_and(|t| *t + minute <= now)
+            {
+                state.recent_content.pop_front();
+            }
+            if let Some(last) = state.recent_content.back() {
+                wait = wait.max(until(*last + self.config.content_interval));
+            }
+            if state.recent_content.len() >= self.config.content_per_minute {
+                if let Some(first) = state.recent_content.front() {
+                    wait = wait.max(until(*first + minute));
+                }
+            }
+        }
+        wait
+    }
+}
+
+impl<T: Transport, C: Clock> Transport for Throttle<T, C> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let mut attempt = 0;
+        loop {
+            self.wait_for_slot(&request.endpoint);
+            let response = self.inner.send(request)?;
+            if !self.note_secondary_limit(&request.endpoint, &response)
+                || attempt >= self.config.max_retries
+            {
+                return Ok(response);
+            }
+            attempt += 1;
+            self.state.lock().unwrap().metrics.retries += 1;
+        }
+    }
+
+    /// Waits like [`Transport::send`] but does not retry, since the body has
+    /// been read; a secondary limit still holds back the calls after it.
+    fn send_body(
+        &self,
+        request: &Request,
+        body: &mut dyn Read,
+        len: u64,
+    ) -> Result<Response, Error> {
+        self.wait_for_slot(&request.endpoint);
+        let response = self.inner.send_body(request, body, len)?;
+        self.note_secondary_limit(&request.endpoint, &response);
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::UNIX_EPOCH;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::clock::FakeClock;
+    use crate::fake::{json, FakeGitHub};
+
+    fn s(text: &str) -> String {
+        text.to_string()
+    }
+
+    fn clock() -> FakeClock {
+        FakeClock::new(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+    }
+
+    fn issue() -> Request {
+        Request::new(EndPoints::PostReposownerrepoIssues(s("acme"), s("api")))
+    }
+
+    fn secondary_limit(retry_after: Option<&str>) -> Response {
+        let mut response = json(
+            403,
+            &json!({
+                "message": "You have exceeded a secondary rate limit. Please wait a few minutes before you try again.",
+                "documentation_url": "https://docs.github.com/rest/overview/rate-limits-for-the-rest-api",
+            }),
+        );
+        if let Some(retry_after) = retry_after {
+            response.headers.insert("Retry-After", retry_after);
+        }
+        response
+    }
+
+    /// Answers `PostReposownerrepoIssues` with `limited` `rejections` times,
+    /// then with 201.
+    fn limited(rejections: usize, limited: Response) -> FakeGitHub {
+        let github = FakeGitHub::start().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        github.on(EndPoints::PostReposownerrepoIssues, move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) < rejections {
+                limited.clone()
+            } else {
+                json(201, &json!({ "number": 1 }))
+            }
+        });
+        github
+    }
+
+    #[test]
+    fn write_class_of_reads_mutations_and_content() {
+        assert_eq!(
+            EndPoints::GetReposownerrepoIssues(s("acme"), s("api")).write_class(),
+            WriteClass::Read
+        );
+        assert_eq!(
+            EndPoints::PostReposownerrepoIssuesissueNumberLabels(s("acme"), s("api"), s("1"))
+                .write_class(),
+            WriteClass::Mutation
+        );
+        assert_eq!(
+            EndPoints::DeleteReposownerrepoIssuesissueNumberLabelsname(
+                s("acme"),
+                s("api"),
+                s("1"),
+                s("bug")
+            )
+            .write_class(),
+            WriteClass::Mutation
+        );
+        assert_eq!(
+            EndPoints::PutReposownerrepoContentspath(s("acme"), s("api"), s("README.md"))
+                .write_class(),
+            WriteClass::Mutation
+        );
+        for endpoint in [
+            EndPoints::PostReposownerrepoIssues(s("acme"), s("api")),
+            EndPoints::PostReposownerrepoIssuesissueNumberComments(s("acme"), s("api"), s("1")),
+            EndPoints::PostReposownerrepoPulls(s("acme"), s("api")),
+            EndPoints::PostUserRepos(),
+        ] {
+            assert_eq!(
+                endpoint.write_class(),
+                WriteClass::ContentCreation,
+                "{}",
+                endpoint.name()
+            );
+        }
+    }
+
+    #[test]
+    fn waits_out_retry_after_before_retrying() {
+        let github = limited(1, secondary_limit(Some("30")));
+        let clock = clock();
+        let throttle = Throttle::with_clock(&github, ThrottleConfig::default(), clock.clone());
+
+        let response = throttle.send(&issue()).unwrap();
+        assert_eq!(response.status, 201);
+        assert_eq!(clock.sleeps(), [Duration::from_secs(30)]);
+        assert_eq!(
+            throttle.metrics(),
+            ThrottleMetrics {
+                waits: 1,
+                total_wait: Duration::from_secs(30),
+                secondary_limit_hits: 1,
+                retries: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_default_wait_without_a_usable_retry_after() {
+        // An HTTP date is not a number of seconds.
+        for retry_after in [None, Some("Wed, 21 Oct 2015 07:28:00 GMT")] {
+            let github = limited(1, secondary_limit(retry_after));
+            let clock = clock();
+            let config = ThrottleConfig {
+                default_retry_after: Duration::from_secs(45),
+                ..ThrottleConfig::default()
+            };
+            let throttle = Throttle::with_clock(&github, config, clock.clone());
+
+            assert_eq!(throttle.send(&issue()).unwrap().status, 201);
+            assert_eq!(
+                clock.sleeps(),
+                [Duration::from_secs(45)],
+                "{:?}",
+                retry_after
+            );
+        }
+    }
+
+    #[test]
+    fn a_429_with_retry_after_is_a_secondary_limit() {
+        let mut too_many = json(429, &json!({ "message": "Too Many Requests" }));
+        too_many.headers.insert("Retry-After", " 5 ");
+        let github = limited(1, too_many);
+        let clock = clock();
+        let throttle = Throttle::with_clock(&github, ThrottleConfig::default(), clock.clone());
+
+        assert_eq!(throttle.send(&issue()).unwrap().status, 201);
+        assert_eq!(clock.sleeps(), [Duration::from_secs(5)]);
+    }
+
+    #[test]
+    fn gives_back_the_rejection_after_max_retries() {
+        let github = limited(usize::MAX, secondary_limit(Some("10")));
+        let clock = clock();
+        let throttle = Throttle::with_clock(&github, ThrottleConfig::default(), clock.clone());
+
+        let response = throttle.send(&issue()).unwrap();
+        assert_eq!(response.status, 403);
+        assert_eq!(github.calls(EndPoints::PostReposownerrepoIssues).len(), 3);
+        assert_eq!(throttle.metrics().secondary_limit_hits, 3);
+        assert_eq!(throttle.metrics().retries, 2);
+    }
+
+    #[test]
+    fn a_secondary_limit_holds_back_reads_too() {
+        let github = limited(usize::MAX, secondary_limit(Some("20")));
+        github.on(EndPoints::GetReposownerrepoIssues, |_| {
+            json(200, &json!([]))
+        });
+        let clock = clock();
+        let config = ThrottleConfig {
+            max_retries: 0,
+            ..ThrottleConfig::default()
+        };
+        let throttle = Throttle::with_clock(&github, config, clock.clone());
+
+        assert_eq!(throttle.send(&issue()).unwrap().status, 403);
+        clock.advance(Duration::from_secs(5));
+        let read = Request::new(EndPoints::GetReposownerrepoIssues(s("acme"), s("api")));
+        throttle.send(&read).unwrap();
+        assert_eq!(clock.sleeps(), [Duration::from_secs(15)]);
+    }
+
+    #[test]
+    fn spaces_out_mutations_but_not_reads() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoIssues, |_| {
+            json(200, &json!([]))
+        });
+        github.on(EndPoints::PostReposownerrepoIssuesissueNumberLabels, |_| {
+            json(200, &json!([]))
+        });
+        let clock = clock();
+        let throttle = Throttle::with_clock(&github, ThrottleConfig::default(), clock.clone());
+        let label = || {
+            Request::new(EndPoints::PostReposownerrepoIssuesissueNumberLabels(
+                s("acme"),
+                s("api"),
+                s("1"),
+            ))
+        };
+        let read = Request::new(EndPoints::GetReposownerrepoIssues(s("acme"), s("api")));
+
+        throttle.send(&label()).unwrap();
+        throttle.send(&read).unwrap();
+        assert!(clock.sleeps().is_empty());
+        clock.advance(Duration::from_millis(400));
+        throttle.send(&label()).unwrap();
+        assert_eq!(clock.sleeps(), [Duration::from_millis(600)]);
+    }
+}
@@ -0,0 +1,394 @@
+//! Receiving webhooks: checking that a delivery was signed with the hook's secret.
+//!
+//! GitHub signs every delivery body with HMAC-SHA256 in `X-Hub-Signature-256`, and
+//! with HMAC-SHA1 in the legacy `X-Hub-Signature`. A [`Verifier`] holds one or more
+//! secrets so a secret can be rotated without dropping deliveries: add the new one,
+//! update the hook, and remove the old one once [`Verified::secret`] stops pointing
+//! at it.
+//!
+//! The test vector from GitHub's documentation:
+//!
+//! ```
+//! use github_api_octocat::http::Headers;
+//! use github_api_octocat::webhooks::{Algorithm, SignatureError, Verifier};
+//!
+//! let verifier = Verifier::new("It's a Secret to Everybody");
+//! let body = b"Hello, World!";
+//!
+//! let mut headers = Headers::new();
+//! headers.insert(
+//!     "X-Hub-Signature-256",
+//!     "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+//! );
+//! let verified = verifier.verify(&headers, body).unwrap();
+//! assert_eq!(verified.algorithm, Algorithm::Sha256);
+//!
+//! let mut legacy = Headers::new();
+//! legacy.insert("X-Hub-Signature", "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59");
+//! assert_eq!(verifier.verify(&legacy, body).unwrap().algorithm, Algorithm::Sha1);
+//!
+//! assert_eq!(
+//!     verifier.verify(&headers, b"Hello, World?"),
+//!     Err(SignatureError::Mismatch)
+//! );
+//! assert_eq!(
+//!     verifier.verify(&Headers::new(), body),
+//!     Err(SignatureError::Missing)
+//! );
+//! ```
+
+use std::fmt;
+
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::Sha256;
+
+use crate::http::{decode_component, Headers};
+
+pub mod deliveries;
+pub mod events;
+pub mod fixtures;
+#[cfg(feature = "webhook-receiver")]
+pub mod receiver;
+pub mod sync;
+
+pub use events::WebhookEvent;
+
+pub const SIGNATURE_256_HEADER: &str = "X-Hub-Signature-256";
+/// Only sent when the hook has a secret, like its SHA-256 sibling. Kept for
+/// receivers that predate it.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    Sha256,
+    Sha1,
+}
+
+impl Algorithm {
+    /// The prefix before `=` in the header value.
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha1 => "sha1",
+        }
+    }
+
+    pub fn header(&self) -> &'static str {
+        match self {
+            Algorithm::Sha256 => SIGNATURE_256_HEADER,
+            Algorithm::Sha1 => SIGNATURE_HEADER,
+        }
+    }
+
+    /// The header value GitHub would send for `body`, e.g. `sha256=7571...`.
+    ///
+    /// ```
+    /// use github_api_octocat::webhooks::Algorithm;
+    ///
+    /// assert_eq!(
+    ///     Algorithm::Sha1.sign(b"It's a Secret to Everybody", b"Hello, World!"),
+    ///     "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59"
+    /// );
+    /// ```
+    pub fn sign(&self, secret: &[u8], body: &[u8]) -> String {
+        let digest = match self {
+            Algorithm::Sha256 => sha256(secret, body).finalize().into_bytes().to_vec(),
+            Algorithm::Sha1 => sha1(secret, body).finalize().into_bytes().to_vec(),
+        };
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}={}", self.prefix(), hex)
+    }
+
+    /// Constant-time comparison of `digest` with the HMAC of `body`.
+    fn matches(&self, secret: &[u8], body: &[u8], digest: &[u8]) -> bool {
+        match self {
+            Algorithm::Sha256 => sha256(secret, body).verify_slice(digest).is_ok(),
+            Algorithm::Sha1 => sha1(secret, body).verify_slice(digest).is_ok(),
+        }
+    }
+}
+
+fn sha256(secret: &[u8], body: &[u8]) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC takes keys of any length");
+    mac.update(body);
+    mac
+}
+
+fn sha1(secret: &[u8], body: &[u8]) -> Hmac<Sha1> {
+    let mut mac = Hmac::<Sha1>::new_from_slice(secret).expect("HMAC takes keys of any length");
+    mac.update(body);
+    mac
+}
+
+/// Why a delivery was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    /// Neither signature header is present, or only SHA-1 when it is not accepted.
+    Missing,
+    /// The header is not `<algorithm>=<hex digest>` with a digest of the right length.
+    Malformed(String),
+    /// The header names an algorithm other than the one it belongs to.
+    UnsupportedAlgorithm(String),
+    /// No secret produces this signature: a wrong secret or a tampered body.
+    Mismatch,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignatureError::Missing => write!(f, "webhook delivery is not signed"),
+            SignatureError::Malformed(value) => {
+                write!(f, "malformed webhook signature {:?}", value)
+            }
+            SignatureError::UnsupportedAlgorithm(algorithm) => {
+                write!(f, "unsupported webhook signature algorithm {:?}", algorithm)
+            }
+            SignatureError::Mismatch => write!(f, "webhook signature does not match"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// A successful check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Verified {
+    pub algorithm: Algorithm,
+    /// Index of the matching secret, in the order they were added.
+    pub secret: usize,
+}
+
+/// Checks delivery signatures against a set of secrets. See the [module docs](self).
+#[derive(Clone)]
+pub struct Verifier {
+    secrets: Vec<Vec<u8>>,
+    accept_sha1: bool,
+}
+
+impl Verifier {
+    pub fn new(secret: impl AsRef<[u8]>) -> Self {
+        Verifier {
+            secrets: vec![secret.as_ref().to_vec()],
+            accept_sha1: true,
+        }
+    }
+
+    /// Also accepts deliveries signed with `secret`, e.g. while rotating.
+    pub fn secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.secrets.push(secret.as_ref().to_vec());
+        self
+    }
+
+    /// Whether a delivery carrying only `X-Hub-Signature` is accepted. On by
+    /// default; SHA-256 is always preferred when both are present.
+    pub fn accept_sha1(mut self, accept: bool) -> Self {
+        self.accept_sha1 = accept;
+        self
+    }
+
+    /// Checks the signature headers of a delivery against its raw body, exactly
+    /// as received.
+    pub fn verify(&self, headers: &Headers, body: &[u8]) -> Result<Verified, SignatureError> {
+        if let Some(signature) = headers.get(SIGNATURE_256_HEADER) {
+            return self.verify_signature(Algorithm::Sha256, signature, body);
+        }
+        match headers.get(SIGNATURE_HEADER) {
+            Some(signature) if self.accept_sha1 => {
+                self.verify_signature(Algorithm::Sha1, signature, body)
+            }
+            _ => Err(SignatureError::Missing),
+        }
+    }
+
+    /// Checks one header value, e.g. `sha256=7571...`, for frameworks with their
+    /// own header types.
+    pub fn verify_signature(
+        &self,
+        algorithm: Algorithm,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<Verified, SignatureError> {
+        let signature = signature.trim();
+        let (prefix, hex) = signature
+            .split_once('=')
+            .ok_or_else(|| SignatureError::Malformed(signature.to_string()))?;
+        if !prefix.eq_ignore_ascii_case(algorithm.prefix()) {
+            return Err(SignatureError::UnsupportedAlgorithm(prefix.to_string()));
+        }
+        let digest =
+            decode_hex(hex).ok_or_else(|| SignatureError::Malformed(signature.to_string()))?;
+        let expected_len = match algorithm {
+            Algorithm::Sha256 => 32,
+            Algorithm::Sha1 => 20,
+        };
+        if digest.len() != expected_len {
+            return Err(SignatureError::Malformed(signature.to_string()));
+        }
+        // Every secret is tried so timing does not reveal which one matched.
+        let mut matched = None;
+        for (index, secret) in self.secrets.iter().enumerate() {
+            if algorithm.matches(secret, body, &digest) && matched.is_none() {
+                matched = Some(index);
+            }
+        }
+        matched
+            .map(|secret| Verified { algorithm, secret })
+            .ok_or(SignatureError::Mismatch)
+    }
+}
+
+impl fmt::Debug for Verifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Verifier")
+            .field("secrets", &self.secrets.len())
+            .field("accept_sha1", &self.accept_sha1)
+            .finish()
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let digits: Option<Vec<u8>> = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect();
+    let digits = digits?;
+    if digits.len() % 2 != 0 {
+        return None;
+    }
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect(),
+    )
+}
+
+/// A query parameter of the `rel="next"` link, e.g. `page`, or `cursor` for
+/// delivery lists. `None` on the last page.
+fn next_link_param(link: &str, name: &str) -> Option<String> {
+    let next = link.split(',').find(|part| part.contains("rel=\"next\""))?;
+    let url = next.split(['<', '>']).nth(1)?;
+    let (_, query) = url.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| decode_component(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &[u8] = b"Hello, World!";
+    const SIGNATURE_256: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    const SIGNATURE_1: &str = "sha1=01dc10d0c83e72ed246219cdd91669667fe2ca59";
+
+    fn signed(header: &str, value: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(header, value);
+        headers
+    }
+
+    #[test]
+    fn any_secret_verifies_and_is_reported_by_index() {
+        let verifier = Verifier::new("old secret").secret("It's a Secret to Everybody");
+
+        let verified = verifier
+            .verify(&signed(SIGNATURE_256_HEADER, SIGNATURE_256), BODY)
+            .unwrap();
+        assert_eq!(
+            verified,
+            Verified {
+                algorithm: Algorithm::Sha256,
+                secret: 1
+            }
+        );
+        let signature = Algorithm::Sha256.sign(b"old secret", BODY);
+        let verified = verifier
+            .verify(&signed(SIGNATURE_256_HEADER, &signature), BODY)
+            .unwrap();
+        assert_eq!(verified.secret, 0);
+        assert_eq!(
+            Verifier::new("old secret").verify(&signed(SIGNATURE_256_HEADER, SIGNATURE_256), BODY),
+            Err(SignatureError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn malformed_signatures_are_rejected() {
+        let verifier = Verifier::new("It's a Secret to Everybody");
+        for value in [
+            "757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17",
+            "sha256=not hex",
+            "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e1",
+            "sha256=757107ea",
+            "sha256=",
+        ] {
+            assert_eq!(
+                verifier.verify(&signed(SIGNATURE_256_HEADER, value), BODY),
+                Err(SignatureError::Malformed(value.to_string())),
+                "{}",
+                value
+            );
+        }
+        // Surrounding whitespace and the prefix's case do not matter.
+        let padded = format!(" {} ", SIGNATURE_256.replace("sha256", "SHA256"));
+        assert!(verifier
+            .verify(&signed(SIGNATURE_256_HEADER, &padded), BODY)
+            .is_ok());
+    }
+
+    #[test]
+    fn an_algorithm_other_than_the_headers_is_unsupported() {
+        let verifier = Verifier::new("It's a Secret to Everybody");
+
+        assert_eq!(
+            verifier.verify(&signed(SIGNATURE_256_HEADER, SIGNATURE_1), BODY),
+            Err(SignatureError::UnsupportedAlgorithm("sha1".to_string()))
+        );
+        assert_eq!(
+            verifier.verify_signature(Algorithm::Sha1, "md5=00", BODY),
+            Err(SignatureError::UnsupportedAlgorithm("md5".to_string()))
+        );
+    }
+
+    #[test]
+    fn unsigned_deliveries_are_missing_a_signature() {
+        let verifier = Verifier::new("It's a Secret to Everybody");
+
+        assert_eq!(
+            verifier.verify(&Headers::new(), BODY),
+            Err(SignatureError::Missing)
+        );
+        assert_eq!(
+            verifier.verify(&signed("X-Hub-Signature-512", SIGNATURE_256), BODY),
+            Err(SignatureError::Missing)
+        );
+    }
+
+    #[test]
+    fn sha1_only_deliveries_can_be_refused() {
+        let verifier = Verifier::new("It's a Secret to Everybody").accept_sha1(false);
+
+        assert_eq!(
+            verifier.verify(&signed(SIGNATURE_HEADER, SIGNATURE_1), BODY),
+            Err(SignatureError::Missing)
+        );
+        let mut both = signed(SIGNATURE_HEADER, SIGNATURE_1);
+        both.insert(SIGNATURE_256_HEADER, SIGNATURE_256);
+        assert_eq!(
+            verifier.verify(&both, BODY).unwrap().algorithm,
+            Algorithm::Sha256
+        );
+        // SHA-256 is preferred even when SHA-1 is accepted, and is not bypassed
+        // by a valid SHA-1 signature next to a wrong one.
+        both.insert(SIGNATURE_256_HEADER, Algorithm::Sha256.sign(b"wrong", BODY));
+        assert_eq!(
+            Verifier::new("It's a Secret to Everybody").verify(&both, BODY),
+            Err(SignatureError::Mismatch)
+        );
+    }
+}
@@ -0,0 +1,546 @@
+//! Finding deliveries a receiver missed and asking GitHub to send them again.
+//!
+//! GitHub keeps a hook's deliveries, failed ones included, for a few days. A
+//! [`Reconciler`] pages through them newest first, groups attempts by `guid`
+//! (redeliveries keep the guid of the original), and redelivers every guid
+//! none of whose attempts got a successful response.
+//!
+//! ```no_run
+//! use std::time::{Duration, SystemTime};
+//!
+//! use github_api_octocat::http::Transport;
+//! use github_api_octocat::webhooks::deliveries::{Hook, Reconciler};
+//! # fn reconcile(transport: impl Transport) -> Result<(), github_api_octocat::Error> {
+//! let report = Reconciler::new(transport, Hook::repository("octocat", "hello-world", 1))
+//!     .since(SystemTime::now() - Duration::from_secs(6 * 3600))
+//!     .run()?;
+//! println!("{}", report);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::next_link_param;
+use crate::clock::{Clock, SystemClock};
+use crate::end_points::EndPoints;
+use crate::error::Error;
+use crate::http::{Request, Transport};
+use crate::token::parse_timestamp;
+
+const PER_PAGE: usize = 100;
+
+/// The hook whose deliveries to reconcile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hook {
+    /// The webhook of the GitHub App the transport authenticates as, with a JWT.
+    App,
+    Repository {
+        owner: String,
+        repo: String,
+        hook_id: u64,
+    },
+    Organization {
+        org: String,
+        hook_id: u64,
+    },
+}
+
+impl Hook {
+    pub fn repository(owner: impl Into<String>, repo: impl Into<String>, hook_id: u64) -> Self {
+        Hook::Repository {
+            owner: owner.into(),
+            repo: repo.into(),
+            hook_id,
+        }
+    }
+
+    pub fn organization(org: impl Into<String>, hook_id: u64) -> Self {
+        Hook::Organization {
+            org: org.into(),
+            hook_id,
+        }
+    }
+
+    pub fn list_deliveries(&self) -> EndPoints {
+        match self {
+            Hook::App => EndPoints::GetAppHookDeliveries(),
+            Hook::Repository {
+                owner,
+                repo,
+                hook_id,
+            } => EndPoints::GetReposownerrepoHookshookIdDeliveries(
+                owner.clone(),
+                repo.clone(),
+                hook_id.to_string(),
+            ),
+            Hook::Organization { org, hook_id } => {
+                EndPoints::GetOrgsorgHookshookIdDeliveries(org.clone(), hook_id.to_string())
+            }
+        }
+    }
+
+    pub fn redeliver(&self, delivery_id: u64) -> EndPoints {
+        let delivery_id = delivery_id.to_string();
+        match self {
+            Hook::App => EndPoints::PostAppHookDeliveriesdeliveryIdAttempts(delivery_id),
+            Hook::Repository {
+                owner,
+                repo,
+                hook_id,
+            } => EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts(
+                owner.clone(),
+                repo.clone(),
+                hook_id.to_string(),
+                delivery_id,
+            ),
+            Hook::Organization { org, hook_id } => {
+                EndPoints::PostOrgsorgHookshookIdDeliveriesdeliveryIdAttempts(
+                    org.clone(),
+                    hook_id.to_string(),
+                    delivery_id,
+                )
+            }
+        }
+    }
+}
+
+/// One delivery attempt, as listed by `GetReposownerrepoHookshookIdDeliveries`
+/// and its siblings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delivery {
+    pub id: u64,
+    /// Shared by a delivery and all its redeliveries.
+    pub guid: String,
+    pub delivered_at: String,
+    #[serde(default)]
+    pub redelivery: bool,
+    #[serde(default)]
+    pub duration: f64,
+    /// e.g. `OK` or `Invalid HTTP Response: 503`.
+    #[serde(default)]
+    pub status: String,
+    /// `None` or `0` when the receiver never answered.
+    pub status_code: Option<u16>,
+    pub event: String,
+    pub action: Option<String>,
+    pub installation_id: Option<u64>,
+    pub repository_id: Option<u64>,
+}
+
+impl Delivery {
+    /// Whether the receiver answered with a status below 400.
+    pub fn succeeded(&self) -> bool {
+        matches!(self.status_code, Some(code) if code != 0 && code < 400)
+    }
+
+    pub fn delivered_at(&self) -> Option<SystemTime> {
+        parse_timestamp(&self.delivered_at)
+    }
+}
+
+/// A redelivery GitHub did not accept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RedeliveryError {
+    pub guid: String,
+    pub delivery_id: u64,
+    pub error: String,
+}
+
+/// What a [`Reconciler::run`] found and did.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Report {
+    pub dry_run: bool,
+    /// Attempts listed inside the window.
+    pub scanned: usize,
+    /// Distinct guids among them.
+    pub guids: usize,
+    /// The latest attempt of every guid that never succeeded, oldest first.
+    pub failed: Vec<Delivery>,
+    /// Guids GitHub accepted a redelivery for. Empty on a dry run.
+    pub redelivered: Vec<String>,
+    pub errors: Vec<RedeliveryError>,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "scanned {} attempts of {} deliveries: {} failed, {} redelivered, {} errors",
+            self.scanned,
+            self.guids,
+            self.failed.len(),
+            self.redelivered.len(),
+            self.errors.len()
+        )?;
+        for delivery in &self.failed {
+            let outcome = if self.dry_run {
+                "would redeliver".to_string()
+            } else if self.redelivered.contains(&delivery.guid) {
+                "redelivered".to_string()
+            } else if let Some(e) = self.errors.iter().find(|e| e.guid == delivery.guid) {
+                format!("error: {}", e.error)
+            } else {
+                "not redelivered".to_string()
+            };
+            writeln!(
+                f,
+                "  {} {} {}{} [{}] {}",
+                delivery.delivered_at,
+                delivery.guid,
+                delivery.event,
+                delivery
+                    .action
+                    .as_deref()
+                    .map_or_else(String::new, |a| format!(".{}", a)),
+                delivery.status,
+                outcome
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// See the [module docs](self).
+#[derive(Debug)]
+pub struct Reconciler<T, C = SystemClock> {
+    transport: T,
+    clock: C,
+    hook: Hook,
+    since: Option<SystemTime>,
+    until: Option<SystemTime>,
+    interval: Duration,
+    dry_run: bool,
+}
+
+impl<T: Transport> Reconciler<T> {
+    pub fn new(transport: T, hook: Hook) -> Self {
+        Reconciler::with_clock(transport, hook, SystemClock)
+    }
+}
+
+impl<T: Transport, C: Clock> Reconciler<T, C> {
+    pub fn with_clock(transport: T, hook: Hook, clock: C) -> Self {
+        Reconciler {
+            transport,
+            clock,
+            hook,
+            since: None,
+            until: None,
+            interval: Duration::from_secs(1),
+            dry_run: false,
+        }
+    }
+
+    /// Ignores attempts older than `since`. Without it, everything GitHub still has.
+    pub fn since(mut self, since: SystemTime) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Ignores attempts newer than `until`, e.g. ones still being retried.
+    pub fn until(mut self, until: SystemTime) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Pause between two redeliveries. A second by default, as GitHub asks of
+    /// mutating calls.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Only reports what would be redelivered.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Scans the window and redelivers what failed. Listing errors abort the
+    /// run; a rejected redelivery is recorded in the report and the rest go on.
+    pub fn run(&self) -> Result<Report, Error> {
+        let deliveries = self.scan()?;
+        let mut report = Report {
+            dry_run: self.dry_run,
+            scanned: deliveries.len(),
+            ..Report::default()
+        };
+        // Newest first, so the first attempt seen for a guid is its latest.
+        let mut latest: HashMap<&str, &Delivery> = HashMap::new();
+        let mut succeeded: HashMap<&str, bool> = HashMap::new();
+        for delivery in &deliveries {
+            latest.entry(&delivery.guid).or_insert(delivery);
+            *succeeded.entry(&delivery.guid).or_default() |= delivery.succeeded();
+        }
+        report.guids = latest.len();
+        report.failed = latest
+            .into_iter()
+            .filter(|(guid, _)| !succeeded[guid])
+            .map(|(_, delivery)| delivery.clone())
+            .collect();
+        report
+            .failed
+            .sort_by(|a, b| (&a.delivered_at, a.id).cmp(&(&b.delivered_at, b.id)));
+        if self.dry_run {
+            return Ok(report);
+        }
+        for (i, delivery) in report.failed.iter().enumerate() {
+            if i > 0 {
+                self.clock.sleep(self.interval);
+            }
+            let request = Request::new(self.hook.redeliver(delivery.id));
+            match self.transport.execute(&request) {
+                Ok(_) => report.redelivered.push(delivery.guid.clone()),
+                Err(e) => report.errors.push(RedeliveryError {
+                    guid: delivery.guid.clone(),
+                    delivery_id: delivery.id,
+                    error: e.to_string(),
+                }),
+            }
+        }
+        Ok(report)
+    }
+
+    /// Every attempt in the window, newest first.
+    fn scan(&self) -> Result<Vec<Delivery>, Error> {
+        let until = self.until.unwrap_or_else(|| self.clock.now());
+        let since = self.since.unwrap_or(UNIX_EPOCH);
+        let mut deliveries = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let mut request = Request::new(self.hook.list_deliveries()).query("per_page", PER_PAGE);
+            if let Some(cursor) = &cursor {
+                request = request.query("cursor", cursor);
+            }
+            let response = self.transport.execute(&request)?;
+            let page: Vec<Delivery> = response.json()?;
+            let mut past_window = false;
+            for delivery in page {
+                match delivery.delivered_at() {
+                    Some(at) if at < since => past_window = true,
+                    Some(at) if at > until => {}
+                    _ => deliveries.push(delivery),
+                }
+            }
+            cursor = response
+                .headers
+                .get("link")
+                .and_then(|link| next_link_param(link, "cursor"));
+            if past_window || cursor.is_none() {
+                return Ok(deliveries);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::clock::FakeClock;
+    use crate::fake::{json, FakeGitHub};
+
+    /// 2024-05-01T13:00:00Z.
+    const NOW: u64 = 1_714_568_400;
+
+    fn clock() -> FakeClock {
+        FakeClock::new(UNIX_EPOCH + Duration::from_secs(NOW))
+    }
+
+    fn hook() -> Hook {
+        Hook::repository("acme", "api", 7)
+    }
+
+    fn delivery(id: u64, guid: &str, minute: u32, status_code: Value) -> Value {
+        json!({
+            "id": id,
+            "guid": guid,
+            "delivered_at": format!("2024-05-01T12:{:02}:00Z", minute),
+            "redelivery": false,
+            "duration": 0.2,
+            "status": "Invalid HTTP Response: 503",
+            "status_code": status_code,
+            "event": "push",
+            "action": null,
+        })
+    }
+
+    /// Newest first, as GitHub lists them.
+    fn deliveries() -> Vec<Value> {
+        vec![
+            delivery(7, "e", 6, json!(400)),
+            // A redelivery that got through makes its guid a success.
+            delivery(6, "a", 5, json!(200)),
+            // The receiver never answered, either time.
+            delivery(5, "b", 4, json!(0)),
+            delivery(4, "c", 3, Value::Null),
+            delivery(3, "d", 2, json!(302)),
+            delivery(2, "b", 1, json!(502)),
+            delivery(1, "a", 0, json!(500)),
+        ]
+    }
+
+    fn serve(github: &FakeGitHub, deliveries: Vec<Value>) {
+        github.on(
+            EndPoints::GetReposownerrepoHookshookIdDeliveries,
+            move |_| json(200, &deliveries),
+        );
+    }
+
+    fn ids(deliveries: &[Delivery]) -> Vec<u64> {
+        deliveries.iter().map(|d| d.id).collect()
+    }
+
+    #[test]
+    fn a_status_of_400_or_none_at_all_is_a_failure() {
+        let parse = |code: Value| -> Delivery {
+            serde_json::from_value(delivery(1, "a", 0, code)).unwrap()
+        };
+        assert!(parse(json!(200)).succeeded());
+        assert!(parse(json!(399)).succeeded());
+        assert!(!parse(json!(400)).succeeded());
+        assert!(!parse(json!(503)).succeeded());
+        assert!(!parse(json!(0)).succeeded());
+        assert!(!parse(Value::Null).succeeded());
+        let mut missing = delivery(1, "a", 0, Value::Null);
+        missing.as_object_mut().unwrap().remove("status_code");
+        assert!(!serde_json::from_value::<Delivery>(missing)
+            .unwrap()
+            .succeeded());
+    }
+
+    #[test]
+    fn redelivers_the_latest_attempt_of_each_failed_guid() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, deliveries());
+        github.on(
+            EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts,
+            |request| match request.endpoint.args()[3] {
+                "7" => json(422, &json!({ "message": "Validation Failed" })),
+                _ => json(202, &json!({})),
+            },
+        );
+        let clock = clock();
+        let reconciler = Reconciler::with_clock(&github, hook(), clock.clone())
+            .interval(Duration::from_millis(500));
+
+        let report = reconciler.run().unwrap();
+        assert_eq!(report.scanned, 7);
+        assert_eq!(report.guids, 5);
+        // Oldest first; b's second attempt stands for both.
+        assert_eq!(ids(&report.failed), [4, 5, 7]);
+        assert_eq!(report.redelivered, ["c", "b"]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].guid, "e");
+        assert_eq!(report.errors[0].delivery_id, 7);
+
+        let paths: Vec<String> = github
+            .calls(EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts)
+            .iter()
+            .map(|r| r.endpoint.path())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/repos/acme/api/hooks/7/deliveries/4/attempts",
+                "/repos/acme/api/hooks/7/deliveries/5/attempts",
+                "/repos/acme/api/hooks/7/deliveries/7/attempts",
+            ]
+        );
+        // Paced between redeliveries, not before the first.
+        assert_eq!(clock.sleeps(), [Duration::from_millis(500); 2]);
+
+        let text = report.to_string();
+        assert!(text.starts_with(
+            "scanned 7 attempts of 5 deliveries: 3 failed, 2 redelivered, 1 errors\n"
+        ));
+        assert!(
+            text.contains("2024-05-01T12:03:00Z c push [Invalid HTTP Response: 503] redelivered")
+        );
+        assert!(text.contains("e push [Invalid HTTP Response: 503] error: "));
+    }
+
+    #[test]
+    fn a_dry_run_redelivers_nothing() {
+        let github = FakeGitHub::start().unwrap();
+        serve(&github, deliveries());
+        let clock = clock();
+
+        let report = Reconciler::with_clock(&github, hook(), clock.clone())
+            .dry_run(true)
+            .run()
+            .unwrap();
+        assert!(report.dry_run);
+        assert_eq!(ids(&report.failed), [4, 5, 7]);
+        assert!(report.redelivered.is_empty());
+        assert!(report.errors.is_empty());
+        assert!(clock.sleeps().is_empty());
+        assert!(github
+            .calls(EndPoints::PostReposownerrepoHookshookIdDeliveriesdeliveryIdAttempts)
+            .is_empty());
+        assert_eq!(report.to_string().matches("would redeliver").count(), 3);
+    }
+
+    #[test]
+    fn scans_the_window_across_cursor_pages() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoHookshookIdDeliveries, |request| {
+            let cursor = request
+                .query
+                .iter()
+                .find(|(key, _)| key == "cursor")
+                .map(|(_, value)| value.clone());
+            let mut response = match cursor.as_deref() {
+                None => json(
+                    200,
+                    &[
+                        // Newer than `until`: still being retried.
+                        delivery(5, "e", 59, json!(500)),
+                        delivery(4, "d", 30, json!(500)),
+                    ],
+                ),
+                Some("v1_4") => json(
+                    200,
+                    &[
+                        delivery(3, "c", 20, json!(500)),
+                        // Older than `since`: the scan stops here.
+                        delivery(2, "b", 5, json!(500)),
+                    ],
+                ),
+                Some(other) => panic!("read past the window, cursor {}", other),
+            };
+            let next = match cursor {
+                None => "v1_4",
+                Some(_) => "v1_2",
+            };
+            response.headers.insert(
+                "Link",
+                format!(
+                    "<https://api.github.com/repos/acme/api/hooks/7/deliveries?per_page=100&cursor={}>; rel=\"next\"",
+                    next
+                ),
+            );
+            response
+        });
+
+        let report = Reconciler::with_clock(&github, hook(), clock())
+            .since(UNIX_EPOCH + Duration::from_secs(NOW - 50 * 60))
+            .until(UNIX_EPOCH + Duration::from_secs(NOW - 10 * 60))
+            .dry_run(true)
+            .run()
+            .unwrap();
+        assert_eq!(report.scanned, 2);
+        assert_eq!(ids(&report.failed), [3, 4]);
+        let calls = github.calls(EndPoints::GetReposownerrepoHookshookIdDeliveries);
+        assert_eq!(calls.len(), 2);
+        assert!(calls[1]
+            .query
+            .contains(&("per_page".to_string(), "100".to_string())));
+    }
+}
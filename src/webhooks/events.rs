@@ -0,0 +1,643 @@
+//! Typed webhook payloads, keyed on `X-GitHub-Event`, and a [`Router`] to hand
+//! them to per-event handlers.
+//!
+//! ```
+//! use github_api_octocat::http::Headers;
+//! use github_api_octocat::webhooks::events::{PullRequestAction, PullRequestEvent, Router};
+//! use github_api_octocat::webhooks::WebhookEvent;
+//!
+//! let router = Router::new().on(|event: &PullRequestEvent| {
+//!     if event.action == PullRequestAction::Opened {
+//!         println!("#{} {}", event.number, event.pull_request.title);
+//!     }
+//!     Ok(())
+//! });
+//!
+//! let mut headers = Headers::new();
+//! headers.insert("X-GitHub-Event", "ping");
+//! let body = br#"{"zen": "Keep it logically awesome.", "hook_id": 1}"#;
+//! let event = WebhookEvent::parse(&headers, body).unwrap();
+//! assert_eq!(event.name(), "ping");
+//! assert!(!router.dispatch(&event).unwrap());
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::BoxError;
+use crate::http::Headers;
+use crate::models::{
+    CheckRun, Comment, GitActor, Installation, Issue, Label, Milestone, PullRequest, PushCommit,
+    Repository, User, WorkflowRun,
+};
+
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// Unique per delivery, and the same across redeliveries of it.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+
+/// Sent when a hook is created, and by `PostReposownerrepoHookshookIdPings`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PingEvent {
+    pub zen: String,
+    pub hook_id: u64,
+    pub hook: Option<Value>,
+    pub repository: Option<Repository>,
+    pub sender: Option<User>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PushEvent {
+    /// The full ref, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub git_ref: String,
+    pub before: String,
+    pub after: String,
+    #[serde(default)]
+    pub created: bool,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub forced: bool,
+    pub compare: Option<String>,
+    #[serde(default)]
+    pub commits: Vec<PushCommit>,
+    pub head_commit: Option<PushCommit>,
+    pub pusher: GitActor,
+    pub repository: Repository,
+    pub sender: Option<User>,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PullRequestAction {
+    Opened,
+    Edited,
+    Closed,
+    Reopened,
+    Synchronize,
+    Assigned,
+    Unassigned,
+    Labeled,
+    Unlabeled,
+    ReviewRequested,
+    ReviewRequestRemoved,
+    ReadyForReview,
+    ConvertedToDraft,
+    Locked,
+    Unlocked,
+    Milestoned,
+    Demilestoned,
+    AutoMergeEnabled,
+    AutoMergeDisabled,
+    Enqueued,
+    Dequeued,
+    /// An action added after this was written.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PullRequestEvent {
+    pub action: PullRequestAction,
+    pub number: u64,
+    pub pull_request: PullRequest,
+    /// For `labeled` and `unlabeled`.
+    pub label: Option<Label>,
+    /// For `assigned` and `unassigned`.
+    pub assignee: Option<User>,
+    /// For `review_requested` and `review_request_removed` on a user.
+    pub requested_reviewer: Option<User>,
+    /// For `synchronize`.
+    pub before: Option<String>,
+    pub after: Option<String>,
+    /// For `edited`: the previous values of what changed.
+    pub changes: Option<Value>,
+    pub repository: Repository,
+    pub sender: User,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssuesAction {
+    Opened,
+    Edited,
+    Deleted,
+    Closed,
+    Reopened,
+    Assigned,
+    Unassigned,
+    Labeled,
+    Unlabeled,
+    Milestoned,
+    Demilestoned,
+    Locked,
+    Unlocked,
+    Pinned,
+    Unpinned,
+    Transferred,
+    /// An action added after this was written.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssuesEvent {
+    pub action: IssuesAction,
+    pub issue: Issue,
+    /// For `labeled` and `unlabeled`.
+    pub label: Option<Label>,
+    /// For `assigned` and `unassigned`.
+    pub assignee: Option<User>,
+    /// For `milestoned` and `demilestoned`.
+    pub milestone: Option<Milestone>,
+    /// For `edited`: the previous values of what changed.
+    pub changes: Option<Value>,
+    pub repository: Repository,
+    pub sender: User,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueCommentAction {
+    Created,
+    Edited,
+    Deleted,
+    #[serde(other)]
+    Other,
+}
+
+/// A comment on an issue or, when [`Issue::is_pull_request`], a pull request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IssueCommentEvent {
+    pub action: IssueCommentAction,
+    pub issue: Issue,
+    pub comment: Comment,
+    pub changes: Option<Value>,
+    pub repository: Repository,
+    pub sender: User,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckRunAction {
+    Created,
+    Completed,
+    Rerequested,
+    RequestedAction,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckRunEvent {
+    pub action: CheckRunAction,
+    pub check_run: CheckRun,
+    /// For `requested_action`: the button the user pressed.
+    pub requested_action: Option<Value>,
+    pub repository: Repository,
+    pub sender: User,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkflowRunAction {
+    Requested,
+    InProgress,
+    Completed,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowRunEvent {
+    pub action: WorkflowRunAction,
+    pub workflow_run: WorkflowRun,
+    pub workflow: Option<Value>,
+    pub repository: Repository,
+    pub sender: User,
+    pub installation: Option<Installation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstallationAction {
+    Created,
+    Deleted,
+    Suspend,
+    Unsuspend,
+    NewPermissionsAccepted,
+    #[serde(other)]
+    Other,
+}
+
+/// A repository as listed in `installation` payloads.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstalledRepository {
+    pub id: u64,
+    pub name: String,
+    pub full_name: String,
+    #[serde(default)]
+    pub private: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstallationEvent {
+    pub action: InstallationAction,
+    pub installation: Installation,
+    /// For `created`, when the app was given selected repositories.
+    pub repositories: Option<Vec<InstalledRepository>>,
+    pub requester: Option<User>,
+    pub sender: User,
+}
+
+/// A parsed delivery. Events without a typed model are kept as JSON in
+/// [`WebhookEvent::Other`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebhookEvent {
+    Ping(Box<PingEvent>),
+    Push(Box<PushEvent>),
+    PullRequest(Box<PullRequestEvent>),
+    Issues(Box<IssuesEvent>),
+    IssueComment(Box<IssueCommentEvent>),
+    CheckRun(Box<CheckRunEvent>),
+    WorkflowRun(Box<WorkflowRunEvent>),
+    Installation(Box<InstallationEvent>),
+    Other { event: String, payload: Value },
+}
+
+/// Why a delivery could not be parsed.
+#[derive(Debug)]
+pub enum EventError {
+    /// No `X-GitHub-Event` header.
+    MissingEvent,
+    /// The body does not fit the model for `event`.
+    Json {
+        event: String,
+        error: serde_json::Error,
+    },
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::MissingEvent => write!(f, "missing {} header", EVENT_HEADER),
+            EventError::Json { event, error } => {
+                write!(f, "invalid {} payload: {}", event, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for EventError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EventError::MissingEvent => None,
+            EventError::Json { error, .. } => Some(error),
+        }
+    }
+}
+
+impl WebhookEvent {
+    /// Parses a delivery body according to its `X-GitHub-Event` header. Check
+    /// the signature first, see [`Verifier`](super::Verifier).
+    pub fn parse(headers: &Headers, body: &[u8]) -> Result<Self, EventError> {
+        let event = headers.get(EVENT_HEADER).ok_or(EventError::MissingEvent)?;
+        WebhookEvent::from_slice(event.trim(), body)
+    }
+
+    /// Parses `body` as the payload of an `event` delivery, e.g. `pull_request`.
+    pub fn from_slice(event: &str, body: &[u8]) -> Result<Self, EventError> {
+        fn typed<T: serde::de::DeserializeOwned>(
+            event: &str,
+            body: &[u8],
+        ) -> Result<Box<T>, EventError> {
+            serde_json::from_slice(body).map_err(|error| EventError::Json {
+                event: event.to_string(),
+                error,
+            })
+        }
+        Ok(match event {
+            PingEvent::NAME => WebhookEvent::Ping(typed(event, body)?),
+            PushEvent::NAME => WebhookEvent::Push(typed(event, body)?),
+            PullRequestEvent::NAME => WebhookEvent::PullRequest(typed(event, body)?),
+            IssuesEvent::NAME => WebhookEvent::Issues(typed(event, body)?),
+            IssueCommentEvent::NAME => WebhookEvent::IssueComment(typed(event, body)?),
+            CheckRunEvent::NAME => WebhookEvent::CheckRun(typed(event, body)?),
+            WorkflowRunEvent::NAME => WebhookEvent::WorkflowRun(typed(event, body)?),
+            InstallationEvent::NAME => WebhookEvent::Installation(typed(event, body)?),
+            _ => WebhookEvent::Other {
+                event: event.to_string(),
+                payload: *typed(event, body)?,
+            },
+        })
+    }
+
+    /// The `X-GitHub-Event` value, e.g. `pull_request`.
+    pub fn name(&self) -> &str {
+        match self {
+            WebhookEvent::Ping(_) => PingEvent::NAME,
+            WebhookEvent::Push(_) => PushEvent::NAME,
+            WebhookEvent::PullRequest(_) => PullRequestEvent::NAME,
+            WebhookEvent::Issues(_) => IssuesEvent::NAME,
+            WebhookEvent::IssueComment(_) => IssueCommentEvent::NAME,
+            WebhookEvent::CheckRun(_) => CheckRunEvent::NAME,
+            WebhookEvent::WorkflowRun(_) => WorkflowRunEvent::NAME,
+            WebhookEvent::Installation(_) => InstallationEvent::NAME,
+            WebhookEvent::Other { event, .. } => event,
+        }
+    }
+}
+
+/// A payload type with its own [`WebhookEvent`] variant.
+pub trait Event: Sized {
+    /// The `X-GitHub-Event` value.
+    const NAME: &'static str;
+
+    fn from_event(event: &WebhookEvent) -> Option<&Self>;
+}
+
+macro_rules! event {
+    ($name:literal, $variant:ident, $payload:ident) => {
+        impl Event for $payload {
+            const NAME: &'static str = $name;
+
+            fn from_event(event: &WebhookEvent) -> Option<&Self> {
+                match event {
+                    WebhookEvent::$variant(payload) => Some(payload),
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+event!("ping", Ping, PingEvent);
+event!("push", Push, PushEvent);
+event!("pull_request", PullRequest, PullRequestEvent);
+event!("issues", Issues, IssuesEvent);
+event!("issue_comment", IssueComment, IssueCommentEvent);
+event!("check_run", CheckRun, CheckRunEvent);
+event!("workflow_run", WorkflowRun, WorkflowRunEvent);
+event!("installation", Installation, InstallationEvent);
+
+type Handler = Box<dyn Fn(&WebhookEvent) -> Result<(), BoxError> + Send + Sync>;
+
+/// Dispatches parsed events to the handlers registered for their type.
+#[derive(Default)]
+pub struct Router {
+    handlers: HashMap<&'static str, Vec<Handler>>,
+    fallback: Option<Handler>,
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Router::default()
+    }
+
+    /// Runs `handler` for every `E` event, after any registered before it.
+    pub fn on<E: Event + 'static>(
+        mut self,
+        handler: impl Fn(&E) -> Result<(), BoxError> + Send + Sync + 'static,
+    ) -> Self {
+        self.handlers
+            .entry(E::NAME)
+            .or_default()
+            .push(Box::new(move |event| match E::from_event(event) {
+                Some(payload) => handler(payload),
+                None => Ok(()),
+            }));
+        self
+    }
+
+    /// Runs `handler` for events no [`Router::on`] handler is registered for,
+    /// including those without a typed model.
+    pub fn fallback(
+        mut self,
+        handler: impl Fn(&WebhookEvent) -> Result<(), BoxError> + Send + Sync + 'static,
+    ) -> Self {
+        self.fallback = Some(Box::new(handler));
+        self
+    }
+
+    /// Runs the handlers for `event` in order, stopping at the first error.
+    /// Returns whether any handler ran.
+    pub fn dispatch(&self, event: &WebhookEvent) -> Result<bool, BoxError> {
+        match (self.handlers.get(event.name()), &self.fallback) {
+            (Some(handlers), _) => {
+                for handler in handlers {
+                    handler(event)?;
+                }
+                Ok(true)
+            }
+            (None, Some(fallback)) => fallback(event).map(|()| true),
+            (None, None) => Ok(false),
+        }
+    }
+}
+
+impl fmt::Debug for Router {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Router")
+            .field("events", &self.handlers.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::models::State;
+
+    /// Parses a payload captured from a delivery, as `X-GitHub-Event: event`.
+    fn captured(event: &str) -> WebhookEvent {
+        let body = match event {
+            "push" => include_str!("testdata/push.json"),
+            "pull_request" => include_str!("testdata/pull_request.json"),
+            "issues" => include_str!("testdata/issues.json"),
+            "issue_comment" => include_str!("testdata/issue_comment.json"),
+            "check_run" => include_str!("testdata/check_run.json"),
+            "workflow_run" => include_str!("testdata/workflow_run.json"),
+            "installation" => include_str!("testdata/installation.json"),
+            _ => unreachable!("no capture for {}", event),
+        };
+        let mut headers = Headers::new();
+        headers.insert(EVENT_HEADER, event);
+        let parsed = WebhookEvent::parse(&headers, body.as_bytes()).unwrap();
+        assert_eq!(parsed.name(), event);
+        parsed
+    }
+
+    /// The captured payload with `action` replaced.
+    fn with_action(event: &str, action: &str) -> WebhookEvent {
+        let body = match event {
+            "pull_request" => include_str!("testdata/pull_request.json"),
+            "installation" => include_str!("testdata/installation.json"),
+            _ => unreachable!("no capture for {}", event),
+        };
+        let mut payload: Value = serde_json::from_str(body).unwrap();
+        payload["action"] = action.into();
+        WebhookEvent::from_slice(event, &serde_json::to_vec(&payload).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parses_captured_push() {
+        let WebhookEvent::Push(push) = captured("push") else {
+            panic!("not a push");
+        };
+        assert_eq!(push.git_ref, "refs/heads/master");
+        assert_eq!(push.after, "6113728f27ae82c7b1a177c8d03f9e96e0adf246");
+        assert_eq!(push.commits.len(), 1);
+        assert_eq!(push.commits[0].modified, ["README.md"]);
+        assert_eq!(
+            push.commits[0].author.username.as_deref(),
+            Some("Codertocat")
+        );
+        assert_eq!(push.pusher.name, "Codertocat");
+        // Unix seconds here, unlike everywhere else.
+        assert_eq!(push.repository.created_at, Some(1557933565.into()));
+        assert_eq!(push.repository.pushed_at, Some(1557933657.into()));
+    }
+
+    #[test]
+    fn parses_captured_pull_request() {
+        let WebhookEvent::PullRequest(event) = captured("pull_request") else {
+            panic!("not a pull_request");
+        };
+        assert_eq!(event.action, PullRequestAction::Opened);
+        assert_eq!(event.number, 2);
+        assert_eq!(event.pull_request.state, State::Open);
+        assert_eq!(event.pull_request.head.git_ref, "changes");
+        assert_eq!(event.pull_request.base.git_ref, "master");
+        assert_eq!(event.pull_request.merged, Some(false));
+        assert_eq!(event.repository.full_name, "Codertocat/Hello-World");
+        assert_eq!(event.installation.unwrap().id, 2311213);
+    }
+
+    #[test]
+    fn parses_captured_issues_and_comments() {
+        let WebhookEvent::Issues(event) = captured("issues") else {
+            panic!("not issues");
+        };
+        assert_eq!(event.action, IssuesAction::Milestoned);
+        assert_eq!(event.issue.labels[0].name, "bug");
+        assert_eq!(event.milestone.unwrap().title, "v1.0");
+        assert!(!event.issue.is_pull_request());
+
+        let WebhookEvent::IssueComment(event) = captured("issue_comment") else {
+            panic!("not issue_comment");
+        };
+        assert_eq!(event.action, IssueCommentAction::Created);
+        assert_eq!(event.comment.id, 492700400);
+        assert_eq!(event.comment.author_association.as_deref(), Some("OWNER"));
+        assert_eq!(event.sender.login, "Codertocat");
+    }
+
+    #[test]
+    fn parses_captured_runs() {
+        let WebhookEvent::CheckRun(event) = captured("check_run") else {
+            panic!("not check_run");
+        };
+        assert_eq!(event.action, CheckRunAction::Completed);
+        assert_eq!(event.check_run.conclusion.as_deref(), Some("success"));
+        assert_eq!(
+            event.check_run.app.unwrap().slug.as_deref(),
+            Some("octoapp")
+        );
+
+        let WebhookEvent::WorkflowRun(event) = captured("workflow_run") else {
+            panic!("not workflow_run");
+        };
+        assert_eq!(event.action, WorkflowRunAction::Completed);
+        assert_eq!(event.workflow_run.run_attempt, 2);
+        assert_eq!(event.workflow_run.event, "push");
+        assert_eq!(event.workflow_run.conclusion.as_deref(), Some("failure"));
+    }
+
+    #[test]
+    fn parses_captured_installation() {
+        let WebhookEvent::Installation(event) = captured("installation") else {
+            panic!("not installation");
+        };
+        assert_eq!(event.action, InstallationAction::Created);
+        assert_eq!(event.installation.account.unwrap().login, "octocat");
+        assert_eq!(event.installation.permissions["issues"], "write");
+        assert_eq!(
+            event.repositories.unwrap()[0].full_name,
+            "octocat/Hello-World"
+        );
+    }
+
+    #[test]
+    fn unknown_actions_parse_as_other() {
+        let WebhookEvent::PullRequest(event) = with_action("pull_request", "teleported") else {
+            panic!("not a pull_request");
+        };
+        assert_eq!(event.action, PullRequestAction::Other);
+        let WebhookEvent::Installation(event) = with_action("installation", "moved") else {
+            panic!("not installation");
+        };
+        assert_eq!(event.action, InstallationAction::Other);
+    }
+
+    #[test]
+    fn unknown_events_are_kept_as_json() {
+        let body = br#"{"action": "created", "starred_at": "2019-05-15T15:20:40Z"}"#;
+        let event = WebhookEvent::from_slice("star", body).unwrap();
+        assert_eq!(event.name(), "star");
+        let WebhookEvent::Other { payload, .. } = event else {
+            panic!("typed star event");
+        };
+        assert_eq!(payload["action"], "created");
+
+        assert!(matches!(
+            WebhookEvent::parse(&Headers::new(), body),
+            Err(EventError::MissingEvent)
+        ));
+        let error = WebhookEvent::from_slice("push", body).unwrap_err();
+        assert!(error.to_string().starts_with("invalid push payload: "));
+    }
+
+    #[test]
+    fn router_dispatches_by_type_and_falls_back() {
+        let runs = Arc::new(AtomicUsize::new(0));
+        let fallbacks = Arc::new(AtomicUsize::new(0));
+        let (counted, fell_back) = (Arc::clone(&runs), Arc::clone(&fallbacks));
+        let router = Router::new()
+            .on(move |event: &PullRequestEvent| {
+                assert_eq!(event.number, 2);
+                counted.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            })
+            .on(|_: &PullRequestEvent| Err("handler failed".into()))
+            .on(|_: &PullRequestEvent| panic!("runs after an error"));
+
+        let error = router.dispatch(&captured("pull_request")).unwrap_err();
+        assert_eq!(error.to_string(), "handler failed");
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(!router.dispatch(&captured("push")).unwrap());
+
+        let router = router.fallback(move |event| {
+            assert_ne!(event.name(), "pull_request");
+            fell_back.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+        assert!(router.dispatch(&captured("push")).unwrap());
+        let star = WebhookEvent::from_slice("star", b"{}").unwrap();
+        assert!(router.dispatch(&star).unwrap());
+        assert!(router.dispatch(&captured("pull_request")).is_err());
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        assert_eq!(fallbacks.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,638 @@
+//! Realistic webhook deliveries for tests, signed like GitHub signs them.
+//!
+//! Payloads are built from the typed events, so they always parse, and can be
+//! adjusted with [`Payload::with`] before being turned into a [`Delivery`]:
+//! headers plus body, ready for [`WebhookEvent::parse`], a [`Router`], or a
+//! receiver on localhost. Everything is fixed apart from what is set
+//! explicitly, so snapshots stay stable: delivery GUIDs count up from 1 for
+//! each [`Fixtures`], whatever else runs in the same process.
+//!
+//! ```
+//! use github_api_octocat::webhooks::events::{PullRequestAction, PullRequestEvent, Router};
+//! use github_api_octocat::webhooks::fixtures::Fixtures;
+//! use github_api_octocat::webhooks::Verifier;
+//!
+//! let fixtures = Fixtures::new("octocat/hello-world").secret("s3cret");
+//! let delivery = fixtures
+//!     .pull_request(PullRequestAction::Opened, 7)
+//!     .with(|event| event.pull_request.title = "Add a README".to_string())
+//!     .delivery();
+//!
+//! assert!(Verifier::new("s3cret").verify(&delivery.headers, &delivery.body).is_ok());
+//! let router = Router::new().on(|event: &PullRequestEvent| {
+//!     assert_eq!(event.pull_request.title, "Add a README");
+//!     Ok(())
+//! });
+//! assert!(router.dispatch(&delivery.event().unwrap()).unwrap());
+//! ```
+//!
+//! [`Router`]: super::events::Router
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use serde::Serialize;
+
+use super::events::{
+    CheckRunAction, CheckRunEvent, Event, EventError, InstallationAction, InstallationEvent,
+    InstalledRepository, IssueCommentAction, IssueCommentEvent, IssuesAction, IssuesEvent,
+    PingEvent, PullRequestAction, PullRequestEvent, PushEvent, WorkflowRunAction, WorkflowRunEvent,
+    DELIVERY_HEADER, EVENT_HEADER,
+};
+use super::{Algorithm, WebhookEvent};
+use crate::http::Headers;
+use crate::models::{
+    Branch, CheckRun, Comment, GitActor, Installation, Issue, PullRequest, PushCommit, Repository,
+    State, User, WorkflowRun,
+};
+use crate::util::djb2;
+
+/// When every fixture happened.
+const TIMESTAMP: &str = "2024-01-01T00:00:00Z";
+const HOOK_ID: u64 = 1;
+
+/// A delivery as GitHub would POST it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Delivery {
+    pub headers: Headers,
+    pub body: Vec<u8>,
+}
+
+impl Delivery {
+    pub fn event(&self) -> Result<WebhookEvent, EventError> {
+        WebhookEvent::parse(&self.headers, &self.body)
+    }
+
+    /// POSTs the delivery to `path` on a receiver at `addr` and returns the
+    /// response status.
+    pub fn send(&self, addr: impl ToSocketAddrs, path: &str) -> io::Result<u16> {
+        let mut stream = TcpStream::connect(addr)?;
+        let mut head = format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n",
+            path,
+            self.body.len()
+        );
+        for (name, value) in self.headers.iter() {
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(&self.body)?;
+        let mut status_line = String::new();
+        BufReader::new(stream).read_line(&mut status_line)?;
+        status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "bad status line"))
+    }
+}
+
+/// A payload on its way to becoming a [`Delivery`].
+#[derive(Debug, Clone)]
+pub struct Payload<E> {
+    pub event: E,
+    secret: Option<Vec<u8>>,
+    guid: Option<String>,
+    deliveries: Arc<AtomicU64>,
+}
+
+impl<E: Event + Serialize> Payload<E> {
+    /// Adjusts the payload, e.g. to set a title or a label.
+    pub fn with(mut self, change: impl FnOnce(&mut E)) -> Self {
+        change(&mut self.event);
+        self
+    }
+
+    /// Sets `X-GitHub-Delivery`, e.g. to test deduplication. Otherwise the next
+    /// GUID of the [`Fixtures`] it came from.
+    pub fn guid(mut self, guid: impl Into<String>) -> Self {
+        self.guid = Some(guid.into());
+        self
+    }
+
+    /// Signs the body with both algorithms when [`Fixtures::secret`] is set.
+    pub fn delivery(&self) -> Delivery {
+        let body = serde_json::to_vec_pretty(&self.event).expect("serializable event");
+        let guid = self.guid.clone().unwrap_or_else(|| {
+            let n = self.deliveries.fetch_add(1, Ordering::Relaxed) + 1;
+            format!("{:08x}-0000-4000-8000-{:012x}", n, n)
+        });
+        let mut headers = Headers::new();
+        headers.insert("User-Agent", "GitHub-Hookshot/fixture");
+        headers.insert("Content-Type", "application/json");
+        headers.insert(EVENT_HEADER, E::NAME);
+        headers.insert(DELIVERY_HEADER, guid);
+        headers.insert("X-GitHub-Hook-ID", HOOK_ID.to_string());
+        if let Some(secret) = &self.secret {
+            for algorithm in [Algorithm::Sha256, Algorithm::Sha1] {
+                headers.insert(algorithm.header(), algorithm.sign(secret, &body));
+            }
+        }
+        Delivery { headers, body }
+    }
+}
+
+/// Builds payloads about one repository. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    repository: Repository,
+    sender: User,
+    installation: Option<u64>,
+    secret: Option<Vec<u8>>,
+    /// Shared by clones, so their GUIDs do not collide either.
+    deliveries: Arc<AtomicU64>,
+}
+
+impl Fixtures {
+    /// Fixtures about `full_name`, an `owner/repo`, sent by its owner.
+    ///
+    /// # Panics
+    ///
+    /// If `full_name` is not of the form `owner/repo`.
+    pub fn new(full_name: &str) -> Self {
+        let (owner, name) = full_name
+            .split_once('/')
+            .filter(|(owner, name)| !owner.is_empty() && !name.is_empty() && !name.contains('/'))
+            .unwrap_or_else(|| panic!("Fixtures::new takes an owner/repo, got {:?}", full_name));
+        let owner = user(owner);
+        Fixtures {
+            repository: Repository {
+                id: id_for(full_name),
+                node_id: format!("R_{}", id_for(full_name)),
+                name: name.to_string(),
+                full_name: format!("{}/{}", owner.login, name),
+                html_url: format!("https://github.com/{}/{}", owner.login, name),
+                url: format!("https://api.github.com/repos/{}/{}", owner.login, name),
+                owner: owner.clone(),
+                private: false,
+                description: None,
+                fork: false,
+                default_branch: Some("main".to_string()),
+                archived: false,
+                visibility: Some("public".to_string()),
+                created_at: Some(TIMESTAMP.into()),
+                updated_at: Some(TIMESTAMP.to_string()),
+                pushed_at: Some(TIMESTAMP.into()),
+            },
+            sender: owner,
+            installation: None,
+            secret: None,
+            deliveries: Arc::default(),
+        }
+    }
+
+    /// Who triggered the events.
+    pub fn sender(mut self, login: &str) -> Self {
+        self.sender = user(login);
+        self
+    }
+
+    /// Marks the events as delivered to a GitHub App installation.
+    pub fn installation(mut self, id: u64) -> Self {
+        self.installation = Some(id);
+        self
+    }
+
+    pub fn secret(mut self, secret: impl AsRef<[u8]>) -> Self {
+        self.secret = Some(secret.as_ref().to_vec());
+        self
+    }
+
+    pub fn ping(&self) -> Payload<PingEvent> {
+        self.payload(PingEvent {
+            zen: "Keep it logically awesome.".to_string(),
+            hook_id: HOOK_ID,
+            hook: None,
+            repository: Some(self.repository.clone()),
+            sender: Some(self.sender.clone()),
+        })
+    }
+
+    /// A push of one commit to `branch`.
+    pub fn push(&self, branch: &str) -> Payload<PushEvent> {
+        let before = sha("before", branch);
+        let commit = PushCommit {
+            id: sha("after", branch),
+            tree_id: Some(sha("tree", branch)),
+            message: "Update README.md".to_string(),
+            timestamp: Some(TIMESTAMP.to_string()),
+            url: format!(
+                "{}/commit/{}",
+                self.repository.html_url,
+                sha("after", branch)
+            ),
+            author: self.actor(),
+            committer: self.actor(),
+            distinct: true,
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: vec!["README.md".to_string()],
+        };
+        self.payload(PushEvent {
+            git_ref: format!("refs/heads/{}", branch),
+            compare: Some(format!(
+                "{}/compare/{}...{}",
+                self.repository.html_url,
+                &before[..12],
+                &commit.id[..12]
+            )),
+            before,
+            after: commit.id.clone(),
+            created: false,
+            deleted: false,
+            forced: false,
+            commits: vec![commit.clone()],
+            head_commit: Some(commit),
+            pusher: GitActor {
+                name: self.sender.login.clone(),
+                email: Some(self.email()),
+                username: None,
+            },
+            repository: self.repository.clone(),
+            sender: Some(self.sender.clone()),
+            installation: self.installation_ref(),
+        })
+    }
+
+    /// Pull request `number` from `feature-<number>` into the default branch.
+    pub fn pull_request(
+        &self,
+        action: PullRequestAction,
+        number: u64,
+    ) -> Payload<PullRequestEvent> {
+        let closed = action == PullRequestAction::Closed;
+        let branch = |git_ref: String| Branch {
+            label: Some(format!("{}:{}", self.repository.owner.login, git_ref)),
+            sha: sha("head", &git_ref),
+            git_ref,
+            user: Some(self.repository.owner.clone()),
+            repo: Some(self.repository.clone()),
+        };
+        self.payload(PullRequestEvent {
+            action,
+            number,
+            pull_request: PullRequest {
+                id: id_for(&format!("{}#{}", self.repository.full_name, number)),
+                node_id: format!("PR_{}", number),
+                number,
+                title: format!("Pull request {}", number),
+                body: None,
+                user: Some(self.sender.clone()),
+                state: if closed { State::Closed } else { State::Open },
+                draft: false,
+                locked: false,
+                head: branch(format!("feature-{}", number)),
+                base: branch(
+                    self.repository
+                        .default_branch
+                        .clone()
+                        .unwrap_or_else(|| "main".to_string()),
+                ),
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                requested_reviewers: Vec::new(),
+                milestone: None,
+                merged: Some(false),
+                mergeable: None,
+                merge_commit_sha: None,
+                merged_by: None,
+                html_url: format!("{}/pull/{}", self.repository.html_url, number),
+                created_at: Some(TIMESTAMP.to_string()),
+                updated_at: Some(TIMESTAMP.to_string()),
+                closed_at: closed.then(|| TIMESTAMP.to_string()),
+                merged_at: None,
+            },
+            label: None,
+            assignee: None,
+            requested_reviewer: None,
+            before: None,
+            after: None,
+            changes: None,
+            repository: self.repository.clone(),
+            sender: self.sender.clone(),
+            installation: self.installation_ref(),
+        })
+    }
+
+    pub fn issues(&self, action: IssuesAction, number: u64) -> Payload<IssuesEvent> {
+        self.payload(IssuesEvent {
+            action,
+            issue: self.issue(number, action == IssuesAction::Closed),
+            label: None,
+            assignee: None,
+            milestone: None,
+            changes: None,
+            repository: self.repository.clone(),
+            sender: self.sender.clone(),
+            installation: self.installation_ref(),
+        })
+    }
+
+    /// A comment saying `body` on issue `number`.
+    pub fn issue_comment(
+        &self,
+        action: IssueCommentAction,
+        number: u64,
+        body: &str,
+    ) -> Payload<IssueCommentEvent> {
+        let id = id_for(&format!(
+            "{}#{}:{}",
+            self.repository.full_name, number, body
+        ));
+        self.payload(IssueCommentEvent {
+            action,
+            issue: self.issue(number, false),
+            comment: Comment {
+                id,
+                node_id: format!("IC_{}", id),
+                body: Some(body.to_string()),
+                user: Some(self.sender.clone()),
+                html_url: format!(
+                    "{}/issues/{}#issuecomment-{}",
+                    self.repository.html_url, number, id
+                ),
+                author_association: Some("OWNER".to_string()),
+                created_at: Some(TIMESTAMP.to_string()),
+                updated_at: Some(TIMESTAMP.to_string()),
+            },
+            changes: None,
+            repository: self.repository.clone(),
+            sender: self.sender.clone(),
+            installation: self.installation_ref(),
+        })
+    }
+
+    /// Check run `name` on the default branch; `completed` ones succeeded.
+    pub fn check_run(&self, action: CheckRunAction, name: &str) -> Payload<CheckRunEvent> {
+        let completed = action == CheckRunAction::Completed;
+        let id = id_for(name);
+        self.payload(CheckRunEvent {
+            action,
+            check_run: CheckRun {
+                id,
+                name: name.to_string(),
+                head_sha: sha("head", "main"),
+                status: if completed { "completed" } else { "queued" }.to_string(),
+                conclusion: completed.then(|| "success".to_string()),
+                external_id: None,
+                html_url: format!("{}/runs/{}", self.repository.html_url, id),
+                details_url: None,
+                started_at: Some(TIMESTAMP.to_string()),
+                completed_at: completed.then(|| TIMESTAMP.to_string()),
+                app: None,
+            },
+            requested_action: None,
+            repository: self.repository.clone(),
+            sender: self.sender.clone(),
+            installation: self.installation_ref(),
+        })
+    }
+
+    /// A run of workflow `name` on the default branch; `completed` ones succeeded.
+    pub fn workflow_run(&self, action: WorkflowRunAction, name: &str) -> Payload<WorkflowRunEvent> {
+        let completed = action == WorkflowRunAction::Completed;
+        let id = id_for(name);
+        self.payload(WorkflowRunEvent {
+            action,
+            workflow_run: WorkflowRun {
+                id,
+                name: Some(name.to_string()),
+                workflow_id: id_for(&format!("workflow:{}", name)),
+                run_number: 1,
+                run_attempt: 1,
+                event: "push".to_string(),
+                head_branch: Some("main".to_string()),
+                head_sha: sha("head", "main"),
+                status: Some(
+                    match action {
+                        WorkflowRunAction::Completed => "completed",
+                        WorkflowRunAction::InProgress => "in_progress",
+                        _ => "queued",
+                    }
+                    .to_string(),
+                ),
+                conclusion: completed.then(|| "success".to_string()),
+                html_url: format!("{}/actions/runs/{}", self.repository.html_url, id),
+                actor: Some(self.sender.clone()),
+                created_at: Some(TIMESTAMP.to_string()),
+                updated_at: Some(TIMESTAMP.to_string()),
+            },
+            workflow: None,
+            repository: self.repository.clone(),
+            sender: self.sender.clone(),
+            installation: self.installation_ref(),
+        })
+    }
+
+    /// The app being installed on the repository owner's account, for the
+    /// repository alone. Uses [`Fixtures::installation`]'s id, or 1.
+    pub fn installation_event(&self, action: InstallationAction) -> Payload<InstallationEvent> {
+        self.payload(InstallationEvent {
+            action,
+            installation: Installation {
+                id: self.installation.unwrap_or(1),
+                account: Some(self.repository.owner.clone()),
+                app_id: Some(1),
+                repository_selection: Some("selected".to_string()),
+                permissions: [("metadata", "read"), ("issues", "write")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+                events: vec!["issues".to_string()],
+                suspended_at: None,
+            },
+            repositories: Some(vec![InstalledRepository {
+                id: self.repository.id,
+                name: self.repository.name.clone(),
+                full_name: self.repository.full_name.clone(),
+                private: self.repository.private,
+            }]),
+            requester: None,
+            sender: self.sender.clone(),
+        })
+    }
+
+    fn payload<E>(&self, event: E) -> Payload<E> {
+        Payload {
+            event,
+            secret: self.secret.clone(),
+            guid: None,
+            deliveries: self.deliveries.clone(),
+        }
+    }
+
+    fn issue(&self, number: u64, closed: bool) -> Issue {
+        Issue {
+            id: id_for(&format!("{}#{}", self.repository.full_name, number)),
+            node_id: format!("I_{}", number),
+            number,
+            title: format!("Issue {}", number),
+            body: None,
+            user: Some(self.sender.clone()),
+            state: if closed { State::Closed } else { State::Open },
+            state_reason: closed.then(|| "completed".to_string()),
+            labels: Vec::new(),
+            assignees: Vec::new(),
+            milestone: None,
+            locked: false,
+            comments: 0,
+            pull_request: None,
+            html_url: format!("{}/issues/{}", self.repository.html_url, number),
+            created_at: Some(TIMESTAMP.to_string()),
+            updated_at: Some(TIMESTAMP.to_string()),
+            closed_at: closed.then(|| TIMESTAMP.to_string()),
+        }
+    }
+
+    fn actor(&self) -> GitActor {
+        GitActor {
+            name: self.sender.login.clone(),
+            email: Some(self.email()),
+            username: Some(self.sender.login.clone()),
+        }
+    }
+
+    fn email(&self) -> String {
+        format!(
+            "{}+{}@users.noreply.github.com",
+            self.sender.id, self.sender.login
+        )
+    }
+
+    fn installation_ref(&self) -> Option<Installation> {
+        self.installation.map(|id| Installation {
+            id,
+            account: None,
+            app_id: None,
+            repository_selection: None,
+            permissions: Default::default(),
+            events: Vec::new(),
+            suspended_at: None,
+        })
+    }
+}
+
+fn user(login: &str) -> User {
+    User {
+        login: login.to_string(),
+        id: id_for(login),
+        node_id: format!("U_{}", id_for(login)),
+        avatar_url: format!("https://avatars.githubusercontent.com/u/{}", id_for(login)),
+        html_url: format!("https://github.com/{}", login),
+        kind: "User".to_string(),
+        site_admin: false,
+    }
+}
+
+/// A stable id for `key`, so fixtures do not change between runs.
+fn id_for(key: &str) -> u64 {
+    djb2(key) % 100_000_000
+}
+
+/// A stable, made-up commit SHA.
+fn sha(kind: &str, key: &str) -> String {
+    let hash = id_for(&format!("{}:{}", kind, key));
+    let hash = hash.wrapping_mul(0x9e37_79b9_7f4a_7c15);
+    format!(
+        "{:016x}{:016x}{:08x}",
+        hash,
+        hash.rotate_left(21),
+        hash.rotate_left(42) as u32
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::webhooks::Verifier;
+
+    fn guid(delivery: &Delivery) -> String {
+        delivery.headers.get(DELIVERY_HEADER).unwrap().to_string()
+    }
+
+    #[test]
+    fn guids_count_up_per_fixtures() {
+        let fixtures = Fixtures::new("octocat/hello");
+        let first = fixtures.push("main").delivery();
+        let second = fixtures.clone().ping().delivery();
+
+        assert_eq!(guid(&first), "00000001-0000-4000-8000-000000000001");
+        assert_eq!(guid(&second), "00000002-0000-4000-8000-000000000002");
+        // Independent of what other fixtures did before.
+        let other = Fixtures::new("octocat/hello").push("main").delivery();
+        assert_eq!(other, first);
+        let fixed = fixtures.ping().guid("redelivery").delivery();
+        assert_eq!(guid(&fixed), "redelivery");
+    }
+
+    #[test]
+    #[should_panic(expected = "Fixtures::new takes an owner/repo, got \"hello\"")]
+    fn names_without_an_owner_are_rejected() {
+        Fixtures::new("hello");
+    }
+
+    #[test]
+    fn rejects_malformed_names() {
+        for name in ["/hello", "octocat/", "octocat/hello/world"] {
+            let result = std::panic::catch_unwind(|| Fixtures::new(name));
+            assert!(result.is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn every_event_parses_and_verifies() {
+        let fixtures = Fixtures::new("octocat/hello")
+            .sender("hubot")
+            .installation(42)
+            .secret("s3cret");
+        let deliveries = [
+            fixtures.ping().delivery(),
+            fixtures.push("main").delivery(),
+            fixtures
+                .pull_request(PullRequestAction::Opened, 7)
+                .delivery(),
+            fixtures.issues(IssuesAction::Opened, 3).delivery(),
+            fixtures
+                .issue_comment(IssueCommentAction::Created, 3, "LGTM")
+                .delivery(),
+            fixtures
+                .check_run(CheckRunAction::Completed, "ci")
+                .delivery(),
+            fixtures
+                .workflow_run(WorkflowRunAction::Completed, "CI")
+                .delivery(),
+            fixtures
+                .installation_event(InstallationAction::Created)
+                .delivery(),
+        ];
+
+        let verifier = Verifier::new("s3cret");
+        for delivery in &deliveries {
+            verifier.verify(&delivery.headers, &delivery.body).unwrap();
+            let event = delivery.event().unwrap();
+            assert!(!matches!(event, WebhookEvent::Other { .. }), "{:?}", event);
+        }
+        assert!(Verifier::new("wrong")
+            .verify(&deliveries[0].headers, &deliveries[0].body)
+            .is_err());
+    }
+
+    #[test]
+    fn payloads_are_stable() {
+        let build = || {
+            Fixtures::new("octocat/hello")
+                .pull_request(PullRequestAction::Opened, 7)
+                .with(|event| event.pull_request.title = "Add a README".to_string())
+                .delivery()
+        };
+        assert_eq!(build(), build());
+        let WebhookEvent::PullRequest(event) = build().event().unwrap() else {
+            panic!("not a pull request");
+        };
+        assert_eq!(event.repository.full_name, "octocat/hello");
+        assert_eq!(event.pull_request.title, "Add a README");
+    }
+}
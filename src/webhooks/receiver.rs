@@ -0,0 +1,520 @@
+//! A ready-made webhook endpoint: verifies signatures, parses events and hands
+//! them to an async handler on a Tokio runtime, a bounded number at a time.
+//!
+//! Deliveries are acknowledged with `202` as soon as they are queued, since
+//! GitHub gives up on a receiver after ten seconds. When the queue is full the
+//! answer is `503`, which GitHub records as a failed delivery that
+//! [`Reconciler`](super::deliveries::Reconciler) can redeliver later. `ping`
+//! events are answered directly. `GET /healthz` and `GET /metrics` (Prometheus
+//! text format) are served next to the webhook path.
+//!
+//! ```no_run
+//! use github_api_octocat::webhooks::receiver::{Incoming, WebhookReceiver};
+//! use github_api_octocat::webhooks::{Verifier, WebhookEvent};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> std::io::Result<()> {
+//! let receiver = WebhookReceiver::new(Verifier::new("secret"), |incoming: Incoming| async move {
+//!     if let WebhookEvent::PullRequest(event) = &incoming.event {
+//!         println!("{:?} #{}", event.action, event.number);
+//!     }
+//!     Ok(())
+//! })
+//! .path("/github")
+//! .max_concurrency(4)
+//! .start("0.0.0.0:8080")?;
+//! println!("listening on {}", receiver.url());
+//! # std::future::pending::<()>().await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+use tokio::runtime::{Handle, Runtime};
+use tokio::sync::Semaphore;
+
+use super::events::{PingEvent, DELIVERY_HEADER};
+use super::{Verifier, WebhookEvent};
+use crate::error::BoxError;
+use crate::fake::json;
+use crate::http::{Headers, Response};
+use crate::server::{HttpServer, Limits, RawRequest};
+
+pub type HandlerFuture = Pin<Box<dyn Future<Output = Result<(), BoxError>> + Send>>;
+
+type Handler = Arc<dyn Fn(Incoming) -> HandlerFuture + Send + Sync>;
+
+/// A verified, parsed delivery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incoming {
+    /// `X-GitHub-Delivery`, the same across redeliveries.
+    pub guid: Option<String>,
+    /// `X-GitHub-Hook-ID`.
+    pub hook_id: Option<String>,
+    pub event: WebhookEvent,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReceiverMetrics {
+    /// Deliveries queued for the handler.
+    pub accepted: u64,
+    pub pings: u64,
+    /// Unsigned or wrongly signed deliveries, answered with `401`.
+    pub bad_signatures: u64,
+    /// Deliveries whose payload could not be parsed, answered with `400`.
+    pub bad_payloads: u64,
+    /// Deliveries turned away with `503` because the queue was full.
+    pub rejected_busy: u64,
+    pub handled: u64,
+    /// Handler calls that returned an error or panicked.
+    pub failed: u64,
+    /// Deliveries queued or being handled right now.
+    pub pending: u64,
+}
+
+impl ReceiverMetrics {
+    /// The Prometheus text exposition served on `/metrics`.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        out.push_str("# TYPE github_webhook_deliveries_total counter\n");
+        for (outcome, count) in [
+            ("accepted", self.accepted),
+            ("ping", self.pings),
+            ("bad_signature", self.bad_signatures),
+            ("bad_payload", self.bad_payloads),
+            ("busy", self.rejected_busy),
+        ] {
+            out.push_str(&format!(
+                "github_webhook_deliveries_total{{outcome=\"{}\"}} {}\n",
+                outcome, count
+            ));
+        }
+        out.push_str("# TYPE github_webhook_handler_results_total counter\n");
+        out.push_str(&format!(
+            "github_webhook_handler_results_total{{result=\"ok\"}} {}\n",
+            self.handled
+        ));
+        out.push_str(&format!(
+            "github_webhook_handler_results_total{{result=\"error\"}} {}\n",
+            self.failed
+        ));
+        out.push_str("# TYPE github_webhook_pending gauge\n");
+        out.push_str(&format!("github_webhook_pending {}\n", self.pending));
+        out
+    }
+}
+
+/// Configuration for a receiver; [`WebhookReceiver::start`] puts it on a port.
+pub struct WebhookReceiver {
+    verifier: Verifier,
+    handler: Handler,
+    path: String,
+    max_concurrency: usize,
+    max_queued: usize,
+    limits: Limits,
+    runtime: Option<Handle>,
+}
+
+impl WebhookReceiver {
+    pub fn new<F, Fut>(verifier: Verifier, handler: F) -> Self
+    where
+        F: Fn(Incoming) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), BoxError>> + Send + 'static,
+    {
+        WebhookReceiver {
+            verifier,
+            handler: Arc::new(move |incoming| Box::pin(handler(incoming))),
+            path: "/".to_string(),
+            max_concurrency: 8,
+            max_queued: 64,
+            limits: Limits::default(),
+            runtime: None,
+        }
+    }
+
+    /// Where GitHub POSTs deliveries. `/` by default.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Handler calls running at once. 8 by default.
+    pub fn max_concurrency(mut self, max: usize) -> Self {
+        self.max_concurrency = max.max(1);
+        self
+    }
+
+    /// Deliveries waiting for a handler slot before new ones get `503`. 64 by default.
+    pub fn max_queued(mut self, max: usize) -> Self {
+        self.max_queued = max;
+        self
+    }
+
+    /// Open connections, each with a thread reading it, before new ones get
+    /// `503`. 64 by default.
+    pub fn max_connections(mut self, max: usize) -> Self {
+        self.limits.max_connections = max.max(1);
+        self
+    }
+
+    /// How long a connection may wait on the client before it is dropped.
+    /// 10 seconds by default.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.limits.read_timeout = timeout;
+        self
+    }
+
+    /// Runs handlers on `runtime`. By default the runtime `start` is called
+    /// from, or a runtime of the receiver's own outside of one.
+    pub fn runtime(mut self, runtime: Handle) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
+
+    /// Binds `addr` and starts serving on background threads.
+    pub fn start(self, addr: impl ToSocketAddrs) -> io::Result<WebhookServer> {
+        let (runtime, owned_runtime) = match self.runtime.or_else(|| Handle::try_current().ok()) {
+            Some(handle) => (handle, None),
+            None => {
+                let runtime = tokio::runtime::Builder::new_multi_thread()
+                    .worker_threads(self.max_concurrency)
+                    .enable_all()
+                    .build()?;
+                (runtime.handle().clone(), Some(runtime))
+            }
+        };
+        let shared = Arc::new(Shared {
+            verifier: self.verifier,
+            handler: self.handler,
+            path: self.path,
+            slots: Arc::new(Semaphore::new(self.max_concurrency)),
+            capacity: self.max_concurrency + self.max_queued,
+            pending: AtomicUsize::new(0),
+            metrics: Mutex::new(ReceiverMetrics::default()),
+            runtime,
+        });
+        let routed = shared.clone();
+        let server =
+            HttpServer::bind_with(addr, Arc::new(move |raw| routed.route(raw)), self.limits)?;
+        Ok(WebhookServer {
+            shared,
+            server: Some(server),
+            owned_runtime,
+        })
+    }
+}
+
+impl fmt::Debug for WebhookReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookReceiver")
+            .field("verifier", &self.verifier)
+            .field("path", &self.path)
+            .field("max_concurrency", &self.max_concurrency)
+            .field("max_queued", &self.max_queued)
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
+
+struct Shared {
+    verifier: Verifier,
+    handler: Handler,
+    path: String,
+    slots: Arc<Semaphore>,
+    /// Handler slots plus queue.
+    capacity: usize,
+    pending: AtomicUsize,
+    metrics: Mutex<ReceiverMetrics>,
+    runtime: Handle,
+}
+
+impl Shared {
+    fn route(self: &Arc<Self>, raw: RawRequest) -> Response {
+        match (raw.method.as_str(), raw.path.as_str()) {
+            ("POST", path) if path == self.path => self.receive(&raw.headers, &raw.body),
+            ("GET", "/healthz") => json(200, &json!({ "status": "ok" })),
+            ("GET", "/metrics") => {
+                let mut headers = Headers::new();
+                headers.insert("Content-Type", "text/plain; version=0.0.4");
+                Response {
+                    status: 200,
+                    headers,
+                    body: self.metrics().to_prometheus().into_bytes(),
+                }
+            }
+            _ => message(404, "Not Found"),
+        }
+    }
+
+    fn receive(self: &Arc<Self>, headers: &Headers, body: &[u8]) -> Response {
+        if let Err(e) = self.verifier.verify(headers, body) {
+            self.metrics.lock().unwrap().bad_signatures += 1;
+            return message(401, &e.to_string());
+        }
+        let event = match WebhookEvent::parse(headers, body) {
+            Ok(event) => event,
+            Err(e) => {
+                self.metrics.lock().unwrap().bad_payloads += 1;
+                return message(400, &e.to_string());
+            }
+        };
+        if let WebhookEvent::Ping(ping) = &event {
+            self.metrics.lock().unwrap().pings += 1;
+            return pong(ping);
+        }
+        let reserved = self
+            .pending
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |pending| {
+                (pending < self.capacity).then_some(pending + 1)
+            });
+        if reserved.is_err() {
+            self.metrics.lock().unwrap().rejected_busy += 1;
+            return message(503, "receiver is busy");
+        }
+        self.metrics.lock().unwrap().accepted += 1;
+        let incoming = Incoming {
+            guid: headers.get(DELIVERY_HEADER).map(str::to_string),
+            hook_id: headers.get("X-GitHub-Hook-ID").map(str::to_string),
+            event,
+        };
+        let shared = self.clone();
+        self.runtime.spawn(async move {
+            let permit = shared.slots.clone().acquire_owned().await;
+            // A separate task, so a panicking handler is reported as a failure.
+            let outcome = tokio::spawn((shared.handler)(incoming)).await;
+            drop(permit);
+            let mut metrics = shared.metrics.lock().unwrap();
+            match outcome {
+                Ok(Ok(())) => metrics.handled += 1,
+                _ => metrics.failed += 1,
+            }
+            shared.pending.fetch_sub(1, Ordering::SeqCst);
+        });
+        json(202, &json!({ "message": "accepted" }))
+    }
+
+    fn metrics(&self) -> ReceiverMetrics {
+        ReceiverMetrics {
+            pending: self.pending.load(Ordering::SeqCst) as u64,
+            ..*self.metrics.lock().unwrap()
+        }
+    }
+}
+
+fn message(status: u16, message: &str) -> Response {
+    json(status, &json!({ "message": message }))
+}
+
+fn pong(ping: &PingEvent) -> Response {
+    json(
+        200,
+        &json!({ "message": "pong", "zen": ping.zen, "hook_id": ping.hook_id }),
+    )
+}
+
+/// A running receiver, stopped on drop. Handlers already queued still run if
+/// the runtime outlives it.
+pub struct WebhookServer {
+    shared: Arc<Shared>,
+    server: Option<HttpServer>,
+    owned_runtime: Option<Runtime>,
+}
+
+impl WebhookServer {
+    pub fn addr(&self) -> SocketAddr {
+        self.server.as_ref().expect("running").addr()
+    }
+
+    /// The delivery URL to configure on the hook.
+    pub fn url(&self) -> String {
+        format!("http://{}{}", self.addr(), self.shared.path)
+    }
+
+    pub fn metrics(&self) -> ReceiverMetrics {
+        self.shared.metrics()
+    }
+
+    /// Handles a delivery in-process, as if it had been POSTed, e.g. one built
+    /// for a test.
+    pub fn receive(&self, headers: &Headers, body: &[u8]) -> Response {
+        self.shared.receive(headers, body)
+    }
+
+    /// Blocks until every accepted delivery has been handled, or `timeout`
+    /// passes. Returns whether the receiver went idle. Not for use on a runtime
+    /// thread.
+    pub fn wait_idle(&self, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while self.shared.pending.load(Ordering::SeqCst) > 0 {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        true
+    }
+}
+
+impl fmt::Debug for WebhookServer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookServer")
+            .field("addr", &self.addr())
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+impl Drop for WebhookServer {
+    fn drop(&mut self) {
+        self.server.take();
+        if let Some(runtime) = self.owned_runtime.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    use tokio::sync::Notify;
+
+    use super::*;
+    use crate::webhooks::events::PullRequestAction;
+    use crate::webhooks::fixtures::Fixtures;
+
+    fn start(receiver: WebhookReceiver) -> WebhookServer {
+        receiver.path("/hooks").start("127.0.0.1:0").unwrap()
+    }
+
+    fn noop() -> WebhookReceiver {
+        WebhookReceiver::new(Verifier::new("secret"), |_| async { Ok(()) })
+    }
+
+    fn get(server: &WebhookServer, path: &str) -> String {
+        let mut stream = TcpStream::connect(server.addr()).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn accepts_signed_deliveries() {
+        let server = start(noop());
+        let fixtures = Fixtures::new("octocat/hello").secret("secret");
+        let delivery = fixtures
+            .pull_request(PullRequestAction::Opened, 7)
+            .delivery();
+
+        assert_eq!(delivery.send(server.addr(), "/hooks").unwrap(), 202);
+        assert!(server.wait_idle(Duration::from_secs(5)));
+        let metrics = server.metrics();
+        assert_eq!((metrics.accepted, metrics.handled), (1, 1));
+    }
+
+    #[test]
+    fn rejects_bad_signatures() {
+        let server = start(noop());
+        let fixtures = Fixtures::new("octocat/hello").secret("wrong");
+        let delivery = fixtures
+            .pull_request(PullRequestAction::Opened, 7)
+            .delivery();
+
+        assert_eq!(delivery.send(server.addr(), "/hooks").unwrap(), 401);
+        assert_eq!(server.metrics().bad_signatures, 1);
+        assert_eq!(server.metrics().accepted, 0);
+    }
+
+    #[test]
+    fn answers_busy_when_full() {
+        let gate = Arc::new(Notify::new());
+        let waiting = gate.clone();
+        let receiver = WebhookReceiver::new(Verifier::new("secret"), move |_| {
+            let gate = waiting.clone();
+            async move {
+                gate.notified().await;
+                Ok(())
+            }
+        })
+        .max_concurrency(1)
+        .max_queued(0);
+        let server = start(receiver);
+        let fixtures = Fixtures::new("octocat/hello").secret("secret");
+        let delivery = fixtures.push("main").delivery();
+
+        assert_eq!(delivery.send(server.addr(), "/hooks").unwrap(), 202);
+        assert_eq!(delivery.send(server.addr(), "/hooks").unwrap(), 503);
+        gate.notify_one();
+        assert!(server.wait_idle(Duration::from_secs(5)));
+        assert_eq!(delivery.send(server.addr(), "/hooks").unwrap(), 202);
+        gate.notify_one();
+        assert!(server.wait_idle(Duration::from_secs(5)));
+        let metrics = server.metrics();
+        assert_eq!((metrics.handled, metrics.rejected_busy), (2, 1));
+    }
+
+    #[test]
+    fn answers_pings() {
+        let server = start(noop());
+        let fixtures = Fixtures::new("octocat/hello").secret("secret");
+
+        assert_eq!(
+            fixtures
+                .ping()
+                .delivery()
+                .send(server.addr(), "/hooks")
+                .unwrap(),
+            200
+        );
+        assert_eq!(server.metrics().pings, 1);
+        assert_eq!(server.metrics().accepted, 0);
+    }
+
+    #[test]
+    fn serves_healthz_and_metrics() {
+        let server = start(noop());
+
+        assert!(get(&server, "/healthz").starts_with("HTTP/1.1 200"));
+        let metrics = get(&server, "/metrics");
+        assert!(metrics.starts_with("HTTP/1.1 200"), "{}", metrics);
+        assert!(get(&server, "/nowhere").starts_with("HTTP/1.1 404"));
+    }
+
+    #[test]
+    fn drops_idle_connections() {
+        let server = start(noop().read_timeout(Duration::from_millis(100)));
+        let mut idle = TcpStream::connect(server.addr()).unwrap();
+        idle.write_all(b"POST /hooks HTTP/1.1\r\nContent-Length: 10\r\n\r\n")
+            .unwrap();
+
+        let mut rest = Vec::new();
+        idle.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        assert_eq!(idle.read_to_end(&mut rest).unwrap(), 0);
+        assert!(get(&server, "/healthz").starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn caps_open_connections() {
+        let server = start(noop().max_connections(1));
+        let _held = TcpStream::connect(server.addr()).unwrap();
+        // Let the accept loop hand the first connection its thread.
+        std::thread::sleep(Duration::from_millis(50));
+
+        // Turned away before the request is read, so there is no need to send one.
+        let mut turned_away = TcpStream::connect(server.addr()).unwrap();
+        let mut response = String::new();
+        turned_away.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503"), "{}", response);
+    }
+}
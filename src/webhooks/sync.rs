@@ -0,0 +1,887 @@
+//! Keeping repository and organization webhooks in line with a config kept in Git.
+//!
+//! A [`HookSync`] lists the hooks of every repository and organization the
+//! desired config mentions, matches them by URL, and makes a [`Plan`] of the
+//! creates, updates and deletes that close the gap. Print the plan for a dry
+//! run, or hand it to [`HookSync::apply`].
+//!
+//! ```toml
+//! [[hooks]]
+//! repos = ["acme/api", "acme/web"]
+//! url = "https://hooks.acme.dev/github"
+//! events = ["push", "pull_request"]
+//! # Read from the environment when loading, so the secret stays out of Git.
+//! secret_env = "ACME_WEBHOOK_SECRET"
+//!
+//! [[hooks]]
+//! orgs = ["acme"]
+//! url = "https://audit.acme.dev/github"
+//! events = ["*"]
+//! content_type = "form"
+//! ```
+//!
+//! GitHub never returns secrets, so a hook whose other settings match is left
+//! alone unless [`HookSync::update_secrets`] is set.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use super::next_link_param;
+use crate::end_points::EndPoints;
+use crate::error::Error;
+use crate::http::{Request, Transport};
+use crate::models::Webhook;
+
+/// Where a hook lives.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HookScope {
+    Repository { owner: String, repo: String },
+    Organization { org: String },
+}
+
+impl HookScope {
+    pub fn repository(owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        HookScope::Repository {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    pub fn organization(org: impl Into<String>) -> Self {
+        HookScope::Organization { org: org.into() }
+    }
+
+    /// Parses `owner/repo`.
+    fn parse_repository(full_name: &str) -> io::Result<Self> {
+        match full_name.split_once('/') {
+            Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() => {
+                Ok(HookScope::repository(owner, repo))
+            }
+            _ => Err(invalid(format!("expected owner/repo, got {:?}", full_name))),
+        }
+    }
+
+    fn list(&self) -> EndPoints {
+        match self {
+            HookScope::Repository { owner, repo } => {
+                EndPoints::GetReposownerrepoHooks(owner.clone(), repo.clone())
+            }
+            HookScope::Organization { org } => EndPoints::GetOrgsorgHooks(org.clone()),
+        }
+    }
+
+    fn create(&self) -> EndPoints {
+        match self {
+            HookScope::Repository { owner, repo } => {
+                EndPoints::PostReposownerrepoHooks(owner.clone(), repo.clone())
+            }
+            HookScope::Organization { org } => EndPoints::PostOrgsorgHooks(org.clone()),
+        }
+    }
+
+    fn update(&self, hook_id: u64) -> EndPoints {
+        match self {
+            HookScope::Repository { owner, repo } => EndPoints::PatchReposownerrepoHookshookId(
+                owner.clone(),
+                repo.clone(),
+                hook_id.to_string(),
+            ),
+            HookScope::Organization { org } => {
+                EndPoints::PatchOrgsorgHookshookId(org.clone(), hook_id.to_string())
+            }
+        }
+    }
+
+    fn update_config(&self, hook_id: u64) -> EndPoints {
+        match self {
+            HookScope::Repository { owner, repo } => {
+                EndPoints::PatchReposownerrepoHookshookIdConfig(
+                    owner.clone(),
+                    repo.clone(),
+                    hook_id.to_string(),
+                )
+            }
+            HookScope::Organization { org } => {
+                EndPoints::PatchOrgsorgHookshookIdConfig(org.clone(), hook_id.to_string())
+            }
+        }
+    }
+
+    fn delete(&self, hook_id: u64) -> EndPoints {
+        match self {
+            HookScope::Repository { owner, repo } => EndPoints::DeleteReposownerrepoHookshookId(
+                owner.clone(),
+                repo.clone(),
+                hook_id.to_string(),
+            ),
+            HookScope::Organization { org } => {
+                EndPoints::DeleteOrgsorgHookshookId(org.clone(), hook_id.to_string())
+            }
+        }
+    }
+}
+
+impl fmt::Display for HookScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HookScope::Repository { owner, repo } => write!(f, "{}/{}", owner, repo),
+            HookScope::Organization { org } => write!(f, "org {}", org),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentType {
+    #[default]
+    Json,
+    Form,
+}
+
+impl ContentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentType::Json => "json",
+            ContentType::Form => "form",
+        }
+    }
+}
+
+/// One hook as it should be.
+#[derive(Clone, PartialEq, Eq)]
+pub struct DesiredHook {
+    pub scope: HookScope,
+    /// Identifies the hook within its scope.
+    pub url: String,
+    pub events: BTreeSet<String>,
+    pub content_type: ContentType,
+    /// `None` leaves whatever secret the hook has alone.
+    pub secret: Option<String>,
+    pub insecure_ssl: bool,
+    pub active: bool,
+}
+
+impl DesiredHook {
+    /// An active JSON hook for `push` events, like GitHub's defaults.
+    pub fn new(scope: HookScope, url: impl Into<String>) -> Self {
+        DesiredHook {
+            scope,
+            url: url.into(),
+            events: BTreeSet::from(["push".to_string()]),
+            content_type: ContentType::Json,
+            secret: None,
+            insecure_ssl: false,
+            active: true,
+        }
+    }
+
+    pub fn events<I: IntoIterator<Item = S>, S: Into<String>>(mut self, events: I) -> Self {
+        self.events = events.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn content_type(mut self, content_type: ContentType) -> Self {
+        self.content_type = content_type;
+        self
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    pub fn insecure_ssl(mut self, insecure_ssl: bool) -> Self {
+        self.insecure_ssl = insecure_ssl;
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    fn config(&self) -> Value {
+        let mut config = json!({
+            "url": self.url,
+            "content_type": self.content_type.as_str(),
+            "insecure_ssl": if self.insecure_ssl { "1" } else { "0" },
+        });
+        if let Some(secret) = &self.secret {
+            config["secret"] = json!(secret);
+        }
+        config
+    }
+}
+
+impl fmt::Debug for DesiredHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DesiredHook")
+            .field("scope", &self.scope)
+            .field("url", &self.url)
+            .field("events", &self.events)
+            .field("content_type", &self.content_type)
+            .field("secret", &self.secret.as_ref().map(|_| "********"))
+            .field("insecure_ssl", &self.insecure_ssl)
+            .field("active", &self.active)
+            .finish()
+    }
+}
+
+/// The file format in the [module docs](self).
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookConfig {
+    #[serde(default)]
+    pub hooks: Vec<HookSpec>,
+}
+
+/// One `[[hooks]]` table: a hook applied to every listed repository and organization.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HookSpec {
+    /// `owner/repo` names.
+    #[serde(default)]
+    pub repos: Vec<String>,
+    #[serde(default)]
+    pub orgs: Vec<String>,
+    pub url: String,
+    #[serde(default = "default_events")]
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub content_type: ContentType,
+    pub secret: Option<String>,
+    /// Environment variable holding the secret.
+    pub secret_env: Option<String>,
+    #[serde(default)]
+    pub insecure_ssl: bool,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_events() -> Vec<String> {
+    vec!["push".to_string()]
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl HookConfig {
+    pub fn from_toml(text: &str) -> io::Result<Self> {
+        toml::from_str(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        HookConfig::from_toml(&fs::read_to_string(path)?)
+    }
+
+    /// One [`DesiredHook`] per spec and scope, with `secret_env` read from the
+    /// environment.
+    pub fn desired(&self) -> io::Result<Vec<DesiredHook>> {
+        let mut desired = Vec::new();
+        for spec in &self.hooks {
+            let secret = match (&spec.secret, &spec.secret_env) {
+                (Some(_), Some(_)) => {
+                    return Err(invalid(format!(
+                        "{}: set secret or secret_env, not both",
+                        spec.url
+                    )))
+                }
+                (Some(secret), None) => Some(secret.clone()),
+                (None, Some(var)) => Some(std::env::var(var).map_err(|_| {
+                    invalid(format!(
+                        "{}: environment variable {} is not set",
+                        spec.url, var
+                    ))
+                })?),
+                (None, None) => None,
+            };
+            let scopes = spec
+                .repos
+                .iter()
+                .map(|r| HookScope::parse_repository(r))
+                .chain(spec.orgs.iter().map(|o| Ok(HookScope::organization(o))));
+            for scope in scopes {
+                let mut hook = DesiredHook::new(scope?, &spec.url)
+                    .events(&spec.events)
+                    .content_type(spec.content_type)
+                    .insecure_ssl(spec.insecure_ssl)
+                    .active(spec.active);
+                hook.secret = secret.clone();
+                desired.push(hook);
+            }
+        }
+        Ok(desired)
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// One step of a [`Plan`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Create(DesiredHook),
+    Update {
+        hook_id: u64,
+        desired: DesiredHook,
+        /// Human-readable differences, e.g. `events: [push] -> [push, release]`.
+        differences: Vec<String>,
+        /// Whether `events` or `active` changed, which are set on the hook itself.
+        hook: bool,
+        /// Whether the URL's settings changed, which are set on its config.
+        config: bool,
+    },
+    Delete {
+        scope: HookScope,
+        hook_id: u64,
+        url: String,
+    },
+}
+
+impl Change {
+    pub fn scope(&self) -> &HookScope {
+        match self {
+            Change::Create(desired) | Change::Update { desired, .. } => &desired.scope,
+            Change::Delete { scope, .. } => scope,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Create(desired) => write!(
+                f,
+                "+ {} {} [{}]",
+                desired.scope,
+                desired.url,
+                desired
+                    .events
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Change::Update {
+                hook_id,
+                desired,
+                differences,
+                ..
+            } => write!(
+                f,
+                "~ {} {} (hook {}): {}",
+                desired.scope,
+                desired.url,
+                hook_id,
+                differences.join("; ")
+            ),
+            Change::Delete {
+                scope,
+                hook_id,
+                url,
+            } => write!(f, "- {} {} (hook {})", scope, url, hook_id),
+        }
+    }
+}
+
+/// What [`HookSync::plan`] found. Its `Display` is the dry-run output.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    pub changes: Vec<Change>,
+    /// Desired hooks that already match.
+    pub unchanged: usize,
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        let count = |wanted: fn(&Change) -> bool| self.changes.iter().filter(|c| wanted(c)).count();
+        writeln!(
+            f,
+            "{} to create, {} to update, {} to delete, {} unchanged",
+            count(|c| matches!(c, Change::Create(_))),
+            count(|c| matches!(c, Change::Update { .. })),
+            count(|c| matches!(c, Change::Delete { .. })),
+            self.unchanged
+        )
+    }
+}
+
+/// A change [`HookSync::apply`] could not make.
+#[derive(Debug)]
+pub struct ApplyError {
+    pub change: Change,
+    pub error: Error,
+}
+
+/// What [`HookSync::apply`] did.
+#[derive(Debug, Default)]
+pub struct ApplyReport {
+    pub applied: usize,
+    pub errors: Vec<ApplyError>,
+}
+
+impl fmt::Display for ApplyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} changes applied, {} failed",
+            self.applied,
+            self.errors.len()
+        )?;
+        for e in &self.errors {
+            writeln!(f, "  {}: {}", e.change, e.error)?;
+        }
+        Ok(())
+    }
+}
+
+/// See the [module docs](self).
+#[derive(Debug)]
+pub struct HookSync<T> {
+    transport: T,
+    desired: Vec<DesiredHook>,
+    prune: bool,
+    update_secrets: bool,
+}
+
+impl<T: Transport> HookSync<T> {
+    pub fn new(transport: T, desired: Vec<DesiredHook>) -> Self {
+        HookSync {
+            transport,
+            desired,
+            prune: false,
+            update_secrets: false,
+        }
+    }
+
+    /// Also deletes hooks in the managed scopes that the config does not list,
+    /// and duplicates of ones it does. Off by default, since other
+    /// integrations add hooks of their own.
+    pub fn prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+
+    /// Always sends configured secrets, since the current ones cannot be read back.
+    pub fn update_secrets(mut self, update_secrets: bool) -> Self {
+        self.update_secrets = update_secrets;
+        self
+    }
+
+    /// Lists the current hooks and diffs them against the desired ones. Makes
+    /// no changes.
+    pub fn plan(&self) -> Result<Plan, Error> {
+        let scopes: BTreeSet<&HookScope> = self.desired.iter().map(|d| &d.scope).collect();
+        let mut plan = Plan::default();
+        for scope in scopes {
+            let mut existing = self.list(scope)?;
+            for desired in self.desired.iter().filter(|d| &d.scope == scope) {
+                let position = existing
+                    .iter()
+                    .position(|hook| hook.config.url.as_deref() == Some(desired.url.as_str()));
+                let Some(position) = position else {
+                    plan.changes.push(Change::Create(desired.clone()));
+                    continue;
+                };
+                match self.diff(existing.remove(position), desired) {
+                    Some(change) => plan.changes.push(change),
+                    None => plan.unchanged += 1,
+                }
+            }
+            if self.prune {
+                plan.changes
+                    .extend(existing.into_iter().map(|hook| Change::Delete {
+                        scope: scope.clone(),
+                        hook_id: hook.id,
+                        url: hook.config.url.unwrap_or_default(),
+                    }));
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Makes the changes of `plan` in order. A failed change is recorded and
+    /// the rest still go ahead; planning and applying again retries it.
+    pub fn apply(&self, plan: &Plan) -> ApplyReport {
+        let mut report = ApplyReport::default();
+        for change in &plan.changes {
+            match self.apply_change(change) {
+                Ok(()) => report.applied += 1,
+                Err(error) => report.errors.push(ApplyError {
+                    change: change.clone(),
+                    error,
+                }),
+            }
+        }
+        report
+    }
+
+    fn apply_change(&self, change: &Change) -> Result<(), Error> {
+        match change {
+            Change::Create(desired) => {
+                let body = json!({
+                    "name": "web",
+                    "active": desired.active,
+                    "events": desired.events,
+                    "config": desired.config(),
+                });
+                self.transport
+                    .execute(&Request::new(desired.scope.create()).json(&body)?)?;
+            }
+            Change::Update {
+                hook_id,
+                desired,
+                hook,
+                config,
+                ..
+            } => {
+                if *hook {
+                    let body = json!({ "active": desired.active, "events": desired.events });
+                    self.transport
+                        .execute(&Request::new(desired.scope.update(*hook_id)).json(&body)?)?;
+                }
+                if *config {
+                    let request = Request::new(desired.scope.update_config(*hook_id))
+                        .json(&desired.config())?;
+                    self.transport.execute(&request)?;
+                }
+            }
+            Change::Delete { scope, hook_id, .. } => {
+                self.transport
+                    .execute(&Request::new(scope.delete(*hook_id)))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn list(&self, scope: &HookScope) -> Result<Vec<Webhook>, Error> {
+        let mut hooks = Vec::new();
+        let mut page = Some("1".to_string());
+        while let Some(current) = page {
+            let request = Request::new(scope.list())
+                .query("per_page", 100)
+                .query("page", current);
+            let response = self.transport.execute(&request)?;
+            let listed: Vec<Webhook> = response.json()?;
+            // Only webhooks have a URL; GitHub Services leftovers are ignored.
+            hooks.extend(listed.into_iter().filter(|h| h.config.url.is_some()));
+            page = response
+                .headers
+                .get("link")
+                .and_then(|link| next_link_param(link, "page"));
+        }
+        Ok(hooks)
+    }
+
+    fn diff(&self, existing: Webhook, desired: &DesiredHook) -> Option<Change> {
+        let mut differences = Vec::new();
+        let events: BTreeSet<String> = existing.events.iter().cloned().collect();
+        if events != desired.events {
+            differences.push(format!(
+                "events: [{}] -> [{}]",
+                existing.events.join(", "),
+                desired
+                    .events
+                    .iter()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+        if existing.active != desired.active {
+            differences.push(format!("active: {} -> {}", existing.active, desired.active));
+        }
+        let hook_differences = differences.len();
+        let content_type = existing.config.content_type.as_deref().unwrap_or("form");
+        if content_type != desired.content_type.as_str() {
+            differences.push(format!(
+                "content_type: {} -> {}",
+                content_type,
+                desired.content_type.as_str()
+            ));
+        }
+        let insecure_ssl = match &existing.config.insecure_ssl {
+            Some(Value::String(s)) => s == "1",
+            Some(Value::Number(n)) => n.as_u64() == Some(1),
+            _ => false,
+        };
+        if insecure_ssl != desired.insecure_ssl {
+            differences.push(format!(
+                "insecure_ssl: {} -> {}",
+                insecure_ssl, desired.insecure_ssl
+            ));
+        }
+        match (&existing.config.secret, &desired.secret) {
+            (None, Some(_)) => differences.push("secret: added".to_string()),
+            (Some(_), Some(_)) if self.update_secrets => {
+                differences.push("secret: rewritten".to_string())
+            }
+            _ => {}
+        }
+        if differences.is_empty() {
+            return None;
+        }
+        Some(Change::Update {
+            hook_id: existing.id,
+            desired: desired.clone(),
+            hook: hook_differences > 0,
+            config: differences.len() > hook_differences,
+            differences,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fake::{json, FakeGitHub};
+
+    const API: &str = "https://hooks.acme.dev/api";
+    const AUDIT: &str = "https://hooks.acme.dev/audit";
+    const DEPLOY: &str = "https://hooks.acme.dev/deploy";
+
+    fn hook(id: u64, url: &str, events: &[&str]) -> Value {
+        json!({
+            "id": id,
+            "name": "web",
+            "active": true,
+            "events": events,
+            "config": { "url": url, "content_type": "json", "insecure_ssl": "0" },
+        })
+    }
+
+    fn api() -> HookScope {
+        HookScope::repository("acme", "api")
+    }
+
+    fn serve(github: &FakeGitHub, hooks: Vec<Value>) {
+        github.on(EndPoints::GetReposownerrepoHooks, move |_| {
+            json(200, &hooks)
+        });
+    }
+
+    #[test]
+    fn plan_sorts_hooks_into_create_update_and_unchanged() {
+        let github = FakeGitHub::start().unwrap();
+        serve(
+            &github,
+            vec![
+                hook(1, API, &["push"]),
+                hook(2, AUDIT, &["push"]),
+                hook(3, "https://ci.example.com/hook", &["push"]),
+            ],
+        );
+        let sync = HookSync::new(
+            &github,
+            vec![
+                DesiredHook::new(api(), API),
+                DesiredHook::new(api(), AUDIT).events(["push", "release"]),
+                DesiredHook::new(api(), DEPLOY),
+            ],
+        );
+
+        let plan = sync.plan().unwrap();
+        assert_eq!(plan.unchanged, 1);
+        assert_eq!(plan.changes.len(), 2);
+        match &plan.changes[0] {
+            Change::Update {
+                hook_id,
+                differences,
+                hook,
+                config,
+                ..
+            } => {
+                assert_eq!(*hook_id, 2);
+                assert_eq!(differences, &["events: [push] -> [push, release]"]);
+                assert!(*hook);
+                assert!(!*config);
+            }
+            other => panic!("expected an update, got {:?}", other),
+        }
+        assert!(matches!(&plan.changes[1], Change::Create(d) if d.url == DEPLOY));
+        // Without prune, the hook the config does not list stays.
+        assert!(plan
+            .to_string()
+            .ends_with("1 to create, 1 to update, 0 to delete, 1 unchanged\n"));
+        assert_eq!(github.calls(EndPoints::GetReposownerrepoHooks).len(), 1);
+    }
+
+    #[test]
+    fn diff_reports_config_settings_on_the_config() {
+        let github = FakeGitHub::start().unwrap();
+        let mut form = hook(1, API, &["push"]);
+        form["config"]["content_type"] = json!("form");
+        form["config"]["insecure_ssl"] = json!(1);
+        form["active"] = json!(false);
+        serve(&github, vec![form]);
+        let sync = HookSync::new(&github, vec![DesiredHook::new(api(), API)]);
+
+        let plan = sync.plan().unwrap();
+        let Change::Update {
+            differences,
+            hook,
+            config,
+            ..
+        } = &plan.changes[0]
+        else {
+            panic!("expected an update, got {:?}", plan.changes);
+        };
+        assert_eq!(
+            differences,
+            &[
+                "active: false -> true",
+                "content_type: form -> json",
+                "insecure_ssl: true -> false",
+            ]
+        );
+        assert!(*hook && *config);
+    }
+
+    #[test]
+    fn plan_follows_link_pagination() {
+        let github = FakeGitHub::start().unwrap();
+        github.on(EndPoints::GetReposownerrepoHooks, |request| {
+            let page = request
+                .query
+                .iter()
+                .find(|(key, _)| key == "page")
+                .map(|(_, value)| value.as_str());
+            match page {
+                Some("1") => {
+                    let mut response = json(200, &[hook(1, AUDIT, &["push"])]);
+                    response.headers.insert(
+                        "Link",
+                        "<https://api.github.com/repositories/1/hooks?per_page=100&page=2>; \
+                         rel=\"next\", \
+                         <https://api.github.com/repositories/1/hooks?per_page=100&page=2>; \
+                         rel=\"last\"",
+                    );
+                    response
+                }
+                _ => json(200, &[hook(2, API, &["push"])]),
+            }
+        });
+        let sync = HookSync::new(&github, vec![DesiredHook::new(api(), API)]).prune(true);
+
+        let plan = sync.plan().unwrap();
+        assert_eq!(plan.unchanged, 1);
+        assert_eq!(
+            plan.changes,
+            [Change::Delete {
+                scope: api(),
+                hook_id: 1,
+                url: AUDIT.to_string(),
+            }]
+        );
+        let calls = github.calls(EndPoints::GetReposownerrepoHooks);
+        assert_eq!(calls.len(), 2);
+        assert!(calls[1]
+            .query
+            .contains(&("per_page".to_string(), "100".to_string())));
+        assert!(calls[1]
+            .query
+            .contains(&("page".to_string(), "2".to_string())));
+    }
+
+    #[test]
+    fn secrets_are_only_rewritten_when_asked() {
+        let github = FakeGitHub::start().unwrap();
+        let mut masked = hook(1, API, &["push"]);
+        masked["config"]["secret"] = json!("********");
+        serve(&github, vec![masked, hook(2, AUDIT, &["push"])]);
+        let desired = vec![
+            DesiredHook::new(api(), API).secret("s3cret"),
+            DesiredHook::new(api(), AUDIT).secret("s3cret"),
+        ];
+
+        // A hook without a secret gets one either way.
+        let plan = HookSync::new(&github, desired.clone()).plan().unwrap();
+        assert_eq!(plan.unchanged, 1);
+        assert!(matches!(
+            &plan.changes[..],
+            [Change::Update { hook_id: 2, differences, hook: false, config: true, .. }]
+                if differences == &["secret: added"]
+        ));
+
+        let sync = HookSync::new(&github, desired).update_secrets(true);
+        let plan = sync.plan().unwrap();
+        assert_eq!(plan.unchanged, 0);
+        assert!(matches!(
+            &plan.changes[0],
+            Change::Update { hook_id: 1, differences, hook: false, config: true, .. }
+                if differences == &["secret: rewritten"]
+        ));
+
+        github.on(EndPoints::PatchReposownerrepoHookshookIdConfig, |_| {
+            json(200, &json!({}))
+        });
+        github
+            .expect(EndPoints::PatchReposownerrepoHookshookIdConfig)
+            .times(2)
+            .with_json(json!({ "secret": "s3cret", "content_type": "json" }));
+        let report = sync.apply(&plan);
+        assert_eq!(report.applied, 2);
+        assert!(report.errors.is_empty());
+        // Only the config changed, so the hook itself is not patched.
+        assert!(github
+            .calls(EndPoints::PatchReposownerrepoHookshookId)
+            .is_empty());
+    }
+
+    #[test]
+    fn prune_deletes_extras_and_duplicates_in_managed_scopes_only() {
+        let github = FakeGitHub::start().unwrap();
+        serve(
+            &github,
+            vec![
+                hook(1, API, &["push"]),
+                hook(2, API, &["push"]),
+                hook(3, "https://ci.example.com/hook", &["push"]),
+            ],
+        );
+        github.on(EndPoints::GetOrgsorgHooks, |_| {
+            json(200, &[hook(9, "https://other.example.com", &["*"])])
+        });
+        let sync = HookSync::new(&github, vec![DesiredHook::new(api(), API)]).prune(true);
+
+        let plan = sync.plan().unwrap();
+        assert_eq!(plan.unchanged, 1);
+        let deleted: Vec<(&HookScope, u64)> = plan
+            .changes
+            .iter()
+            .map(|change| match change {
+                Change::Delete { scope, hook_id, .. } => (scope, *hook_id),
+                other => panic!("expected a delete, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(deleted, [(&api(), 2), (&api(), 3)]);
+        // The organization is not in the config, so it is never even listed.
+        assert!(github.calls(EndPoints::GetOrgsorgHooks).is_empty());
+
+        github.on(EndPoints::DeleteReposownerrepoHookshookId, |_| {
+            json(204, &json!(null))
+        });
+        let report = sync.apply(&plan);
+        assert_eq!(report.applied, 2);
+        let deletes = github.calls(EndPoints::DeleteReposownerrepoHookshookId);
+        assert_eq!(deletes[0].endpoint.path(), "/repos/acme/api/hooks/2");
+        assert_eq!(deletes[1].endpoint.path(), "/repos/acme/api/hooks/3");
+    }
+}